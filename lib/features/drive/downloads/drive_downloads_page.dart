@@ -241,6 +241,8 @@ class _DownloadTile extends StatelessWidget {
   Widget build(BuildContext context) {
     final statusLabel = () {
       switch (task.status) {
+        case DownloadStatus.queued:
+          return '排队中';
         case DownloadStatus.inProgress:
           return '下载中';
         case DownloadStatus.completed:
@@ -258,7 +260,7 @@ class _DownloadTile extends StatelessWidget {
     final totalLabel = task.sizeLabel != null
         ? formatFileSize(_bigIntToSafeInt(task.sizeLabel))
         : '未知';
-    final speed = manager.speedFor(task.item.id);
+    final speed = manager.speedFor(task.taskId);
     final speedLabel = _formatSpeed(speed);
 
     Widget buildSubtitle() {
@@ -436,7 +438,7 @@ class _DownloadAction extends StatelessWidget {
     if (isInProgress) {
       final cancelling =
           ref.watch(driveDownloadManagerProvider.notifier).isCancelling(
-                task.item.id,
+                task.taskId,
               );
       return Row(
         mainAxisSize: MainAxisSize.min,
@@ -460,7 +462,7 @@ class _DownloadAction extends StatelessWidget {
                 : () => unawaited(
                       ref
                           .read(driveDownloadManagerProvider.notifier)
-                          .cancelTask(task.item.id),
+                          .cancelTask(task.taskId),
                     ),
             style: FButtonStyle.outline(),
             child: Icon(
@@ -473,7 +475,7 @@ class _DownloadAction extends StatelessWidget {
     }
     return FButton.icon(
       onPress: () => unawaited(
-        ref.read(driveDownloadManagerProvider.notifier).removeTask(task.item.id),
+        ref.read(driveDownloadManagerProvider.notifier).removeTask(task.taskId),
       ),
       style: FButtonStyle.outline(),
       child: const Icon(FIcons.trash2, size: 16),
@@ -186,8 +186,8 @@ class _DriveWorkspacePageState extends ConsumerState<DriveWorkspacePage> {
       );
       final file = await openFile(acceptedTypeGroups: [typeGroup]);
       if (file == null) return;
-      final bytes = await file.readAsBytes();
-      if (bytes.length > _simpleUploadMaxBytes) {
+      final fileSize = await file.length();
+      if (fileSize > _simpleUploadMaxBytes) {
         _showPlaceholder('文件超过 250MB，请使用分片上传');
         return;
       }
@@ -199,7 +199,6 @@ class _DriveWorkspacePageState extends ConsumerState<DriveWorkspacePage> {
         parentId: parentId,
         fileName: file.name,
         localPath: file.path,
-        content: bytes,
         overwrite: false,
       );
       _showPlaceholder('已加入上传队列：${file.name}');
@@ -48,16 +48,19 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
   }
 
   /// 入队新的下载任务，并立即刷新状态以驱动 UI。
+  /// `conflictPolicy` 为空时使用设置中的默认冲突处理方式。
   Future<void> enqueue(
     drive_api.DriveItemSummary item, {
     required String targetDirectory,
-    bool overwrite = false,
+    drive_api.DownloadConflictPolicy? conflictPolicy,
+    drive_api.TransferPriority? priority,
   }) async {
     try {
       final updated = await _service.enqueue(
         item: item,
         targetDir: targetDirectory,
-        overwrite: overwrite,
+        conflictPolicy: conflictPolicy,
+        priority: priority,
       );
       _pruneSpeeds(updated.active);
       state = updated;
@@ -75,29 +78,29 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
   }
 
   /// 从队列中移除任意状态的任务，通常用于手动清除条目。
-  Future<void> removeTask(String itemId) async {
-    final updated = await _service.removeTask(itemId);
-    _speedMeters.remove(itemId);
+  Future<void> removeTask(String taskId) async {
+    final updated = await _service.removeTask(taskId);
+    _speedMeters.remove(taskId);
     _pruneSpeeds(updated.active);
     state = updated;
   }
 
   /// 发送取消指令给 Rust，同时做乐观更新，避免按钮出现延迟。
-  Future<void> cancelTask(String itemId) async {
-    _pendingCancel.add(itemId);
+  Future<void> cancelTask(String taskId) async {
+    _pendingCancel.add(taskId);
     state = drive_api.DownloadQueueState(
       active: state.active,
       completed: state.completed,
       failed: state.failed,
     );
-    final updated = await _service.cancelTask(itemId);
+    final updated = await _service.cancelTask(taskId);
     _pruneSpeeds(updated.active);
     state = updated;
-    _pendingCancel.remove(itemId);
+    _pendingCancel.remove(taskId);
   }
 
   /// 查询指定任务是否处于“等待取消确认”状态。
-  bool isCancelling(String itemId) => _pendingCancel.contains(itemId);
+  bool isCancelling(String taskId) => _pendingCancel.contains(taskId);
 
   /// 一键清理所有失败记录，由 Rust 批量执行删除操作。
   Future<void> clearFailedTasks() async {
@@ -116,7 +119,7 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
   }
 
   /// 返回缓存的实时速度，若任务未记录速度则返回 null。
-  double? speedFor(String itemId) => _speedMeters[itemId];
+  double? speedFor(String taskId) => _speedMeters[taskId];
 
   /// 强制刷新 Rust 队列快照，兜底进度流异常或应用刚启动时的状态。
   Future<void> _refreshQueue({bool force = false}) async {
@@ -154,7 +157,7 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
     final updatedActive = <drive_api.DownloadTask>[];
     var touched = false;
     for (final task in state.active) {
-      if (task.item.id == update.itemId) {
+      if (task.taskId == update.taskId) {
         touched = true;
         updatedActive.add(_mergeTaskWithProgress(task, update));
       } else {
@@ -164,7 +167,7 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
     if (!touched) return;
 
     if (update.speedBps != null) {
-      _speedMeters[update.itemId] = update.speedBps!.toDouble();
+      _speedMeters[update.taskId] = update.speedBps!.toDouble();
     }
     _pruneSpeeds(updatedActive);
     state = drive_api.DownloadQueueState(
@@ -180,6 +183,7 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
     drive_api.DownloadProgressUpdate update,
   ) {
     return drive_api.DownloadTask(
+      taskId: task.taskId,
       item: task.item,
       status: task.status,
       startedAt: task.startedAt,
@@ -188,12 +192,19 @@ class DriveDownloadManager extends Notifier<drive_api.DownloadQueueState> {
       sizeLabel: update.expectedSize ?? task.sizeLabel,
       bytesDownloaded: update.bytesDownloaded,
       errorMessage: task.errorMessage,
+      targetDir: task.targetDir,
+      conflictPolicy: task.conflictPolicy,
+      conflictOutcome: task.conflictOutcome,
+      retryCount: task.retryCount,
+      priority: task.priority,
+      failureReason: task.failureReason,
+      hookOutcomes: task.hookOutcomes,
     );
   }
 
   /// 清理已完成/删除任务对应的速度缓存，防止内存泄漏。
   void _pruneSpeeds(Iterable<drive_api.DownloadTask> activeTasks) {
-    final activeIds = activeTasks.map((e) => e.item.id).toSet();
+    final activeIds = activeTasks.map((e) => e.taskId).toSet();
     _speedMeters.removeWhere((key, _) => !activeIds.contains(key));
   }
 }
@@ -46,14 +46,14 @@ class DriveUploadManager extends Notifier<drive_api.UploadQueueState> {
       active: <drive_api.UploadTask>[],
       completed: <drive_api.UploadTask>[],
       failed: <drive_api.UploadTask>[],
+      groups: <drive_api.UploadGroup>[],
     );
   }
 
-  /// 入队新上传任务。
+  /// 入队新上传任务，Rust 端直接从 localPath 流式读取文件内容。
   Future<void> enqueue({
     required String fileName,
     required String localPath,
-    required Uint8List content,
     String? parentId,
     bool overwrite = false,
   }) async {
@@ -63,7 +63,6 @@ class DriveUploadManager extends Notifier<drive_api.UploadQueueState> {
         parentId: parentId,
         fileName: fileName,
         localPath: localPath,
-        content: content,
         overwrite: overwrite,
       );
       _pruneSpeeds(updated.active);
@@ -104,6 +103,7 @@ class DriveUploadManager extends Notifier<drive_api.UploadQueueState> {
       active: state.active,
       completed: state.completed,
       failed: state.failed,
+      groups: state.groups,
     );
     final updated = await upload_manager_api.cancelUploadTask(taskId: taskId);
     _pruneSpeeds(updated.active);
@@ -191,6 +191,7 @@ class DriveUploadManager extends Notifier<drive_api.UploadQueueState> {
       active: updatedActive,
       completed: state.completed,
       failed: state.failed,
+      groups: state.groups,
     );
   }
 
@@ -213,6 +214,17 @@ class DriveUploadManager extends Notifier<drive_api.UploadQueueState> {
       bytesUploaded: update.bytesUploaded,
       errorMessage: task.errorMessage,
       sessionUrl: task.sessionUrl,
+      sessionExpiresAt: task.sessionExpiresAt,
+      retryCount: task.retryCount,
+      priority: task.priority,
+      failureReason: task.failureReason,
+      groupId: task.groupId,
+      conflictBehavior: task.conflictBehavior,
+      remoteName: task.remoteName,
+      skippedIdentical: task.skippedIdentical,
+      sourceModifiedAt: task.sourceModifiedAt,
+      sourceFingerprint: task.sourceFingerprint,
+      sourceUrl: task.sourceUrl,
     );
   }
 
@@ -9,12 +9,14 @@ class DriveDownloadService {
   Future<drive_api.DownloadQueueState> enqueue({
     required drive_api.DriveItemSummary item,
     required String targetDir,
-    bool overwrite = false,
+    drive_api.DownloadConflictPolicy? conflictPolicy,
+    drive_api.TransferPriority? priority,
   }) {
     return drive_manager_api.enqueueDownloadTask(
       item: item,
       targetDir: targetDir,
-      overwrite: overwrite,
+      conflictPolicy: conflictPolicy,
+      priority: priority,
     );
   }
 
@@ -30,12 +32,12 @@ class DriveDownloadService {
     return drive_manager_api.clearFailedDownloadTasks();
   }
 
-  Future<drive_api.DownloadQueueState> removeTask(String itemId) {
-    return drive_manager_api.removeDownloadTask(itemId: itemId);
+  Future<drive_api.DownloadQueueState> removeTask(String taskId) {
+    return drive_manager_api.removeDownloadTask(taskId: taskId);
   }
 
-  Future<drive_api.DownloadQueueState> cancelTask(String itemId) {
-    return drive_manager_api.cancelDownloadTask(itemId: itemId);
+  Future<drive_api.DownloadQueueState> cancelTask(String taskId) {
+    return drive_manager_api.cancelDownloadTask(taskId: taskId);
   }
 
   Stream<drive_api.DownloadProgressUpdate> progressStream() {
@@ -242,6 +242,8 @@ class _UploadTile extends StatelessWidget {
 
     final statusLabel = () {
       switch (task.status) {
+        case UploadStatus.queued:
+          return '排队中';
         case UploadStatus.inProgress:
           return '上传中';
         case UploadStatus.completed:
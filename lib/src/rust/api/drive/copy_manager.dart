@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 复制文件/文件夹：可复制到其他 Drive，或在复制时重命名。复制由服务端异步执行，
+/// 返回的任务通过 `copy_job_stream` 推送进度与结果，完成后 `new_item_id` 为新项目的 id。
+/// `target_parent_id` 为空时复制到目标 Drive 的根目录。
+Future<CopyJob> copyDriveItem({
+  required String itemId,
+  String? targetDriveId,
+  String? targetParentId,
+  String? newName,
+}) => RustLib.instance.api.crateApiDriveCopyManagerCopyDriveItem(
+  itemId: itemId,
+  targetDriveId: targetDriveId,
+  targetParentId: targetParentId,
+  newName: newName,
+);
+
+/// 全部复制任务（含重启前未结束、仍在跟踪的任务）。
+Future<List<CopyJob>> copyJobs() =>
+    RustLib.instance.api.crateApiDriveCopyManagerCopyJobs();
+
+/// 清除已完成或失败的复制任务记录。
+Future<List<CopyJob>> clearFinishedCopyJobs() =>
+    RustLib.instance.api.crateApiDriveCopyManagerClearFinishedCopyJobs();
+
+/// 推送复制任务的最新状态：发起、进度百分比变化与结束时各推送一次。
+Stream<CopyJob> copyJobStream() =>
+    RustLib.instance.api.crateApiDriveCopyManagerCopyJobStream();
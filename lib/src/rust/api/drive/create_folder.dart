@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `conflict_behavior_param`, `create_folder`, `ensure_child_folder`, `find_existing_folder`, `into_summary`, `split_folder_path`, `validate_folder_name`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FolderFacetDto`, `FolderItemDto`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+
+/// 在父目录（为空时为根目录）下新建文件夹，按 `conflict_behavior` 处理同名项。
+Future<DriveItemSummary> createDriveFolder({
+  String? parentId,
+  required String name,
+  required FolderConflictBehavior conflictBehavior,
+}) => RustLib.instance.api.crateApiDriveCreateFolderCreateDriveFolder(
+  parentId: parentId,
+  name: name,
+  conflictBehavior: conflictBehavior,
+);
+
+/// 类似 `mkdir -p`：确保父目录（为空时为根目录）下的相对路径（如 `Projects/2026/Q4`）逐级存在，
+/// 已存在的文件夹直接复用，返回最末一级文件夹。路径中任一级是文件时返回错误。
+Future<DriveItemSummary> ensureDriveFolderPath({
+  String? parentId,
+  required String path,
+}) => RustLib.instance.api.crateApiDriveCreateFolderEnsureDriveFolderPath(
+  parentId: parentId,
+  path: path,
+);
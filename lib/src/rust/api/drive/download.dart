@@ -7,9 +7,9 @@ import '../../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `download_drive_item_internal`, `download_drive_item_with_progress`, `fetch_download_metadata`, `prepare_destination`, `sanitize_file_name`, `stream_download`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DriveFileFacet`, `DriveItemDownloadDto`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `commit_partial`, `download_drive_item_internal`, `download_drive_item_with_progress`, `endpoint_status_error`, `fetch_download_metadata`, `is_remote_newer`, `next_available_path`, `parse_graph_timestamp`, `partial_path`, `partial_state_path`, `prepare_destination`, `sanitize_file_name`, `save_partial_state`, `stream_download`, `stream_download_once`, `take_partial_state`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DriveFileFacet`, `DriveItemDownloadDto`, `StreamError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`
 
 /// 下载指定 drive item（仅文件），保存到 target_dir。
 /// - 优先使用 Graph 返回的 downloadUrl（免鉴权）。
@@ -10,31 +10,90 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 Future<DownloadQueueState> downloadQueueState() =>
     RustLib.instance.api.crateApiDriveDownloadManagerDownloadQueueState();
 
+/// 新任务先以 Queued 状态排队，`priority` 为空时按普通优先级处理。
 Future<DownloadQueueState> enqueueDownloadTask({
   required DriveItemSummary item,
   required String targetDir,
-  required bool overwrite,
+  DownloadConflictPolicy? conflictPolicy,
+  TransferPriority? priority,
 }) => RustLib.instance.api.crateApiDriveDownloadManagerEnqueueDownloadTask(
   item: item,
   targetDir: targetDir,
-  overwrite: overwrite,
+  conflictPolicy: conflictPolicy,
+  priority: priority,
 );
 
-Future<DownloadQueueState> removeDownloadTask({required String itemId}) =>
+/// 为等待用户决定的冲突任务指定处理方式，任务会以该策略重新开始下载。
+Future<DownloadQueueState> resolveDownloadConflict({
+  required String taskId,
+  required DownloadConflictPolicy policy,
+}) => RustLib.instance.api.crateApiDriveDownloadManagerResolveDownloadConflict(
+  taskId: taskId,
+  policy: policy,
+);
+
+Future<DownloadQueueState> removeDownloadTask({required String taskId}) =>
     RustLib.instance.api.crateApiDriveDownloadManagerRemoveDownloadTask(
-      itemId: itemId,
+      taskId: taskId,
     );
 
-Future<DownloadQueueState> cancelDownloadTask({required String itemId}) =>
+/// 使用系统默认程序打开已完成任务的文件。
+Future<void> openDownloadedFile({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveDownloadManagerOpenDownloadedFile(taskId: taskId);
+
+/// 在系统文件管理器中显示并选中已完成任务的文件。
+Future<void> revealDownloadedFile({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveDownloadManagerRevealDownloadedFile(taskId: taskId);
+
+Future<DownloadQueueState> cancelDownloadTask({required String taskId}) =>
     RustLib.instance.api.crateApiDriveDownloadManagerCancelDownloadTask(
-      itemId: itemId,
+      taskId: taskId,
     );
 
 Future<DownloadQueueState> clearFailedDownloadTasks() =>
     RustLib.instance.api.crateApiDriveDownloadManagerClearFailedDownloadTasks();
 
+/// 将所有失败任务重新入队，等待冲突决定的任务需通过 `resolve_download_conflict` 处理。
+Future<DownloadQueueState> retryFailedDownloadTasks() =>
+    RustLib.instance.api.crateApiDriveDownloadManagerRetryFailedDownloadTasks();
+
+/// 将等待中的下载任务前移一位。
+Future<DownloadQueueState> moveDownloadTaskUp({required String taskId}) =>
+    RustLib.instance.api.crateApiDriveDownloadManagerMoveDownloadTaskUp(
+      taskId: taskId,
+    );
+
+/// 将等待中的下载任务后移一位。
+Future<DownloadQueueState> moveDownloadTaskDown({required String taskId}) =>
+    RustLib.instance.api.crateApiDriveDownloadManagerMoveDownloadTaskDown(
+      taskId: taskId,
+    );
+
+/// 将等待中的下载任务移到队首，下一个空闲名额即开始下载。
+Future<DownloadQueueState> moveDownloadTaskToTop({required String taskId}) =>
+    RustLib.instance.api.crateApiDriveDownloadManagerMoveDownloadTaskToTop(
+      taskId: taskId,
+    );
+
+/// 修改未结束下载任务的优先级，等待中的任务会按新优先级重新排队。
+Future<DownloadQueueState> setDownloadTaskPriority({
+  required String taskId,
+  required TransferPriority priority,
+}) => RustLib.instance.api.crateApiDriveDownloadManagerSetDownloadTaskPriority(
+  taskId: taskId,
+  priority: priority,
+);
+
 Future<DownloadQueueState> clearDownloadHistory() =>
     RustLib.instance.api.crateApiDriveDownloadManagerClearDownloadHistory();
 
 Stream<DownloadProgressUpdate> downloadProgressStream() =>
     RustLib.instance.api.crateApiDriveDownloadManagerDownloadProgressStream();
+
+/// 推送下载任务的生命周期事件，UI 无需轮询队列快照即可感知状态变化。
+Stream<DownloadTaskEvent> downloadTaskEventStream() =>
+    RustLib.instance.api.crateApiDriveDownloadManagerDownloadTaskEventStream();
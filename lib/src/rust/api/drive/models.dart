@@ -6,10 +6,188 @@
 import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+/// 一天中的限速时段，以分钟计（0..1440）；结束早于开始时表示跨越午夜，
+/// 开始与结束相同时表示全天。
+class BandwidthSchedule {
+  final int startMinute;
+  final int endMinute;
+  final BigInt globalKibPerSec;
+  final BigInt downloadKibPerSec;
+  final BigInt uploadKibPerSec;
+
+  const BandwidthSchedule({
+    required this.startMinute,
+    required this.endMinute,
+    required this.globalKibPerSec,
+    required this.downloadKibPerSec,
+    required this.uploadKibPerSec,
+  });
+
+  @override
+  int get hashCode =>
+      startMinute.hashCode ^
+      endMinute.hashCode ^
+      globalKibPerSec.hashCode ^
+      downloadKibPerSec.hashCode ^
+      uploadKibPerSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BandwidthSchedule &&
+          runtimeType == other.runtimeType &&
+          startMinute == other.startMinute &&
+          endMinute == other.endMinute &&
+          globalKibPerSec == other.globalKibPerSec &&
+          downloadKibPerSec == other.downloadKibPerSec &&
+          uploadKibPerSec == other.uploadKibPerSec;
+}
+
+/// 带宽限速设置，单位 KiB/s，0 表示不限速。
+/// 总限速与上/下行限速同时生效，取更严格者。
+class BandwidthSettings {
+  final BigInt globalKibPerSec;
+  final BigInt downloadKibPerSec;
+  final BigInt uploadKibPerSec;
+  /// 按时段覆盖上述限速，命中第一条即生效。
+  final List<BandwidthSchedule> schedules;
+
+  const BandwidthSettings({
+    required this.globalKibPerSec,
+    required this.downloadKibPerSec,
+    required this.uploadKibPerSec,
+    required this.schedules,
+  });
+
+  static Future<BandwidthSettings> default_() =>
+      RustLib.instance.api.crateApiDriveModelsBandwidthSettingsDefault();
+
+  @override
+  int get hashCode =>
+      globalKibPerSec.hashCode ^
+      downloadKibPerSec.hashCode ^
+      uploadKibPerSec.hashCode ^
+      schedules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BandwidthSettings &&
+          runtimeType == other.runtimeType &&
+          globalKibPerSec == other.globalKibPerSec &&
+          downloadKibPerSec == other.downloadKibPerSec &&
+          uploadKibPerSec == other.uploadKibPerSec &&
+          schedules == other.schedules;
+}
+
+/// 一次复制操作，重启后继续跟踪未结束的任务。
+class CopyJob {
+  final String jobId;
+  final String sourceItemId;
+  /// 复制后的名称：指定了新名称时为新名称，否则为源项目名称。
+  final String itemName;
+  /// 目标 Drive；为空表示当前账户的 Drive。
+  final String? targetDriveId;
+  final String? targetParentId;
+  final String? newName;
+  final String monitorUrl;
+  final CopyJobStatus status;
+  /// 0-100；服务端未报告时为空。
+  final double? percentageComplete;
+  /// 复制完成后新项目的 id。
+  final String? newItemId;
+  final String? errorMessage;
+  final PlatformInt64 startedAt;
+  final PlatformInt64? completedAt;
+
+  const CopyJob({
+    required this.jobId,
+    required this.sourceItemId,
+    required this.itemName,
+    this.targetDriveId,
+    this.targetParentId,
+    this.newName,
+    required this.monitorUrl,
+    required this.status,
+    this.percentageComplete,
+    this.newItemId,
+    this.errorMessage,
+    required this.startedAt,
+    this.completedAt,
+  });
+
+  @override
+  int get hashCode =>
+      jobId.hashCode ^
+      sourceItemId.hashCode ^
+      itemName.hashCode ^
+      targetDriveId.hashCode ^
+      targetParentId.hashCode ^
+      newName.hashCode ^
+      monitorUrl.hashCode ^
+      status.hashCode ^
+      percentageComplete.hashCode ^
+      newItemId.hashCode ^
+      errorMessage.hashCode ^
+      startedAt.hashCode ^
+      completedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CopyJob &&
+          runtimeType == other.runtimeType &&
+          jobId == other.jobId &&
+          sourceItemId == other.sourceItemId &&
+          itemName == other.itemName &&
+          targetDriveId == other.targetDriveId &&
+          targetParentId == other.targetParentId &&
+          newName == other.newName &&
+          monitorUrl == other.monitorUrl &&
+          status == other.status &&
+          percentageComplete == other.percentageComplete &&
+          newItemId == other.newItemId &&
+          errorMessage == other.errorMessage &&
+          startedAt == other.startedAt &&
+          completedAt == other.completedAt;
+}
+
+/// 复制任务的状态；Graph 在服务端异步执行复制，客户端只跟踪进度。
+enum CopyJobStatus { inProgress, completed, failed }
+
+/// 冲突处理的实际结果，随任务持久化，便于在历史中回溯。
+enum DownloadConflictOutcome {
+  /// 目标路径不存在同名文件。
+  noConflict,
+  /// 已覆盖本地文件。
+  overwritten,
+  /// 保留本地文件，未下载。
+  skipped,
+  /// 以新文件名保存，`saved_path` 为重命名后的路径。
+  keptBoth,
+  /// 等待用户决定，任务暂存于 failed 列表。
+  awaitingDecision,
+}
+
+/// 下载目标已存在同名文件时的处理策略，可按任务指定，也可在设置中配置默认值。
+enum DownloadConflictPolicy {
+  /// 直接覆盖本地文件。
+  overwrite,
+  /// 保留本地文件，跳过本次下载。
+  skip,
+  /// 两者都保留，新文件以 " (1)" 等后缀重命名。
+  keepBoth,
+  /// 仅当远端修改时间晚于本地文件时覆盖，否则跳过。
+  overwriteIfNewer,
+  /// 暂停任务，等待用户选择处理方式。
+  ask,
+}
 
 /// 下载进度事件，通过 StreamSink 推送给 Flutter，供 UI 实时刷新进度与速度。
 class DownloadProgressUpdate {
+  final String taskId;
   final String itemId;
   final BigInt bytesDownloaded;
   final BigInt? expectedSize;
@@ -17,6 +195,7 @@ class DownloadProgressUpdate {
   final PlatformInt64 timestampMillis;
 
   const DownloadProgressUpdate({
+    required this.taskId,
     required this.itemId,
     required this.bytesDownloaded,
     this.expectedSize,
@@ -26,6 +205,7 @@ class DownloadProgressUpdate {
 
   @override
   int get hashCode =>
+      taskId.hashCode ^
       itemId.hashCode ^
       bytesDownloaded.hashCode ^
       expectedSize.hashCode ^
@@ -37,6 +217,7 @@ class DownloadProgressUpdate {
       identical(this, other) ||
       other is DownloadProgressUpdate &&
           runtimeType == other.runtimeType &&
+          taskId == other.taskId &&
           itemId == other.itemId &&
           bytesDownloaded == other.bytesDownloaded &&
           expectedSize == other.expectedSize &&
@@ -73,10 +254,18 @@ class DownloadQueueState {
 }
 
 /// 下载任务状态，迁移至 Rust 端统一管理。
-enum DownloadStatus { inProgress, completed, failed }
+enum DownloadStatus {
+  inProgress,
+  completed,
+  failed,
+  /// 已入队但尚未获得并发名额，等待调度。
+  queued,
+}
 
 /// 单条下载任务详情，供 Flutter 展示进度与历史。
 class DownloadTask {
+  /// 任务唯一标识；同一文件下载到不同位置会产生不同任务。
+  final String taskId;
   final DriveItemSummary item;
   final DownloadStatus status;
   final PlatformInt64 startedAt;
@@ -85,8 +274,19 @@ class DownloadTask {
   final BigInt? sizeLabel;
   final BigInt? bytesDownloaded;
   final String? errorMessage;
+  final String targetDir;
+  final DownloadConflictPolicy conflictPolicy;
+  final DownloadConflictOutcome? conflictOutcome;
+  /// 本次下载已自动重试的次数。
+  final int retryCount;
+  final TransferPriority priority;
+  /// 预检失败的类型；其他原因的失败为空。
+  final TransferFailureReason? failureReason;
+  /// 下载完成后各项自动动作的执行结果，按执行顺序排列。
+  final List<PostDownloadHookOutcome> hookOutcomes;
 
   const DownloadTask({
+    required this.taskId,
     required this.item,
     required this.status,
     required this.startedAt,
@@ -95,10 +295,18 @@ class DownloadTask {
     this.sizeLabel,
     this.bytesDownloaded,
     this.errorMessage,
+    required this.targetDir,
+    required this.conflictPolicy,
+    this.conflictOutcome,
+    required this.retryCount,
+    required this.priority,
+    this.failureReason,
+    required this.hookOutcomes,
   });
 
   @override
   int get hashCode =>
+      taskId.hashCode ^
       item.hashCode ^
       status.hashCode ^
       startedAt.hashCode ^
@@ -106,13 +314,21 @@ class DownloadTask {
       savedPath.hashCode ^
       sizeLabel.hashCode ^
       bytesDownloaded.hashCode ^
-      errorMessage.hashCode;
+      errorMessage.hashCode ^
+      targetDir.hashCode ^
+      conflictPolicy.hashCode ^
+      conflictOutcome.hashCode ^
+      retryCount.hashCode ^
+      priority.hashCode ^
+      failureReason.hashCode ^
+      hookOutcomes.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is DownloadTask &&
           runtimeType == other.runtimeType &&
+          taskId == other.taskId &&
           item == other.item &&
           status == other.status &&
           startedAt == other.startedAt &&
@@ -120,7 +336,40 @@ class DownloadTask {
           savedPath == other.savedPath &&
           sizeLabel == other.sizeLabel &&
           bytesDownloaded == other.bytesDownloaded &&
-          errorMessage == other.errorMessage;
+          errorMessage == other.errorMessage &&
+          targetDir == other.targetDir &&
+          conflictPolicy == other.conflictPolicy &&
+          conflictOutcome == other.conflictOutcome &&
+          retryCount == other.retryCount &&
+          priority == other.priority &&
+          failureReason == other.failureReason &&
+          hookOutcomes == other.hookOutcomes;
+}
+
+/// 下载任务生命周期事件，附带变化后的完整任务，
+/// 失败原因、保存路径等信息可直接从 `task` 读取。
+class DownloadTaskEvent {
+  final TransferEventKind kind;
+  final DownloadTask task;
+  final PlatformInt64 timestampMillis;
+
+  const DownloadTaskEvent({
+    required this.kind,
+    required this.task,
+    required this.timestampMillis,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ task.hashCode ^ timestampMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadTaskEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          task == other.task &&
+          timestampMillis == other.timestampMillis;
 }
 
 /// 下载完成后的结果描述，便于前端提示保存路径与大小。
@@ -129,12 +378,14 @@ class DriveDownloadResult {
   final String savedPath;
   final BigInt bytesDownloaded;
   final BigInt? expectedSize;
+  final DownloadConflictOutcome conflictOutcome;
 
   const DriveDownloadResult({
     required this.fileName,
     required this.savedPath,
     required this.bytesDownloaded,
     this.expectedSize,
+    required this.conflictOutcome,
   });
 
   @override
@@ -142,7 +393,8 @@ class DriveDownloadResult {
       fileName.hashCode ^
       savedPath.hashCode ^
       bytesDownloaded.hashCode ^
-      expectedSize.hashCode;
+      expectedSize.hashCode ^
+      conflictOutcome.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -152,7 +404,8 @@ class DriveDownloadResult {
           fileName == other.fileName &&
           savedPath == other.savedPath &&
           bytesDownloaded == other.bytesDownloaded &&
-          expectedSize == other.expectedSize;
+          expectedSize == other.expectedSize &&
+          conflictOutcome == other.conflictOutcome;
 }
 
 /// OneDrive 概览信息（配额、类型、所有者）。
@@ -381,12 +634,152 @@ class DriveQuota {
           state == other.state;
 }
 
+/// 新建文件夹时目标位置已存在同名项的处理方式。
+enum FolderConflictBehavior {
+  /// 不创建，返回错误。
+  fail,
+  /// 由 OneDrive 为新文件夹自动重命名。
+  rename,
+  /// 替换同名项（原有内容进入回收站）。
+  replace,
+  /// 同名项是文件夹时直接复用，是文件时返回错误。
+  useExisting,
+}
+
 /// 分享链接范围（与 Graph 对齐）。
 enum LinkScope { anonymous, organization, users }
 
 /// 分享链接类型（与 Graph 对齐）。
 enum LinkType { view, edit, embed }
 
+/// 传输结束时的桌面通知设置，上传与下载共用。
+/// 免打扰时段以分钟计（0..1440），跨午夜与全天的规则与 `BandwidthSchedule` 相同。
+class NotificationSettings {
+  /// 总开关，关闭后不发送任何通知。
+  final bool enabled;
+  /// 单个任务完成时通知。
+  final bool notifyOnTaskCompleted;
+  /// 单个任务失败时通知（用户取消不算失败）。
+  final bool notifyOnTaskFailed;
+  /// 队列清空时汇总本批次的完成与失败数量，仅在批次包含多个任务时发送。
+  final bool notifyOnBatchFinished;
+  final bool quietHoursEnabled;
+  final int quietHoursStartMinute;
+  final int quietHoursEndMinute;
+
+  const NotificationSettings({
+    required this.enabled,
+    required this.notifyOnTaskCompleted,
+    required this.notifyOnTaskFailed,
+    required this.notifyOnBatchFinished,
+    required this.quietHoursEnabled,
+    required this.quietHoursStartMinute,
+    required this.quietHoursEndMinute,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      notifyOnTaskCompleted.hashCode ^
+      notifyOnTaskFailed.hashCode ^
+      notifyOnBatchFinished.hashCode ^
+      quietHoursEnabled.hashCode ^
+      quietHoursStartMinute.hashCode ^
+      quietHoursEndMinute.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NotificationSettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          notifyOnTaskCompleted == other.notifyOnTaskCompleted &&
+          notifyOnTaskFailed == other.notifyOnTaskFailed &&
+          notifyOnBatchFinished == other.notifyOnBatchFinished &&
+          quietHoursEnabled == other.quietHoursEnabled &&
+          quietHoursStartMinute == other.quietHoursStartMinute &&
+          quietHoursEndMinute == other.quietHoursEndMinute;
+}
+
+/// 一条下载后动作配置，按列表顺序依次执行；前一条移动文件后，后续动作使用新路径。
+class PostDownloadHook {
+  final PostDownloadHookKind kind;
+  final bool enabled;
+  /// 仅对这些扩展名生效（不含点，忽略大小写）；为空表示对所有文件生效。
+  final List<String> extensions;
+  /// RunCommand：要执行的程序路径或命令名，不经过 shell 解析。
+  final String? program;
+  /// RunCommand：程序参数，`{path}` 会替换为保存路径；均未包含时把路径追加为最后一个参数。
+  final List<String> args;
+  /// MoveByType：目标子文件夹名，相对于文件所在目录。
+  final String? subfolder;
+
+  const PostDownloadHook({
+    required this.kind,
+    required this.enabled,
+    required this.extensions,
+    this.program,
+    required this.args,
+    this.subfolder,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      enabled.hashCode ^
+      extensions.hashCode ^
+      program.hashCode ^
+      args.hashCode ^
+      subfolder.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PostDownloadHook &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          enabled == other.enabled &&
+          extensions == other.extensions &&
+          program == other.program &&
+          args == other.args &&
+          subfolder == other.subfolder;
+}
+
+/// 下载完成后自动执行的动作类型。
+enum PostDownloadHookKind {
+  /// 以保存路径为参数运行用户指定的程序。
+  runCommand,
+  /// 将 zip 压缩包解压到同目录下以文件名命名的文件夹。
+  extractArchive,
+  /// 按扩展名把文件移动到保存目录下的子文件夹。
+  moveByType,
+}
+
+/// 单条下载后动作的执行结果，记录在任务上供界面展示。
+class PostDownloadHookOutcome {
+  final PostDownloadHookKind kind;
+  final bool success;
+  final String message;
+
+  const PostDownloadHookOutcome({
+    required this.kind,
+    required this.success,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ success.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PostDownloadHookOutcome &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          success == other.success &&
+          message == other.message;
+}
+
 /// 当前账户可用的分享能力（基于 driveType 推断）。
 class ShareCapabilities {
   final String? driveType;
@@ -467,6 +860,178 @@ class ShareLinkResult {
           passwordProtected == other.passwordProtected;
 }
 
+/// 任务生命周期变化类型，上传与下载共用。
+enum TransferEventKind {
+  /// 进入等待队列（新建、手动重试或自动重试前重新排队）。
+  queued,
+  /// 获得并发名额，开始传输。
+  started,
+  /// 遇到可恢复错误，等待退避后自动重试；错误详情见任务的 `error_message`。
+  retrying,
+  completed,
+  failed,
+  cancelled,
+  /// 目标位置已有同名文件，等待用户选择冲突处理方式（仅下载）。
+  awaitingDecision,
+  /// 任务记录被移除。
+  removed,
+}
+
+/// 传输失败的原因，便于界面给出针对性的处理建议；无法归类的失败不带原因。
+enum TransferFailureReason {
+  /// 本地磁盘剩余空间不足以容纳文件与保留空间。
+  insufficientDiskSpace,
+  /// OneDrive 剩余配额不足以容纳待上传的数据。
+  insufficientQuota,
+  /// 上传完成后远端内容哈希与本地文件不一致。
+  integrityMismatch,
+  /// 上传期间本地文件被修改，重新上传后仍在变化。
+  sourceChanged,
+  /// 没有访问权限或登录已失效。
+  accessDenied,
+  /// 远端项目不存在或已被删除。
+  notFound,
+  /// 可重试的失败在自动重试次数耗尽后仍未恢复。
+  retriesExhausted,
+}
+
+/// 传输任务优先级：决定新任务插入等待队列的位置，高优先级排在低优先级之前，
+/// 同级之间先进先出。
+enum TransferPriority {
+  low,
+  normal,
+  high;
+
+  static Future<TransferPriority> default_() =>
+      RustLib.instance.api.crateApiDriveModelsTransferPriorityDefault();
+}
+
+/// 传输失败后的自动重试策略，上传与下载共用。
+/// 第 n 次重试前等待 `initial_delay_ms * 2^(n-1)`，不超过 `max_delay_ms`。
+class TransferRetryPolicy {
+  /// 单个任务最多自动重试的次数，0 表示关闭自动重试。
+  final int maxRetries;
+  final BigInt initialDelayMs;
+  final BigInt maxDelayMs;
+
+  const TransferRetryPolicy({
+    required this.maxRetries,
+    required this.initialDelayMs,
+    required this.maxDelayMs,
+  });
+
+  @override
+  int get hashCode =>
+      maxRetries.hashCode ^ initialDelayMs.hashCode ^ maxDelayMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TransferRetryPolicy &&
+          runtimeType == other.runtimeType &&
+          maxRetries == other.maxRetries &&
+          initialDelayMs == other.initialDelayMs &&
+          maxDelayMs == other.maxDelayMs;
+}
+
+/// 上传目标已存在同名项时的处理方式，可按任务指定，也可在设置中配置默认值。
+enum UploadConflictBehavior {
+  /// 保留远端文件，本次上传失败。
+  fail,
+  /// 覆盖远端文件（保留为新版本）。
+  replace,
+  /// 两者都保留，由 OneDrive 为新文件自动重命名。
+  rename,
+  /// 大小与内容哈希均相同时跳过上传，否则覆盖远端文件。
+  skipIfIdentical,
+}
+
+/// 文件夹上传任务组：本地目录下的每个文件作为子任务进入上传队列，
+/// 进度与失败数量在组级别汇总。
+class UploadGroup {
+  final String groupId;
+  final String localRoot;
+  /// 在 OneDrive 上对应的根文件夹名，与本地目录同名。
+  final String folderName;
+  final String? parentId;
+  final UploadGroupStatus status;
+  final PlatformInt64 createdAt;
+  final PlatformInt64? completedAt;
+  /// 准备阶段的错误信息，包括无法创建而被跳过的文件夹；子文件的失败记录在各自任务上。
+  final String? errorMessage;
+  final int totalFiles;
+  final int completedFiles;
+  final int failedFiles;
+  final BigInt totalBytes;
+  final BigInt uploadedBytes;
+
+  const UploadGroup({
+    required this.groupId,
+    required this.localRoot,
+    required this.folderName,
+    this.parentId,
+    required this.status,
+    required this.createdAt,
+    this.completedAt,
+    this.errorMessage,
+    required this.totalFiles,
+    required this.completedFiles,
+    required this.failedFiles,
+    required this.totalBytes,
+    required this.uploadedBytes,
+  });
+
+  @override
+  int get hashCode =>
+      groupId.hashCode ^
+      localRoot.hashCode ^
+      folderName.hashCode ^
+      parentId.hashCode ^
+      status.hashCode ^
+      createdAt.hashCode ^
+      completedAt.hashCode ^
+      errorMessage.hashCode ^
+      totalFiles.hashCode ^
+      completedFiles.hashCode ^
+      failedFiles.hashCode ^
+      totalBytes.hashCode ^
+      uploadedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UploadGroup &&
+          runtimeType == other.runtimeType &&
+          groupId == other.groupId &&
+          localRoot == other.localRoot &&
+          folderName == other.folderName &&
+          parentId == other.parentId &&
+          status == other.status &&
+          createdAt == other.createdAt &&
+          completedAt == other.completedAt &&
+          errorMessage == other.errorMessage &&
+          totalFiles == other.totalFiles &&
+          completedFiles == other.completedFiles &&
+          failedFiles == other.failedFiles &&
+          totalBytes == other.totalBytes &&
+          uploadedBytes == other.uploadedBytes;
+}
+
+/// 文件夹上传任务组的状态。
+enum UploadGroupStatus {
+  /// 正在扫描本地目录并在 OneDrive 上创建文件夹结构。
+  preparing,
+  /// 子文件任务仍在排队或上传中。
+  uploading,
+  /// 所有文件均已上传成功。
+  completed,
+  /// 上传已结束，但部分文件失败。
+  partiallyFailed,
+  /// 准备阶段出错，或所有文件均上传失败。
+  failed,
+  cancelled,
+}
+
 /// 上传进度事件，用于前端展示实时上传状态。
 class UploadProgressUpdate {
   final String taskId;
@@ -508,18 +1073,22 @@ class UploadQueueState {
   final List<UploadTask> active;
   final List<UploadTask> completed;
   final List<UploadTask> failed;
+  /// 文件夹上传任务组，子任务同时出现在上面的列表中。
+  final List<UploadGroup> groups;
 
   const UploadQueueState({
     required this.active,
     required this.completed,
     required this.failed,
+    required this.groups,
   });
 
   static Future<UploadQueueState> default_() =>
       RustLib.instance.api.crateApiDriveModelsUploadQueueStateDefault();
 
   @override
-  int get hashCode => active.hashCode ^ completed.hashCode ^ failed.hashCode;
+  int get hashCode =>
+      active.hashCode ^ completed.hashCode ^ failed.hashCode ^ groups.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -528,11 +1097,19 @@ class UploadQueueState {
           runtimeType == other.runtimeType &&
           active == other.active &&
           completed == other.completed &&
-          failed == other.failed;
+          failed == other.failed &&
+          groups == other.groups;
 }
 
 /// 上传任务状态。
-enum UploadStatus { inProgress, completed, failed, cancelled }
+enum UploadStatus {
+  inProgress,
+  completed,
+  failed,
+  cancelled,
+  /// 已入队但尚未获得并发名额，等待调度。
+  queued,
+}
 
 /// 单条上传任务详情。
 class UploadTask {
@@ -549,6 +1126,26 @@ class UploadTask {
   final BigInt? bytesUploaded;
   final String? errorMessage;
   final String? sessionUrl;
+  /// 上传会话的过期时间（毫秒时间戳）；临近过期的会话不再续传，改为新建会话重新上传。
+  final PlatformInt64? sessionExpiresAt;
+  /// 本次上传已自动重试的次数。
+  final int retryCount;
+  final TransferPriority priority;
+  /// 预检失败的类型；其他原因的失败为空。
+  final TransferFailureReason? failureReason;
+  /// 所属文件夹上传任务组；单独上传的文件为空。
+  final String? groupId;
+  final UploadConflictBehavior conflictBehavior;
+  /// 上传完成后 OneDrive 上的实际文件名；按重命名策略处理冲突时可能与 `file_name` 不同。
+  final String? remoteName;
+  /// 远端已存在内容相同的文件而跳过了上传。
+  final bool skippedIdentical;
+  /// 开始上传时本地文件的修改时间（毫秒时间戳），与 `size` 一起用于发现上传期间的改写。
+  final PlatformInt64? sourceModifiedAt;
+  /// 开始上传时本地文件首尾采样的 quickXorHash。
+  final String? sourceFingerprint;
+  /// 从网络地址上传时的来源 URL；为空表示上传本地文件。
+  final String? sourceUrl;
 
   const UploadTask({
     required this.taskId,
@@ -564,6 +1161,17 @@ class UploadTask {
     this.bytesUploaded,
     this.errorMessage,
     this.sessionUrl,
+    this.sessionExpiresAt,
+    required this.retryCount,
+    required this.priority,
+    this.failureReason,
+    this.groupId,
+    required this.conflictBehavior,
+    this.remoteName,
+    required this.skippedIdentical,
+    this.sourceModifiedAt,
+    this.sourceFingerprint,
+    this.sourceUrl,
   });
 
   @override
@@ -580,7 +1188,18 @@ class UploadTask {
       completedAt.hashCode ^
       bytesUploaded.hashCode ^
       errorMessage.hashCode ^
-      sessionUrl.hashCode;
+      sessionUrl.hashCode ^
+      sessionExpiresAt.hashCode ^
+      retryCount.hashCode ^
+      priority.hashCode ^
+      failureReason.hashCode ^
+      groupId.hashCode ^
+      conflictBehavior.hashCode ^
+      remoteName.hashCode ^
+      skippedIdentical.hashCode ^
+      sourceModifiedAt.hashCode ^
+      sourceFingerprint.hashCode ^
+      sourceUrl.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -599,5 +1218,41 @@ class UploadTask {
           completedAt == other.completedAt &&
           bytesUploaded == other.bytesUploaded &&
           errorMessage == other.errorMessage &&
-          sessionUrl == other.sessionUrl;
+          sessionUrl == other.sessionUrl &&
+          sessionExpiresAt == other.sessionExpiresAt &&
+          retryCount == other.retryCount &&
+          priority == other.priority &&
+          failureReason == other.failureReason &&
+          groupId == other.groupId &&
+          conflictBehavior == other.conflictBehavior &&
+          remoteName == other.remoteName &&
+          skippedIdentical == other.skippedIdentical &&
+          sourceModifiedAt == other.sourceModifiedAt &&
+          sourceFingerprint == other.sourceFingerprint &&
+          sourceUrl == other.sourceUrl;
+}
+
+/// 上传任务生命周期事件，远端 item id、错误信息等从 `task` 读取。
+class UploadTaskEvent {
+  final TransferEventKind kind;
+  final UploadTask task;
+  final PlatformInt64 timestampMillis;
+
+  const UploadTaskEvent({
+    required this.kind,
+    required this.task,
+    required this.timestampMillis,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ task.hashCode ^ timestampMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UploadTaskEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          task == other.task &&
+          timestampMillis == other.timestampMillis;
 }
@@ -7,22 +7,24 @@ import '../../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `align_chunk_size`, `cancel_upload_session`, `create_upload_session`, `get_upload_session_status`, `new`, `parse_next_start`, `parse_upload_session_response`, `upload_chunk_with_retry`, `upload_large_file_with_hooks`, `upload_small_file_with_hooks`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DriveItemUploadResponse`, `ProgressReader`, `UploadChunkError`, `UploadChunkResult`, `UploadFileFacet`, `UploadSessionResponse`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `from`, `read`
+// These functions are ignored because they are not marked as `pub`: `cancel_upload_session`, `conflict_behavior_param`, `create_upload_session`, `finish_digest`, `get_upload_session_status`, `into_outcome`, `new`, `parse_next_start`, `parse_session_expiration`, `parse_upload_session_response`, `simple_upload`, `upload_chunk`, `upload_chunks`, `upload_large_file_with_hooks`, `upload_small_file_from_path_with_hooks`, `upload_stream_with_hooks`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DriveItemUploadResponse`, `ProgressReader`, `UploadChunkError`, `UploadChunkResult`, `UploadFileFacet`, `UploadFileHashes`, `UploadOutcome`, `UploadSessionResponse`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `fmt`, `from`, `read`
 
 /// 上传小文件（推荐 10MB 内，硬上限 250MB），存放到指定文件夹。
+/// - 从 local_path 流式读取文件内容，无需在 Dart 侧把文件读入内存。
 /// - 当 overwrite=true 时，如果存在同名文件，将直接覆盖。
 /// - 当 overwrite=false 时，使用 Graph 的 rename 行为避免冲突。
+/// - 需要 fail 或跳过相同文件等策略时请使用上传队列。
 /// - parent_id 为空时默认上传到根目录。
 Future<DriveItemSummary> uploadSmallFile({
   String? parentId,
   required String fileName,
-  required List<int> content,
+  required String localPath,
   required bool overwrite,
 }) => RustLib.instance.api.crateApiDriveUploadUploadSmallFile(
   parentId: parentId,
   fileName: fileName,
-  content: content,
+  localPath: localPath,
   overwrite: overwrite,
 );
@@ -7,35 +7,94 @@ import '../../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `requested_conflict_behavior`
+
 Future<UploadQueueState> uploadQueueState() =>
     RustLib.instance.api.crateApiDriveUploadManagerUploadQueueState();
 
+/// 按本地路径入队上传，任意大小的文件都从磁盘流式读取；
+/// 不超过简易上传阈值的文件单请求上传，更大的文件走可续传的分片会话。
+/// `conflict_behavior` 优先于 `overwrite`；两者都未指定时使用设置中的默认冲突处理方式。
 Future<UploadQueueState> enqueueUploadTask({
   String? parentId,
   required String fileName,
   required String localPath,
-  required List<int> content,
   required bool overwrite,
+  TransferPriority? priority,
+  UploadConflictBehavior? conflictBehavior,
 }) => RustLib.instance.api.crateApiDriveUploadManagerEnqueueUploadTask(
   parentId: parentId,
   fileName: fileName,
   localPath: localPath,
-  content: content,
   overwrite: overwrite,
+  priority: priority,
+  conflictBehavior: conflictBehavior,
 );
 
+/// 兼容旧调用：与 `enqueue_upload_task` 相同，由上传队列按文件大小选择上传方式。
 Future<UploadQueueState> enqueueLargeUploadTask({
   String? parentId,
   required String fileName,
   required String localPath,
   required bool overwrite,
+  TransferPriority? priority,
+  UploadConflictBehavior? conflictBehavior,
 }) => RustLib.instance.api.crateApiDriveUploadManagerEnqueueLargeUploadTask(
   parentId: parentId,
   fileName: fileName,
   localPath: localPath,
   overwrite: overwrite,
+  priority: priority,
+  conflictBehavior: conflictBehavior,
+);
+
+/// 递归上传本地文件夹：在 OneDrive 上重建目录结构（已存在的文件夹会复用），
+/// 每个文件作为任务组的子任务排队上传。include/exclude 为 glob 规则，
+/// 不含 `/` 时匹配文件或目录名（如 `.git`、`target`、`*.tmp`），含 `/` 时匹配相对路径。
+Future<UploadQueueState> enqueueUploadFolder({
+  String? parentId,
+  required String localDir,
+  required List<String> include,
+  required List<String> exclude,
+  UploadConflictBehavior? conflictBehavior,
+  TransferPriority? priority,
+}) => RustLib.instance.api.crateApiDriveUploadManagerEnqueueUploadFolder(
+  parentId: parentId,
+  localDir: localDir,
+  include: include,
+  exclude: exclude,
+  conflictBehavior: conflictBehavior,
+  priority: priority,
 );
 
+/// 从网络地址上传文件：个人版 OneDrive 由服务端直接拉取，其他账户由本端边下载边上传，
+/// 不在本地暂存；进度与本地上传一样在上传队列中展示。`file_name` 为空时取地址中的文件名。
+Future<UploadQueueState> enqueueUploadFromUrl({
+  String? parentId,
+  required String sourceUrl,
+  String? fileName,
+  UploadConflictBehavior? conflictBehavior,
+  TransferPriority? priority,
+}) => RustLib.instance.api.crateApiDriveUploadManagerEnqueueUploadFromUrl(
+  parentId: parentId,
+  sourceUrl: sourceUrl,
+  fileName: fileName,
+  conflictBehavior: conflictBehavior,
+  priority: priority,
+);
+
+/// 取消文件夹上传任务组，未结束的子任务一并取消。
+Future<UploadQueueState> cancelUploadGroup({required String groupId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveUploadManagerCancelUploadGroup(groupId: groupId);
+
+/// 移除文件夹上传任务组及其全部子任务记录。
+Future<UploadQueueState> removeUploadGroup({required String groupId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveUploadManagerRemoveUploadGroup(groupId: groupId);
+
 Future<UploadQueueState> removeUploadTask({required String taskId}) => RustLib
     .instance
     .api
@@ -46,11 +105,53 @@ Future<UploadQueueState> cancelUploadTask({required String taskId}) => RustLib
     .api
     .crateApiDriveUploadManagerCancelUploadTask(taskId: taskId);
 
+/// 将等待中的上传任务前移一位。
+Future<UploadQueueState> moveUploadTaskUp({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveUploadManagerMoveUploadTaskUp(taskId: taskId);
+
+/// 将等待中的上传任务后移一位。
+Future<UploadQueueState> moveUploadTaskDown({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveUploadManagerMoveUploadTaskDown(taskId: taskId);
+
+/// 将等待中的上传任务移到队首，下一个空闲名额即开始上传。
+Future<UploadQueueState> moveUploadTaskToTop({required String taskId}) =>
+    RustLib.instance.api.crateApiDriveUploadManagerMoveUploadTaskToTop(
+      taskId: taskId,
+    );
+
+/// 修改未结束上传任务的优先级，等待中的任务会按新优先级重新排队。
+Future<UploadQueueState> setUploadTaskPriority({
+  required String taskId,
+  required TransferPriority priority,
+}) => RustLib.instance.api.crateApiDriveUploadManagerSetUploadTaskPriority(
+  taskId: taskId,
+  priority: priority,
+);
+
 Future<UploadQueueState> clearFailedUploadTasks() =>
     RustLib.instance.api.crateApiDriveUploadManagerClearFailedUploadTasks();
 
+/// 按原参数重新上传全部失败或已取消的任务，本地文件已不存在等无法重试的任务保留在失败列表。
+Future<UploadQueueState> retryFailedUploadTasks() =>
+    RustLib.instance.api.crateApiDriveUploadManagerRetryFailedUploadTasks();
+
+/// 按原参数重新上传单个失败或已取消的任务：目标目录、文件名、冲突处理方式与优先级均沿用入队时的设置，
+/// 上传会话仍有效时从断点续传，否则新建会话。本地文件已不存在或同名文件已在队列中时返回错误。
+Future<UploadQueueState> retryUploadTask({required String taskId}) => RustLib
+    .instance
+    .api
+    .crateApiDriveUploadManagerRetryUploadTask(taskId: taskId);
+
 Future<UploadQueueState> clearUploadHistory() =>
     RustLib.instance.api.crateApiDriveUploadManagerClearUploadHistory();
 
 Stream<UploadProgressUpdate> uploadProgressStream() =>
     RustLib.instance.api.crateApiDriveUploadManagerUploadProgressStream();
+
+/// 推送上传任务的生命周期事件，UI 无需轮询队列快照即可感知状态变化。
+Stream<UploadTaskEvent> uploadTaskEventStream() =>
+    RustLib.instance.api.crateApiDriveUploadManagerUploadTaskEventStream();
@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取带宽限速设置。
+Future<BandwidthSettings> getBandwidthSettings() =>
+    RustLib.instance.api.crateApiSettingsBandwidthLimitGetBandwidthSettings();
+
+/// FRB 对外接口：更新带宽限速设置，进行中的传输立即按新限速执行。
+Future<BandwidthSettings> setBandwidthSettings({
+  required BandwidthSettings settings,
+}) => RustLib.instance.api.crateApiSettingsBandwidthLimitSetBandwidthSettings(
+  settings: settings,
+);
@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取下载前需保留的本地磁盘空闲空间（MiB）。
+Future<BigInt> getDiskSpaceReserveMib() => RustLib.instance.api
+    .crateApiSettingsDiskSpaceReserveGetDiskSpaceReserveMib();
+
+/// FRB 对外接口：更新保留空间，下一个开始的下载任务即按新值检查。
+Future<BigInt> setDiskSpaceReserveMib({required BigInt reserveMib}) =>
+    RustLib.instance.api.crateApiSettingsDiskSpaceReserveSetDiskSpaceReserveMib(
+      reserveMib: reserveMib,
+    );
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取默认的下载冲突策略。
+Future<DownloadConflictPolicy> getDownloadConflictPolicy() => RustLib
+    .instance
+    .api
+    .crateApiSettingsDownloadConflictPolicyGetDownloadConflictPolicy();
+
+/// FRB 对外接口：更新默认的下载冲突策略，仅影响之后入队的任务。
+Future<DownloadConflictPolicy> setDownloadConflictPolicy({
+  required DownloadConflictPolicy policy,
+}) => RustLib.instance.api
+    .crateApiSettingsDownloadConflictPolicySetDownloadConflictPolicy(
+      policy: policy,
+    );
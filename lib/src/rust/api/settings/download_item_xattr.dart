@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取是否把 OneDrive item id 与 eTag 写入下载文件的扩展属性。
+Future<bool> getDownloadItemXattrEnabled() => RustLib.instance.api
+    .crateApiSettingsDownloadItemXattrGetDownloadItemXattrEnabled();
+
+/// FRB 对外接口：开启或关闭扩展属性写入，对之后完成的下载生效。
+Future<bool> setDownloadItemXattrEnabled({required bool enabled}) =>
+    RustLib.instance.api.crateApiSettingsDownloadItemXattrSetDownloadItemXattrEnabled(
+      enabled: enabled,
+    );
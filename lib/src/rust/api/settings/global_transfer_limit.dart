@@ -0,0 +1,15 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取上传与下载共享的总并发上限，`None` 表示不限制。
+Future<int?> getGlobalTransferLimit() => RustLib.instance.api
+    .crateApiSettingsGlobalTransferLimitGetGlobalTransferLimit();
+
+/// FRB 对外接口：更新总并发上限并立即生效；调低时已在运行的任务不受影响。
+Future<int?> setGlobalTransferLimit({int? limit}) => RustLib.instance.api
+    .crateApiSettingsGlobalTransferLimitSetGlobalTransferLimit(limit: limit);
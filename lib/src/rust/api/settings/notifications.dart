@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取传输结束时的桌面通知设置。
+Future<NotificationSettings> getNotificationSettings() =>
+    RustLib.instance.api.crateApiSettingsNotificationsGetNotificationSettings();
+
+/// FRB 对外接口：更新桌面通知设置，对之后结束的传输生效。
+Future<NotificationSettings> setNotificationSettings({
+  required NotificationSettings settings,
+}) => RustLib.instance.api.crateApiSettingsNotificationsSetNotificationSettings(
+  settings: settings,
+);
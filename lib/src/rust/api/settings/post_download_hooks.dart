@@ -0,0 +1,18 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取下载完成后自动执行的动作列表。
+Future<List<PostDownloadHook>> getPostDownloadHooks() => RustLib.instance.api
+    .crateApiSettingsPostDownloadHooksGetPostDownloadHooks();
+
+/// FRB 对外接口：更新下载后动作列表，对之后完成的下载生效。
+Future<List<PostDownloadHook>> setPostDownloadHooks({
+  required List<PostDownloadHook> hooks,
+}) => RustLib.instance.api
+    .crateApiSettingsPostDownloadHooksSetPostDownloadHooks(hooks: hooks);
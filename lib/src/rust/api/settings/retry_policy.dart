@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取传输失败后的自动重试策略。
+Future<TransferRetryPolicy> getTransferRetryPolicy() =>
+    RustLib.instance.api.crateApiSettingsRetryPolicyGetTransferRetryPolicy();
+
+/// FRB 对外接口：更新自动重试策略，下一次失败时即按新策略处理。
+Future<TransferRetryPolicy> setTransferRetryPolicy({
+  required TransferRetryPolicy policy,
+}) => RustLib.instance.api.crateApiSettingsRetryPolicySetTransferRetryPolicy(
+  policy: policy,
+);
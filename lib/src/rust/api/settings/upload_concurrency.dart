@@ -0,0 +1,15 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取当前并行上传数设置。
+Future<int> getUploadConcurrency() => RustLib.instance.api
+    .crateApiSettingsUploadConcurrencyGetUploadConcurrency();
+
+/// FRB 对外接口：更新并行上传数，并立即通知上传管理器生效。
+Future<int> setUploadConcurrency({required int limit}) => RustLib.instance.api
+    .crateApiSettingsUploadConcurrencySetUploadConcurrency(limit: limit);
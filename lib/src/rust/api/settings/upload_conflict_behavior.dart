@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../drive/models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取默认的上传冲突处理方式。
+Future<UploadConflictBehavior> getUploadConflictBehavior() => RustLib
+    .instance
+    .api
+    .crateApiSettingsUploadConflictBehaviorGetUploadConflictBehavior();
+
+/// FRB 对外接口：更新默认的上传冲突处理方式，仅影响之后入队的任务。
+Future<UploadConflictBehavior> setUploadConflictBehavior({
+  required UploadConflictBehavior behavior,
+}) => RustLib.instance.api
+    .crateApiSettingsUploadConflictBehaviorSetUploadConflictBehavior(
+      behavior: behavior,
+    );
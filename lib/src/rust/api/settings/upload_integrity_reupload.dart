@@ -0,0 +1,17 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// FRB 对外接口：获取上传完整性校验失败时是否自动重新上传。
+Future<bool> getUploadIntegrityReuploadEnabled() => RustLib.instance.api
+    .crateApiSettingsUploadIntegrityReuploadGetUploadIntegrityReuploadEnabled();
+
+/// FRB 对外接口：开启或关闭校验失败后的自动重传，关闭时任务直接标记为校验失败。
+Future<bool> setUploadIntegrityReuploadEnabled({required bool enabled}) =>
+    RustLib.instance.api.crateApiSettingsUploadIntegrityReuploadSetUploadIntegrityReuploadEnabled(
+      enabled: enabled,
+    );
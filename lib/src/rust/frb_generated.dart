@@ -5,6 +5,8 @@
 
 import 'api/auth/auth.dart';
 import 'api/auth/refresh.dart';
+import 'api/drive/copy_manager.dart';
+import 'api/drive/create_folder.dart';
 import 'api/drive/delete.dart';
 import 'api/drive/details.dart';
 import 'api/drive/download.dart';
@@ -16,8 +18,19 @@ import 'api/drive/move_item.dart';
 import 'api/drive/share.dart';
 import 'api/drive/upload.dart';
 import 'api/drive/upload_manager.dart';
+import 'api/settings/bandwidth_limit.dart';
+import 'api/settings/disk_space_reserve.dart';
 import 'api/settings/download_concurrency.dart';
+import 'api/settings/download_conflict_policy.dart';
 import 'api/settings/download_directory.dart';
+import 'api/settings/download_item_xattr.dart';
+import 'api/settings/global_transfer_limit.dart';
+import 'api/settings/notifications.dart';
+import 'api/settings/post_download_hooks.dart';
+import 'api/settings/retry_policy.dart';
+import 'api/settings/upload_concurrency.dart';
+import 'api/settings/upload_conflict_behavior.dart';
+import 'api/settings/upload_integrity_reupload.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -81,7 +94,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1430268093;
+  int get rustContentHash => 2032451979;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -97,8 +110,14 @@ abstract class RustLibApi extends BaseApi {
     required List<String> scopes,
   });
 
+  Future<BandwidthSettings> crateApiDriveModelsBandwidthSettingsDefault();
+
   Future<DownloadQueueState> crateApiDriveDownloadManagerCancelDownloadTask({
-    required String itemId,
+    required String taskId,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerCancelUploadGroup({
+    required String groupId,
   });
 
   Future<UploadQueueState> crateApiDriveUploadManagerCancelUploadTask({
@@ -112,10 +131,29 @@ abstract class RustLibApi extends BaseApi {
 
   Future<UploadQueueState> crateApiDriveUploadManagerClearFailedUploadTasks();
 
+  Future<List<CopyJob>> crateApiDriveCopyManagerClearFinishedCopyJobs();
+
   Future<void> crateApiAuthAuthClearPersistedAuthState();
 
   Future<UploadQueueState> crateApiDriveUploadManagerClearUploadHistory();
 
+  Future<CopyJob> crateApiDriveCopyManagerCopyDriveItem({
+    required String itemId,
+    String? targetDriveId,
+    String? targetParentId,
+    String? newName,
+  });
+
+  Stream<CopyJob> crateApiDriveCopyManagerCopyJobStream();
+
+  Future<List<CopyJob>> crateApiDriveCopyManagerCopyJobs();
+
+  Future<DriveItemSummary> crateApiDriveCreateFolderCreateDriveFolder({
+    String? parentId,
+    required String name,
+    required FolderConflictBehavior conflictBehavior,
+  });
+
   Future<ShareLinkResult> crateApiDriveShareCreateShareLink({
     required String itemId,
     required LinkType linkType,
@@ -145,10 +183,14 @@ abstract class RustLibApi extends BaseApi {
 
   Future<DownloadQueueState> crateApiDriveModelsDownloadQueueStateDefault();
 
+  Stream<DownloadTaskEvent>
+  crateApiDriveDownloadManagerDownloadTaskEventStream();
+
   Future<DownloadQueueState> crateApiDriveDownloadManagerEnqueueDownloadTask({
     required DriveItemSummary item,
     required String targetDir,
-    required bool overwrite,
+    DownloadConflictPolicy? conflictPolicy,
+    TransferPriority? priority,
   });
 
   Future<UploadQueueState> crateApiDriveUploadManagerEnqueueLargeUploadTask({
@@ -156,28 +198,82 @@ abstract class RustLibApi extends BaseApi {
     required String fileName,
     required String localPath,
     required bool overwrite,
+    TransferPriority? priority,
+    UploadConflictBehavior? conflictBehavior,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadFolder({
+    String? parentId,
+    required String localDir,
+    required List<String> include,
+    required List<String> exclude,
+    UploadConflictBehavior? conflictBehavior,
+    TransferPriority? priority,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadFromUrl({
+    String? parentId,
+    required String sourceUrl,
+    String? fileName,
+    UploadConflictBehavior? conflictBehavior,
+    TransferPriority? priority,
   });
 
   Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadTask({
     String? parentId,
     required String fileName,
     required String localPath,
-    required List<int> content,
     required bool overwrite,
+    TransferPriority? priority,
+    UploadConflictBehavior? conflictBehavior,
+  });
+
+  Future<DriveItemSummary> crateApiDriveCreateFolderEnsureDriveFolderPath({
+    String? parentId,
+    required String path,
   });
 
+  Future<BandwidthSettings>
+  crateApiSettingsBandwidthLimitGetBandwidthSettings();
+
+  Future<BigInt> crateApiSettingsDiskSpaceReserveGetDiskSpaceReserveMib();
+
   Future<int> crateApiSettingsDownloadConcurrencyGetDownloadConcurrency();
 
+  Future<DownloadConflictPolicy>
+  crateApiSettingsDownloadConflictPolicyGetDownloadConflictPolicy();
+
   Future<String> crateApiSettingsDownloadDirectoryGetDownloadDirectory();
 
+  Future<bool> crateApiSettingsDownloadItemXattrGetDownloadItemXattrEnabled();
+
   Future<DriveItemDetails> crateApiDriveDetailsGetDriveItemDetails({
     required String itemId,
   });
 
   Future<DriveInfo> crateApiDriveInfoGetDriveOverview();
 
+  Future<int?> crateApiSettingsGlobalTransferLimitGetGlobalTransferLimit();
+
+  Future<NotificationSettings>
+  crateApiSettingsNotificationsGetNotificationSettings();
+
+  Future<List<PostDownloadHook>>
+  crateApiSettingsPostDownloadHooksGetPostDownloadHooks();
+
   Future<ShareCapabilities> crateApiDriveShareGetShareCapabilities();
 
+  Future<TransferRetryPolicy>
+  crateApiSettingsRetryPolicyGetTransferRetryPolicy();
+
+  Future<int> crateApiSettingsUploadConcurrencyGetUploadConcurrency();
+
+  Future<UploadConflictBehavior>
+  crateApiSettingsUploadConflictBehaviorGetUploadConflictBehavior();
+
+  Future<bool>
+  crateApiSettingsUploadIntegrityReuploadGetUploadIntegrityReuploadEnabled();
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
@@ -190,6 +286,18 @@ abstract class RustLibApi extends BaseApi {
 
   Future<StoredAuthState?> crateApiAuthAuthLoadPersistedAuthState();
 
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskDown({
+    required String taskId,
+  });
+
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskToTop({
+    required String taskId,
+  });
+
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskUp({
+    required String taskId,
+  });
+
   Future<DriveItemSummary> crateApiDriveMoveItemMoveDriveItem({
     required String itemId,
     String? newParentId,
@@ -197,6 +305,22 @@ abstract class RustLibApi extends BaseApi {
     String? ifMatch,
   });
 
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskDown({
+    required String taskId,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskToTop({
+    required String taskId,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskUp({
+    required String taskId,
+  });
+
+  Future<void> crateApiDriveDownloadManagerOpenDownloadedFile({
+    required String taskId,
+  });
+
   Future<void> crateApiAuthAuthPersistAuthState({
     required String clientId,
     required AuthTokens tokens,
@@ -205,21 +329,107 @@ abstract class RustLibApi extends BaseApi {
   Future<StoredAuthState> crateApiAuthRefreshRefreshTokens();
 
   Future<DownloadQueueState> crateApiDriveDownloadManagerRemoveDownloadTask({
-    required String itemId,
+    required String taskId,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerRemoveUploadGroup({
+    required String groupId,
   });
 
   Future<UploadQueueState> crateApiDriveUploadManagerRemoveUploadTask({
     required String taskId,
   });
 
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerResolveDownloadConflict({
+    required String taskId,
+    required DownloadConflictPolicy policy,
+  });
+
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerRetryFailedDownloadTasks();
+
+  Future<UploadQueueState> crateApiDriveUploadManagerRetryFailedUploadTasks();
+
+  Future<UploadQueueState> crateApiDriveUploadManagerRetryUploadTask({
+    required String taskId,
+  });
+
+  Future<void> crateApiDriveDownloadManagerRevealDownloadedFile({
+    required String taskId,
+  });
+
+  Future<BandwidthSettings> crateApiSettingsBandwidthLimitSetBandwidthSettings({
+    required BandwidthSettings settings,
+  });
+
+  Future<BigInt> crateApiSettingsDiskSpaceReserveSetDiskSpaceReserveMib({
+    required BigInt reserveMib,
+  });
+
   Future<int> crateApiSettingsDownloadConcurrencySetDownloadConcurrency({
     required int limit,
   });
 
+  Future<DownloadConflictPolicy>
+  crateApiSettingsDownloadConflictPolicySetDownloadConflictPolicy({
+    required DownloadConflictPolicy policy,
+  });
+
   Future<String> crateApiSettingsDownloadDirectorySetDownloadDirectory({
     required String path,
   });
 
+  Future<bool> crateApiSettingsDownloadItemXattrSetDownloadItemXattrEnabled({
+    required bool enabled,
+  });
+
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerSetDownloadTaskPriority({
+    required String taskId,
+    required TransferPriority priority,
+  });
+
+  Future<int?> crateApiSettingsGlobalTransferLimitSetGlobalTransferLimit({
+    int? limit,
+  });
+
+  Future<NotificationSettings>
+  crateApiSettingsNotificationsSetNotificationSettings({
+    required NotificationSettings settings,
+  });
+
+  Future<List<PostDownloadHook>>
+  crateApiSettingsPostDownloadHooksSetPostDownloadHooks({
+    required List<PostDownloadHook> hooks,
+  });
+
+  Future<TransferRetryPolicy>
+  crateApiSettingsRetryPolicySetTransferRetryPolicy({
+    required TransferRetryPolicy policy,
+  });
+
+  Future<int> crateApiSettingsUploadConcurrencySetUploadConcurrency({
+    required int limit,
+  });
+
+  Future<UploadConflictBehavior>
+  crateApiSettingsUploadConflictBehaviorSetUploadConflictBehavior({
+    required UploadConflictBehavior behavior,
+  });
+
+  Future<bool>
+  crateApiSettingsUploadIntegrityReuploadSetUploadIntegrityReuploadEnabled({
+    required bool enabled,
+  });
+
+  Future<UploadQueueState> crateApiDriveUploadManagerSetUploadTaskPriority({
+    required String taskId,
+    required TransferPriority priority,
+  });
+
+  Future<TransferPriority> crateApiDriveModelsTransferPriorityDefault();
+
   Stream<UploadProgressUpdate> crateApiDriveUploadManagerUploadProgressStream();
 
   Future<UploadQueueState> crateApiDriveUploadManagerUploadQueueState();
@@ -229,9 +439,11 @@ abstract class RustLibApi extends BaseApi {
   Future<DriveItemSummary> crateApiDriveUploadUploadSmallFile({
     String? parentId,
     required String fileName,
-    required List<int> content,
+    required String localPath,
     required bool overwrite,
   });
+
+  Stream<UploadTaskEvent> crateApiDriveUploadManagerUploadTaskEventStream();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -277,19 +489,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["clientId", "scopes"],
       );
 
+  @override
+  Future<BandwidthSettings> crateApiDriveModelsBandwidthSettingsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bandwidth_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDriveModelsBandwidthSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveModelsBandwidthSettingsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "bandwidth_settings_default",
+        argNames: [],
+      );
+
   @override
   Future<DownloadQueueState> crateApiDriveDownloadManagerCancelDownloadTask({
-    required String itemId,
+    required String taskId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(itemId, serializer);
+          sse_encode_String(taskId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 3,
             port: port_,
           );
         },
@@ -298,7 +540,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_String,
         ),
         constMeta: kCrateApiDriveDownloadManagerCancelDownloadTaskConstMeta,
-        argValues: [itemId],
+        argValues: [taskId],
         apiImpl: this,
       ),
     );
@@ -307,7 +549,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDriveDownloadManagerCancelDownloadTaskConstMeta =>
       const TaskConstMeta(
         debugName: "cancel_download_task",
-        argNames: ["itemId"],
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerCancelUploadGroup({
+    required String groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerCancelUploadGroupConstMeta,
+        argValues: [groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerCancelUploadGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_upload_group",
+        argNames: ["groupId"],
       );
 
   @override
@@ -322,7 +597,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 5,
             port: port_,
           );
         },
@@ -353,7 +628,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 6,
             port: port_,
           );
         },
@@ -382,7 +657,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 7,
             port: port_,
           );
         },
@@ -414,7 +689,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 8,
             port: port_,
           );
         },
@@ -433,6 +708,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get kCrateApiDriveUploadManagerClearFailedUploadTasksConstMeta =>
       const TaskConstMeta(debugName: "clear_failed_upload_tasks", argNames: []);
 
+  @override
+  Future<List<CopyJob>> crateApiDriveCopyManagerClearFinishedCopyJobs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_copy_job,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDriveCopyManagerClearFinishedCopyJobsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveCopyManagerClearFinishedCopyJobsConstMeta =>
+      const TaskConstMeta(debugName: "clear_finished_copy_jobs", argNames: []);
+
   @override
   Future<void> crateApiAuthAuthClearPersistedAuthState() {
     return handler.executeNormal(
@@ -442,7 +744,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 10,
             port: port_,
           );
         },
@@ -472,7 +774,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 11,
             port: port_,
           );
         },
@@ -491,86 +793,224 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "clear_upload_history", argNames: []);
 
   @override
-  Future<ShareLinkResult> crateApiDriveShareCreateShareLink({
+  Future<CopyJob> crateApiDriveCopyManagerCopyDriveItem({
     required String itemId,
-    required LinkType linkType,
-    required LinkScope scope,
-    String? password,
-    String? expirationDateTime,
-    bool? retainInheritedPermissions,
-    List<String>? recipients,
+    String? targetDriveId,
+    String? targetParentId,
+    String? newName,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(itemId, serializer);
-          sse_encode_link_type(linkType, serializer);
-          sse_encode_link_scope(scope, serializer);
-          sse_encode_opt_String(password, serializer);
-          sse_encode_opt_String(expirationDateTime, serializer);
-          sse_encode_opt_box_autoadd_bool(
-            retainInheritedPermissions,
-            serializer,
-          );
-          sse_encode_opt_list_String(recipients, serializer);
+          sse_encode_opt_String(targetDriveId, serializer);
+          sse_encode_opt_String(targetParentId, serializer);
+          sse_encode_opt_String(newName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 12,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_share_link_result,
+          decodeSuccessData: sse_decode_copy_job,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveShareCreateShareLinkConstMeta,
-        argValues: [
-          itemId,
-          linkType,
-          scope,
-          password,
-          expirationDateTime,
-          retainInheritedPermissions,
-          recipients,
-        ],
+        constMeta: kCrateApiDriveCopyManagerCopyDriveItemConstMeta,
+        argValues: [itemId, targetDriveId, targetParentId, newName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveShareCreateShareLinkConstMeta =>
+  TaskConstMeta get kCrateApiDriveCopyManagerCopyDriveItemConstMeta =>
       const TaskConstMeta(
-        debugName: "create_share_link",
-        argNames: [
-          "itemId",
-          "linkType",
-          "scope",
-          "password",
-          "expirationDateTime",
-          "retainInheritedPermissions",
-          "recipients",
-        ],
+        debugName: "copy_drive_item",
+        argNames: ["itemId", "targetDriveId", "targetParentId", "newName"],
       );
 
   @override
-  Future<void> crateApiDriveDeleteDeleteDriveItem({
-    required String itemId,
-    String? ifMatch,
-    required bool bypassLocks,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(itemId, serializer);
-          sse_encode_opt_String(ifMatch, serializer);
-          sse_encode_bool(bypassLocks, serializer);
+  Stream<CopyJob> crateApiDriveCopyManagerCopyJobStream() {
+    final streamSink = RustStreamSink<CopyJob>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_copy_job_Sse(streamSink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 13,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDriveCopyManagerCopyJobStreamConstMeta,
+          argValues: [streamSink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return streamSink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDriveCopyManagerCopyJobStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "copy_job_stream",
+        argNames: ["streamSink"],
+      );
+
+  @override
+  Future<List<CopyJob>> crateApiDriveCopyManagerCopyJobs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_copy_job,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDriveCopyManagerCopyJobsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveCopyManagerCopyJobsConstMeta =>
+      const TaskConstMeta(debugName: "copy_jobs", argNames: []);
+
+  @override
+  Future<DriveItemSummary> crateApiDriveCreateFolderCreateDriveFolder({
+    String? parentId,
+    required String name,
+    required FolderConflictBehavior conflictBehavior,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_folder_conflict_behavior(conflictBehavior, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_item_summary,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveCreateFolderCreateDriveFolderConstMeta,
+        argValues: [parentId, name, conflictBehavior],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveCreateFolderCreateDriveFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_drive_folder",
+        argNames: ["parentId", "name", "conflictBehavior"],
+      );
+
+  @override
+  Future<ShareLinkResult> crateApiDriveShareCreateShareLink({
+    required String itemId,
+    required LinkType linkType,
+    required LinkScope scope,
+    String? password,
+    String? expirationDateTime,
+    bool? retainInheritedPermissions,
+    List<String>? recipients,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(itemId, serializer);
+          sse_encode_link_type(linkType, serializer);
+          sse_encode_link_scope(scope, serializer);
+          sse_encode_opt_String(password, serializer);
+          sse_encode_opt_String(expirationDateTime, serializer);
+          sse_encode_opt_box_autoadd_bool(
+            retainInheritedPermissions,
+            serializer,
+          );
+          sse_encode_opt_list_String(recipients, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_share_link_result,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveShareCreateShareLinkConstMeta,
+        argValues: [
+          itemId,
+          linkType,
+          scope,
+          password,
+          expirationDateTime,
+          retainInheritedPermissions,
+          recipients,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveShareCreateShareLinkConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_share_link",
+        argNames: [
+          "itemId",
+          "linkType",
+          "scope",
+          "password",
+          "expirationDateTime",
+          "retainInheritedPermissions",
+          "recipients",
+        ],
+      );
+
+  @override
+  Future<void> crateApiDriveDeleteDeleteDriveItem({
+    required String itemId,
+    String? ifMatch,
+    required bool bypassLocks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(itemId, serializer);
+          sse_encode_opt_String(ifMatch, serializer);
+          sse_encode_bool(bypassLocks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
             port: port_,
           );
         },
@@ -607,7 +1047,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 18,
             port: port_,
           );
         },
@@ -644,7 +1084,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 12,
+              funcId: 19,
               port: port_,
             );
           },
@@ -678,7 +1118,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 20,
             port: port_,
           );
         },
@@ -705,7 +1145,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 21,
             port: port_,
           );
         },
@@ -726,11 +1166,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Stream<DownloadTaskEvent>
+  crateApiDriveDownloadManagerDownloadTaskEventStream() {
+    final streamSink = RustStreamSink<DownloadTaskEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_download_task_event_Sse(
+              streamSink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 22,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta:
+              kCrateApiDriveDownloadManagerDownloadTaskEventStreamConstMeta,
+          argValues: [streamSink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return streamSink.stream;
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerDownloadTaskEventStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "download_task_event_stream",
+        argNames: ["streamSink"],
+      );
+
   @override
   Future<DownloadQueueState> crateApiDriveDownloadManagerEnqueueDownloadTask({
     required DriveItemSummary item,
     required String targetDir,
-    required bool overwrite,
+    DownloadConflictPolicy? conflictPolicy,
+    TransferPriority? priority,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -738,11 +1220,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_box_autoadd_drive_item_summary(item, serializer);
           sse_encode_String(targetDir, serializer);
-          sse_encode_bool(overwrite, serializer);
+          sse_encode_opt_box_autoadd_download_conflict_policy(
+            conflictPolicy,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_transfer_priority(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 23,
             port: port_,
           );
         },
@@ -751,7 +1237,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_String,
         ),
         constMeta: kCrateApiDriveDownloadManagerEnqueueDownloadTaskConstMeta,
-        argValues: [item, targetDir, overwrite],
+        argValues: [item, targetDir, conflictPolicy, priority],
         apiImpl: this,
       ),
     );
@@ -760,7 +1246,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDriveDownloadManagerEnqueueDownloadTaskConstMeta =>
       const TaskConstMeta(
         debugName: "enqueue_download_task",
-        argNames: ["item", "targetDir", "overwrite"],
+        argNames: ["item", "targetDir", "conflictPolicy", "priority"],
       );
 
   @override
@@ -769,6 +1255,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String fileName,
     required String localPath,
     required bool overwrite,
+    TransferPriority? priority,
+    UploadConflictBehavior? conflictBehavior,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -778,10 +1266,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(fileName, serializer);
           sse_encode_String(localPath, serializer);
           sse_encode_bool(overwrite, serializer);
+          sse_encode_opt_box_autoadd_transfer_priority(priority, serializer);
+          sse_encode_opt_box_autoadd_upload_conflict_behavior(
+            conflictBehavior,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 24,
             port: port_,
           );
         },
@@ -790,7 +1283,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_String,
         ),
         constMeta: kCrateApiDriveUploadManagerEnqueueLargeUploadTaskConstMeta,
-        argValues: [parentId, fileName, localPath, overwrite],
+        argValues: [
+          parentId,
+          fileName,
+          localPath,
+          overwrite,
+          priority,
+          conflictBehavior,
+        ],
         apiImpl: this,
       ),
     );
@@ -800,30 +1300,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get kCrateApiDriveUploadManagerEnqueueLargeUploadTaskConstMeta =>
       const TaskConstMeta(
         debugName: "enqueue_large_upload_task",
-        argNames: ["parentId", "fileName", "localPath", "overwrite"],
+        argNames: [
+          "parentId",
+          "fileName",
+          "localPath",
+          "overwrite",
+          "priority",
+          "conflictBehavior",
+        ],
       );
 
   @override
-  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadTask({
+  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadFolder({
     String? parentId,
-    required String fileName,
-    required String localPath,
-    required List<int> content,
-    required bool overwrite,
+    required String localDir,
+    required List<String> include,
+    required List<String> exclude,
+    UploadConflictBehavior? conflictBehavior,
+    TransferPriority? priority,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_opt_String(parentId, serializer);
-          sse_encode_String(fileName, serializer);
-          sse_encode_String(localPath, serializer);
-          sse_encode_list_prim_u_8_loose(content, serializer);
-          sse_encode_bool(overwrite, serializer);
+          sse_encode_String(localDir, serializer);
+          sse_encode_list_String(include, serializer);
+          sse_encode_list_String(exclude, serializer);
+          sse_encode_opt_box_autoadd_upload_conflict_behavior(
+            conflictBehavior,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_transfer_priority(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 25,
             port: port_,
           );
         },
@@ -831,58 +1343,311 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_upload_queue_state,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveUploadManagerEnqueueUploadTaskConstMeta,
-        argValues: [parentId, fileName, localPath, content, overwrite],
+        constMeta: kCrateApiDriveUploadManagerEnqueueUploadFolderConstMeta,
+        argValues: [
+          parentId,
+          localDir,
+          include,
+          exclude,
+          conflictBehavior,
+          priority,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveUploadManagerEnqueueUploadTaskConstMeta =>
+  TaskConstMeta get kCrateApiDriveUploadManagerEnqueueUploadFolderConstMeta =>
       const TaskConstMeta(
-        debugName: "enqueue_upload_task",
-        argNames: ["parentId", "fileName", "localPath", "content", "overwrite"],
+        debugName: "enqueue_upload_folder",
+        argNames: [
+          "parentId",
+          "localDir",
+          "include",
+          "exclude",
+          "conflictBehavior",
+          "priority",
+        ],
       );
 
   @override
-  Future<int> crateApiSettingsDownloadConcurrencyGetDownloadConcurrency() {
+  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadFromUrl({
+    String? parentId,
+    required String sourceUrl,
+    String? fileName,
+    UploadConflictBehavior? conflictBehavior,
+    TransferPriority? priority,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_String(sourceUrl, serializer);
+          sse_encode_opt_String(fileName, serializer);
+          sse_encode_opt_box_autoadd_upload_conflict_behavior(
+            conflictBehavior,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_transfer_priority(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+          decodeSuccessData: sse_decode_upload_queue_state,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta:
-            kCrateApiSettingsDownloadConcurrencyGetDownloadConcurrencyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiDriveUploadManagerEnqueueUploadFromUrlConstMeta,
+        argValues: [parentId, sourceUrl, fileName, conflictBehavior, priority],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiSettingsDownloadConcurrencyGetDownloadConcurrencyConstMeta =>
-      const TaskConstMeta(debugName: "get_download_concurrency", argNames: []);
+  TaskConstMeta get kCrateApiDriveUploadManagerEnqueueUploadFromUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "enqueue_upload_from_url",
+        argNames: [
+          "parentId",
+          "sourceUrl",
+          "fileName",
+          "conflictBehavior",
+          "priority",
+        ],
+      );
 
   @override
-  Future<String> crateApiSettingsDownloadDirectoryGetDownloadDirectory() {
+  Future<UploadQueueState> crateApiDriveUploadManagerEnqueueUploadTask({
+    String? parentId,
+    required String fileName,
+    required String localPath,
+    required bool overwrite,
+    TransferPriority? priority,
+    UploadConflictBehavior? conflictBehavior,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_String(fileName, serializer);
+          sse_encode_String(localPath, serializer);
+          sse_encode_bool(overwrite, serializer);
+          sse_encode_opt_box_autoadd_transfer_priority(priority, serializer);
+          sse_encode_opt_box_autoadd_upload_conflict_behavior(
+            conflictBehavior,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerEnqueueUploadTaskConstMeta,
+        argValues: [
+          parentId,
+          fileName,
+          localPath,
+          overwrite,
+          priority,
+          conflictBehavior,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerEnqueueUploadTaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "enqueue_upload_task",
+        argNames: [
+          "parentId",
+          "fileName",
+          "localPath",
+          "overwrite",
+          "priority",
+          "conflictBehavior",
+        ],
+      );
+
+  @override
+  Future<DriveItemSummary> crateApiDriveCreateFolderEnsureDriveFolderPath({
+    String? parentId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_item_summary,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveCreateFolderEnsureDriveFolderPathConstMeta,
+        argValues: [parentId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveCreateFolderEnsureDriveFolderPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "ensure_drive_folder_path",
+        argNames: ["parentId", "path"],
+      );
+
+  @override
+  Future<BandwidthSettings>
+  crateApiSettingsBandwidthLimitGetBandwidthSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bandwidth_settings,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSettingsBandwidthLimitGetBandwidthSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsBandwidthLimitGetBandwidthSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_bandwidth_settings", argNames: []);
+
+  @override
+  Future<BigInt> crateApiSettingsDiskSpaceReserveGetDiskSpaceReserveMib() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDiskSpaceReserveGetDiskSpaceReserveMibConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDiskSpaceReserveGetDiskSpaceReserveMibConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_disk_space_reserve_mib",
+        argNames: [],
+      );
+
+  @override
+  Future<int> crateApiSettingsDownloadConcurrencyGetDownloadConcurrency() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDownloadConcurrencyGetDownloadConcurrencyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDownloadConcurrencyGetDownloadConcurrencyConstMeta =>
+      const TaskConstMeta(debugName: "get_download_concurrency", argNames: []);
+
+  @override
+  Future<DownloadConflictPolicy>
+  crateApiSettingsDownloadConflictPolicyGetDownloadConflictPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_conflict_policy,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDownloadConflictPolicyGetDownloadConflictPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDownloadConflictPolicyGetDownloadConflictPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_conflict_policy",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiSettingsDownloadDirectoryGetDownloadDirectory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 33,
             port: port_,
           );
         },
@@ -903,442 +1668,1605 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "get_download_directory", argNames: []);
 
   @override
-  Future<DriveItemDetails> crateApiDriveDetailsGetDriveItemDetails({
-    required String itemId,
+  Future<bool> crateApiSettingsDownloadItemXattrGetDownloadItemXattrEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDownloadItemXattrGetDownloadItemXattrEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDownloadItemXattrGetDownloadItemXattrEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download_item_xattr_enabled",
+        argNames: [],
+      );
+
+  @override
+  Future<DriveItemDetails> crateApiDriveDetailsGetDriveItemDetails({
+    required String itemId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(itemId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_item_details,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDetailsGetDriveItemDetailsConstMeta,
+        argValues: [itemId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveDetailsGetDriveItemDetailsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_drive_item_details",
+        argNames: ["itemId"],
+      );
+
+  @override
+  Future<DriveInfo> crateApiDriveInfoGetDriveOverview() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveInfoGetDriveOverviewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveInfoGetDriveOverviewConstMeta =>
+      const TaskConstMeta(debugName: "get_drive_overview", argNames: []);
+
+  @override
+  Future<int?> crateApiSettingsGlobalTransferLimitGetGlobalTransferLimit() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsGlobalTransferLimitGetGlobalTransferLimitConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsGlobalTransferLimitGetGlobalTransferLimitConstMeta =>
+      const TaskConstMeta(debugName: "get_global_transfer_limit", argNames: []);
+
+  @override
+  Future<NotificationSettings>
+  crateApiSettingsNotificationsGetNotificationSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_notification_settings,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsNotificationsGetNotificationSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsNotificationsGetNotificationSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_notification_settings", argNames: []);
+
+  @override
+  Future<List<PostDownloadHook>>
+  crateApiSettingsPostDownloadHooksGetPostDownloadHooks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_post_download_hook,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsPostDownloadHooksGetPostDownloadHooksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsPostDownloadHooksGetPostDownloadHooksConstMeta =>
+      const TaskConstMeta(debugName: "get_post_download_hooks", argNames: []);
+
+  @override
+  Future<ShareCapabilities> crateApiDriveShareGetShareCapabilities() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_share_capabilities,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveShareGetShareCapabilitiesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveShareGetShareCapabilitiesConstMeta =>
+      const TaskConstMeta(debugName: "get_share_capabilities", argNames: []);
+
+  @override
+  Future<TransferRetryPolicy>
+  crateApiSettingsRetryPolicyGetTransferRetryPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transfer_retry_policy,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSettingsRetryPolicyGetTransferRetryPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsRetryPolicyGetTransferRetryPolicyConstMeta =>
+      const TaskConstMeta(debugName: "get_transfer_retry_policy", argNames: []);
+
+  @override
+  Future<int> crateApiSettingsUploadConcurrencyGetUploadConcurrency() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsUploadConcurrencyGetUploadConcurrencyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsUploadConcurrencyGetUploadConcurrencyConstMeta =>
+      const TaskConstMeta(debugName: "get_upload_concurrency", argNames: []);
+
+  @override
+  Future<UploadConflictBehavior>
+  crateApiSettingsUploadConflictBehaviorGetUploadConflictBehavior() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_conflict_behavior,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsUploadConflictBehaviorGetUploadConflictBehaviorConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsUploadConflictBehaviorGetUploadConflictBehaviorConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_upload_conflict_behavior",
+        argNames: [],
+      );
+
+  @override
+  Future<bool>
+  crateApiSettingsUploadIntegrityReuploadGetUploadIntegrityReuploadEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsUploadIntegrityReuploadGetUploadIntegrityReuploadEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsUploadIntegrityReuploadGetUploadIntegrityReuploadEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_upload_integrity_reupload_enabled",
+        argNames: [],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<DrivePage> crateApiDriveListListDriveChildren({
+    String? folderId,
+    String? folderPath,
+    String? nextLink,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(folderId, serializer);
+          sse_encode_opt_String(folderPath, serializer);
+          sse_encode_opt_String(nextLink, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_page,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveListListDriveChildrenConstMeta,
+        argValues: [folderId, folderPath, nextLink],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveListListDriveChildrenConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_drive_children",
+        argNames: ["folderId", "folderPath", "nextLink"],
+      );
+
+  @override
+  Future<StoredAuthState?> crateApiAuthAuthLoadPersistedAuthState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stored_auth_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAuthAuthLoadPersistedAuthStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAuthAuthLoadPersistedAuthStateConstMeta =>
+      const TaskConstMeta(debugName: "load_persisted_auth_state", argNames: []);
+
+  @override
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskDown({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerMoveDownloadTaskDownConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerMoveDownloadTaskDownConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_download_task_down",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskToTop({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerMoveDownloadTaskToTopConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerMoveDownloadTaskToTopConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_download_task_to_top",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<DownloadQueueState> crateApiDriveDownloadManagerMoveDownloadTaskUp({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerMoveDownloadTaskUpConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveDownloadManagerMoveDownloadTaskUpConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_download_task_up",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<DriveItemSummary> crateApiDriveMoveItemMoveDriveItem({
+    required String itemId,
+    String? newParentId,
+    String? newName,
+    String? ifMatch,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(itemId, serializer);
+          sse_encode_opt_String(newParentId, serializer);
+          sse_encode_opt_String(newName, serializer);
+          sse_encode_opt_String(ifMatch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_drive_item_summary,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveMoveItemMoveDriveItemConstMeta,
+        argValues: [itemId, newParentId, newName, ifMatch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveMoveItemMoveDriveItemConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_drive_item",
+        argNames: ["itemId", "newParentId", "newName", "ifMatch"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskDown({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerMoveUploadTaskDownConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerMoveUploadTaskDownConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_upload_task_down",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskToTop({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerMoveUploadTaskToTopConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerMoveUploadTaskToTopConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_upload_task_to_top",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerMoveUploadTaskUp({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerMoveUploadTaskUpConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerMoveUploadTaskUpConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_upload_task_up",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<void> crateApiDriveDownloadManagerOpenDownloadedFile({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerOpenDownloadedFileConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveDownloadManagerOpenDownloadedFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_downloaded_file",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<void> crateApiAuthAuthPersistAuthState({
+    required String clientId,
+    required AuthTokens tokens,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(clientId, serializer);
+          sse_encode_box_autoadd_auth_tokens(tokens, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAuthAuthPersistAuthStateConstMeta,
+        argValues: [clientId, tokens],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAuthAuthPersistAuthStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "persist_auth_state",
+        argNames: ["clientId", "tokens"],
+      );
+
+  @override
+  Future<StoredAuthState> crateApiAuthRefreshRefreshTokens() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_stored_auth_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiAuthRefreshRefreshTokensConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAuthRefreshRefreshTokensConstMeta =>
+      const TaskConstMeta(debugName: "refresh_tokens", argNames: []);
+
+  @override
+  Future<DownloadQueueState> crateApiDriveDownloadManagerRemoveDownloadTask({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerRemoveDownloadTaskConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveDownloadManagerRemoveDownloadTaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_download_task",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerRemoveUploadGroup({
+    required String groupId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerRemoveUploadGroupConstMeta,
+        argValues: [groupId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerRemoveUploadGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_upload_group",
+        argNames: ["groupId"],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerRemoveUploadTask({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerRemoveUploadTaskConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerRemoveUploadTaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_upload_task",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerResolveDownloadConflict({
+    required String taskId,
+    required DownloadConflictPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          sse_encode_download_conflict_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiDriveDownloadManagerResolveDownloadConflictConstMeta,
+        argValues: [taskId, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerResolveDownloadConflictConstMeta =>
+      const TaskConstMeta(
+        debugName: "resolve_download_conflict",
+        argNames: ["taskId", "policy"],
+      );
+
+  @override
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerRetryFailedDownloadTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiDriveDownloadManagerRetryFailedDownloadTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerRetryFailedDownloadTasksConstMeta =>
+      const TaskConstMeta(
+        debugName: "retry_failed_download_tasks",
+        argNames: [],
+      );
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerRetryFailedUploadTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerRetryFailedUploadTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveUploadManagerRetryFailedUploadTasksConstMeta =>
+      const TaskConstMeta(debugName: "retry_failed_upload_tasks", argNames: []);
+
+  @override
+  Future<UploadQueueState> crateApiDriveUploadManagerRetryUploadTask({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveUploadManagerRetryUploadTaskConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerRetryUploadTaskConstMeta =>
+      const TaskConstMeta(debugName: "retry_upload_task", argNames: ["taskId"]);
+
+  @override
+  Future<void> crateApiDriveDownloadManagerRevealDownloadedFile({
+    required String taskId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiDriveDownloadManagerRevealDownloadedFileConstMeta,
+        argValues: [taskId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerRevealDownloadedFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "reveal_downloaded_file",
+        argNames: ["taskId"],
+      );
+
+  @override
+  Future<BandwidthSettings> crateApiSettingsBandwidthLimitSetBandwidthSettings({
+    required BandwidthSettings settings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_bandwidth_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bandwidth_settings,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiSettingsBandwidthLimitSetBandwidthSettingsConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsBandwidthLimitSetBandwidthSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_bandwidth_settings",
+        argNames: ["settings"],
+      );
+
+  @override
+  Future<BigInt> crateApiSettingsDiskSpaceReserveSetDiskSpaceReserveMib({
+    required BigInt reserveMib,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(reserveMib, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDiskSpaceReserveSetDiskSpaceReserveMibConstMeta,
+        argValues: [reserveMib],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDiskSpaceReserveSetDiskSpaceReserveMibConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_disk_space_reserve_mib",
+        argNames: ["reserveMib"],
+      );
+
+  @override
+  Future<int> crateApiSettingsDownloadConcurrencySetDownloadConcurrency({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiSettingsDownloadConcurrencySetDownloadConcurrencyConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiSettingsDownloadConcurrencySetDownloadConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_concurrency",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<DownloadConflictPolicy>
+  crateApiSettingsDownloadConflictPolicySetDownloadConflictPolicy({
+    required DownloadConflictPolicy policy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(itemId, serializer);
+          sse_encode_download_conflict_policy(policy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 70,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_drive_item_details,
+          decodeSuccessData: sse_decode_download_conflict_policy,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveDetailsGetDriveItemDetailsConstMeta,
-        argValues: [itemId],
+        constMeta:
+            kCrateApiSettingsDownloadConflictPolicySetDownloadConflictPolicyConstMeta,
+        argValues: [policy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveDetailsGetDriveItemDetailsConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsDownloadConflictPolicySetDownloadConflictPolicyConstMeta =>
       const TaskConstMeta(
-        debugName: "get_drive_item_details",
-        argNames: ["itemId"],
+        debugName: "set_download_conflict_policy",
+        argNames: ["policy"],
       );
 
   @override
-  Future<DriveInfo> crateApiDriveInfoGetDriveOverview() {
+  Future<String> crateApiSettingsDownloadDirectorySetDownloadDirectory({
+    required String path,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 71,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_drive_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveInfoGetDriveOverviewConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiSettingsDownloadDirectorySetDownloadDirectoryConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveInfoGetDriveOverviewConstMeta =>
-      const TaskConstMeta(debugName: "get_drive_overview", argNames: []);
+  TaskConstMeta
+  get kCrateApiSettingsDownloadDirectorySetDownloadDirectoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_directory",
+        argNames: ["path"],
+      );
 
   @override
-  Future<ShareCapabilities> crateApiDriveShareGetShareCapabilities() {
+  Future<bool> crateApiSettingsDownloadItemXattrSetDownloadItemXattrEnabled({
+    required bool enabled,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 72,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_share_capabilities,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveShareGetShareCapabilitiesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDriveShareGetShareCapabilitiesConstMeta =>
-      const TaskConstMeta(debugName: "get_share_capabilities", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta:
+            kCrateApiSettingsDownloadItemXattrSetDownloadItemXattrEnabledConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta
+  get kCrateApiSettingsDownloadItemXattrSetDownloadItemXattrEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_item_xattr_enabled",
+        argNames: ["enabled"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<DownloadQueueState>
+  crateApiDriveDownloadManagerSetDownloadTaskPriority({
+    required String taskId,
+    required TransferPriority priority,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(taskId, serializer);
+          sse_encode_transfer_priority(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 73,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_download_queue_state,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiDriveDownloadManagerSetDownloadTaskPriorityConstMeta,
+        argValues: [taskId, priority],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta
+  get kCrateApiDriveDownloadManagerSetDownloadTaskPriorityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_download_task_priority",
+        argNames: ["taskId", "priority"],
+      );
 
   @override
-  Future<DrivePage> crateApiDriveListListDriveChildren({
-    String? folderId,
-    String? folderPath,
-    String? nextLink,
+  Future<int?> crateApiSettingsGlobalTransferLimitSetGlobalTransferLimit({
+    int? limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(folderId, serializer);
-          sse_encode_opt_String(folderPath, serializer);
-          sse_encode_opt_String(nextLink, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 74,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_drive_page,
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveListListDriveChildrenConstMeta,
-        argValues: [folderId, folderPath, nextLink],
+        constMeta:
+            kCrateApiSettingsGlobalTransferLimitSetGlobalTransferLimitConstMeta,
+        argValues: [limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveListListDriveChildrenConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsGlobalTransferLimitSetGlobalTransferLimitConstMeta =>
       const TaskConstMeta(
-        debugName: "list_drive_children",
-        argNames: ["folderId", "folderPath", "nextLink"],
+        debugName: "set_global_transfer_limit",
+        argNames: ["limit"],
       );
 
   @override
-  Future<StoredAuthState?> crateApiAuthAuthLoadPersistedAuthState() {
+  Future<NotificationSettings>
+  crateApiSettingsNotificationsSetNotificationSettings({
+    required NotificationSettings settings,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_notification_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 75,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_stored_auth_state,
+          decodeSuccessData: sse_decode_notification_settings,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAuthAuthLoadPersistedAuthStateConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiSettingsNotificationsSetNotificationSettingsConstMeta,
+        argValues: [settings],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAuthAuthLoadPersistedAuthStateConstMeta =>
-      const TaskConstMeta(debugName: "load_persisted_auth_state", argNames: []);
+  TaskConstMeta
+  get kCrateApiSettingsNotificationsSetNotificationSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_notification_settings",
+        argNames: ["settings"],
+      );
 
   @override
-  Future<DriveItemSummary> crateApiDriveMoveItemMoveDriveItem({
-    required String itemId,
-    String? newParentId,
-    String? newName,
-    String? ifMatch,
+  Future<List<PostDownloadHook>>
+  crateApiSettingsPostDownloadHooksSetPostDownloadHooks({
+    required List<PostDownloadHook> hooks,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(itemId, serializer);
-          sse_encode_opt_String(newParentId, serializer);
-          sse_encode_opt_String(newName, serializer);
-          sse_encode_opt_String(ifMatch, serializer);
+          sse_encode_list_post_download_hook(hooks, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 76,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_drive_item_summary,
+          decodeSuccessData: sse_decode_list_post_download_hook,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveMoveItemMoveDriveItemConstMeta,
-        argValues: [itemId, newParentId, newName, ifMatch],
+        constMeta:
+            kCrateApiSettingsPostDownloadHooksSetPostDownloadHooksConstMeta,
+        argValues: [hooks],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveMoveItemMoveDriveItemConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsPostDownloadHooksSetPostDownloadHooksConstMeta =>
       const TaskConstMeta(
-        debugName: "move_drive_item",
-        argNames: ["itemId", "newParentId", "newName", "ifMatch"],
+        debugName: "set_post_download_hooks",
+        argNames: ["hooks"],
       );
 
   @override
-  Future<void> crateApiAuthAuthPersistAuthState({
-    required String clientId,
-    required AuthTokens tokens,
+  Future<TransferRetryPolicy>
+  crateApiSettingsRetryPolicySetTransferRetryPolicy({
+    required TransferRetryPolicy policy,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(clientId, serializer);
-          sse_encode_box_autoadd_auth_tokens(tokens, serializer);
+          sse_encode_box_autoadd_transfer_retry_policy(policy, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 77,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_transfer_retry_policy,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAuthAuthPersistAuthStateConstMeta,
-        argValues: [clientId, tokens],
+        constMeta: kCrateApiSettingsRetryPolicySetTransferRetryPolicyConstMeta,
+        argValues: [policy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAuthAuthPersistAuthStateConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsRetryPolicySetTransferRetryPolicyConstMeta =>
       const TaskConstMeta(
-        debugName: "persist_auth_state",
-        argNames: ["clientId", "tokens"],
+        debugName: "set_transfer_retry_policy",
+        argNames: ["policy"],
       );
 
   @override
-  Future<StoredAuthState> crateApiAuthRefreshRefreshTokens() {
+  Future<int> crateApiSettingsUploadConcurrencySetUploadConcurrency({
+    required int limit,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 78,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_stored_auth_state,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAuthRefreshRefreshTokensConstMeta,
-        argValues: [],
+        constMeta:
+            kCrateApiSettingsUploadConcurrencySetUploadConcurrencyConstMeta,
+        argValues: [limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAuthRefreshRefreshTokensConstMeta =>
-      const TaskConstMeta(debugName: "refresh_tokens", argNames: []);
+  TaskConstMeta
+  get kCrateApiSettingsUploadConcurrencySetUploadConcurrencyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_upload_concurrency",
+        argNames: ["limit"],
+      );
 
   @override
-  Future<DownloadQueueState> crateApiDriveDownloadManagerRemoveDownloadTask({
-    required String itemId,
+  Future<UploadConflictBehavior>
+  crateApiSettingsUploadConflictBehaviorSetUploadConflictBehavior({
+    required UploadConflictBehavior behavior,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(itemId, serializer);
+          sse_encode_upload_conflict_behavior(behavior, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 79,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_download_queue_state,
+          decodeSuccessData: sse_decode_upload_conflict_behavior,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveDownloadManagerRemoveDownloadTaskConstMeta,
-        argValues: [itemId],
+        constMeta:
+            kCrateApiSettingsUploadConflictBehaviorSetUploadConflictBehaviorConstMeta,
+        argValues: [behavior],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveDownloadManagerRemoveDownloadTaskConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsUploadConflictBehaviorSetUploadConflictBehaviorConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_download_task",
-        argNames: ["itemId"],
+        debugName: "set_upload_conflict_behavior",
+        argNames: ["behavior"],
       );
 
   @override
-  Future<UploadQueueState> crateApiDriveUploadManagerRemoveUploadTask({
-    required String taskId,
+  Future<bool>
+  crateApiSettingsUploadIntegrityReuploadSetUploadIntegrityReuploadEnabled({
+    required bool enabled,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(taskId, serializer);
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 80,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_upload_queue_state,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiDriveUploadManagerRemoveUploadTaskConstMeta,
-        argValues: [taskId],
+        constMeta:
+            kCrateApiSettingsUploadIntegrityReuploadSetUploadIntegrityReuploadEnabledConstMeta,
+        argValues: [enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveUploadManagerRemoveUploadTaskConstMeta =>
+  TaskConstMeta
+  get kCrateApiSettingsUploadIntegrityReuploadSetUploadIntegrityReuploadEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_upload_task",
-        argNames: ["taskId"],
+        debugName: "set_upload_integrity_reupload_enabled",
+        argNames: ["enabled"],
       );
 
   @override
-  Future<int> crateApiSettingsDownloadConcurrencySetDownloadConcurrency({
-    required int limit,
+  Future<UploadQueueState> crateApiDriveUploadManagerSetUploadTaskPriority({
+    required String taskId,
+    required TransferPriority priority,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(limit, serializer);
+          sse_encode_String(taskId, serializer);
+          sse_encode_transfer_priority(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 81,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+          decodeSuccessData: sse_decode_upload_queue_state,
           decodeErrorData: sse_decode_String,
         ),
-        constMeta:
-            kCrateApiSettingsDownloadConcurrencySetDownloadConcurrencyConstMeta,
-        argValues: [limit],
+        constMeta: kCrateApiDriveUploadManagerSetUploadTaskPriorityConstMeta,
+        argValues: [taskId, priority],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiSettingsDownloadConcurrencySetDownloadConcurrencyConstMeta =>
+  TaskConstMeta get kCrateApiDriveUploadManagerSetUploadTaskPriorityConstMeta =>
       const TaskConstMeta(
-        debugName: "set_download_concurrency",
-        argNames: ["limit"],
+        debugName: "set_upload_task_priority",
+        argNames: ["taskId", "priority"],
       );
 
   @override
-  Future<String> crateApiSettingsDownloadDirectorySetDownloadDirectory({
-    required String path,
-  }) {
+  Future<TransferPriority> crateApiDriveModelsTransferPriorityDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 82,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_transfer_priority,
+          decodeErrorData: null,
         ),
-        constMeta:
-            kCrateApiSettingsDownloadDirectorySetDownloadDirectoryConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiDriveModelsTransferPriorityDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kCrateApiSettingsDownloadDirectorySetDownloadDirectoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_download_directory",
-        argNames: ["path"],
-      );
+  TaskConstMeta get kCrateApiDriveModelsTransferPriorityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "transfer_priority_default", argNames: []);
 
   @override
   Stream<UploadProgressUpdate>
@@ -1356,7 +3284,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 34,
+              funcId: 83,
               port: port_,
             );
           },
@@ -1388,7 +3316,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 84,
             port: port_,
           );
         },
@@ -1415,7 +3343,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 85,
             port: port_,
           );
         },
@@ -1440,7 +3368,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<DriveItemSummary> crateApiDriveUploadUploadSmallFile({
     String? parentId,
     required String fileName,
-    required List<int> content,
+    required String localPath,
     required bool overwrite,
   }) {
     return handler.executeNormal(
@@ -1449,12 +3377,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_opt_String(parentId, serializer);
           sse_encode_String(fileName, serializer);
-          sse_encode_list_prim_u_8_loose(content, serializer);
+          sse_encode_String(localPath, serializer);
           sse_encode_bool(overwrite, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 86,
             port: port_,
           );
         },
@@ -1463,16 +3391,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_String,
         ),
         constMeta: kCrateApiDriveUploadUploadSmallFileConstMeta,
-        argValues: [parentId, fileName, content, overwrite],
+        argValues: [parentId, fileName, localPath, overwrite],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDriveUploadUploadSmallFileConstMeta =>
+  TaskConstMeta get kCrateApiDriveUploadUploadSmallFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "upload_small_file",
+        argNames: ["parentId", "fileName", "localPath", "overwrite"],
+      );
+
+  @override
+  Stream<UploadTaskEvent> crateApiDriveUploadManagerUploadTaskEventStream() {
+    final streamSink = RustStreamSink<UploadTaskEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_upload_task_event_Sse(streamSink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 87,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiDriveUploadManagerUploadTaskEventStreamConstMeta,
+          argValues: [streamSink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return streamSink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDriveUploadManagerUploadTaskEventStreamConstMeta =>
       const TaskConstMeta(
-        debugName: "upload_small_file",
-        argNames: ["parentId", "fileName", "content", "overwrite"],
+        debugName: "upload_task_event_stream",
+        argNames: ["streamSink"],
       );
 
   @protected
@@ -1481,6 +3444,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(raw as String);
   }
 
+  @protected
+  RustStreamSink<CopyJob> dco_decode_StreamSink_copy_job_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<DownloadProgressUpdate>
   dco_decode_StreamSink_download_progress_update_Sse(dynamic raw) {
@@ -1488,6 +3457,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<DownloadTaskEvent>
+  dco_decode_StreamSink_download_task_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<UploadProgressUpdate>
   dco_decode_StreamSink_upload_progress_update_Sse(dynamic raw) {
@@ -1495,6 +3471,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<UploadTaskEvent> dco_decode_StreamSink_upload_task_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1517,6 +3501,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BandwidthSchedule dco_decode_bandwidth_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BandwidthSchedule(
+      startMinute: dco_decode_u_32(arr[0]),
+      endMinute: dco_decode_u_32(arr[1]),
+      globalKibPerSec: dco_decode_u_64(arr[2]),
+      downloadKibPerSec: dco_decode_u_64(arr[3]),
+      uploadKibPerSec: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  BandwidthSettings dco_decode_bandwidth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BandwidthSettings(
+      globalKibPerSec: dco_decode_u_64(arr[0]),
+      downloadKibPerSec: dco_decode_u_64(arr[1]),
+      uploadKibPerSec: dco_decode_u_64(arr[2]),
+      schedules: dco_decode_list_bandwidth_schedule(arr[3]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1529,12 +3542,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_auth_tokens(raw);
   }
 
+  @protected
+  BandwidthSettings dco_decode_box_autoadd_bandwidth_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bandwidth_settings(raw);
+  }
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  DownloadConflictOutcome dco_decode_box_autoadd_download_conflict_outcome(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_download_conflict_outcome(raw);
+  }
+
+  @protected
+  DownloadConflictPolicy dco_decode_box_autoadd_download_conflict_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_download_conflict_policy(raw);
+  }
+
   @protected
   DriveItemSummary dco_decode_box_autoadd_drive_item_summary(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1565,30 +3600,116 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_i_64(raw);
   }
 
+  @protected
+  NotificationSettings dco_decode_box_autoadd_notification_settings(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_notification_settings(raw);
+  }
+
   @protected
   StoredAuthState dco_decode_box_autoadd_stored_auth_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_stored_auth_state(raw);
   }
 
+  @protected
+  TransferFailureReason dco_decode_box_autoadd_transfer_failure_reason(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transfer_failure_reason(raw);
+  }
+
+  @protected
+  TransferPriority dco_decode_box_autoadd_transfer_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transfer_priority(raw);
+  }
+
+  @protected
+  TransferRetryPolicy dco_decode_box_autoadd_transfer_retry_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transfer_retry_policy(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  UploadConflictBehavior dco_decode_box_autoadd_upload_conflict_behavior(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_upload_conflict_behavior(raw);
+  }
+
+  @protected
+  CopyJob dco_decode_copy_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return CopyJob(
+      jobId: dco_decode_String(arr[0]),
+      sourceItemId: dco_decode_String(arr[1]),
+      itemName: dco_decode_String(arr[2]),
+      targetDriveId: dco_decode_opt_String(arr[3]),
+      targetParentId: dco_decode_opt_String(arr[4]),
+      newName: dco_decode_opt_String(arr[5]),
+      monitorUrl: dco_decode_String(arr[6]),
+      status: dco_decode_copy_job_status(arr[7]),
+      percentageComplete: dco_decode_opt_box_autoadd_f_64(arr[8]),
+      newItemId: dco_decode_opt_String(arr[9]),
+      errorMessage: dco_decode_opt_String(arr[10]),
+      startedAt: dco_decode_i_64(arr[11]),
+      completedAt: dco_decode_opt_box_autoadd_i_64(arr[12]),
+    );
+  }
+
+  @protected
+  CopyJobStatus dco_decode_copy_job_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CopyJobStatus.values[raw as int];
+  }
+
+  @protected
+  DownloadConflictOutcome dco_decode_download_conflict_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DownloadConflictOutcome.values[raw as int];
+  }
+
+  @protected
+  DownloadConflictPolicy dco_decode_download_conflict_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DownloadConflictPolicy.values[raw as int];
+  }
+
   @protected
   DownloadProgressUpdate dco_decode_download_progress_update(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return DownloadProgressUpdate(
-      itemId: dco_decode_String(arr[0]),
-      bytesDownloaded: dco_decode_u_64(arr[1]),
-      expectedSize: dco_decode_opt_box_autoadd_u_64(arr[2]),
-      speedBps: dco_decode_opt_box_autoadd_f_64(arr[3]),
-      timestampMillis: dco_decode_i_64(arr[4]),
+      taskId: dco_decode_String(arr[0]),
+      itemId: dco_decode_String(arr[1]),
+      bytesDownloaded: dco_decode_u_64(arr[2]),
+      expectedSize: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      speedBps: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      timestampMillis: dco_decode_i_64(arr[5]),
     );
   }
 
@@ -1615,17 +3736,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   DownloadTask dco_decode_download_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
     return DownloadTask(
-      item: dco_decode_drive_item_summary(arr[0]),
-      status: dco_decode_download_status(arr[1]),
-      startedAt: dco_decode_i_64(arr[2]),
-      completedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
-      savedPath: dco_decode_opt_String(arr[4]),
-      sizeLabel: dco_decode_opt_box_autoadd_u_64(arr[5]),
-      bytesDownloaded: dco_decode_opt_box_autoadd_u_64(arr[6]),
-      errorMessage: dco_decode_opt_String(arr[7]),
+      taskId: dco_decode_String(arr[0]),
+      item: dco_decode_drive_item_summary(arr[1]),
+      status: dco_decode_download_status(arr[2]),
+      startedAt: dco_decode_i_64(arr[3]),
+      completedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      savedPath: dco_decode_opt_String(arr[5]),
+      sizeLabel: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      bytesDownloaded: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      errorMessage: dco_decode_opt_String(arr[8]),
+      targetDir: dco_decode_String(arr[9]),
+      conflictPolicy: dco_decode_download_conflict_policy(arr[10]),
+      conflictOutcome:
+          dco_decode_opt_box_autoadd_download_conflict_outcome(arr[11]),
+      retryCount: dco_decode_u_32(arr[12]),
+      priority: dco_decode_transfer_priority(arr[13]),
+      failureReason:
+          dco_decode_opt_box_autoadd_transfer_failure_reason(arr[14]),
+      hookOutcomes: dco_decode_list_post_download_hook_outcome(arr[15]),
+    );
+  }
+
+  @protected
+  DownloadTaskEvent dco_decode_download_task_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DownloadTaskEvent(
+      kind: dco_decode_transfer_event_kind(arr[0]),
+      task: dco_decode_download_task(arr[1]),
+      timestampMillis: dco_decode_i_64(arr[2]),
     );
   }
 
@@ -1633,13 +3777,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   DriveDownloadResult dco_decode_drive_download_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return DriveDownloadResult(
       fileName: dco_decode_String(arr[0]),
       savedPath: dco_decode_String(arr[1]),
       bytesDownloaded: dco_decode_u_64(arr[2]),
       expectedSize: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      conflictOutcome: dco_decode_download_conflict_outcome(arr[4]),
     );
   }
 
@@ -1746,6 +3891,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  FolderConflictBehavior dco_decode_folder_conflict_behavior(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FolderConflictBehavior.values[raw as int];
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1776,6 +3927,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<BandwidthSchedule> dco_decode_list_bandwidth_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_bandwidth_schedule).toList();
+  }
+
+  @protected
+  List<CopyJob> dco_decode_list_copy_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_copy_job).toList();
+  }
+
   @protected
   List<DownloadTask> dco_decode_list_download_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1789,9 +3952,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+  List<PostDownloadHook> dco_decode_list_post_download_hook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_post_download_hook).toList();
+  }
+
+  @protected
+  List<PostDownloadHookOutcome> dco_decode_list_post_download_hook_outcome(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+    return (raw as List<dynamic>)
+        .map(dco_decode_post_download_hook_outcome)
+        .toList();
   }
 
   @protected
@@ -1800,12 +3973,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as Uint8List;
   }
 
+  @protected
+  List<UploadGroup> dco_decode_list_upload_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_upload_group).toList();
+  }
+
   @protected
   List<UploadTask> dco_decode_list_upload_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_upload_task).toList();
   }
 
+  @protected
+  NotificationSettings dco_decode_notification_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return NotificationSettings(
+      enabled: dco_decode_bool(arr[0]),
+      notifyOnTaskCompleted: dco_decode_bool(arr[1]),
+      notifyOnTaskFailed: dco_decode_bool(arr[2]),
+      notifyOnBatchFinished: dco_decode_bool(arr[3]),
+      quietHoursEnabled: dco_decode_bool(arr[4]),
+      quietHoursStartMinute: dco_decode_u_32(arr[5]),
+      quietHoursEndMinute: dco_decode_u_32(arr[6]),
+    );
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1818,6 +4014,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_bool(raw);
   }
 
+  @protected
+  DownloadConflictOutcome? dco_decode_opt_box_autoadd_download_conflict_outcome(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_download_conflict_outcome(raw);
+  }
+
+  @protected
+  DownloadConflictPolicy? dco_decode_opt_box_autoadd_download_conflict_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_download_conflict_policy(raw);
+  }
+
   @protected
   DriveOwner? dco_decode_opt_box_autoadd_drive_owner(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1848,18 +4064,85 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_stored_auth_state(raw);
   }
 
+  @protected
+  TransferFailureReason? dco_decode_opt_box_autoadd_transfer_failure_reason(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_transfer_failure_reason(raw);
+  }
+
+  @protected
+  TransferPriority? dco_decode_opt_box_autoadd_transfer_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_transfer_priority(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  UploadConflictBehavior? dco_decode_opt_box_autoadd_upload_conflict_behavior(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_upload_conflict_behavior(raw);
+  }
+
   @protected
   List<String>? dco_decode_opt_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_list_String(raw);
   }
 
+  @protected
+  PostDownloadHook dco_decode_post_download_hook(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PostDownloadHook(
+      kind: dco_decode_post_download_hook_kind(arr[0]),
+      enabled: dco_decode_bool(arr[1]),
+      extensions: dco_decode_list_String(arr[2]),
+      program: dco_decode_opt_String(arr[3]),
+      args: dco_decode_list_String(arr[4]),
+      subfolder: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  PostDownloadHookKind dco_decode_post_download_hook_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PostDownloadHookKind.values[raw as int];
+  }
+
+  @protected
+  PostDownloadHookOutcome dco_decode_post_download_hook_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PostDownloadHookOutcome(
+      kind: dco_decode_post_download_hook_kind(arr[0]),
+      success: dco_decode_bool(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   ShareCapabilities dco_decode_share_capabilities(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1905,6 +4188,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TransferEventKind dco_decode_transfer_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransferEventKind.values[raw as int];
+  }
+
+  @protected
+  TransferFailureReason dco_decode_transfer_failure_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransferFailureReason.values[raw as int];
+  }
+
+  @protected
+  TransferPriority dco_decode_transfer_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TransferPriority.values[raw as int];
+  }
+
+  @protected
+  TransferRetryPolicy dco_decode_transfer_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TransferRetryPolicy(
+      maxRetries: dco_decode_u_32(arr[0]),
+      initialDelayMs: dco_decode_u_64(arr[1]),
+      maxDelayMs: dco_decode_u_64(arr[2]),
+    );
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1929,6 +4243,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  UploadConflictBehavior dco_decode_upload_conflict_behavior(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UploadConflictBehavior.values[raw as int];
+  }
+
+  @protected
+  UploadGroup dco_decode_upload_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return UploadGroup(
+      groupId: dco_decode_String(arr[0]),
+      localRoot: dco_decode_String(arr[1]),
+      folderName: dco_decode_String(arr[2]),
+      parentId: dco_decode_opt_String(arr[3]),
+      status: dco_decode_upload_group_status(arr[4]),
+      createdAt: dco_decode_i_64(arr[5]),
+      completedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      errorMessage: dco_decode_opt_String(arr[7]),
+      totalFiles: dco_decode_u_32(arr[8]),
+      completedFiles: dco_decode_u_32(arr[9]),
+      failedFiles: dco_decode_u_32(arr[10]),
+      totalBytes: dco_decode_u_64(arr[11]),
+      uploadedBytes: dco_decode_u_64(arr[12]),
+    );
+  }
+
+  @protected
+  UploadGroupStatus dco_decode_upload_group_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UploadGroupStatus.values[raw as int];
+  }
+
   @protected
   UploadProgressUpdate dco_decode_upload_progress_update(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1948,12 +4297,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   UploadQueueState dco_decode_upload_queue_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return UploadQueueState(
       active: dco_decode_list_upload_task(arr[0]),
       completed: dco_decode_list_upload_task(arr[1]),
       failed: dco_decode_list_upload_task(arr[2]),
+      groups: dco_decode_list_upload_group(arr[3]),
     );
   }
 
@@ -1967,8 +4317,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   UploadTask dco_decode_upload_task(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    if (arr.length != 24)
+      throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
     return UploadTask(
       taskId: dco_decode_String(arr[0]),
       fileName: dco_decode_String(arr[1]),
@@ -1983,6 +4333,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       bytesUploaded: dco_decode_opt_box_autoadd_u_64(arr[10]),
       errorMessage: dco_decode_opt_String(arr[11]),
       sessionUrl: dco_decode_opt_String(arr[12]),
+      sessionExpiresAt: dco_decode_opt_box_autoadd_i_64(arr[13]),
+      retryCount: dco_decode_u_32(arr[14]),
+      priority: dco_decode_transfer_priority(arr[15]),
+      failureReason:
+          dco_decode_opt_box_autoadd_transfer_failure_reason(arr[16]),
+      groupId: dco_decode_opt_String(arr[17]),
+      conflictBehavior: dco_decode_upload_conflict_behavior(arr[18]),
+      remoteName: dco_decode_opt_String(arr[19]),
+      skippedIdentical: dco_decode_bool(arr[20]),
+      sourceModifiedAt: dco_decode_opt_box_autoadd_i_64(arr[21]),
+      sourceFingerprint: dco_decode_opt_String(arr[22]),
+      sourceUrl: dco_decode_opt_String(arr[23]),
+    );
+  }
+
+  @protected
+  UploadTaskEvent dco_decode_upload_task_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UploadTaskEvent(
+      kind: dco_decode_transfer_event_kind(arr[0]),
+      task: dco_decode_upload_task(arr[1]),
+      timestampMillis: dco_decode_i_64(arr[2]),
     );
   }
 
@@ -1993,6 +4368,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AnyhowException(inner);
   }
 
+  @protected
+  RustStreamSink<CopyJob> sse_decode_StreamSink_copy_job_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<DownloadProgressUpdate>
   sse_decode_StreamSink_download_progress_update_Sse(
@@ -2002,6 +4385,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<DownloadTaskEvent>
+  sse_decode_StreamSink_download_task_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<UploadProgressUpdate>
   sse_decode_StreamSink_upload_progress_update_Sse(
@@ -2011,6 +4401,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<UploadTaskEvent> sse_decode_StreamSink_upload_task_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2037,6 +4435,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BandwidthSchedule sse_decode_bandwidth_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMinute = sse_decode_u_32(deserializer);
+    var var_endMinute = sse_decode_u_32(deserializer);
+    var var_globalKibPerSec = sse_decode_u_64(deserializer);
+    var var_downloadKibPerSec = sse_decode_u_64(deserializer);
+    var var_uploadKibPerSec = sse_decode_u_64(deserializer);
+    return BandwidthSchedule(
+      startMinute: var_startMinute,
+      endMinute: var_endMinute,
+      globalKibPerSec: var_globalKibPerSec,
+      downloadKibPerSec: var_downloadKibPerSec,
+      uploadKibPerSec: var_uploadKibPerSec,
+    );
+  }
+
+  @protected
+  BandwidthSettings sse_decode_bandwidth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_globalKibPerSec = sse_decode_u_64(deserializer);
+    var var_downloadKibPerSec = sse_decode_u_64(deserializer);
+    var var_uploadKibPerSec = sse_decode_u_64(deserializer);
+    var var_schedules = sse_decode_list_bandwidth_schedule(deserializer);
+    return BandwidthSettings(
+      globalKibPerSec: var_globalKibPerSec,
+      downloadKibPerSec: var_downloadKibPerSec,
+      uploadKibPerSec: var_uploadKibPerSec,
+      schedules: var_schedules,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2049,12 +4483,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_auth_tokens(deserializer));
   }
 
+  @protected
+  BandwidthSettings sse_decode_box_autoadd_bandwidth_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bandwidth_settings(deserializer));
+  }
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  DownloadConflictOutcome sse_decode_box_autoadd_download_conflict_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_download_conflict_outcome(deserializer));
+  }
+
+  @protected
+  DownloadConflictPolicy sse_decode_box_autoadd_download_conflict_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_download_conflict_policy(deserializer));
+  }
+
   @protected
   DriveItemSummary sse_decode_box_autoadd_drive_item_summary(
     SseDeserializer deserializer,
@@ -2087,6 +4545,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_i_64(deserializer));
   }
 
+  @protected
+  NotificationSettings sse_decode_box_autoadd_notification_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_notification_settings(deserializer));
+  }
+
   @protected
   StoredAuthState sse_decode_box_autoadd_stored_auth_state(
     SseDeserializer deserializer,
@@ -2095,23 +4561,121 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_stored_auth_state(deserializer));
   }
 
+  @protected
+  TransferFailureReason sse_decode_box_autoadd_transfer_failure_reason(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transfer_failure_reason(deserializer));
+  }
+
+  @protected
+  TransferPriority sse_decode_box_autoadd_transfer_priority(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transfer_priority(deserializer));
+  }
+
+  @protected
+  TransferRetryPolicy sse_decode_box_autoadd_transfer_retry_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transfer_retry_policy(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  UploadConflictBehavior sse_decode_box_autoadd_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_upload_conflict_behavior(deserializer));
+  }
+
+  @protected
+  CopyJob sse_decode_copy_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_jobId = sse_decode_String(deserializer);
+    var var_sourceItemId = sse_decode_String(deserializer);
+    var var_itemName = sse_decode_String(deserializer);
+    var var_targetDriveId = sse_decode_opt_String(deserializer);
+    var var_targetParentId = sse_decode_opt_String(deserializer);
+    var var_newName = sse_decode_opt_String(deserializer);
+    var var_monitorUrl = sse_decode_String(deserializer);
+    var var_status = sse_decode_copy_job_status(deserializer);
+    var var_percentageComplete = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_newItemId = sse_decode_opt_String(deserializer);
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return CopyJob(
+      jobId: var_jobId,
+      sourceItemId: var_sourceItemId,
+      itemName: var_itemName,
+      targetDriveId: var_targetDriveId,
+      targetParentId: var_targetParentId,
+      newName: var_newName,
+      monitorUrl: var_monitorUrl,
+      status: var_status,
+      percentageComplete: var_percentageComplete,
+      newItemId: var_newItemId,
+      errorMessage: var_errorMessage,
+      startedAt: var_startedAt,
+      completedAt: var_completedAt,
+    );
+  }
+
+  @protected
+  CopyJobStatus sse_decode_copy_job_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CopyJobStatus.values[inner];
+  }
+
+  @protected
+  DownloadConflictOutcome sse_decode_download_conflict_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownloadConflictOutcome.values[inner];
+  }
+
+  @protected
+  DownloadConflictPolicy sse_decode_download_conflict_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownloadConflictPolicy.values[inner];
+  }
+
   @protected
   DownloadProgressUpdate sse_decode_download_progress_update(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_taskId = sse_decode_String(deserializer);
     var var_itemId = sse_decode_String(deserializer);
     var var_bytesDownloaded = sse_decode_u_64(deserializer);
     var var_expectedSize = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_speedBps = sse_decode_opt_box_autoadd_f_64(deserializer);
     var var_timestampMillis = sse_decode_i_64(deserializer);
     return DownloadProgressUpdate(
+      taskId: var_taskId,
       itemId: var_itemId,
       bytesDownloaded: var_bytesDownloaded,
       expectedSize: var_expectedSize,
@@ -2145,6 +4709,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   DownloadTask sse_decode_download_task(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_taskId = sse_decode_String(deserializer);
     var var_item = sse_decode_drive_item_summary(deserializer);
     var var_status = sse_decode_download_status(deserializer);
     var var_startedAt = sse_decode_i_64(deserializer);
@@ -2153,7 +4718,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_sizeLabel = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_bytesDownloaded = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_errorMessage = sse_decode_opt_String(deserializer);
+    var var_targetDir = sse_decode_String(deserializer);
+    var var_conflictPolicy = sse_decode_download_conflict_policy(deserializer);
+    var var_conflictOutcome =
+        sse_decode_opt_box_autoadd_download_conflict_outcome(deserializer);
+    var var_retryCount = sse_decode_u_32(deserializer);
+    var var_priority = sse_decode_transfer_priority(deserializer);
+    var var_failureReason =
+        sse_decode_opt_box_autoadd_transfer_failure_reason(deserializer);
+    var var_hookOutcomes =
+        sse_decode_list_post_download_hook_outcome(deserializer);
     return DownloadTask(
+      taskId: var_taskId,
       item: var_item,
       status: var_status,
       startedAt: var_startedAt,
@@ -2162,6 +4738,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sizeLabel: var_sizeLabel,
       bytesDownloaded: var_bytesDownloaded,
       errorMessage: var_errorMessage,
+      targetDir: var_targetDir,
+      conflictPolicy: var_conflictPolicy,
+      conflictOutcome: var_conflictOutcome,
+      retryCount: var_retryCount,
+      priority: var_priority,
+      failureReason: var_failureReason,
+      hookOutcomes: var_hookOutcomes,
+    );
+  }
+
+  @protected
+  DownloadTaskEvent sse_decode_download_task_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_transfer_event_kind(deserializer);
+    var var_task = sse_decode_download_task(deserializer);
+    var var_timestampMillis = sse_decode_i_64(deserializer);
+    return DownloadTaskEvent(
+      kind: var_kind,
+      task: var_task,
+      timestampMillis: var_timestampMillis,
     );
   }
 
@@ -2174,11 +4772,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_savedPath = sse_decode_String(deserializer);
     var var_bytesDownloaded = sse_decode_u_64(deserializer);
     var var_expectedSize = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_conflictOutcome =
+        sse_decode_download_conflict_outcome(deserializer);
     return DriveDownloadResult(
       fileName: var_fileName,
       savedPath: var_savedPath,
       bytesDownloaded: var_bytesDownloaded,
       expectedSize: var_expectedSize,
+      conflictOutcome: var_conflictOutcome,
     );
   }
 
@@ -2301,6 +4902,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FolderConflictBehavior sse_decode_folder_conflict_behavior(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FolderConflictBehavior.values[inner];
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2339,6 +4949,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<BandwidthSchedule> sse_decode_list_bandwidth_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BandwidthSchedule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_bandwidth_schedule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CopyJob> sse_decode_list_copy_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CopyJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_copy_job(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DownloadTask> sse_decode_list_download_task(
     SseDeserializer deserializer,
@@ -2368,10 +5004,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  List<PostDownloadHook> sse_decode_list_post_download_hook(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <PostDownloadHook>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_post_download_hook(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PostDownloadHookOutcome> sse_decode_list_post_download_hook_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PostDownloadHookOutcome>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_post_download_hook_outcome(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -2381,6 +5038,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<UploadGroup> sse_decode_list_upload_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UploadGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_upload_group(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<UploadTask> sse_decode_list_upload_task(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2390,98 +5059,197 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     for (var idx_ = 0; idx_ < len_; ++idx_) {
       ans_.add(sse_decode_upload_task(deserializer));
     }
-    return ans_;
+    return ans_;
+  }
+
+  @protected
+  NotificationSettings sse_decode_notification_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_notifyOnTaskCompleted = sse_decode_bool(deserializer);
+    var var_notifyOnTaskFailed = sse_decode_bool(deserializer);
+    var var_notifyOnBatchFinished = sse_decode_bool(deserializer);
+    var var_quietHoursEnabled = sse_decode_bool(deserializer);
+    var var_quietHoursStartMinute = sse_decode_u_32(deserializer);
+    var var_quietHoursEndMinute = sse_decode_u_32(deserializer);
+    return NotificationSettings(
+      enabled: var_enabled,
+      notifyOnTaskCompleted: var_notifyOnTaskCompleted,
+      notifyOnTaskFailed: var_notifyOnTaskFailed,
+      notifyOnBatchFinished: var_notifyOnBatchFinished,
+      quietHoursEnabled: var_quietHoursEnabled,
+      quietHoursStartMinute: var_quietHoursStartMinute,
+      quietHoursEndMinute: var_quietHoursEndMinute,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DownloadConflictOutcome? sse_decode_opt_box_autoadd_download_conflict_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_download_conflict_outcome(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DownloadConflictPolicy? sse_decode_opt_box_autoadd_download_conflict_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_download_conflict_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DriveOwner? sse_decode_opt_box_autoadd_drive_owner(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_drive_owner(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DriveQuota? sse_decode_opt_box_autoadd_drive_quota(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_drive_quota(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
+      return (sse_decode_box_autoadd_f_64(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
+      return (sse_decode_box_autoadd_i_64(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  DriveOwner? sse_decode_opt_box_autoadd_drive_owner(
+  StoredAuthState? sse_decode_opt_box_autoadd_stored_auth_state(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_drive_owner(deserializer));
+      return (sse_decode_box_autoadd_stored_auth_state(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  DriveQuota? sse_decode_opt_box_autoadd_drive_quota(
+  TransferFailureReason? sse_decode_opt_box_autoadd_transfer_failure_reason(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_drive_quota(deserializer));
+      return (sse_decode_box_autoadd_transfer_failure_reason(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  TransferPriority? sse_decode_opt_box_autoadd_transfer_priority(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
+      return (sse_decode_box_autoadd_transfer_priority(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
+      return (sse_decode_box_autoadd_u_32(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  StoredAuthState? sse_decode_opt_box_autoadd_stored_auth_state(
-    SseDeserializer deserializer,
-  ) {
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_stored_auth_state(deserializer));
+      return (sse_decode_box_autoadd_u_64(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  UploadConflictBehavior? sse_decode_opt_box_autoadd_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
+      return (sse_decode_box_autoadd_upload_conflict_behavior(deserializer));
     } else {
       return null;
     }
@@ -2498,6 +5266,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PostDownloadHook sse_decode_post_download_hook(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_post_download_hook_kind(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_program = sse_decode_opt_String(deserializer);
+    var var_args = sse_decode_list_String(deserializer);
+    var var_subfolder = sse_decode_opt_String(deserializer);
+    return PostDownloadHook(
+      kind: var_kind,
+      enabled: var_enabled,
+      extensions: var_extensions,
+      program: var_program,
+      args: var_args,
+      subfolder: var_subfolder,
+    );
+  }
+
+  @protected
+  PostDownloadHookKind sse_decode_post_download_hook_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PostDownloadHookKind.values[inner];
+  }
+
+  @protected
+  PostDownloadHookOutcome sse_decode_post_download_hook_outcome(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_post_download_hook_kind(deserializer);
+    var var_success = sse_decode_bool(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return PostDownloadHookOutcome(
+      kind: var_kind,
+      success: var_success,
+      message: var_message,
+    );
+  }
+
   @protected
   ShareCapabilities sse_decode_share_capabilities(
     SseDeserializer deserializer,
@@ -2551,6 +5362,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TransferEventKind sse_decode_transfer_event_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TransferEventKind.values[inner];
+  }
+
+  @protected
+  TransferFailureReason sse_decode_transfer_failure_reason(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TransferFailureReason.values[inner];
+  }
+
+  @protected
+  TransferPriority sse_decode_transfer_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TransferPriority.values[inner];
+  }
+
+  @protected
+  TransferRetryPolicy sse_decode_transfer_retry_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_initialDelayMs = sse_decode_u_64(deserializer);
+    var var_maxDelayMs = sse_decode_u_64(deserializer);
+    return TransferRetryPolicy(
+      maxRetries: var_maxRetries,
+      initialDelayMs: var_initialDelayMs,
+      maxDelayMs: var_maxDelayMs,
+    );
+  }
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2574,6 +5425,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  UploadConflictBehavior sse_decode_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UploadConflictBehavior.values[inner];
+  }
+
+  @protected
+  UploadGroup sse_decode_upload_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_groupId = sse_decode_String(deserializer);
+    var var_localRoot = sse_decode_String(deserializer);
+    var var_folderName = sse_decode_String(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_status = sse_decode_upload_group_status(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_completedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    var var_totalFiles = sse_decode_u_32(deserializer);
+    var var_completedFiles = sse_decode_u_32(deserializer);
+    var var_failedFiles = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_uploadedBytes = sse_decode_u_64(deserializer);
+    return UploadGroup(
+      groupId: var_groupId,
+      localRoot: var_localRoot,
+      folderName: var_folderName,
+      parentId: var_parentId,
+      status: var_status,
+      createdAt: var_createdAt,
+      completedAt: var_completedAt,
+      errorMessage: var_errorMessage,
+      totalFiles: var_totalFiles,
+      completedFiles: var_completedFiles,
+      failedFiles: var_failedFiles,
+      totalBytes: var_totalBytes,
+      uploadedBytes: var_uploadedBytes,
+    );
+  }
+
+  @protected
+  UploadGroupStatus sse_decode_upload_group_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return UploadGroupStatus.values[inner];
+  }
+
   @protected
   UploadProgressUpdate sse_decode_upload_progress_update(
     SseDeserializer deserializer,
@@ -2599,10 +5501,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_active = sse_decode_list_upload_task(deserializer);
     var var_completed = sse_decode_list_upload_task(deserializer);
     var var_failed = sse_decode_list_upload_task(deserializer);
+    var var_groups = sse_decode_list_upload_group(deserializer);
     return UploadQueueState(
       active: var_active,
       completed: var_completed,
       failed: var_failed,
+      groups: var_groups,
     );
   }
 
@@ -2629,6 +5533,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_bytesUploaded = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_errorMessage = sse_decode_opt_String(deserializer);
     var var_sessionUrl = sse_decode_opt_String(deserializer);
+    var var_sessionExpiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_retryCount = sse_decode_u_32(deserializer);
+    var var_priority = sse_decode_transfer_priority(deserializer);
+    var var_failureReason =
+        sse_decode_opt_box_autoadd_transfer_failure_reason(deserializer);
+    var var_groupId = sse_decode_opt_String(deserializer);
+    var var_conflictBehavior =
+        sse_decode_upload_conflict_behavior(deserializer);
+    var var_remoteName = sse_decode_opt_String(deserializer);
+    var var_skippedIdentical = sse_decode_bool(deserializer);
+    var var_sourceModifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_sourceFingerprint = sse_decode_opt_String(deserializer);
+    var var_sourceUrl = sse_decode_opt_String(deserializer);
     return UploadTask(
       taskId: var_taskId,
       fileName: var_fileName,
@@ -2643,6 +5560,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       bytesUploaded: var_bytesUploaded,
       errorMessage: var_errorMessage,
       sessionUrl: var_sessionUrl,
+      sessionExpiresAt: var_sessionExpiresAt,
+      retryCount: var_retryCount,
+      priority: var_priority,
+      failureReason: var_failureReason,
+      groupId: var_groupId,
+      conflictBehavior: var_conflictBehavior,
+      remoteName: var_remoteName,
+      skippedIdentical: var_skippedIdentical,
+      sourceModifiedAt: var_sourceModifiedAt,
+      sourceFingerprint: var_sourceFingerprint,
+      sourceUrl: var_sourceUrl,
+    );
+  }
+
+  @protected
+  UploadTaskEvent sse_decode_upload_task_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_transfer_event_kind(deserializer);
+    var var_task = sse_decode_upload_task(deserializer);
+    var var_timestampMillis = sse_decode_i_64(deserializer);
+    return UploadTaskEvent(
+      kind: var_kind,
+      task: var_task,
+      timestampMillis: var_timestampMillis,
     );
   }
 
@@ -2655,6 +5596,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_copy_job_Sse(
+    RustStreamSink<CopyJob> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_copy_job,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_download_progress_update_Sse(
     RustStreamSink<DownloadProgressUpdate> self,
@@ -2672,6 +5630,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_download_task_event_Sse(
+    RustStreamSink<DownloadTaskEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_download_task_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_upload_progress_update_Sse(
     RustStreamSink<UploadProgressUpdate> self,
@@ -2689,6 +5664,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_upload_task_event_Sse(
+    RustStreamSink<UploadTaskEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_upload_task_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2696,92 +5688,240 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_auth_tokens(AuthTokens self, SseSerializer serializer) {
+  void sse_encode_auth_tokens(AuthTokens self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.accessToken, serializer);
+    sse_encode_opt_String(self.refreshToken, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.expiresIn, serializer);
+    sse_encode_opt_String(self.idToken, serializer);
+    sse_encode_opt_String(self.scope, serializer);
+    sse_encode_opt_String(self.tokenType, serializer);
+  }
+
+  @protected
+  void sse_encode_bandwidth_schedule(
+    BandwidthSchedule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.startMinute, serializer);
+    sse_encode_u_32(self.endMinute, serializer);
+    sse_encode_u_64(self.globalKibPerSec, serializer);
+    sse_encode_u_64(self.downloadKibPerSec, serializer);
+    sse_encode_u_64(self.uploadKibPerSec, serializer);
+  }
+
+  @protected
+  void sse_encode_bandwidth_settings(
+    BandwidthSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.globalKibPerSec, serializer);
+    sse_encode_u_64(self.downloadKibPerSec, serializer);
+    sse_encode_u_64(self.uploadKibPerSec, serializer);
+    sse_encode_list_bandwidth_schedule(self.schedules, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_auth_tokens(
+    AuthTokens self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auth_tokens(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bandwidth_settings(
+    BandwidthSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bandwidth_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_download_conflict_outcome(
+    DownloadConflictOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_download_conflict_outcome(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_download_conflict_policy(
+    DownloadConflictPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_download_conflict_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_drive_item_summary(
+    DriveItemSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_drive_item_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_drive_owner(
+    DriveOwner self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_drive_owner(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_drive_quota(
+    DriveQuota self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_drive_quota(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_notification_settings(
+    NotificationSettings self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.accessToken, serializer);
-    sse_encode_opt_String(self.refreshToken, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.expiresIn, serializer);
-    sse_encode_opt_String(self.idToken, serializer);
-    sse_encode_opt_String(self.scope, serializer);
-    sse_encode_opt_String(self.tokenType, serializer);
+    sse_encode_notification_settings(self, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_stored_auth_state(
+    StoredAuthState self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_stored_auth_state(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_auth_tokens(
-    AuthTokens self,
+  void sse_encode_box_autoadd_transfer_failure_reason(
+    TransferFailureReason self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_auth_tokens(self, serializer);
+    sse_encode_transfer_failure_reason(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_transfer_priority(
+    TransferPriority self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
+    sse_encode_transfer_priority(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_drive_item_summary(
-    DriveItemSummary self,
+  void sse_encode_box_autoadd_transfer_retry_policy(
+    TransferRetryPolicy self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_drive_item_summary(self, serializer);
+    sse_encode_transfer_retry_policy(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_drive_owner(
-    DriveOwner self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_drive_owner(self, serializer);
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_drive_quota(
-    DriveQuota self,
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_upload_conflict_behavior(
+    UploadConflictBehavior self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_drive_quota(self, serializer);
+    sse_encode_upload_conflict_behavior(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_copy_job(CopyJob self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+    sse_encode_String(self.jobId, serializer);
+    sse_encode_String(self.sourceItemId, serializer);
+    sse_encode_String(self.itemName, serializer);
+    sse_encode_opt_String(self.targetDriveId, serializer);
+    sse_encode_opt_String(self.targetParentId, serializer);
+    sse_encode_opt_String(self.newName, serializer);
+    sse_encode_String(self.monitorUrl, serializer);
+    sse_encode_copy_job_status(self.status, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.percentageComplete, serializer);
+    sse_encode_opt_String(self.newItemId, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
+  void sse_encode_copy_job_status(
+    CopyJobStatus self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_stored_auth_state(
-    StoredAuthState self,
+  void sse_encode_download_conflict_outcome(
+    DownloadConflictOutcome self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stored_auth_state(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_download_conflict_policy(
+    DownloadConflictPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -2790,6 +5930,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.taskId, serializer);
     sse_encode_String(self.itemId, serializer);
     sse_encode_u_64(self.bytesDownloaded, serializer);
     sse_encode_opt_box_autoadd_u_64(self.expectedSize, serializer);
@@ -2820,6 +5961,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_download_task(DownloadTask self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.taskId, serializer);
     sse_encode_drive_item_summary(self.item, serializer);
     sse_encode_download_status(self.status, serializer);
     sse_encode_i_64(self.startedAt, serializer);
@@ -2828,6 +5970,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.sizeLabel, serializer);
     sse_encode_opt_box_autoadd_u_64(self.bytesDownloaded, serializer);
     sse_encode_opt_String(self.errorMessage, serializer);
+    sse_encode_String(self.targetDir, serializer);
+    sse_encode_download_conflict_policy(self.conflictPolicy, serializer);
+    sse_encode_opt_box_autoadd_download_conflict_outcome(
+      self.conflictOutcome,
+      serializer,
+    );
+    sse_encode_u_32(self.retryCount, serializer);
+    sse_encode_transfer_priority(self.priority, serializer);
+    sse_encode_opt_box_autoadd_transfer_failure_reason(
+      self.failureReason,
+      serializer,
+    );
+    sse_encode_list_post_download_hook_outcome(self.hookOutcomes, serializer);
+  }
+
+  @protected
+  void sse_encode_download_task_event(
+    DownloadTaskEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transfer_event_kind(self.kind, serializer);
+    sse_encode_download_task(self.task, serializer);
+    sse_encode_i_64(self.timestampMillis, serializer);
   }
 
   @protected
@@ -2840,6 +6006,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.savedPath, serializer);
     sse_encode_u_64(self.bytesDownloaded, serializer);
     sse_encode_opt_box_autoadd_u_64(self.expectedSize, serializer);
+    sse_encode_download_conflict_outcome(self.conflictOutcome, serializer);
   }
 
   @protected
@@ -2921,6 +6088,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_folder_conflict_behavior(
+    FolderConflictBehavior self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2954,6 +6130,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_bandwidth_schedule(
+    List<BandwidthSchedule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_bandwidth_schedule(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_copy_job(List<CopyJob> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_copy_job(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_download_task(
     List<DownloadTask> self,
@@ -2979,15 +6176,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_list_post_download_hook(
+    List<PostDownloadHook> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    for (final item in self) {
+      sse_encode_post_download_hook(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_post_download_hook_outcome(
+    List<PostDownloadHookOutcome> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_post_download_hook_outcome(item, serializer);
+    }
   }
 
   @protected
@@ -3000,6 +6209,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_upload_group(
+    List<UploadGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_upload_group(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_upload_task(
     List<UploadTask> self,
@@ -3012,6 +6233,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_notification_settings(
+    NotificationSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.notifyOnTaskCompleted, serializer);
+    sse_encode_bool(self.notifyOnTaskFailed, serializer);
+    sse_encode_bool(self.notifyOnBatchFinished, serializer);
+    sse_encode_bool(self.quietHoursEnabled, serializer);
+    sse_encode_u_32(self.quietHoursStartMinute, serializer);
+    sse_encode_u_32(self.quietHoursEndMinute, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3032,6 +6268,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_download_conflict_outcome(
+    DownloadConflictOutcome? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_download_conflict_outcome(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_download_conflict_policy(
+    DownloadConflictPolicy? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_download_conflict_policy(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_drive_owner(
     DriveOwner? self,
@@ -3094,6 +6356,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_failure_reason(
+    TransferFailureReason? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_transfer_failure_reason(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_priority(
+    TransferPriority? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_transfer_priority(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3104,6 +6402,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_upload_conflict_behavior(
+    UploadConflictBehavior? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_upload_conflict_behavior(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_String(
     List<String>? self,
@@ -3117,6 +6428,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_post_download_hook(
+    PostDownloadHook self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_post_download_hook_kind(self.kind, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_opt_String(self.program, serializer);
+    sse_encode_list_String(self.args, serializer);
+    sse_encode_opt_String(self.subfolder, serializer);
+  }
+
+  @protected
+  void sse_encode_post_download_hook_kind(
+    PostDownloadHookKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_post_download_hook_outcome(
+    PostDownloadHookOutcome self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_post_download_hook_kind(self.kind, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
   @protected
   void sse_encode_share_capabilities(
     ShareCapabilities self,
@@ -3156,6 +6501,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.updatedAtMillis, serializer);
   }
 
+  @protected
+  void sse_encode_transfer_event_kind(
+    TransferEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_failure_reason(
+    TransferFailureReason self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_priority(
+    TransferPriority self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_transfer_retry_policy(
+    TransferRetryPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_u_64(self.initialDelayMs, serializer);
+    sse_encode_u_64(self.maxDelayMs, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3179,6 +6562,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_upload_conflict_behavior(
+    UploadConflictBehavior self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_upload_group(UploadGroup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.groupId, serializer);
+    sse_encode_String(self.localRoot, serializer);
+    sse_encode_String(self.folderName, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_upload_group_status(self.status, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.completedAt, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
+    sse_encode_u_32(self.totalFiles, serializer);
+    sse_encode_u_32(self.completedFiles, serializer);
+    sse_encode_u_32(self.failedFiles, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.uploadedBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_upload_group_status(
+    UploadGroupStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_upload_progress_update(
     UploadProgressUpdate self,
@@ -3201,6 +6620,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_upload_task(self.active, serializer);
     sse_encode_list_upload_task(self.completed, serializer);
     sse_encode_list_upload_task(self.failed, serializer);
+    sse_encode_list_upload_group(self.groups, serializer);
   }
 
   @protected
@@ -3225,5 +6645,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.bytesUploaded, serializer);
     sse_encode_opt_String(self.errorMessage, serializer);
     sse_encode_opt_String(self.sessionUrl, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.sessionExpiresAt, serializer);
+    sse_encode_u_32(self.retryCount, serializer);
+    sse_encode_transfer_priority(self.priority, serializer);
+    sse_encode_opt_box_autoadd_transfer_failure_reason(
+      self.failureReason,
+      serializer,
+    );
+    sse_encode_opt_String(self.groupId, serializer);
+    sse_encode_upload_conflict_behavior(self.conflictBehavior, serializer);
+    sse_encode_opt_String(self.remoteName, serializer);
+    sse_encode_bool(self.skippedIdentical, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.sourceModifiedAt, serializer);
+    sse_encode_opt_String(self.sourceFingerprint, serializer);
+    sse_encode_opt_String(self.sourceUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_upload_task_event(
+    UploadTaskEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transfer_event_kind(self.kind, serializer);
+    sse_encode_upload_task(self.task, serializer);
+    sse_encode_i_64(self.timestampMillis, serializer);
   }
 }
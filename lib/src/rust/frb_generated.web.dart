@@ -8,6 +8,8 @@
 
 import 'api/auth/auth.dart';
 import 'api/auth/refresh.dart';
+import 'api/drive/copy_manager.dart';
+import 'api/drive/create_folder.dart';
 import 'api/drive/delete.dart';
 import 'api/drive/details.dart';
 import 'api/drive/download.dart';
@@ -19,8 +21,19 @@ import 'api/drive/move_item.dart';
 import 'api/drive/share.dart';
 import 'api/drive/upload.dart';
 import 'api/drive/upload_manager.dart';
+import 'api/settings/bandwidth_limit.dart';
+import 'api/settings/disk_space_reserve.dart';
 import 'api/settings/download_concurrency.dart';
+import 'api/settings/download_conflict_policy.dart';
 import 'api/settings/download_directory.dart';
+import 'api/settings/download_item_xattr.dart';
+import 'api/settings/global_transfer_limit.dart';
+import 'api/settings/notifications.dart';
+import 'api/settings/post_download_hooks.dart';
+import 'api/settings/retry_policy.dart';
+import 'api/settings/upload_concurrency.dart';
+import 'api/settings/upload_conflict_behavior.dart';
+import 'api/settings/upload_integrity_reupload.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -38,29 +51,60 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<CopyJob> dco_decode_StreamSink_copy_job_Sse(dynamic raw);
+
   @protected
   RustStreamSink<DownloadProgressUpdate>
   dco_decode_StreamSink_download_progress_update_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<DownloadTaskEvent>
+  dco_decode_StreamSink_download_task_event_Sse(dynamic raw);
+
   @protected
   RustStreamSink<UploadProgressUpdate>
   dco_decode_StreamSink_upload_progress_update_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<UploadTaskEvent> dco_decode_StreamSink_upload_task_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   AuthTokens dco_decode_auth_tokens(dynamic raw);
 
+  @protected
+  BandwidthSchedule dco_decode_bandwidth_schedule(dynamic raw);
+
+  @protected
+  BandwidthSettings dco_decode_bandwidth_settings(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
   @protected
   AuthTokens dco_decode_box_autoadd_auth_tokens(dynamic raw);
 
+  @protected
+  BandwidthSettings dco_decode_box_autoadd_bandwidth_settings(dynamic raw);
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  DownloadConflictOutcome dco_decode_box_autoadd_download_conflict_outcome(
+    dynamic raw,
+  );
+
+  @protected
+  DownloadConflictPolicy dco_decode_box_autoadd_download_conflict_policy(
+    dynamic raw,
+  );
+
   @protected
   DriveItemSummary dco_decode_box_autoadd_drive_item_summary(dynamic raw);
 
@@ -76,12 +120,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  NotificationSettings dco_decode_box_autoadd_notification_settings(
+    dynamic raw,
+  );
+
   @protected
   StoredAuthState dco_decode_box_autoadd_stored_auth_state(dynamic raw);
 
+  @protected
+  TransferFailureReason dco_decode_box_autoadd_transfer_failure_reason(
+    dynamic raw,
+  );
+
+  @protected
+  TransferPriority dco_decode_box_autoadd_transfer_priority(dynamic raw);
+
+  @protected
+  TransferRetryPolicy dco_decode_box_autoadd_transfer_retry_policy(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UploadConflictBehavior dco_decode_box_autoadd_upload_conflict_behavior(
+    dynamic raw,
+  );
+
+  @protected
+  CopyJob dco_decode_copy_job(dynamic raw);
+
+  @protected
+  CopyJobStatus dco_decode_copy_job_status(dynamic raw);
+
+  @protected
+  DownloadConflictOutcome dco_decode_download_conflict_outcome(dynamic raw);
+
+  @protected
+  DownloadConflictPolicy dco_decode_download_conflict_policy(dynamic raw);
+
   @protected
   DownloadProgressUpdate dco_decode_download_progress_update(dynamic raw);
 
@@ -94,6 +174,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DownloadTask dco_decode_download_task(dynamic raw);
 
+  @protected
+  DownloadTaskEvent dco_decode_download_task_event(dynamic raw);
+
   @protected
   DriveDownloadResult dco_decode_drive_download_result(dynamic raw);
 
@@ -118,6 +201,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FolderConflictBehavior dco_decode_folder_conflict_behavior(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -133,6 +219,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BandwidthSchedule> dco_decode_list_bandwidth_schedule(dynamic raw);
+
+  @protected
+  List<CopyJob> dco_decode_list_copy_job(dynamic raw);
+
   @protected
   List<DownloadTask> dco_decode_list_download_task(dynamic raw);
 
@@ -140,20 +232,41 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<DriveItemSummary> dco_decode_list_drive_item_summary(dynamic raw);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  List<PostDownloadHook> dco_decode_list_post_download_hook(dynamic raw);
+
+  @protected
+  List<PostDownloadHookOutcome> dco_decode_list_post_download_hook_outcome(
+    dynamic raw,
+  );
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<UploadGroup> dco_decode_list_upload_group(dynamic raw);
+
   @protected
   List<UploadTask> dco_decode_list_upload_task(dynamic raw);
 
+  @protected
+  NotificationSettings dco_decode_notification_settings(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+  @protected
+  DownloadConflictOutcome? dco_decode_opt_box_autoadd_download_conflict_outcome(
+    dynamic raw,
+  );
+
+  @protected
+  DownloadConflictPolicy? dco_decode_opt_box_autoadd_download_conflict_policy(
+    dynamic raw,
+  );
+
   @protected
   DriveOwner? dco_decode_opt_box_autoadd_drive_owner(dynamic raw);
 
@@ -169,12 +282,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StoredAuthState? dco_decode_opt_box_autoadd_stored_auth_state(dynamic raw);
 
+  @protected
+  TransferFailureReason? dco_decode_opt_box_autoadd_transfer_failure_reason(
+    dynamic raw,
+  );
+
+  @protected
+  TransferPriority? dco_decode_opt_box_autoadd_transfer_priority(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UploadConflictBehavior? dco_decode_opt_box_autoadd_upload_conflict_behavior(
+    dynamic raw,
+  );
+
   @protected
   List<String>? dco_decode_opt_list_String(dynamic raw);
 
+  @protected
+  PostDownloadHook dco_decode_post_download_hook(dynamic raw);
+
+  @protected
+  PostDownloadHookKind dco_decode_post_download_hook_kind(dynamic raw);
+
+  @protected
+  PostDownloadHookOutcome dco_decode_post_download_hook_outcome(dynamic raw);
+
   @protected
   ShareCapabilities dco_decode_share_capabilities(dynamic raw);
 
@@ -184,6 +322,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StoredAuthState dco_decode_stored_auth_state(dynamic raw);
 
+  @protected
+  TransferEventKind dco_decode_transfer_event_kind(dynamic raw);
+
+  @protected
+  TransferFailureReason dco_decode_transfer_failure_reason(dynamic raw);
+
+  @protected
+  TransferPriority dco_decode_transfer_priority(dynamic raw);
+
+  @protected
+  TransferRetryPolicy dco_decode_transfer_retry_policy(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -196,6 +346,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UploadConflictBehavior dco_decode_upload_conflict_behavior(dynamic raw);
+
+  @protected
+  UploadGroup dco_decode_upload_group(dynamic raw);
+
+  @protected
+  UploadGroupStatus dco_decode_upload_group_status(dynamic raw);
+
   @protected
   UploadProgressUpdate dco_decode_upload_progress_update(dynamic raw);
 
@@ -208,36 +367,74 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UploadTask dco_decode_upload_task(dynamic raw);
 
+  @protected
+  UploadTaskEvent dco_decode_upload_task_event(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<CopyJob> sse_decode_StreamSink_copy_job_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<DownloadProgressUpdate>
   sse_decode_StreamSink_download_progress_update_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<DownloadTaskEvent>
+  sse_decode_StreamSink_download_task_event_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<UploadProgressUpdate>
   sse_decode_StreamSink_upload_progress_update_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<UploadTaskEvent> sse_decode_StreamSink_upload_task_event_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
   @protected
   AuthTokens sse_decode_auth_tokens(SseDeserializer deserializer);
 
+  @protected
+  BandwidthSchedule sse_decode_bandwidth_schedule(SseDeserializer deserializer);
+
+  @protected
+  BandwidthSettings sse_decode_bandwidth_settings(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
   AuthTokens sse_decode_box_autoadd_auth_tokens(SseDeserializer deserializer);
 
+  @protected
+  BandwidthSettings sse_decode_box_autoadd_bandwidth_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  DownloadConflictOutcome sse_decode_box_autoadd_download_conflict_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadConflictPolicy sse_decode_box_autoadd_download_conflict_policy(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DriveItemSummary sse_decode_box_autoadd_drive_item_summary(
     SseDeserializer deserializer,
@@ -255,14 +452,58 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  NotificationSettings sse_decode_box_autoadd_notification_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   StoredAuthState sse_decode_box_autoadd_stored_auth_state(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TransferFailureReason sse_decode_box_autoadd_transfer_failure_reason(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransferPriority sse_decode_box_autoadd_transfer_priority(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransferRetryPolicy sse_decode_box_autoadd_transfer_retry_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UploadConflictBehavior sse_decode_box_autoadd_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CopyJob sse_decode_copy_job(SseDeserializer deserializer);
+
+  @protected
+  CopyJobStatus sse_decode_copy_job_status(SseDeserializer deserializer);
+
+  @protected
+  DownloadConflictOutcome sse_decode_download_conflict_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadConflictPolicy sse_decode_download_conflict_policy(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DownloadProgressUpdate sse_decode_download_progress_update(
     SseDeserializer deserializer,
@@ -279,6 +520,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DownloadTask sse_decode_download_task(SseDeserializer deserializer);
 
+  @protected
+  DownloadTaskEvent sse_decode_download_task_event(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DriveDownloadResult sse_decode_drive_download_result(
     SseDeserializer deserializer,
@@ -305,6 +551,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FolderConflictBehavior sse_decode_folder_conflict_behavior(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -320,6 +571,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BandwidthSchedule> sse_decode_list_bandwidth_schedule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CopyJob> sse_decode_list_copy_job(SseDeserializer deserializer);
+
   @protected
   List<DownloadTask> sse_decode_list_download_task(
     SseDeserializer deserializer,
@@ -331,20 +590,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  List<PostDownloadHook> sse_decode_list_post_download_hook(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PostDownloadHookOutcome> sse_decode_list_post_download_hook_outcome(
+    SseDeserializer deserializer,
+  );
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<UploadGroup> sse_decode_list_upload_group(SseDeserializer deserializer);
+
   @protected
   List<UploadTask> sse_decode_list_upload_task(SseDeserializer deserializer);
 
+  @protected
+  NotificationSettings sse_decode_notification_settings(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  DownloadConflictOutcome? sse_decode_opt_box_autoadd_download_conflict_outcome(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DownloadConflictPolicy? sse_decode_opt_box_autoadd_download_conflict_policy(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DriveOwner? sse_decode_opt_box_autoadd_drive_owner(
     SseDeserializer deserializer,
@@ -366,12 +650,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TransferFailureReason? sse_decode_opt_box_autoadd_transfer_failure_reason(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransferPriority? sse_decode_opt_box_autoadd_transfer_priority(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UploadConflictBehavior? sse_decode_opt_box_autoadd_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
 
+  @protected
+  PostDownloadHook sse_decode_post_download_hook(SseDeserializer deserializer);
+
+  @protected
+  PostDownloadHookKind sse_decode_post_download_hook_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PostDownloadHookOutcome sse_decode_post_download_hook_outcome(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ShareCapabilities sse_decode_share_capabilities(SseDeserializer deserializer);
 
@@ -381,6 +696,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StoredAuthState sse_decode_stored_auth_state(SseDeserializer deserializer);
 
+  @protected
+  TransferEventKind sse_decode_transfer_event_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransferFailureReason sse_decode_transfer_failure_reason(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TransferPriority sse_decode_transfer_priority(SseDeserializer deserializer);
+
+  @protected
+  TransferRetryPolicy sse_decode_transfer_retry_policy(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -393,6 +726,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UploadConflictBehavior sse_decode_upload_conflict_behavior(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  UploadGroup sse_decode_upload_group(SseDeserializer deserializer);
+
+  @protected
+  UploadGroupStatus sse_decode_upload_group_status(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UploadProgressUpdate sse_decode_upload_progress_update(
     SseDeserializer deserializer,
@@ -407,30 +753,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UploadTask sse_decode_upload_task(SseDeserializer deserializer);
 
+  @protected
+  UploadTaskEvent sse_decode_upload_task_event(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_copy_job_Sse(
+    RustStreamSink<CopyJob> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_download_progress_update_Sse(
     RustStreamSink<DownloadProgressUpdate> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_download_task_event_Sse(
+    RustStreamSink<DownloadTaskEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_upload_progress_update_Sse(
     RustStreamSink<UploadProgressUpdate> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_upload_task_event_Sse(
+    RustStreamSink<UploadTaskEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
   void sse_encode_auth_tokens(AuthTokens self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bandwidth_schedule(
+    BandwidthSchedule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bandwidth_settings(
+    BandwidthSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -440,9 +819,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_bandwidth_settings(
+    BandwidthSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_download_conflict_outcome(
+    DownloadConflictOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_download_conflict_policy(
+    DownloadConflictPolicy self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_drive_item_summary(
     DriveItemSummary self,
@@ -470,15 +867,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_notification_settings(
+    NotificationSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_stored_auth_state(
     StoredAuthState self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_transfer_failure_reason(
+    TransferFailureReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_transfer_priority(
+    TransferPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_transfer_retry_policy(
+    TransferRetryPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_upload_conflict_behavior(
+    UploadConflictBehavior self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_copy_job(CopyJob self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_copy_job_status(CopyJobStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_download_conflict_outcome(
+    DownloadConflictOutcome self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_download_conflict_policy(
+    DownloadConflictPolicy self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_download_progress_update(
     DownloadProgressUpdate self,
@@ -500,6 +948,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_download_task(DownloadTask self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_download_task_event(
+    DownloadTaskEvent self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_drive_download_result(
     DriveDownloadResult self,
@@ -533,6 +987,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_folder_conflict_behavior(
+    FolderConflictBehavior self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -548,6 +1008,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_bandwidth_schedule(
+    List<BandwidthSchedule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_copy_job(List<CopyJob> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_download_task(
     List<DownloadTask> self,
@@ -561,7 +1030,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  void sse_encode_list_post_download_hook(
+    List<PostDownloadHook> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_post_download_hook_outcome(
+    List<PostDownloadHookOutcome> self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_list_prim_u_8_strict(
@@ -569,18 +1047,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_upload_group(
+    List<UploadGroup> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_upload_task(
     List<UploadTask> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_notification_settings(
+    NotificationSettings self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_download_conflict_outcome(
+    DownloadConflictOutcome? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_download_conflict_policy(
+    DownloadConflictPolicy? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_drive_owner(
     DriveOwner? self,
@@ -608,12 +1110,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_failure_reason(
+    TransferFailureReason? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_transfer_priority(
+    TransferPriority? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_upload_conflict_behavior(
+    UploadConflictBehavior? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_post_download_hook(
+    PostDownloadHook self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_post_download_hook_kind(
+    PostDownloadHookKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_post_download_hook_outcome(
+    PostDownloadHookOutcome self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_share_capabilities(
     ShareCapabilities self,
@@ -632,6 +1173,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_transfer_event_kind(
+    TransferEventKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transfer_failure_reason(
+    TransferFailureReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transfer_priority(
+    TransferPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transfer_retry_policy(
+    TransferRetryPolicy self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -644,6 +1209,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_upload_conflict_behavior(
+    UploadConflictBehavior self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_upload_group(UploadGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_upload_group_status(
+    UploadGroupStatus self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_upload_progress_update(
     UploadProgressUpdate self,
@@ -661,6 +1241,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_upload_task(UploadTask self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_upload_task_event(
+    UploadTaskEvent self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -202,7 +202,7 @@ fn exchange_code_for_tokens(
         token_type: payload.token_type,
     };
 
-    if let Err(err) = persist_tokens(&client_id, &tokens) {
+    if let Err(err) = persist_tokens(client_id, &tokens) {
         eprintln!("failed to persist auth tokens: {err}");
     }
 
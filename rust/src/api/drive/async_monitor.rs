@@ -0,0 +1,207 @@
+use super::client::build_no_redirect_client;
+use crate::retry::{wait_or_cancel, TransferError};
+use reqwest::header::LOCATION;
+use serde::Deserialize;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
+
+/// 正常情况下查询进度的间隔。
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// 查询遇到瞬时失败时逐次加倍等待，最长不超过该间隔。
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// 停止等待时返回的错误文本。
+const WAIT_CANCELLED_MESSAGE: &str = "stopped waiting for async operation";
+
+/// Graph 长时间操作（复制、按网络地址上传等）的进度状态。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AsyncJobState {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// 轮询 monitor 地址得到的一次状态。
+#[derive(Clone, Debug)]
+struct AsyncJobStatus {
+    state: AsyncJobState,
+    /// 0-100；服务端未报告时为空。
+    percentage_complete: Option<f64>,
+    /// 操作完成后生成的 item id。
+    resource_id: Option<String>,
+    error_message: Option<String>,
+}
+
+/// 查询一次异步操作的状态。monitor 地址自带授权，不需要携带 access token。
+fn poll_async_job(monitor_url: &str) -> Result<AsyncJobStatus, TransferError> {
+    let client =
+        build_no_redirect_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
+    let response = client
+        .get(monitor_url)
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| {
+            TransferError::transient(format!("network error while polling async operation: {e}"))
+        })?;
+    let status = response.status();
+    if status.is_redirection() {
+        // 完成后以 303 指向结果 item：`.../items/{id}`。
+        let resource_id = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(item_id_from_location);
+        return Ok(AsyncJobStatus {
+            state: AsyncJobState::Completed,
+            percentage_complete: Some(100.0),
+            resource_id,
+            error_message: None,
+        });
+    }
+    if status.as_u16() == 404 {
+        return Err(TransferError::permanent(
+            "async operation not found or already expired",
+        ));
+    }
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("graph api returned HTTP {status} while polling async operation"),
+        ));
+    }
+    let payload: AsyncJobDto = response.json().map_err(|e| {
+        TransferError::permanent(format!("failed to parse async operation status: {e}"))
+    })?;
+    let state = match payload.status.as_deref() {
+        Some("completed") => AsyncJobState::Completed,
+        Some("failed") | Some("deleteFailed") | Some("cancelled") => AsyncJobState::Failed,
+        _ => AsyncJobState::InProgress,
+    };
+    Ok(AsyncJobStatus {
+        state,
+        percentage_complete: payload.percentage_complete,
+        resource_id: payload.resource_id,
+        error_message: payload.error.and_then(|error| error.message.or(error.code)),
+    })
+}
+
+/// 轮询直到异步操作结束，返回生成的 item id，期间回报完成百分比（0-100）。
+/// - 网络抖动与服务端临时错误只延长轮询间隔继续等待，永久错误立即结束。
+/// - 操作失败时以 `failure_message` 为前缀附上服务端给出的原因。
+/// - `cancel_flag` 置位后停止等待并返回取消；服务端已开始的操作不会被撤回。
+pub(crate) fn wait_for_async_job(
+    monitor_url: &str,
+    cancel_flag: Option<&AtomicBool>,
+    failure_message: &str,
+    mut on_progress: impl FnMut(f64),
+) -> Result<Option<String>, TransferError> {
+    let cancelled = || cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed));
+    let mut failures = 0u32;
+    loop {
+        if cancelled() {
+            return Err(TransferError::cancelled(WAIT_CANCELLED_MESSAGE));
+        }
+        match poll_async_job(monitor_url) {
+            Ok(status) => {
+                failures = 0;
+                if let Some(percentage) = status.percentage_complete {
+                    on_progress(percentage.clamp(0.0, 100.0));
+                }
+                match status.state {
+                    AsyncJobState::Completed => return Ok(status.resource_id),
+                    AsyncJobState::Failed => {
+                        return Err(TransferError::permanent(match status.error_message {
+                            Some(reason) => format!("{failure_message}: {reason}"),
+                            None => failure_message.to_string(),
+                        }));
+                    }
+                    AsyncJobState::InProgress => {}
+                }
+            }
+            Err(err) if err.is_transient() => {
+                failures = failures.saturating_add(1);
+                eprintln!("[drive-async] failed to poll {monitor_url} ({failures}): {err}");
+            }
+            Err(err) => return Err(err),
+        }
+        let delay = poll_delay(failures);
+        match cancel_flag {
+            Some(flag) => {
+                if !wait_or_cancel(delay, flag) {
+                    return Err(TransferError::cancelled(WAIT_CANCELLED_MESSAGE));
+                }
+            }
+            None => thread::sleep(delay),
+        }
+    }
+}
+
+/// 连续失败 `failures` 次后的等待时长。
+fn poll_delay(failures: u32) -> Duration {
+    POLL_INTERVAL
+        .saturating_mul(2u32.saturating_pow(failures))
+        .min(MAX_POLL_INTERVAL)
+}
+
+fn item_id_from_location(location: &str) -> Option<String> {
+    let path = location.split('?').next()?;
+    let (_, rest) = path.rsplit_once("/items/")?;
+    let id = rest.split('/').next()?;
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AsyncJobDto {
+    status: Option<String>,
+    percentage_complete: Option<f64>,
+    resource_id: Option<String>,
+    error: Option<AsyncJobErrorDto>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsyncJobErrorDto {
+    code: Option<String>,
+    message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_id_is_read_from_location() {
+        assert_eq!(
+            item_id_from_location("https://graph.microsoft.com/v1.0/drives/abc/items/01ABCDEF"),
+            Some("01ABCDEF".to_string())
+        );
+        assert_eq!(
+            item_id_from_location("https://example.com/drive/items/01XYZ/children?select=id"),
+            Some("01XYZ".to_string())
+        );
+    }
+
+    #[test]
+    fn location_without_item_yields_none() {
+        assert_eq!(
+            item_id_from_location("https://example.com/drive/root"),
+            None
+        );
+        assert_eq!(item_id_from_location("https://example.com/items/"), None);
+        assert_eq!(
+            item_id_from_location("https://example.com/x?next=/items/1"),
+            None
+        );
+    }
+
+    #[test]
+    fn poll_delay_backs_off_up_to_limit() {
+        assert_eq!(poll_delay(0), POLL_INTERVAL);
+        assert_eq!(poll_delay(1), POLL_INTERVAL * 2);
+        assert_eq!(poll_delay(3), POLL_INTERVAL * 8);
+        assert_eq!(poll_delay(10), MAX_POLL_INTERVAL);
+        assert_eq!(poll_delay(u32::MAX), MAX_POLL_INTERVAL);
+    }
+}
@@ -19,3 +19,23 @@ pub(crate) fn build_blocking_client(timeout: Duration) -> Result<Client, String>
         .build()
         .map_err(|e| format!("failed to build HTTP client: {e}"))
 }
+
+/// 构建不跟随重定向的客户端，用于读取 Graph 异步操作的 monitor 地址：
+/// 操作完成后 monitor 会以 303 指向结果 item，而该地址需要另行携带 token 访问。
+pub(crate) fn build_no_redirect_client(timeout: Duration) -> Result<Client, String> {
+    Client::builder()
+        .timeout(timeout)
+        .redirect(Policy::none())
+        .build()
+        .map_err(|e| format!("failed to build HTTP client: {e}"))
+}
+
+/// 构建长时间流式读取响应体的客户端：只限制建立连接的时间，不限制整体传输时长。
+pub(crate) fn build_streaming_client(connect_timeout: Duration) -> Result<Client, String> {
+    Client::builder()
+        .connect_timeout(connect_timeout)
+        .timeout(None)
+        .redirect(Policy::limited(10))
+        .build()
+        .map_err(|e| format!("failed to build HTTP client: {e}"))
+}
@@ -0,0 +1,108 @@
+use super::{
+    client::{build_blocking_client, current_access_token},
+    GRAPH_BASE,
+};
+use reqwest::header::LOCATION;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 请求 Graph 复制文件/文件夹，返回异步操作的 monitor 地址。
+/// - `target_drive_id` 为空时复制到当前 Drive；跨 Drive 复制需对目标 Drive 有写入权限。
+/// - `target_parent_id` 为空时复制到目标 Drive 的根目录。
+/// - `new_name` 为空时沿用源项目名称，目标位置已存在同名项时服务端会报告失败。
+pub(crate) fn start_copy(
+    item_id: &str,
+    target_drive_id: Option<&str>,
+    target_parent_id: Option<&str>,
+    new_name: Option<&str>,
+) -> Result<String, String> {
+    let access_token = current_access_token()?;
+    let client = build_blocking_client(Duration::from_secs(30))?;
+    let target_parent_id = match target_parent_id {
+        Some(id) => id.to_string(),
+        None => resolve_root_id(&client, &access_token, target_drive_id)?,
+    };
+    let body = CopyRequest {
+        parent_reference: ParentReference {
+            drive_id: target_drive_id.map(str::to_string),
+            id: target_parent_id,
+        },
+        name: new_name.map(str::to_string),
+    };
+    let response = client
+        .post(format!("{GRAPH_BASE}/me/drive/items/{item_id}/copy"))
+        .bearer_auth(&access_token)
+        .header("Accept", "application/json")
+        .json(&body)
+        .send()
+        .map_err(|e| format!("network error while copying drive item: {e}"))?;
+
+    match response.status().as_u16() {
+        202 => response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| "graph api did not return a monitor url for copy".to_string()),
+        401 => Err("access token rejected by Graph API; please sign in again".to_string()),
+        403 => Err("没有复制到目标位置的权限".to_string()),
+        404 => Err("未找到要复制的项目或目标文件夹，可能已被移动或删除".to_string()),
+        409 => Err("目标位置已存在同名项".to_string()),
+        _ => Err(format!(
+            "graph api returned HTTP {} while copying drive item",
+            response.status()
+        )),
+    }
+}
+
+/// 读取目标 Drive 根目录的 item id。
+fn resolve_root_id(
+    client: &reqwest::blocking::Client,
+    access_token: &str,
+    drive_id: Option<&str>,
+) -> Result<String, String> {
+    let url = match drive_id {
+        Some(id) => format!("{GRAPH_BASE}/drives/{id}/root?$select=id"),
+        None => format!("{GRAPH_BASE}/me/drive/root?$select=id"),
+    };
+    let response = client
+        .get(url)
+        .bearer_auth(access_token)
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| format!("network error while resolving drive root: {e}"))?;
+    if response.status().as_u16() == 401 {
+        return Err("access token rejected by Graph API; please sign in again".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "graph api returned HTTP {} while resolving drive root",
+            response.status()
+        ));
+    }
+    let payload: RootItemDto = response
+        .json()
+        .map_err(|e| format!("failed to parse drive root response: {e}"))?;
+    Ok(payload.id)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CopyRequest {
+    parent_reference: ParentReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ParentReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drive_id: Option<String>,
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RootItemDto {
+    id: String,
+}
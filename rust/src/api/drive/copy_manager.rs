@@ -0,0 +1,42 @@
+use super::models::CopyJob;
+use crate::copy_manager::CopyManager;
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+
+/// 复制文件/文件夹：可复制到其他 Drive，或在复制时重命名。复制由服务端异步执行，
+/// 返回的任务通过 `copy_job_stream` 推送进度与结果，完成后 `new_item_id` 为新项目的 id。
+/// `target_parent_id` 为空时复制到目标 Drive 的根目录。
+#[frb]
+pub fn copy_drive_item(
+    item_id: String,
+    target_drive_id: Option<String>,
+    target_parent_id: Option<String>,
+    new_name: Option<String>,
+) -> Result<CopyJob, String> {
+    CopyManager::shared().start(item_id, target_drive_id, target_parent_id, new_name)
+}
+
+/// 全部复制任务（含重启前未结束、仍在跟踪的任务）。
+#[frb]
+pub fn copy_jobs() -> Vec<CopyJob> {
+    CopyManager::shared().jobs()
+}
+
+/// 清除已完成或失败的复制任务记录。
+#[frb]
+pub fn clear_finished_copy_jobs() -> Vec<CopyJob> {
+    CopyManager::shared().clear_history()
+}
+
+/// 推送复制任务的最新状态：发起、进度百分比变化与结束时各推送一次。
+#[frb]
+pub fn copy_job_stream(stream_sink: StreamSink<CopyJob>) {
+    let rx = CopyManager::shared().subscribe();
+    std::thread::spawn(move || {
+        for job in rx {
+            if stream_sink.add(job).is_err() {
+                break;
+            }
+        }
+    });
+}
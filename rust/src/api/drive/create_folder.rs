@@ -0,0 +1,218 @@
+use super::{
+    client::{build_blocking_client, current_access_token},
+    item_lookup::find_child_item,
+    models::{DriveItemSummary, FolderConflictBehavior},
+    GRAPH_BASE,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+/// OneDrive 文件名中不允许出现的字符。
+const INVALID_NAME_CHARS: &[char] = &['"', '*', ':', '<', '>', '?', '/', '\\', '|'];
+
+/// 在父目录（为空时为根目录）下新建文件夹，按 `conflict_behavior` 处理同名项。
+#[flutter_rust_bridge::frb]
+pub fn create_drive_folder(
+    parent_id: Option<String>,
+    name: String,
+    conflict_behavior: FolderConflictBehavior,
+) -> Result<DriveItemSummary, String> {
+    create_folder(parent_id.as_deref(), &name, conflict_behavior)
+}
+
+/// 类似 `mkdir -p`：确保父目录（为空时为根目录）下的相对路径（如 `Projects/2026/Q4`）逐级存在，
+/// 已存在的文件夹直接复用，返回最末一级文件夹。路径中任一级是文件时返回错误。
+#[flutter_rust_bridge::frb]
+pub fn ensure_drive_folder_path(
+    parent_id: Option<String>,
+    path: String,
+) -> Result<DriveItemSummary, String> {
+    let segments = split_folder_path(&path)?;
+    let mut parent_id = parent_id;
+    let mut folder = None;
+    for segment in segments {
+        let item = create_folder(
+            parent_id.as_deref(),
+            segment,
+            FolderConflictBehavior::UseExisting,
+        )?;
+        parent_id = Some(item.id.clone());
+        folder = Some(item);
+    }
+    folder.ok_or_else(|| "folder path is required".to_string())
+}
+
+/// 在父目录下确保存在名为 `name` 的子文件夹，返回其 item id，供文件夹上传逐级重建目录使用。
+pub(crate) fn ensure_child_folder(parent_id: Option<&str>, name: &str) -> Result<String, String> {
+    create_folder(parent_id, name, FolderConflictBehavior::UseExisting).map(|item| item.id)
+}
+
+/// 创建文件夹。复用已有文件夹时先以 `fail` 冲突策略创建，已存在同名项时（409）按路径查询；
+/// 同名项是文件而非文件夹时返回错误，不做重命名。
+fn create_folder(
+    parent_id: Option<&str>,
+    name: &str,
+    conflict_behavior: FolderConflictBehavior,
+) -> Result<DriveItemSummary, String> {
+    let name = name.trim();
+    validate_folder_name(name)?;
+    let parent_id = parent_id.map(str::trim).filter(|id| !id.is_empty());
+    let access_token = current_access_token()?;
+    let client = build_blocking_client(Duration::from_secs(30))?;
+
+    let url = match parent_id {
+        Some(id) => format!("{GRAPH_BASE}/me/drive/items/{id}/children"),
+        None => format!("{GRAPH_BASE}/me/drive/root/children"),
+    };
+    let body = json!({
+        "name": name,
+        "folder": {},
+        "@microsoft.graph.conflictBehavior": conflict_behavior_param(conflict_behavior),
+    });
+    let response = client
+        .post(url)
+        .bearer_auth(&access_token)
+        .header("Accept", "application/json")
+        .json(&body)
+        .send()
+        .map_err(|e| format!("failed to create folder: {e}"))?;
+
+    if response.status().as_u16() == 401 {
+        return Err("access token rejected by Graph API; please sign in again".to_string());
+    }
+    if response.status().as_u16() == 404 {
+        return Err("未找到目标父文件夹，可能已被移动或删除".to_string());
+    }
+    if response.status().as_u16() == 409 {
+        return match conflict_behavior {
+            FolderConflictBehavior::UseExisting => find_existing_folder(parent_id, name),
+            _ => Err(format!("目标位置已存在同名项“{name}”")),
+        };
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "graph api returned HTTP {} while creating folder {name}",
+            response.status()
+        ));
+    }
+    let payload: FolderItemDto = response
+        .json()
+        .map_err(|e| format!("failed to parse create folder response: {e}"))?;
+    Ok(payload.into_summary(name))
+}
+
+/// 读取父目录下的同名项，仅在其为文件夹时复用。
+fn find_existing_folder(parent_id: Option<&str>, name: &str) -> Result<DriveItemSummary, String> {
+    match find_child_item(parent_id, name)? {
+        Some(item) if item.is_folder => Ok(DriveItemSummary {
+            id: item.id,
+            name: item.name,
+            size: item.size,
+            is_folder: true,
+            child_count: None,
+            mime_type: None,
+            last_modified: None,
+            thumbnail_url: None,
+        }),
+        Some(_) => Err(format!("目标位置已存在同名文件“{name}”，无法创建文件夹")),
+        None => Err(format!("文件夹“{name}”创建冲突，但未能找到同名项")),
+    }
+}
+
+fn conflict_behavior_param(behavior: FolderConflictBehavior) -> &'static str {
+    match behavior {
+        FolderConflictBehavior::Fail | FolderConflictBehavior::UseExisting => "fail",
+        FolderConflictBehavior::Rename => "rename",
+        FolderConflictBehavior::Replace => "replace",
+    }
+}
+
+fn validate_folder_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("folder name is required".to_string());
+    }
+    if name == "." || name == ".." {
+        return Err(format!("无效的文件夹名称“{name}”"));
+    }
+    if name.contains(INVALID_NAME_CHARS) {
+        return Err(format!(
+            "文件夹名称“{name}”包含不允许的字符（\" * : < > ? / \\ |）"
+        ));
+    }
+    Ok(())
+}
+
+/// 按 `/` 或 `\` 拆分相对路径，忽略空段与 `.`；不允许 `..` 跳出父目录。
+fn split_folder_path(path: &str) -> Result<Vec<&str>, String> {
+    let segments: Vec<&str> = path
+        .split(['/', '\\'])
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect();
+    if segments.contains(&"..") {
+        return Err("文件夹路径不能包含“..”".to_string());
+    }
+    if segments.is_empty() {
+        return Err("folder path is required".to_string());
+    }
+    Ok(segments)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FolderItemDto {
+    id: String,
+    name: Option<String>,
+    size: Option<u64>,
+    folder: Option<FolderFacetDto>,
+    last_modified_date_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FolderFacetDto {
+    child_count: Option<i64>,
+}
+
+impl FolderItemDto {
+    fn into_summary(self, requested_name: &str) -> DriveItemSummary {
+        DriveItemSummary {
+            id: self.id,
+            name: self.name.unwrap_or_else(|| requested_name.to_string()),
+            size: self.size,
+            is_folder: true,
+            child_count: self.folder.and_then(|folder| folder.child_count),
+            mime_type: None,
+            last_modified: self.last_modified_date_time,
+            thumbnail_url: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_both_separators_and_trims_segments() {
+        assert_eq!(
+            split_folder_path("/Documents\\ 2024 /./Reports/").unwrap(),
+            ["Documents", "2024", "Reports"]
+        );
+        assert_eq!(split_folder_path("Photos").unwrap(), ["Photos"]);
+    }
+
+    #[test]
+    fn rejects_parent_segments() {
+        assert!(split_folder_path("Documents/../Secrets").is_err());
+        assert!(split_folder_path("..").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_paths() {
+        for path in ["", "/", " / ./ ", "\\\\"] {
+            assert!(split_folder_path(path).is_err(), "{path:?}");
+        }
+    }
+}
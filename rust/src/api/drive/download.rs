@@ -1,23 +1,35 @@
 use super::{
     client::{build_blocking_client, current_access_token},
-    models::DriveDownloadResult,
+    download_attributes::apply_remote_attributes,
+    download_endpoint::{needs_refresh, DownloadEndpoint},
+    download_segments::{segmented_download, should_use_segments, supports_range_requests},
+    models::{DownloadConflictOutcome, DownloadConflictPolicy, DriveDownloadResult},
     GRAPH_BASE,
 };
+use crate::bandwidth::{throttle, TransferDirection};
+use crate::retry::{TransferError, DOWNLOAD_URL_EXPIRED_MESSAGE};
+use chrono::DateTime;
+use reqwest::{blocking::Client, StatusCode};
 use serde::Deserialize;
 use std::{
-    fs::{self, File},
-    io::{BufWriter, Read, Write},
+    fs::{self, File, OpenOptions},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    thread,
+    time::{Duration, SystemTime},
 };
 
+/// 单连接下载在连接中断且没有新进展时的续传次数上限。
+const STREAM_RESUME_MAX_ATTEMPTS: usize = 3;
+const STREAM_RESUME_BASE_DELAY_MS: u64 = 500;
+
 /// 回调函数签名：传入当前已下载字节数以及 Graph 预估的总大小。
 /// - `Option<u64>` 用于处理 Graph 未返回 size 的场景。
-type ProgressCallback = Box<dyn FnMut(u64, Option<u64>) + Send>;
+pub(crate) type ProgressCallback = Box<dyn FnMut(u64, Option<u64>) + Send>;
 
 /// 下载指定 drive item（仅文件），保存到 target_dir。
 /// - 优先使用 Graph 返回的 downloadUrl（免鉴权）。
@@ -29,37 +41,50 @@ pub fn download_drive_item(
     target_dir: String,
     overwrite: bool,
 ) -> Result<DriveDownloadResult, String> {
-    download_drive_item_internal(item_id, target_dir, overwrite, None, None)
+    let policy = if overwrite {
+        DownloadConflictPolicy::Overwrite
+    } else {
+        DownloadConflictPolicy::Ask
+    };
+    let result = download_drive_item_internal(item_id, target_dir, policy, None, None)?;
+    if result.conflict_outcome == DownloadConflictOutcome::AwaitingDecision {
+        return Err(format!(
+            "文件已存在：{}（如需覆盖请设置 overwrite=true）",
+            result.saved_path
+        ));
+    }
+    Ok(result)
 }
 
 /// 供下载管理器调用的进度版下载函数。
 /// - `progress` 为可选回调，便于任务管理器实时同步进度。
+/// - `conflict_policy` 决定目标文件已存在时的处理方式，结果记录在返回值中。
 pub(crate) fn download_drive_item_with_progress(
     item_id: String,
     target_dir: String,
-    overwrite: bool,
+    conflict_policy: DownloadConflictPolicy,
     progress: Option<ProgressCallback>,
     cancel_flag: Option<Arc<AtomicBool>>,
-) -> Result<DriveDownloadResult, String> {
-    download_drive_item_internal(item_id, target_dir, overwrite, progress, cancel_flag)
+) -> Result<DriveDownloadResult, TransferError> {
+    download_drive_item_internal(item_id, target_dir, conflict_policy, progress, cancel_flag)
 }
 
 /// 实际执行下载的内部实现，共享输入验证与文件保存逻辑。
 fn download_drive_item_internal(
     item_id: String,
     target_dir: String,
-    overwrite: bool,
+    conflict_policy: DownloadConflictPolicy,
     mut progress: Option<ProgressCallback>,
     cancel_flag: Option<Arc<AtomicBool>>,
-) -> Result<DriveDownloadResult, String> {
+) -> Result<DriveDownloadResult, TransferError> {
     if item_id.trim().is_empty() {
-        return Err("drive item id is required".to_string());
+        return Err(TransferError::permanent("drive item id is required"));
     }
     if target_dir.trim().is_empty() {
-        return Err("target directory is required".to_string());
+        return Err(TransferError::permanent("target directory is required"));
     }
 
-    let access_token = current_access_token()?;
+    let access_token = current_access_token().map_err(TransferError::permanent)?;
     eprintln!("[drive-download] fetching metadata for item {}", item_id);
     let metadata = fetch_download_metadata(&item_id, &access_token)?;
 
@@ -68,26 +93,15 @@ fn download_drive_item_internal(
             "[drive-download] item {} has no file facet (name={:?})",
             item_id, metadata.name
         );
-        return Err("选中的项目不是可下载的文件".to_string());
+        return Err(TransferError::permanent("选中的项目不是可下载的文件"));
     }
 
-    let (download_endpoint, bearer_token) = match metadata.download_url.as_ref() {
-        Some(url) => {
-            eprintln!(
-                "[drive-download] using pre-authenticated download url for {}",
-                item_id
-            );
-            (url.clone(), None)
-        }
-        None => {
-            eprintln!(
-                "[drive-download] missing downloadUrl, fallback to /content for {}",
-                item_id
-            );
-            let content_url = format!("{GRAPH_BASE}/me/drive/items/{item_id}/content");
-            (content_url, Some(access_token.as_str()))
-        }
-    };
+    let endpoint = DownloadEndpoint::new(
+        &item_id,
+        metadata.download_url.clone(),
+        &access_token,
+        metadata.e_tag.clone(),
+    );
 
     let file_name = metadata
         .name
@@ -95,26 +109,70 @@ fn download_drive_item_internal(
         .map(sanitize_file_name)
         .unwrap_or_else(|| "download.bin".to_string());
 
-    let destination = prepare_destination(&target_dir, &file_name, overwrite)?;
-    if let Some(cb) = progress.as_mut() {
-        cb(0, metadata.size);
+    // 本地文件的修改时间会同步为 fileSystemInfo，比较新旧时需使用同一来源。
+    let remote_modified = metadata
+        .file_system_info
+        .as_ref()
+        .and_then(|info| info.last_modified_date_time.as_deref())
+        .or(metadata.last_modified_date_time.as_deref());
+    let (destination, conflict_outcome) =
+        prepare_destination(&target_dir, &file_name, &conflict_policy, remote_modified)
+            .map_err(TransferError::permanent)?;
+    if matches!(
+        conflict_outcome,
+        DownloadConflictOutcome::Skipped | DownloadConflictOutcome::AwaitingDecision
+    ) {
+        eprintln!(
+            "[drive-download] destination {} exists, outcome={:?}",
+            destination.to_string_lossy(),
+            conflict_outcome
+        );
+        return Ok(DriveDownloadResult {
+            file_name,
+            saved_path: destination.to_string_lossy().into_owned(),
+            bytes_downloaded: 0,
+            expected_size: metadata.size,
+            conflict_outcome,
+        });
     }
-    let mut progress_ref = progress
+    let progress_ref = progress
         .as_mut()
         .map(|cb| cb.as_mut() as &mut (dyn FnMut(u64, Option<u64>) + Send));
-    let bytes_downloaded = stream_download(
-        &download_endpoint,
-        bearer_token,
-        &destination,
-        metadata.size,
-        progress_ref.as_deref_mut(),
-        cancel_flag.as_ref(),
-    )?;
+    // 大文件且端点支持 Range 时走多连接分段下载，否则保持单连接流式写入。
+    let use_segments = metadata.size.map(should_use_segments).unwrap_or(false)
+        && supports_range_requests(&endpoint);
+    let bytes_downloaded = match metadata.size {
+        Some(total_size) if use_segments => segmented_download(
+            &endpoint,
+            &destination,
+            total_size,
+            progress_ref,
+            cancel_flag.as_ref(),
+        )?,
+        _ => stream_download(
+            &endpoint,
+            &destination,
+            metadata.size,
+            progress_ref,
+            cancel_flag.as_ref(),
+        )?,
+    };
     eprintln!(
         "[drive-download] saved {} bytes to {}",
         bytes_downloaded,
         destination.to_string_lossy()
     );
+    apply_remote_attributes(
+        &destination,
+        &item_id,
+        metadata.e_tag.as_deref(),
+        metadata
+            .file_system_info
+            .as_ref()
+            .and_then(|info| info.created_date_time.as_deref())
+            .and_then(parse_graph_timestamp),
+        remote_modified.and_then(parse_graph_timestamp),
+    );
     let saved_path = destination
         .canonicalize()
         .unwrap_or(destination.clone())
@@ -126,41 +184,49 @@ fn download_drive_item_internal(
         saved_path,
         bytes_downloaded,
         expected_size: metadata.size,
+        conflict_outcome,
     })
 }
 
-fn fetch_download_metadata(
+/// 获取下载所需的元数据，链接过期刷新时也会复用。
+pub(super) fn fetch_download_metadata(
     item_id: &str,
     access_token: &str,
-) -> Result<DriveItemDownloadDto, String> {
+) -> Result<DriveItemDownloadDto, TransferError> {
     // 单次请求只关心必要字段，避免传输冗余信息。
-    let client = build_blocking_client(Duration::from_secs(30))?;
+    let client =
+        build_blocking_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
     let url = format!(
-        "{GRAPH_BASE}/me/drive/items/{item_id}?$select=name,size,file,@microsoft.graph.downloadUrl"
+        "{GRAPH_BASE}/me/drive/items/{item_id}?$select=name,size,file,eTag,lastModifiedDateTime,fileSystemInfo,@microsoft.graph.downloadUrl"
     );
     let response = client
         .get(url)
         .bearer_auth(access_token)
         .header("Accept", "application/json")
         .send()
-        .map_err(|e| format!("failed to fetch download metadata: {e}"))?;
+        .map_err(|e| TransferError::transient(format!("failed to fetch download metadata: {e}")))?;
 
-    if response.status().as_u16() == 401 {
-        return Err("access token rejected by Graph API; please sign in again".to_string());
+    let status = response.status();
+    if status.as_u16() == 401 {
+        return Err(TransferError::permanent(
+            "access token rejected by Graph API; please sign in again",
+        ));
     }
-    if response.status().as_u16() == 404 {
-        return Err("找不到指定的文件，可能已经被移动或删除".to_string());
+    if status.as_u16() == 404 {
+        return Err(TransferError::permanent(
+            "找不到指定的文件，可能已经被移动或删除",
+        ));
     }
-    if !response.status().is_success() {
-        return Err(format!(
-            "graph api returned HTTP {} while fetching download info",
-            response.status()
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("graph api returned HTTP {status} while fetching download info"),
         ));
     }
 
     response
         .json::<DriveItemDownloadDto>()
-        .map_err(|e| format!("failed to parse download metadata: {e}"))
+        .map_err(|e| TransferError::permanent(format!("failed to parse download metadata: {e}")))
 }
 
 /// 对 Graph 返回的文件名进行清洗，兼容不同桌面平台的非法字符。
@@ -189,101 +255,371 @@ fn sanitize_file_name(raw: &str) -> String {
     }
 }
 
-/// 创建下载目录并返回目标文件路径，避免覆盖已存在文件（除非设置 overwrite）。
+/// 创建下载目录并按冲突策略决定目标文件路径。
+/// - 返回的 outcome 为 `Skipped`/`AwaitingDecision` 时，路径指向已存在的本地文件，调用方不应写入。
 fn prepare_destination(
     target_dir: &str,
     file_name: &str,
-    overwrite: bool,
-) -> Result<PathBuf, String> {
+    policy: &DownloadConflictPolicy,
+    remote_modified: Option<&str>,
+) -> Result<(PathBuf, DownloadConflictOutcome), String> {
     let dir_path = Path::new(target_dir);
     fs::create_dir_all(dir_path)
         .map_err(|e| format!("无法创建下载目录 {}: {e}", dir_path.to_string_lossy()))?;
 
     let destination = dir_path.join(file_name);
-    if destination.exists() && !overwrite {
-        return Err(format!(
-            "文件已存在：{}（如需覆盖请设置 overwrite=true）",
+    if !destination.exists() {
+        return Ok((destination, DownloadConflictOutcome::NoConflict));
+    }
+
+    let outcome = match policy {
+        DownloadConflictPolicy::Overwrite => DownloadConflictOutcome::Overwritten,
+        DownloadConflictPolicy::Skip => DownloadConflictOutcome::Skipped,
+        DownloadConflictPolicy::Ask => DownloadConflictOutcome::AwaitingDecision,
+        DownloadConflictPolicy::KeepBoth => {
+            let renamed = next_available_path(dir_path, file_name);
+            return Ok((renamed, DownloadConflictOutcome::KeptBoth));
+        }
+        DownloadConflictPolicy::OverwriteIfNewer => {
+            if is_remote_newer(&destination, remote_modified) {
+                DownloadConflictOutcome::Overwritten
+            } else {
+                DownloadConflictOutcome::Skipped
+            }
+        }
+    };
+    Ok((destination, outcome))
+}
+
+/// 为 "保留两者" 生成不冲突的文件名：`name (1).ext`、`name (2).ext`……
+pub(crate) fn next_available_path(dir_path: &Path, file_name: &str) -> PathBuf {
+    let original = Path::new(file_name);
+    let stem = original
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| file_name.to_string());
+    let extension = original
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    let mut index: u32 = 1;
+    loop {
+        let candidate = dir_path.join(format!("{stem} ({index}){extension}"));
+        if !candidate.exists() {
+            return candidate;
+        }
+        index += 1;
+    }
+}
+
+/// 下载过程中写入的临时文件：与目标同目录的 `<name>.part`，成功后再重命名覆盖目标。
+pub(crate) fn partial_path(destination: &Path) -> PathBuf {
+    let mut name = destination
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".part");
+    destination.with_file_name(name)
+}
+
+/// 将下载完成的临时文件替换到目标位置；覆盖策略下原文件直到这一步才被替换。
+pub(crate) fn commit_partial(partial: &Path, destination: &Path) -> Result<(), TransferError> {
+    fs::rename(partial, destination).map_err(|e| {
+        let _ = fs::remove_file(partial);
+        TransferError::permanent(format!(
+            "failed to move downloaded file to {}: {e}",
             destination.to_string_lossy()
-        ));
+        ))
+    })
+}
+
+/// 续传状态文件：与临时文件同目录的 `<name>.part.state`，首行为开始下载时的 eTag，
+/// 分段下载再逐行追加各分段进度。
+fn partial_state_path(partial: &Path) -> PathBuf {
+    let mut name = partial
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(".state");
+    partial.with_file_name(name)
+}
+
+/// 失败后保存续传状态，返回是否保存成功；远端没有 eTag 时无法确认内容未变，不保存。
+pub(crate) fn save_partial_state(partial: &Path, e_tag: Option<&str>, lines: &[String]) -> bool {
+    let Some(e_tag) = e_tag else {
+        return false;
+    };
+    let mut content = format!("{e_tag}\n");
+    for line in lines {
+        content.push_str(line);
+        content.push('\n');
+    }
+    fs::write(partial_state_path(partial), content).is_ok()
+}
+
+/// 读取并删除续传状态；临时文件存在且 eTag 与远端一致时返回附加的进度行，否则返回 None。
+pub(crate) fn take_partial_state(partial: &Path, e_tag: Option<&str>) -> Option<Vec<String>> {
+    let state_path = partial_state_path(partial);
+    let content = fs::read_to_string(&state_path).ok();
+    let _ = fs::remove_file(&state_path);
+    let content = content?;
+    let mut lines = content.lines();
+    if e_tag.is_none() || lines.next() != e_tag || !partial.is_file() {
+        return None;
     }
+    Some(lines.map(str::to_string).collect())
+}
+
+/// 比较远端 lastModifiedDateTime 与本地文件修改时间；任一缺失时视为"不更新"，避免误覆盖。
+fn is_remote_newer(local_path: &Path, remote_modified: Option<&str>) -> bool {
+    let remote = match remote_modified.and_then(parse_graph_timestamp) {
+        Some(value) => value,
+        None => return false,
+    };
+    let local = match fs::metadata(local_path).and_then(|meta| meta.modified()) {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+    remote > local
+}
 
-    Ok(destination)
+/// 解析 Graph 返回的 ISO 8601 时间戳（如 `2024-05-01T08:00:00Z`）。
+pub(crate) fn parse_graph_timestamp(raw: &str) -> Option<SystemTime> {
+    DateTime::parse_from_rfc3339(raw.trim())
+        .ok()
+        .map(SystemTime::from)
 }
 
 /// 实际执行 HTTP 下载并流式写入磁盘，必要时附带 Bearer token。
 /// 逐块读取响应体，写入文件后触发进度回调，确保 UI 能看到实时变化。
+/// 连接中途断开或预签名链接过期时，通过 Range 从已写入位置续传，服务端不支持时从头重写。
 fn stream_download(
-    download_url: &str,
-    bearer_token: Option<&str>,
+    endpoint: &DownloadEndpoint,
     destination: &Path,
     total_size: Option<u64>,
     mut progress: Option<&mut (dyn FnMut(u64, Option<u64>) + Send)>,
     cancel_flag: Option<&Arc<AtomicBool>>,
-) -> Result<u64, String> {
-    let client = build_blocking_client(Duration::from_secs(600))?;
-    let mut request = client.get(download_url);
-    if let Some(token) = bearer_token {
+) -> Result<u64, TransferError> {
+    let client =
+        build_blocking_client(Duration::from_secs(600)).map_err(TransferError::permanent)?;
+    let partial = partial_path(destination);
+    // 上次瞬时失败留下的临时文件且远端未变时从其末尾续传；已达到远端大小的残留无法确认完整，重新下载。
+    let resume_from = match take_partial_state(&partial, endpoint.etag()) {
+        Some(lines) if lines.is_empty() => fs::metadata(&partial).map(|m| m.len()).unwrap_or(0),
+        _ => 0,
+    };
+    let resume_from = match total_size {
+        Some(total) if resume_from >= total => 0,
+        _ => resume_from,
+    };
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(resume_from == 0)
+        .open(&partial)
+        .map_err(|e| {
+            TransferError::permanent(format!(
+                "failed to create temporary file {}: {e}",
+                partial.to_string_lossy()
+            ))
+        })?;
+    file.seek(SeekFrom::Start(resume_from))
+        .map_err(|e| TransferError::permanent(format!("failed to seek temporary file: {e}")))?;
+    let mut writer = BufWriter::new(file);
+
+    let mut downloaded: u64 = resume_from;
+    if resume_from > 0 {
+        eprintln!(
+            "[drive-download] resuming {} from {resume_from} bytes",
+            partial.to_string_lossy()
+        );
+    }
+    if let Some(cb) = progress.as_mut() {
+        cb(downloaded, total_size);
+    }
+    let mut attempt = 0;
+    let result = loop {
+        let before = downloaded;
+        match stream_download_once(
+            &client,
+            endpoint,
+            &mut writer,
+            &mut downloaded,
+            total_size,
+            &mut progress,
+            cancel_flag,
+        ) {
+            Ok(()) => break Ok(()),
+            Err(StreamError::Fatal(err)) => break Err(err),
+            Err(StreamError::Expired(generation)) => {
+                // 换取新链接后立即从当前偏移继续，不计入中断重试次数。
+                if let Err(err) = endpoint.refresh(generation) {
+                    break Err(err);
+                }
+            }
+            Err(StreamError::Interrupted(msg)) => {
+                if downloaded > before {
+                    attempt = 0;
+                }
+                attempt += 1;
+                if attempt > STREAM_RESUME_MAX_ATTEMPTS {
+                    break Err(TransferError::transient(msg));
+                }
+                eprintln!(
+                    "[drive-download] stream interrupted at {downloaded} bytes (attempt {attempt}): {msg}"
+                );
+                let backoff = STREAM_RESUME_BASE_DELAY_MS * 2u64.saturating_pow(attempt as u32);
+                thread::sleep(Duration::from_millis(backoff));
+            }
+        }
+    };
+
+    let result = result.and_then(|_| {
+        writer
+            .flush()
+            .map_err(|e| TransferError::permanent(format!("failed to flush file: {e}")))
+    });
+    drop(writer);
+    if let Err(err) = result {
+        // 瞬时失败保留临时文件供重试续传，其余情况只清理临时文件，覆盖策略下的原文件保持不动。
+        let keep = err.is_transient()
+            && downloaded > 0
+            && save_partial_state(&partial, endpoint.etag(), &[]);
+        if !keep {
+            let _ = fs::remove_file(&partial);
+        }
+        return Err(err);
+    }
+    commit_partial(&partial, destination)?;
+    Ok(downloaded)
+}
+
+enum StreamError {
+    /// 连接层面的中断，可从断点续传。
+    Interrupted(String),
+    /// 端点拒绝了该代次的链接，需要刷新后续传。
+    Expired(u64),
+    Fatal(TransferError),
+}
+
+/// 发起一次 GET 并把响应体追加写入；`downloaded` 大于 0 时携带 Range 续传。
+#[allow(clippy::too_many_arguments)]
+fn stream_download_once(
+    client: &Client,
+    endpoint: &DownloadEndpoint,
+    writer: &mut BufWriter<File>,
+    downloaded: &mut u64,
+    total_size: Option<u64>,
+    progress: &mut Option<&mut (dyn FnMut(u64, Option<u64>) + Send)>,
+    cancel_flag: Option<&Arc<AtomicBool>>,
+) -> Result<(), StreamError> {
+    let target = endpoint.snapshot();
+    let pre_authenticated = target.bearer_token.is_none();
+    let mut request = client.get(&target.url);
+    if *downloaded > 0 {
+        request = request.header("Range", format!("bytes={}-", *downloaded));
+    }
+    if let Some(token) = target.bearer_token.as_deref() {
         request = request.bearer_auth(token);
     }
     let mut response = request
         .send()
-        .map_err(|e| format!("failed to download file: {e}"))?;
+        .map_err(|e| StreamError::Interrupted(format!("failed to download file: {e}")))?;
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "download endpoint returned HTTP {}",
-            response.status()
-        ));
+    let status = response.status();
+    if !status.is_success() {
+        if needs_refresh(status, pre_authenticated) {
+            return Err(StreamError::Expired(target.generation));
+        }
+        let err = endpoint_status_error(status, pre_authenticated);
+        return if err.is_transient() {
+            Err(StreamError::Interrupted(err.message))
+        } else {
+            Err(StreamError::Fatal(err))
+        };
+    }
+    if *downloaded > 0 && status.as_u16() != 206 {
+        // 服务端忽略了 Range，只能丢弃已写入内容从头开始。
+        writer
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| writer.get_ref().set_len(0))
+            .map_err(|e| {
+                StreamError::Fatal(TransferError::permanent(format!(
+                    "failed to reset destination file: {e}"
+                )))
+            })?;
+        *downloaded = 0;
     }
 
-    let file = File::create(destination).map_err(|e| {
-        format!(
-            "failed to create destination file {}: {e}",
-            destination.to_string_lossy()
-        )
-    })?;
-    let mut writer = BufWriter::new(file);
-
-    let mut downloaded: u64 = 0;
     let mut buffer = [0u8; 64 * 1024];
     loop {
         if let Some(flag) = cancel_flag {
             if flag.load(Ordering::Relaxed) {
-                drop(response);
-                drop(writer);
-                let _ = fs::remove_file(destination);
-                return Err("下载已取消".to_string());
+                return Err(StreamError::Fatal(TransferError::cancelled("下载已取消")));
             }
         }
         let read_bytes = response
             .read(&mut buffer)
-            .map_err(|e| format!("failed to read response body: {e}"))?;
+            .map_err(|e| StreamError::Interrupted(format!("failed to read response body: {e}")))?;
         if read_bytes == 0 {
             break;
         }
-        writer
-            .write_all(&buffer[..read_bytes])
-            .map_err(|e| format!("failed to write file: {e}"))?;
-        downloaded += read_bytes as u64;
-        if let Some(ref mut cb) = progress {
-            cb(downloaded, total_size);
+        throttle(
+            TransferDirection::Download,
+            read_bytes,
+            cancel_flag.map(|flag| flag.as_ref()),
+        );
+        writer.write_all(&buffer[..read_bytes]).map_err(|e| {
+            StreamError::Fatal(TransferError::permanent(format!(
+                "failed to write file: {e}"
+            )))
+        })?;
+        *downloaded += read_bytes as u64;
+        if let Some(cb) = progress.as_mut() {
+            cb(*downloaded, total_size);
+        }
+    }
+    if let Some(expected) = total_size {
+        if *downloaded < expected {
+            return Err(StreamError::Interrupted(
+                "connection closed before download completed".to_string(),
+            ));
         }
     }
-    writer
-        .flush()
-        .map_err(|e| format!("failed to flush file: {e}"))?;
+    Ok(())
+}
 
-    Ok(downloaded)
+/// 下载端点返回非成功状态时的错误；预签名链接被拒通常意味着链接已过期，重新获取即可，按瞬时失败处理。
+pub(crate) fn endpoint_status_error(status: StatusCode, pre_authenticated: bool) -> TransferError {
+    if pre_authenticated && matches!(status.as_u16(), 401 | 403 | 410) {
+        return TransferError::transient(format!(
+            "{DOWNLOAD_URL_EXPIRED_MESSAGE}（HTTP {status}）"
+        ));
+    }
+    TransferError::from_status(status, format!("download endpoint returned HTTP {status}"))
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct DriveItemDownloadDto {
+pub(super) struct DriveItemDownloadDto {
     name: Option<String>,
     size: Option<u64>,
     file: Option<DriveFileFacet>,
+    #[serde(rename = "eTag")]
+    pub(super) e_tag: Option<String>,
+    #[serde(rename = "lastModifiedDateTime")]
+    last_modified_date_time: Option<String>,
+    file_system_info: Option<FileSystemInfoDto>,
     #[serde(rename = "@microsoft.graph.downloadUrl")]
-    download_url: Option<String>,
+    pub(super) download_url: Option<String>,
+}
+
+/// 客户端上报的文件时间（上传前本地文件的创建/修改时间），与服务端修改时间不同。
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileSystemInfoDto {
+    created_date_time: Option<String>,
+    last_modified_date_time: Option<String>,
 }
 
 #[allow(dead_code)] // metadata 中可能暂时只读取 mime_type，因此关闭未使用告警
@@ -292,3 +628,141 @@ struct DriveFileFacet {
     #[serde(rename = "mimeType")]
     mime_type: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("skydrivex-download-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn next_available_path_appends_first_free_index() {
+        let dir = temp_dir("next-path");
+        assert_eq!(
+            next_available_path(&dir, "report.pdf"),
+            dir.join("report (1).pdf")
+        );
+        fs::write(dir.join("report (1).pdf"), b"").unwrap();
+        fs::write(dir.join("report (2).pdf"), b"").unwrap();
+        assert_eq!(
+            next_available_path(&dir, "report.pdf"),
+            dir.join("report (3).pdf")
+        );
+        assert_eq!(next_available_path(&dir, "README"), dir.join("README (1)"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn commit_partial_replaces_existing_destination() {
+        let dir = temp_dir("partial");
+        let destination = dir.join("report.pdf");
+        let partial = partial_path(&destination);
+        assert_eq!(partial, dir.join("report.pdf.part"));
+        fs::write(&destination, b"old").unwrap();
+        fs::write(&partial, b"new").unwrap();
+        commit_partial(&partial, &destination).unwrap();
+        assert_eq!(fs::read(&destination).unwrap(), b"new");
+        assert!(!partial.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn partial_state_requires_matching_etag() {
+        let dir = temp_dir("partial-state");
+        let partial = dir.join("report.pdf.part");
+        fs::write(&partial, b"half").unwrap();
+        let lines = vec!["0 9 4".to_string()];
+
+        assert!(!save_partial_state(&partial, None, &lines));
+        assert!(save_partial_state(&partial, Some("etag-1"), &lines));
+        assert_eq!(
+            take_partial_state(&partial, Some("etag-1")),
+            Some(lines.clone())
+        );
+        // 状态读取一次即删除，避免同一份进度被重复使用。
+        assert_eq!(take_partial_state(&partial, Some("etag-1")), None);
+
+        assert!(save_partial_state(&partial, Some("etag-1"), &lines));
+        assert_eq!(take_partial_state(&partial, Some("etag-2")), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn prepare_destination_without_conflict_creates_directory() {
+        let dir = temp_dir("no-conflict").join("nested");
+        let target = dir.to_string_lossy().into_owned();
+        let (path, outcome) =
+            prepare_destination(&target, "a.txt", &DownloadConflictPolicy::Skip, None).unwrap();
+        assert!(dir.is_dir());
+        assert_eq!(path, dir.join("a.txt"));
+        assert_eq!(outcome, DownloadConflictOutcome::NoConflict);
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn prepare_destination_applies_conflict_policy() {
+        let dir = temp_dir("conflict");
+        let target = dir.to_string_lossy().into_owned();
+        let existing = dir.join("a.txt");
+        fs::write(&existing, b"local").unwrap();
+
+        let cases = [
+            (
+                DownloadConflictPolicy::Overwrite,
+                existing.clone(),
+                DownloadConflictOutcome::Overwritten,
+            ),
+            (
+                DownloadConflictPolicy::Skip,
+                existing.clone(),
+                DownloadConflictOutcome::Skipped,
+            ),
+            (
+                DownloadConflictPolicy::Ask,
+                existing.clone(),
+                DownloadConflictOutcome::AwaitingDecision,
+            ),
+            (
+                DownloadConflictPolicy::KeepBoth,
+                dir.join("a (1).txt"),
+                DownloadConflictOutcome::KeptBoth,
+            ),
+        ];
+        for (policy, expected_path, expected_outcome) in cases {
+            let (path, outcome) = prepare_destination(&target, "a.txt", &policy, None).unwrap();
+            assert_eq!(path, expected_path);
+            assert_eq!(outcome, expected_outcome);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn prepare_destination_overwrites_only_when_remote_is_newer() {
+        let dir = temp_dir("if-newer");
+        let target = dir.to_string_lossy().into_owned();
+        let existing = dir.join("a.txt");
+        fs::write(&existing, b"local").unwrap();
+        File::options()
+            .write(true)
+            .open(&existing)
+            .unwrap()
+            .set_modified(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            .unwrap();
+        let policy = DownloadConflictPolicy::OverwriteIfNewer;
+
+        let newer = prepare_destination(&target, "a.txt", &policy, Some("2024-05-01T08:00:00Z"));
+        assert_eq!(newer.unwrap().1, DownloadConflictOutcome::Overwritten);
+        let older = prepare_destination(&target, "a.txt", &policy, Some("2023-01-01T00:00:00Z"));
+        assert_eq!(older.unwrap().1, DownloadConflictOutcome::Skipped);
+        let unknown = prepare_destination(&target, "a.txt", &policy, None);
+        assert_eq!(unknown.unwrap().1, DownloadConflictOutcome::Skipped);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
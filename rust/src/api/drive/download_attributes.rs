@@ -0,0 +1,107 @@
+use crate::settings::download_item_xattr::{
+    default_download_item_xattr_enabled, get_download_item_xattr_enabled,
+};
+use std::{
+    fs::{FileTimes, OpenOptions},
+    path::Path,
+    time::SystemTime,
+};
+
+/// 扩展属性名：Unix 上写入 `user.` 命名空间，Windows 上写入同名 NTFS 备用数据流。
+#[cfg(not(windows))]
+const ITEM_ID_ATTR: &str = "user.skydrivex.item_id";
+#[cfg(not(windows))]
+const ETAG_ATTR: &str = "user.skydrivex.etag";
+#[cfg(windows)]
+const ITEM_ID_ATTR: &str = "skydrivex.item_id";
+#[cfg(windows)]
+const ETAG_ATTR: &str = "skydrivex.etag";
+
+/// 下载完成后写回远端文件属性：扩展属性按设置写入，时间戳始终同步。
+/// 写入备用数据流会刷新 Windows 上的修改时间，因此时间戳放在最后设置。
+/// 任一步骤失败只记录日志，不影响已落盘的下载结果。
+pub(crate) fn apply_remote_attributes(
+    path: &Path,
+    item_id: &str,
+    etag: Option<&str>,
+    created: Option<SystemTime>,
+    modified: Option<SystemTime>,
+) {
+    let xattr_enabled = get_download_item_xattr_enabled().unwrap_or_else(|err| {
+        eprintln!("[drive-download] failed to load xattr setting: {err}; fallback to default");
+        default_download_item_xattr_enabled()
+    });
+    if xattr_enabled {
+        let mut attributes = vec![(ITEM_ID_ATTR, item_id)];
+        if let Some(etag) = etag {
+            attributes.push((ETAG_ATTR, etag));
+        }
+        for (name, value) in attributes {
+            if let Err(err) = write_attribute(path, name, value) {
+                eprintln!(
+                    "[drive-download] failed to write {name} on {}: {err}",
+                    path.display()
+                );
+            }
+        }
+    }
+    if let Err(err) = set_file_times(path, created, modified) {
+        eprintln!(
+            "[drive-download] failed to set timestamps on {}: {err}",
+            path.display()
+        );
+    }
+}
+
+/// 设置修改时间；创建时间仅在 Windows/macOS 上可写，其他平台忽略。
+fn set_file_times(
+    path: &Path,
+    created: Option<SystemTime>,
+    modified: Option<SystemTime>,
+) -> std::io::Result<()> {
+    let mut times = FileTimes::new();
+    let mut changed = false;
+    if let Some(modified) = modified {
+        times = times.set_modified(modified);
+        changed = true;
+    }
+    #[cfg(windows)]
+    if let Some(created) = created {
+        use std::os::windows::fs::FileTimesExt;
+        times = times.set_created(created);
+        changed = true;
+    }
+    #[cfg(target_os = "macos")]
+    if let Some(created) = created {
+        use std::os::macos::fs::FileTimesExt;
+        times = times.set_created(created);
+        changed = true;
+    }
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let _ = created;
+    if !changed {
+        return Ok(());
+    }
+    OpenOptions::new().write(true).open(path)?.set_times(times)
+}
+
+#[cfg(unix)]
+fn write_attribute(path: &Path, name: &str, value: &str) -> std::io::Result<()> {
+    xattr::set(path, name, value.as_bytes())
+}
+
+#[cfg(windows)]
+fn write_attribute(path: &Path, name: &str, value: &str) -> std::io::Result<()> {
+    let mut stream_path = path.as_os_str().to_os_string();
+    stream_path.push(":");
+    stream_path.push(name);
+    std::fs::write(stream_path, value)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn write_attribute(_path: &Path, _name: &str, _value: &str) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "extended attributes are not supported on this platform",
+    ))
+}
@@ -0,0 +1,131 @@
+use super::{client::current_access_token, download::fetch_download_metadata, GRAPH_BASE};
+use crate::lock::recover_lock;
+use crate::retry::{TransferError, DOWNLOAD_URL_EXPIRED_MESSAGE};
+use reqwest::StatusCode;
+use std::sync::Mutex;
+
+/// 单次下载中最多刷新下载链接的次数，防止端点持续拒绝时无限循环。
+const MAX_URL_REFRESHES: u32 = 5;
+
+/// 下载端点：封装预签名 downloadUrl 或 `/content` + Bearer token，
+/// 链接过期时重新获取元数据换取新链接，供单连接与分段下载共享。
+pub(crate) struct DownloadEndpoint {
+    item_id: String,
+    /// 开始下载时的 eTag，刷新链接时用于确认远端内容未变。
+    etag: Option<String>,
+    state: Mutex<EndpointState>,
+}
+
+struct EndpointState {
+    url: String,
+    bearer_token: Option<String>,
+    /// 每刷新一次加一，多个分段同时遇到过期时只需刷新一次。
+    generation: u64,
+    refreshes: u32,
+}
+
+/// 某一时刻的端点快照，请求失败时凭 `generation` 申请刷新。
+pub(crate) struct EndpointSnapshot {
+    pub url: String,
+    pub bearer_token: Option<String>,
+    pub generation: u64,
+}
+
+impl DownloadEndpoint {
+    /// 根据元数据中的 downloadUrl 构造端点；缺失时回退到 `/content` 并携带 token。
+    pub(crate) fn new(
+        item_id: &str,
+        download_url: Option<String>,
+        access_token: &str,
+        etag: Option<String>,
+    ) -> Self {
+        let (url, bearer_token) = resolve_url(item_id, download_url, access_token);
+        Self {
+            item_id: item_id.to_string(),
+            etag,
+            state: Mutex::new(EndpointState {
+                url,
+                bearer_token,
+                generation: 0,
+                refreshes: 0,
+            }),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> EndpointSnapshot {
+        let state = recover_lock(&self.state);
+        EndpointSnapshot {
+            url: state.url.clone(),
+            bearer_token: state.bearer_token.clone(),
+            generation: state.generation,
+        }
+    }
+
+    /// 开始下载时的 eTag，续传前用于确认临时文件与远端内容一致。
+    pub(crate) fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// 重新获取元数据换取新链接，并确认 eTag 未变化。
+    /// - `stale_generation` 已过时说明其他线程刚刷新过，直接复用新链接。
+    /// - 刷新期间持有锁，其余分段会等待这一次刷新完成。
+    /// - 次数耗尽仍按链接过期处理，交给任务级重试重新开始。
+    pub(crate) fn refresh(&self, stale_generation: u64) -> Result<(), TransferError> {
+        let mut state = recover_lock(&self.state);
+        if state.generation != stale_generation {
+            return Ok(());
+        }
+        if state.refreshes >= MAX_URL_REFRESHES {
+            return Err(TransferError::transient(format!(
+                "{DOWNLOAD_URL_EXPIRED_MESSAGE}，多次刷新后仍被拒绝"
+            )));
+        }
+        let access_token = current_access_token().map_err(TransferError::permanent)?;
+        let metadata = fetch_download_metadata(&self.item_id, &access_token)?;
+        if let (Some(expected), Some(actual)) = (self.etag.as_deref(), metadata.e_tag.as_deref()) {
+            if expected != actual {
+                return Err(TransferError::permanent(
+                    "远端文件在下载过程中已被修改，请重新下载",
+                ));
+            }
+        }
+        let (url, bearer_token) = resolve_url(&self.item_id, metadata.download_url, &access_token);
+        state.url = url;
+        state.bearer_token = bearer_token;
+        state.generation += 1;
+        state.refreshes += 1;
+        eprintln!(
+            "[drive-download] refreshed download url for {} (refresh {})",
+            self.item_id, state.refreshes
+        );
+        Ok(())
+    }
+}
+
+/// 端点拒绝请求时是否值得刷新链接：预签名链接过期表现为 401/403/410，
+/// `/content` 只有 401 代表 token 失效，403 属于权限问题不再刷新。
+pub(crate) fn needs_refresh(status: StatusCode, pre_authenticated: bool) -> bool {
+    match status.as_u16() {
+        401 => true,
+        403 | 410 => pre_authenticated,
+        _ => false,
+    }
+}
+
+fn resolve_url(
+    item_id: &str,
+    download_url: Option<String>,
+    access_token: &str,
+) -> (String, Option<String>) {
+    match download_url {
+        Some(url) => {
+            eprintln!("[drive-download] using pre-authenticated download url for {item_id}");
+            (url, None)
+        }
+        None => {
+            eprintln!("[drive-download] missing downloadUrl, fallback to /content for {item_id}");
+            let content_url = format!("{GRAPH_BASE}/me/drive/items/{item_id}/content");
+            (content_url, Some(access_token.to_string()))
+        }
+    }
+}
@@ -1,10 +1,19 @@
 use crate::frb_generated::StreamSink;
 use crate::{
-    api::drive::models::{DownloadProgressUpdate, DownloadQueueState, DriveItemSummary},
+    api::drive::models::{
+        DownloadConflictPolicy, DownloadProgressUpdate, DownloadQueueState, DownloadTaskEvent,
+        DriveItemSummary, TransferPriority,
+    },
     download_manager::{
         cancel_download_task as core_cancel, clear_download_history as core_clear_history,
         clear_failed_download_tasks as core_clear_failed, download_queue_state as core_queue_state,
-        enqueue_download_task as core_enqueue, remove_download_task as core_remove,
+        enqueue_download_task as core_enqueue, move_download_task_down as core_move_down,
+        move_download_task_to_top as core_move_to_top, move_download_task_up as core_move_up,
+        open_downloaded_file as core_open_file, remove_download_task as core_remove,
+        resolve_download_conflict as core_resolve_conflict,
+        retry_failed_download_tasks as core_retry_failed,
+        reveal_downloaded_file as core_reveal_file,
+        set_download_task_priority as core_set_priority, subscribe_events as core_subscribe_events,
         subscribe_progress as core_subscribe_progress,
     },
 };
@@ -15,23 +24,46 @@ pub fn download_queue_state() -> DownloadQueueState {
     core_queue_state()
 }
 
+/// 新任务先以 Queued 状态排队，`priority` 为空时按普通优先级处理。
 #[flutter_rust_bridge::frb]
 pub fn enqueue_download_task(
     item: DriveItemSummary,
     target_dir: String,
-    overwrite: bool,
+    conflict_policy: Option<DownloadConflictPolicy>,
+    priority: Option<TransferPriority>,
+) -> Result<DownloadQueueState, String> {
+    core_enqueue(item, target_dir, conflict_policy, priority)
+}
+
+/// 为等待用户决定的冲突任务指定处理方式，任务会以该策略重新开始下载。
+#[flutter_rust_bridge::frb]
+pub fn resolve_download_conflict(
+    task_id: String,
+    policy: DownloadConflictPolicy,
 ) -> Result<DownloadQueueState, String> {
-    core_enqueue(item, target_dir, overwrite)
+    core_resolve_conflict(&task_id, policy)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn remove_download_task(task_id: String) -> Result<DownloadQueueState, String> {
+    core_remove(&task_id)
+}
+
+/// 使用系统默认程序打开已完成任务的文件。
+#[flutter_rust_bridge::frb]
+pub fn open_downloaded_file(task_id: String) -> Result<(), String> {
+    core_open_file(&task_id)
 }
 
+/// 在系统文件管理器中显示并选中已完成任务的文件。
 #[flutter_rust_bridge::frb]
-pub fn remove_download_task(item_id: String) -> Result<DownloadQueueState, String> {
-    core_remove(&item_id)
+pub fn reveal_downloaded_file(task_id: String) -> Result<(), String> {
+    core_reveal_file(&task_id)
 }
 
 #[flutter_rust_bridge::frb]
-pub fn cancel_download_task(item_id: String) -> Result<DownloadQueueState, String> {
-    core_cancel(&item_id)
+pub fn cancel_download_task(task_id: String) -> Result<DownloadQueueState, String> {
+    core_cancel(&task_id)
 }
 
 #[flutter_rust_bridge::frb]
@@ -39,6 +71,39 @@ pub fn clear_failed_download_tasks() -> Result<DownloadQueueState, String> {
     core_clear_failed()
 }
 
+/// 将所有失败任务重新入队，等待冲突决定的任务需通过 `resolve_download_conflict` 处理。
+#[flutter_rust_bridge::frb]
+pub fn retry_failed_download_tasks() -> Result<DownloadQueueState, String> {
+    core_retry_failed()
+}
+
+/// 将等待中的下载任务前移一位。
+#[flutter_rust_bridge::frb]
+pub fn move_download_task_up(task_id: String) -> Result<DownloadQueueState, String> {
+    core_move_up(&task_id)
+}
+
+/// 将等待中的下载任务后移一位。
+#[flutter_rust_bridge::frb]
+pub fn move_download_task_down(task_id: String) -> Result<DownloadQueueState, String> {
+    core_move_down(&task_id)
+}
+
+/// 将等待中的下载任务移到队首，下一个空闲名额即开始下载。
+#[flutter_rust_bridge::frb]
+pub fn move_download_task_to_top(task_id: String) -> Result<DownloadQueueState, String> {
+    core_move_to_top(&task_id)
+}
+
+/// 修改未结束下载任务的优先级，等待中的任务会按新优先级重新排队。
+#[flutter_rust_bridge::frb]
+pub fn set_download_task_priority(
+    task_id: String,
+    priority: TransferPriority,
+) -> Result<DownloadQueueState, String> {
+    core_set_priority(&task_id, priority)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn clear_download_history() -> Result<DownloadQueueState, String> {
     core_clear_history()
@@ -55,3 +120,16 @@ pub fn download_progress_stream(stream_sink: StreamSink<DownloadProgressUpdate>)
         }
     });
 }
+
+/// 推送下载任务的生命周期事件，UI 无需轮询队列快照即可感知状态变化。
+#[flutter_rust_bridge::frb]
+pub fn download_task_event_stream(stream_sink: StreamSink<DownloadTaskEvent>) {
+    let rx = core_subscribe_events();
+    thread::spawn(move || {
+        for event in rx.iter() {
+            if stream_sink.add(event).is_err() {
+                break;
+            }
+        }
+    });
+}
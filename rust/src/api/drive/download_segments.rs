@@ -0,0 +1,516 @@
+use super::{
+    client::build_blocking_client,
+    download::{
+        commit_partial, endpoint_status_error, partial_path, save_partial_state, take_partial_state,
+    },
+    download_endpoint::{needs_refresh, DownloadEndpoint},
+};
+use crate::bandwidth::{throttle, TransferDirection};
+use crate::retry::TransferError;
+use reqwest::blocking::Client;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// 达到该大小的文件才启用分段下载，小文件单连接即可跑满带宽。
+const SEGMENTED_DOWNLOAD_MIN_SIZE: u64 = 64 * 1024 * 1024;
+/// 并发连接数上限。
+const MAX_SEGMENTS: u64 = 4;
+/// 单个分段的最小长度，避免把中等文件切得过碎。
+const MIN_SEGMENT_SIZE: u64 = 16 * 1024 * 1024;
+/// 单个分段在没有任何进展的情况下允许的连续重试次数。
+const SEGMENT_MAX_RETRY: usize = 5;
+const SEGMENT_RETRY_BASE_DELAY_MS: u64 = 500;
+/// 主线程汇总进度的轮询间隔。
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 判断是否值得启用分段下载。
+pub(crate) fn should_use_segments(total_size: u64) -> bool {
+    total_size >= SEGMENTED_DOWNLOAD_MIN_SIZE
+}
+
+/// 通过 `Range: bytes=0-0` 探测下载端点是否支持范围请求（返回 206）。
+pub(crate) fn supports_range_requests(endpoint: &DownloadEndpoint) -> bool {
+    let client = match build_blocking_client(Duration::from_secs(30)) {
+        Ok(client) => client,
+        Err(_) => return false,
+    };
+    let target = endpoint.snapshot();
+    let mut request = client.get(&target.url).header("Range", "bytes=0-0");
+    if let Some(token) = target.bearer_token.as_deref() {
+        request = request.bearer_auth(token);
+    }
+    match request.send() {
+        Ok(response) => response.status().as_u16() == 206,
+        Err(_) => false,
+    }
+}
+
+/// 将文件按字节区间切分，通过多个连接并发拉取并写入预分配文件的对应偏移。
+/// - 数据先写入 `<name>.part`，全部分段完成后才重命名覆盖目标文件。
+/// - 每个分段独立重试，重试时从该分段已写入的位置继续，不会重复下载。
+/// - 瞬时失败时保留临时文件，并把各分段进度写入续传状态，重试时远端 eTag 未变就据此续传；
+///   取消或永久失败则直接清理。
+/// - 进度由调用线程定期汇总后回调，保持与单连接下载一致的回调语义。
+/// - 链接过期时由首个发现的分段刷新端点，其余分段复用新链接继续。
+pub(crate) fn segmented_download(
+    endpoint: &DownloadEndpoint,
+    destination: &Path,
+    total_size: u64,
+    mut progress: Option<&mut (dyn FnMut(u64, Option<u64>) + Send)>,
+    cancel_flag: Option<&Arc<AtomicBool>>,
+) -> Result<u64, TransferError> {
+    let partial = partial_path(destination);
+    let segments = plan_segments(total_size);
+    let saved = take_partial_state(&partial, endpoint.etag()).unwrap_or_default();
+    if !restore_segment_progress(&partial, &saved, total_size, &segments) {
+        let file = File::create(&partial).map_err(|e| {
+            TransferError::permanent(format!(
+                "failed to create temporary file {}: {e}",
+                partial.to_string_lossy()
+            ))
+        })?;
+        // 预分配完整大小，各分段直接写入自己的偏移位置。
+        file.set_len(total_size).map_err(|e| {
+            TransferError::permanent(format!("failed to preallocate temporary file: {e}"))
+        })?;
+    }
+
+    let client =
+        build_blocking_client(Duration::from_secs(600)).map_err(TransferError::permanent)?;
+    let downloaded = AtomicU64::new(
+        segments
+            .iter()
+            .map(|segment| segment.written.load(Ordering::Relaxed))
+            .sum(),
+    );
+    // 任一分段彻底失败时通知其余分段尽快退出。
+    let abort = AtomicBool::new(false);
+    eprintln!(
+        "[drive-download] segmented download: {} segments for {} bytes",
+        segments.len(),
+        total_size
+    );
+
+    let results: Vec<Result<(), TransferError>> = thread::scope(|scope| {
+        let handles: Vec<_> = segments
+            .iter()
+            .map(|segment| {
+                let context = SegmentContext {
+                    client: &client,
+                    endpoint,
+                    destination: &partial,
+                    downloaded: &downloaded,
+                    abort: &abort,
+                    cancel_flag,
+                };
+                scope.spawn(move || {
+                    let result = download_segment(&context, segment);
+                    if result.is_err() {
+                        context.abort.store(true, Ordering::Relaxed);
+                    }
+                    result
+                })
+            })
+            .collect();
+
+        while handles.iter().any(|handle| !handle.is_finished()) {
+            if let Some(cb) = progress.as_mut() {
+                cb(downloaded.load(Ordering::Relaxed), Some(total_size));
+            }
+            thread::sleep(PROGRESS_POLL_INTERVAL);
+        }
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle.join().unwrap_or_else(|_| {
+                    Err(TransferError::permanent("download segment worker panicked"))
+                })
+            })
+            .collect()
+    });
+
+    let cancelled = cancel_flag
+        .map(|flag| flag.load(Ordering::Relaxed))
+        .unwrap_or(false);
+    if cancelled {
+        let _ = fs::remove_file(&partial);
+        return Err(TransferError::cancelled("下载已取消"));
+    }
+    if let Some(err) = results.into_iter().find_map(Result::err) {
+        // 瞬时失败记下各分段进度以便续传；预分配的临时文件无法自证完整性，其余情况直接清理。
+        let keep = err.is_transient()
+            && save_partial_state(
+                &partial,
+                endpoint.etag(),
+                &segment_progress_lines(&segments),
+            );
+        if !keep {
+            let _ = fs::remove_file(&partial);
+        }
+        return Err(err);
+    }
+    commit_partial(&partial, destination)?;
+
+    let total_downloaded = downloaded.load(Ordering::Relaxed);
+    if let Some(cb) = progress.as_mut() {
+        cb(total_downloaded, Some(total_size));
+    }
+    Ok(total_downloaded)
+}
+
+/// 续传状态中每行记录一个分段：`start end written`。
+fn segment_progress_lines(segments: &[Segment]) -> Vec<String> {
+    segments
+        .iter()
+        .map(|segment| {
+            format!(
+                "{} {} {}",
+                segment.start,
+                segment.end,
+                segment.written.load(Ordering::Relaxed)
+            )
+        })
+        .collect()
+}
+
+/// 临时文件大小与分段划分都与本次下载一致时恢复各分段已写入的字节数，任何不匹配都视为无法续传。
+fn restore_segment_progress(
+    partial: &Path,
+    lines: &[String],
+    total_size: u64,
+    segments: &[Segment],
+) -> bool {
+    let matches_size = fs::metadata(partial)
+        .map(|meta| meta.len() == total_size)
+        .unwrap_or(false);
+    if !matches_size || lines.len() != segments.len() {
+        return false;
+    }
+    let saved: Option<Vec<(u64, u64, u64)>> = lines
+        .iter()
+        .map(|line| {
+            let mut parts = line.split_whitespace().map(|part| part.parse::<u64>().ok());
+            match (parts.next()??, parts.next()??, parts.next()??, parts.next()) {
+                (start, end, written, None) => Some((start, end, written)),
+                _ => None,
+            }
+        })
+        .collect();
+    let Some(saved) = saved else {
+        return false;
+    };
+    let consistent = saved
+        .iter()
+        .zip(segments)
+        .all(|(&(start, end, written), segment)| {
+            start == segment.start && end == segment.end && written <= segment.len()
+        });
+    if !consistent {
+        return false;
+    }
+    for (&(_, _, written), segment) in saved.iter().zip(segments) {
+        segment.written.store(written, Ordering::Relaxed);
+    }
+    true
+}
+
+/// 单个分段：闭区间 `[start, end]`，`written` 记录已落盘的字节数以便断点续传。
+struct Segment {
+    index: usize,
+    start: u64,
+    end: u64,
+    written: AtomicU64,
+}
+
+impl Segment {
+    fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+/// 分段线程共享的只读上下文。
+struct SegmentContext<'a> {
+    client: &'a Client,
+    endpoint: &'a DownloadEndpoint,
+    destination: &'a Path,
+    downloaded: &'a AtomicU64,
+    abort: &'a AtomicBool,
+    cancel_flag: Option<&'a Arc<AtomicBool>>,
+}
+
+impl SegmentContext<'_> {
+    fn should_stop(&self) -> bool {
+        self.abort.load(Ordering::Relaxed)
+            || self
+                .cancel_flag
+                .map(|flag| flag.load(Ordering::Relaxed))
+                .unwrap_or(false)
+    }
+}
+
+enum SegmentError {
+    Stopped,
+    /// 该代次的链接已被拒绝，需要刷新端点。
+    Expired(u64),
+    Retryable(String),
+    Fatal(TransferError),
+}
+
+fn plan_segments(total_size: u64) -> Vec<Segment> {
+    let count = (total_size / MIN_SEGMENT_SIZE).clamp(1, MAX_SEGMENTS);
+    let base = total_size / count;
+    (0..count)
+        .map(|index| {
+            let start = index * base;
+            let end = if index == count - 1 {
+                total_size - 1
+            } else {
+                start + base - 1
+            };
+            Segment {
+                index: index as usize,
+                start,
+                end,
+                written: AtomicU64::new(0),
+            }
+        })
+        .collect()
+}
+
+/// 带重试的分段下载；只要本轮有进展就重置重试计数，避免大分段因超时耗尽重试次数。
+fn download_segment(context: &SegmentContext<'_>, segment: &Segment) -> Result<(), TransferError> {
+    let mut attempt = 0;
+    loop {
+        let before = segment.written.load(Ordering::Relaxed);
+        match fetch_segment_range(context, segment) {
+            Ok(()) => return Ok(()),
+            Err(SegmentError::Stopped) => return Ok(()),
+            Err(SegmentError::Fatal(err)) => return Err(err),
+            Err(SegmentError::Expired(generation)) => {
+                context.endpoint.refresh(generation)?;
+            }
+            Err(SegmentError::Retryable(msg)) => {
+                if segment.written.load(Ordering::Relaxed) > before {
+                    attempt = 0;
+                }
+                attempt += 1;
+                if attempt > SEGMENT_MAX_RETRY {
+                    return Err(TransferError::transient(msg));
+                }
+                eprintln!(
+                    "[drive-download] segment {} failed (attempt {attempt}): {msg}",
+                    segment.index
+                );
+                let backoff = SEGMENT_RETRY_BASE_DELAY_MS * 2u64.saturating_pow(attempt as u32);
+                thread::sleep(Duration::from_millis(backoff));
+            }
+        }
+    }
+}
+
+fn fetch_segment_range(
+    context: &SegmentContext<'_>,
+    segment: &Segment,
+) -> Result<(), SegmentError> {
+    let already = segment.written.load(Ordering::Relaxed);
+    if already >= segment.len() {
+        return Ok(());
+    }
+    if context.should_stop() {
+        return Err(SegmentError::Stopped);
+    }
+    let range_start = segment.start + already;
+    let target = context.endpoint.snapshot();
+    let pre_authenticated = target.bearer_token.is_none();
+    let mut request = context
+        .client
+        .get(&target.url)
+        .header("Range", format!("bytes={range_start}-{}", segment.end));
+    if let Some(token) = target.bearer_token.as_deref() {
+        request = request.bearer_auth(token);
+    }
+    let mut response = request
+        .send()
+        .map_err(|e| SegmentError::Retryable(format!("failed to download segment: {e}")))?;
+
+    let status = response.status();
+    if status.as_u16() != 206 {
+        if needs_refresh(status, pre_authenticated) {
+            return Err(SegmentError::Expired(target.generation));
+        }
+        let err = endpoint_status_error(status, pre_authenticated);
+        return if err.is_transient() {
+            Err(SegmentError::Retryable(err.message))
+        } else {
+            Err(SegmentError::Fatal(err))
+        };
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(context.destination)
+        .map_err(|e| {
+            SegmentError::Fatal(TransferError::permanent(format!(
+                "failed to open destination file: {e}"
+            )))
+        })?;
+    file.seek(SeekFrom::Start(range_start)).map_err(|e| {
+        SegmentError::Fatal(TransferError::permanent(format!(
+            "failed to seek destination file: {e}"
+        )))
+    })?;
+
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        if context.should_stop() {
+            return Err(SegmentError::Stopped);
+        }
+        let remaining = segment.len() - segment.written.load(Ordering::Relaxed);
+        if remaining == 0 {
+            break;
+        }
+        let read_bytes = response
+            .read(&mut buffer)
+            .map_err(|e| SegmentError::Retryable(format!("failed to read response body: {e}")))?;
+        if read_bytes == 0 {
+            return Err(SegmentError::Retryable(
+                "connection closed before segment completed".to_string(),
+            ));
+        }
+        throttle(
+            TransferDirection::Download,
+            read_bytes,
+            context.cancel_flag.map(|flag| flag.as_ref()),
+        );
+        // 服务端多返回的字节不能越界写入下一个分段。
+        let usable = (read_bytes as u64).min(remaining) as usize;
+        file.write_all(&buffer[..usable]).map_err(|e| {
+            SegmentError::Fatal(TransferError::permanent(format!(
+                "failed to write file: {e}"
+            )))
+        })?;
+        segment.written.fetch_add(usable as u64, Ordering::Relaxed);
+        context
+            .downloaded
+            .fetch_add(usable as u64, Ordering::Relaxed);
+    }
+    file.flush().map_err(|e| {
+        SegmentError::Fatal(TransferError::permanent(format!(
+            "failed to flush file: {e}"
+        )))
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn ranges(segments: &[Segment]) -> Vec<(u64, u64)> {
+        segments.iter().map(|s| (s.start, s.end)).collect()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("skydrivex-segments-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn segment_progress_round_trips_through_lines() {
+        let dir = temp_dir("state");
+        let partial = dir.join("movie.mkv.part");
+        let total = MIN_SEGMENT_SIZE * 2;
+        File::create(&partial).unwrap().set_len(total).unwrap();
+
+        let segments = plan_segments(total);
+        segments[0].written.store(1024, Ordering::Relaxed);
+        segments[1]
+            .written
+            .store(segments[1].len(), Ordering::Relaxed);
+        let lines = segment_progress_lines(&segments);
+
+        let restored = plan_segments(total);
+        assert!(restore_segment_progress(&partial, &lines, total, &restored));
+        assert_eq!(restored[0].written.load(Ordering::Relaxed), 1024);
+        assert_eq!(
+            restored[1].written.load(Ordering::Relaxed),
+            restored[1].len()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn segment_progress_is_ignored_when_plan_changes() {
+        let dir = temp_dir("mismatch");
+        let partial = dir.join("movie.mkv.part");
+        let total = MIN_SEGMENT_SIZE * 2;
+        File::create(&partial).unwrap().set_len(total).unwrap();
+        let lines = segment_progress_lines(&plan_segments(total));
+
+        // 远端文件大小变化后分段与临时文件都对不上，必须从头下载。
+        let larger = total + 1;
+        assert!(!restore_segment_progress(
+            &partial,
+            &lines,
+            larger,
+            &plan_segments(larger)
+        ));
+        let garbage = vec!["garbage".to_string(); lines.len()];
+        assert!(!restore_segment_progress(
+            &partial,
+            &garbage,
+            total,
+            &plan_segments(total)
+        ));
+        // 单连接下载留下的状态没有分段行，不能当作分段进度使用。
+        assert!(!restore_segment_progress(
+            &partial,
+            &[],
+            total,
+            &plan_segments(total)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn small_file_uses_single_segment() {
+        let segments = plan_segments(MIN_SEGMENT_SIZE - 1);
+        assert_eq!(ranges(&segments), vec![(0, MIN_SEGMENT_SIZE - 2)]);
+    }
+
+    #[test]
+    fn segment_count_grows_with_size_up_to_limit() {
+        assert_eq!(plan_segments(MIN_SEGMENT_SIZE * 2).len(), 2);
+        assert_eq!(plan_segments(MIN_SEGMENT_SIZE * 3 + 1).len(), 3);
+        assert_eq!(
+            plan_segments(MIN_SEGMENT_SIZE * 100).len(),
+            MAX_SEGMENTS as usize
+        );
+    }
+
+    #[test]
+    fn segments_cover_whole_file_without_gaps() {
+        let total = MIN_SEGMENT_SIZE * 5 + 12_345;
+        let segments = plan_segments(total);
+        assert_eq!(segments.first().unwrap().start, 0);
+        assert_eq!(segments.last().unwrap().end, total - 1);
+        for (index, pair) in segments.windows(2).enumerate() {
+            assert_eq!(pair[0].index, index);
+            assert_eq!(pair[0].end + 1, pair[1].start);
+        }
+    }
+}
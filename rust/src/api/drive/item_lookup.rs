@@ -0,0 +1,84 @@
+use super::{
+    client::{build_blocking_client, current_access_token},
+    GRAPH_BASE,
+};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// 父目录下某个子项的基本信息与内容哈希，用于冲突判断与目录复用。
+#[derive(Clone, Debug)]
+pub(crate) struct ChildItem {
+    pub id: String,
+    pub name: String,
+    pub size: Option<u64>,
+    pub is_folder: bool,
+    pub quick_xor_hash: Option<String>,
+    pub sha1_hash: Option<String>,
+}
+
+/// 按路径读取父目录（为空时为根目录）下名为 `name` 的子项，不存在时返回 `None`。
+pub(crate) fn find_child_item(
+    parent_id: Option<&str>,
+    name: &str,
+) -> Result<Option<ChildItem>, String> {
+    let access_token = current_access_token()?;
+    let client = build_blocking_client(Duration::from_secs(30))?;
+    let encoded_name = utf8_percent_encode(name.trim(), NON_ALPHANUMERIC).to_string();
+    let url = match parent_id.map(str::trim).filter(|id| !id.is_empty()) {
+        Some(id) => format!("{GRAPH_BASE}/me/drive/items/{id}:/{encoded_name}"),
+        None => format!("{GRAPH_BASE}/me/drive/root:/{encoded_name}"),
+    };
+    let response = client
+        .get(url)
+        .bearer_auth(access_token)
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| format!("failed to look up drive item: {e}"))?;
+
+    if response.status().as_u16() == 401 {
+        return Err("access token rejected by Graph API; please sign in again".to_string());
+    }
+    if response.status().as_u16() == 404 {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "graph api returned HTTP {} while looking up {name}",
+            response.status()
+        ));
+    }
+    let payload: ChildItemDto = response
+        .json()
+        .map_err(|e| format!("failed to parse drive item lookup response: {e}"))?;
+    let hashes = payload.file.and_then(|file| file.hashes);
+    Ok(Some(ChildItem {
+        id: payload.id,
+        name: payload.name.unwrap_or_else(|| name.to_string()),
+        size: payload.size,
+        is_folder: payload.folder.is_some(),
+        quick_xor_hash: hashes.as_ref().and_then(|h| h.quick_xor_hash.clone()),
+        sha1_hash: hashes.and_then(|h| h.sha1_hash),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct ChildItemDto {
+    id: String,
+    name: Option<String>,
+    size: Option<u64>,
+    folder: Option<serde_json::Value>,
+    file: Option<FileFacetDto>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileFacetDto {
+    hashes: Option<HashesDto>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HashesDto {
+    quick_xor_hash: Option<String>,
+    sha1_hash: Option<String>,
+}
@@ -1,9 +1,20 @@
+pub(crate) mod async_monitor;
 mod client;
+pub(crate) mod copy_item;
+pub mod copy_manager;
+pub mod create_folder;
 pub mod download;
+mod download_attributes;
+pub(crate) mod upload_attributes;
+pub(crate) mod upload_from_url;
+mod download_endpoint;
+mod download_segments;
+mod upload_chunking;
 pub mod download_manager;
 pub mod delete;
 pub mod details;
 pub mod info;
+pub(crate) mod item_lookup;
 pub mod list;
 pub mod move_item;
 pub mod models;
@@ -11,26 +22,39 @@ pub mod share;
 pub mod upload;
 pub mod upload_manager;
 
+pub use copy_manager::{clear_finished_copy_jobs, copy_drive_item, copy_job_stream, copy_jobs};
+pub use create_folder::{create_drive_folder, ensure_drive_folder_path};
 pub use download::download_drive_item;
 pub use delete::delete_drive_item;
 pub use download_manager::{
-    clear_download_history, download_progress_stream, download_queue_state, enqueue_download_task,
-    remove_download_task,
+    clear_download_history, download_progress_stream, download_queue_state,
+    download_task_event_stream, enqueue_download_task, move_download_task_down,
+    move_download_task_to_top, move_download_task_up, open_downloaded_file, remove_download_task,
+    resolve_download_conflict, retry_failed_download_tasks, reveal_downloaded_file,
+    set_download_task_priority,
 };
 pub use details::get_drive_item_details;
 pub use info::get_drive_overview;
 pub use list::list_drive_children;
 pub use move_item::move_drive_item;
 pub use models::{
-    DownloadQueueState, DownloadStatus, DownloadTask, DriveDownloadResult, DriveInfo, DriveItemDetails,
-    DriveItemSummary, DriveOwner, DrivePage, DriveQuota, LinkScope, LinkType, ShareCapabilities,
-    ShareLinkResult, UploadProgressUpdate, UploadQueueState, UploadStatus, UploadTask,
+    BandwidthSchedule, BandwidthSettings, CopyJob, CopyJobStatus, DownloadConflictOutcome,
+    DownloadConflictPolicy, DownloadQueueState, DownloadStatus, DownloadTask, DownloadTaskEvent,
+    DriveDownloadResult, DriveInfo, DriveItemDetails, DriveItemSummary, DriveOwner, DrivePage,
+    DriveQuota, FolderConflictBehavior, LinkScope, LinkType, NotificationSettings, PostDownloadHook,
+    PostDownloadHookKind, PostDownloadHookOutcome, ShareCapabilities, ShareLinkResult,
+    TransferEventKind, TransferFailureReason, TransferPriority, TransferRetryPolicy,
+    UploadConflictBehavior, UploadGroup, UploadGroupStatus, UploadProgressUpdate, UploadQueueState,
+    UploadStatus, UploadTask, UploadTaskEvent,
 };
 pub use share::{create_share_link, get_share_capabilities};
 pub use upload::upload_small_file;
 pub use upload_manager::{
-    cancel_upload_task, clear_failed_upload_tasks, clear_upload_history, enqueue_upload_task,
-    remove_upload_task, upload_progress_stream, upload_queue_state,
+    cancel_upload_group, cancel_upload_task, clear_failed_upload_tasks, clear_upload_history,
+    enqueue_upload_folder, enqueue_upload_from_url, enqueue_upload_task, move_upload_task_down,
+    move_upload_task_to_top, move_upload_task_up, remove_upload_group, remove_upload_task,
+    retry_failed_upload_tasks, retry_upload_task, set_upload_task_priority,
+    upload_progress_stream, upload_queue_state, upload_task_event_stream,
 };
 
 /// Graph v1 端点常量，集中声明方便今后切换区域或版本。
@@ -29,6 +29,7 @@ pub struct DriveDownloadResult {
     pub saved_path: String,
     pub bytes_downloaded: u64,
     pub expected_size: Option<u64>,
+    pub conflict_outcome: DownloadConflictOutcome,
 }
 
 /// 下载任务状态，迁移至 Rust 端统一管理。
@@ -38,6 +39,93 @@ pub enum DownloadStatus {
     InProgress,
     Completed,
     Failed,
+    /// 已入队但尚未获得并发名额，等待调度。
+    Queued,
+}
+
+/// 下载目标已存在同名文件时的处理策略，可按任务指定，也可在设置中配置默认值。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DownloadConflictPolicy {
+    /// 直接覆盖本地文件。
+    Overwrite,
+    /// 保留本地文件，跳过本次下载。
+    Skip,
+    /// 两者都保留，新文件以 " (1)" 等后缀重命名。
+    KeepBoth,
+    /// 仅当远端修改时间晚于本地文件时覆盖，否则跳过。
+    OverwriteIfNewer,
+    /// 暂停任务，等待用户选择处理方式。
+    Ask,
+}
+
+/// 冲突处理的实际结果，随任务持久化，便于在历史中回溯。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DownloadConflictOutcome {
+    /// 目标路径不存在同名文件。
+    NoConflict,
+    /// 已覆盖本地文件。
+    Overwritten,
+    /// 保留本地文件，未下载。
+    Skipped,
+    /// 以新文件名保存，`saved_path` 为重命名后的路径。
+    KeptBoth,
+    /// 等待用户决定，任务暂存于 failed 列表。
+    AwaitingDecision,
+}
+
+/// 带宽限速设置，单位 KiB/s，0 表示不限速。
+/// 总限速与上/下行限速同时生效，取更严格者。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BandwidthSettings {
+    pub global_kib_per_sec: u64,
+    pub download_kib_per_sec: u64,
+    pub upload_kib_per_sec: u64,
+    /// 按时段覆盖上述限速，命中第一条即生效。
+    pub schedules: Vec<BandwidthSchedule>,
+}
+
+/// 一天中的限速时段，以分钟计（0..1440）；结束早于开始时表示跨越午夜，
+/// 开始与结束相同时表示全天。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BandwidthSchedule {
+    pub start_minute: u32,
+    pub end_minute: u32,
+    pub global_kib_per_sec: u64,
+    pub download_kib_per_sec: u64,
+    pub upload_kib_per_sec: u64,
+}
+
+/// 传输失败后的自动重试策略，上传与下载共用。
+/// 第 n 次重试前等待 `initial_delay_ms * 2^(n-1)`，不超过 `max_delay_ms`。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferRetryPolicy {
+    /// 单个任务最多自动重试的次数，0 表示关闭自动重试。
+    pub max_retries: u32,
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+/// 传输结束时的桌面通知设置，上传与下载共用。
+/// 免打扰时段以分钟计（0..1440），跨午夜与全天的规则与 `BandwidthSchedule` 相同。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationSettings {
+    /// 总开关，关闭后不发送任何通知。
+    pub enabled: bool,
+    /// 单个任务完成时通知。
+    pub notify_on_task_completed: bool,
+    /// 单个任务失败时通知（用户取消不算失败）。
+    pub notify_on_task_failed: bool,
+    /// 队列清空时汇总本批次的完成与失败数量，仅在批次包含多个任务时发送。
+    pub notify_on_batch_finished: bool,
+    pub quiet_hours_enabled: bool,
+    pub quiet_hours_start_minute: u32,
+    pub quiet_hours_end_minute: u32,
 }
 
 /// 上传任务状态。
@@ -48,12 +136,84 @@ pub enum UploadStatus {
     Completed,
     Failed,
     Cancelled,
+    /// 已入队但尚未获得并发名额，等待调度。
+    Queued,
+}
+
+/// 传输失败的原因，便于界面给出针对性的处理建议；无法归类的失败不带原因。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferFailureReason {
+    /// 本地磁盘剩余空间不足以容纳文件与保留空间。
+    InsufficientDiskSpace,
+    /// OneDrive 剩余配额不足以容纳待上传的数据。
+    InsufficientQuota,
+    /// 上传完成后远端内容哈希与本地文件不一致。
+    IntegrityMismatch,
+    /// 上传期间本地文件被修改，重新上传后仍在变化。
+    SourceChanged,
+    /// 没有访问权限或登录已失效。
+    AccessDenied,
+    /// 远端项目不存在或已被删除。
+    NotFound,
+    /// 可重试的失败在自动重试次数耗尽后仍未恢复。
+    RetriesExhausted,
+}
+
+/// 传输任务优先级：决定新任务插入等待队列的位置，高优先级排在低优先级之前，
+/// 同级之间先进先出。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransferPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// 下载完成后自动执行的动作类型。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostDownloadHookKind {
+    /// 以保存路径为参数运行用户指定的程序。
+    RunCommand,
+    /// 将 zip 压缩包解压到同目录下以文件名命名的文件夹。
+    ExtractArchive,
+    /// 按扩展名把文件移动到保存目录下的子文件夹。
+    MoveByType,
+}
+
+/// 一条下载后动作配置，按列表顺序依次执行；前一条移动文件后，后续动作使用新路径。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostDownloadHook {
+    pub kind: PostDownloadHookKind,
+    pub enabled: bool,
+    /// 仅对这些扩展名生效（不含点，忽略大小写）；为空表示对所有文件生效。
+    pub extensions: Vec<String>,
+    /// RunCommand：要执行的程序路径或命令名，不经过 shell 解析。
+    pub program: Option<String>,
+    /// RunCommand：程序参数，`{path}` 会替换为保存路径；均未包含时把路径追加为最后一个参数。
+    pub args: Vec<String>,
+    /// MoveByType：目标子文件夹名，相对于文件所在目录。
+    pub subfolder: Option<String>,
+}
+
+/// 单条下载后动作的执行结果，记录在任务上供界面展示。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostDownloadHookOutcome {
+    pub kind: PostDownloadHookKind,
+    pub success: bool,
+    pub message: String,
 }
 
 /// 单条下载任务详情，供 Flutter 展示进度与历史。
 #[flutter_rust_bridge::frb]
 #[derive(Clone, Debug)]
 pub struct DownloadTask {
+    /// 任务唯一标识；同一文件下载到不同位置会产生不同任务。
+    pub task_id: String,
     pub item: DriveItemSummary,
     pub status: DownloadStatus,
     pub started_at: i64,
@@ -62,6 +222,16 @@ pub struct DownloadTask {
     pub size_label: Option<u64>,
     pub bytes_downloaded: Option<u64>,
     pub error_message: Option<String>,
+    pub target_dir: String,
+    pub conflict_policy: DownloadConflictPolicy,
+    pub conflict_outcome: Option<DownloadConflictOutcome>,
+    /// 本次下载已自动重试的次数。
+    pub retry_count: u32,
+    pub priority: TransferPriority,
+    /// 预检失败的类型；其他原因的失败为空。
+    pub failure_reason: Option<TransferFailureReason>,
+    /// 下载完成后各项自动动作的执行结果，按执行顺序排列。
+    pub hook_outcomes: Vec<PostDownloadHookOutcome>,
 }
 
 /// 单条上传任务详情。
@@ -81,6 +251,93 @@ pub struct UploadTask {
     pub bytes_uploaded: Option<u64>,
     pub error_message: Option<String>,
     pub session_url: Option<String>,
+    /// 上传会话的过期时间（毫秒时间戳）；临近过期的会话不再续传，改为新建会话重新上传。
+    pub session_expires_at: Option<i64>,
+    /// 本次上传已自动重试的次数。
+    pub retry_count: u32,
+    pub priority: TransferPriority,
+    /// 预检失败的类型；其他原因的失败为空。
+    pub failure_reason: Option<TransferFailureReason>,
+    /// 所属文件夹上传任务组；单独上传的文件为空。
+    pub group_id: Option<String>,
+    pub conflict_behavior: UploadConflictBehavior,
+    /// 上传完成后 OneDrive 上的实际文件名；按重命名策略处理冲突时可能与 `file_name` 不同。
+    pub remote_name: Option<String>,
+    /// 远端已存在内容相同的文件而跳过了上传。
+    pub skipped_identical: bool,
+    /// 开始上传时本地文件的修改时间（毫秒时间戳），与 `size` 一起用于发现上传期间的改写。
+    pub source_modified_at: Option<i64>,
+    /// 开始上传时本地文件首尾采样的 quickXorHash。
+    pub source_fingerprint: Option<String>,
+    /// 从网络地址上传时的来源 URL；为空表示上传本地文件。
+    pub source_url: Option<String>,
+}
+
+/// 上传目标已存在同名项时的处理方式，可按任务指定，也可在设置中配置默认值。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadConflictBehavior {
+    /// 保留远端文件，本次上传失败。
+    Fail,
+    /// 覆盖远端文件（保留为新版本）。
+    Replace,
+    /// 两者都保留，由 OneDrive 为新文件自动重命名。
+    Rename,
+    /// 大小与内容哈希均相同时跳过上传，否则覆盖远端文件。
+    SkipIfIdentical,
+}
+
+/// 新建文件夹时目标位置已存在同名项的处理方式。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FolderConflictBehavior {
+    /// 不创建，返回错误。
+    Fail,
+    /// 由 OneDrive 为新文件夹自动重命名。
+    Rename,
+    /// 替换同名项（原有内容进入回收站）。
+    Replace,
+    /// 同名项是文件夹时直接复用，是文件时返回错误。
+    UseExisting,
+}
+
+/// 文件夹上传任务组的状态。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadGroupStatus {
+    /// 正在扫描本地目录并在 OneDrive 上创建文件夹结构。
+    Preparing,
+    /// 子文件任务仍在排队或上传中。
+    Uploading,
+    /// 所有文件均已上传成功。
+    Completed,
+    /// 上传已结束，但部分文件失败。
+    PartiallyFailed,
+    /// 准备阶段出错，或所有文件均上传失败。
+    Failed,
+    Cancelled,
+}
+
+/// 文件夹上传任务组：本地目录下的每个文件作为子任务进入上传队列，
+/// 进度与失败数量在组级别汇总。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug)]
+pub struct UploadGroup {
+    pub group_id: String,
+    pub local_root: String,
+    /// 在 OneDrive 上对应的根文件夹名，与本地目录同名。
+    pub folder_name: String,
+    pub parent_id: Option<String>,
+    pub status: UploadGroupStatus,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+    /// 准备阶段的错误信息，包括无法创建而被跳过的文件夹；子文件的失败记录在各自任务上。
+    pub error_message: Option<String>,
+    pub total_files: u32,
+    pub completed_files: u32,
+    pub failed_files: u32,
+    pub total_bytes: u64,
+    pub uploaded_bytes: u64,
 }
 
 /// 下载队列状态，包含进行中、已完成与失败任务列表。
@@ -99,12 +356,15 @@ pub struct UploadQueueState {
     pub active: Vec<UploadTask>,
     pub completed: Vec<UploadTask>,
     pub failed: Vec<UploadTask>,
+    /// 文件夹上传任务组，子任务同时出现在上面的列表中。
+    pub groups: Vec<UploadGroup>,
 }
 
 /// 下载进度事件，通过 StreamSink 推送给 Flutter，供 UI 实时刷新进度与速度。
 #[flutter_rust_bridge::frb]
 #[derive(Clone, Debug)]
 pub struct DownloadProgressUpdate {
+    pub task_id: String,
     pub item_id: String,
     pub bytes_downloaded: u64,
     pub expected_size: Option<u64>,
@@ -123,6 +383,44 @@ pub struct UploadProgressUpdate {
     pub timestamp_millis: i64,
 }
 
+/// 任务生命周期变化类型，上传与下载共用。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferEventKind {
+    /// 进入等待队列（新建、手动重试或自动重试前重新排队）。
+    Queued,
+    /// 获得并发名额，开始传输。
+    Started,
+    /// 遇到可恢复错误，等待退避后自动重试；错误详情见任务的 `error_message`。
+    Retrying,
+    Completed,
+    Failed,
+    Cancelled,
+    /// 目标位置已有同名文件，等待用户选择冲突处理方式（仅下载）。
+    AwaitingDecision,
+    /// 任务记录被移除。
+    Removed,
+}
+
+/// 下载任务生命周期事件，附带变化后的完整任务，
+/// 失败原因、保存路径等信息可直接从 `task` 读取。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug)]
+pub struct DownloadTaskEvent {
+    pub kind: TransferEventKind,
+    pub task: DownloadTask,
+    pub timestamp_millis: i64,
+}
+
+/// 上传任务生命周期事件，远端 item id、错误信息等从 `task` 读取。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug)]
+pub struct UploadTaskEvent {
+    pub kind: TransferEventKind,
+    pub task: UploadTask,
+    pub timestamp_millis: i64,
+}
+
 /// OneDrive 概览信息（配额、类型、所有者）。
 #[flutter_rust_bridge::frb]
 #[derive(Clone, Debug)]
@@ -215,3 +513,35 @@ pub struct ShareLinkResult {
     pub roles: Vec<String>,
     pub password_protected: bool,
 }
+
+/// 复制任务的状态；Graph 在服务端异步执行复制，客户端只跟踪进度。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyJobStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// 一次复制操作，重启后继续跟踪未结束的任务。
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug)]
+pub struct CopyJob {
+    pub job_id: String,
+    pub source_item_id: String,
+    /// 复制后的名称：指定了新名称时为新名称，否则为源项目名称。
+    pub item_name: String,
+    /// 目标 Drive；为空表示当前账户的 Drive。
+    pub target_drive_id: Option<String>,
+    pub target_parent_id: Option<String>,
+    pub new_name: Option<String>,
+    pub monitor_url: String,
+    pub status: CopyJobStatus,
+    /// 0-100；服务端未报告时为空。
+    pub percentage_complete: Option<f64>,
+    /// 复制完成后新项目的 id。
+    pub new_item_id: Option<String>,
+    pub error_message: Option<String>,
+    pub started_at: i64,
+    pub completed_at: Option<i64>,
+}
@@ -1,67 +1,143 @@
 use super::{
     client::{build_blocking_client, current_access_token},
-    models::DriveItemSummary,
+    download::ProgressCallback,
+    models::{DriveItemSummary, UploadConflictBehavior},
+    upload_attributes::{local_file_system_info, patch_file_system_info, FileSystemInfo},
+    upload_chunking::{fill_chunk, ChunkSizer, ChunkSource, SharedChunkReader, StreamChunkSource},
     GRAPH_BASE,
 };
+use crate::bandwidth::{throttle, TransferDirection};
+use crate::content_hash::{ContentDigest, ContentHashes};
+use crate::retry::TransferError;
+use chrono::DateTime;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use serde::Deserialize;
 use std::fs::File;
-use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
+use std::io::{BufReader, Read};
+use std::path::Path;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::thread;
 
 /// Graph 简易上传的官方上限（单请求），超出需走分片上传。
 const SIMPLE_UPLOAD_MAX_BYTES: usize = 250 * 1024 * 1024;
-// 分片上传推荐 5-10MiB，保持 10MiB（32 * 320KiB）以平衡吞吐与开销。
-const CHUNK_SIZE_BYTES: u64 = 10 * 1024 * 1024;
-const CHUNK_ALIGNMENT: u64 = 320 * 1024;
+/// 上传队列按大小选择上传方式：不超过该值走简易上传，更大的文件走可续传的分片会话。
+pub(crate) const SIMPLE_UPLOAD_THRESHOLD_BYTES: u64 = 4 * 1024 * 1024;
+/// 同一偏移处没有进展时允许的连续失败次数，每次失败后分片会缩小。
 const MAX_RETRY: usize = 4;
 const RETRY_BASE_DELAY_MS: u64 = 400;
+/// 上传会话已过期或被服务端清理（404）时的错误文本；上传队列按 `FailureKind::SessionExpired` 新建会话重传。
+pub(crate) const UPLOAD_SESSION_EXPIRED_MESSAGE: &str = "upload session expired; please retry";
+/// 用户取消上传时各上传路径返回的错误文本。
+pub(crate) const UPLOAD_CANCELLED_MESSAGE: &str = "upload cancelled";
+/// 冲突策略为 fail 且目标已存在同名项时的提示。
+pub(crate) const REMOTE_NAME_CONFLICT_MESSAGE: &str = "目标位置已存在同名文件，已按冲突策略放弃上传";
 
 /// 上传小文件（推荐 10MB 内，硬上限 250MB），存放到指定文件夹。
+/// - 从 local_path 流式读取文件内容，无需在 Dart 侧把文件读入内存。
 /// - 当 overwrite=true 时，如果存在同名文件，将直接覆盖。
 /// - 当 overwrite=false 时，使用 Graph 的 rename 行为避免冲突。
+/// - 需要 fail 或跳过相同文件等策略时请使用上传队列。
 /// - parent_id 为空时默认上传到根目录。
 #[flutter_rust_bridge::frb]
 pub fn upload_small_file(
     parent_id: Option<String>,
     file_name: String,
-    content: Vec<u8>,
+    local_path: String,
     overwrite: bool,
 ) -> Result<DriveItemSummary, String> {
-    upload_small_file_with_hooks(parent_id, file_name, content, overwrite, None, None)
+    let conflict_behavior = if overwrite {
+        UploadConflictBehavior::Replace
+    } else {
+        UploadConflictBehavior::Rename
+    };
+    upload_small_file_from_path_with_hooks(
+        parent_id,
+        file_name,
+        &local_path,
+        conflict_behavior,
+        None,
+        None,
+    )
+    .map(|outcome| outcome.item)
+    .map_err(String::from)
 }
 
-#[allow(dead_code)]
+/// 以简易上传方式从本地路径流式读取文件，无需把整个文件读入内存。
+/// 简易上传无法携带元数据，完成后再写回本地创建/修改时间；写回失败只记录日志。
 #[flutter_rust_bridge::frb(ignore)]
-pub(crate) fn upload_small_file_with_hooks(
+pub(crate) fn upload_small_file_from_path_with_hooks(
     parent_id: Option<String>,
     file_name: String,
-    content: Vec<u8>,
-    overwrite: bool,
+    local_path: &str,
+    conflict_behavior: UploadConflictBehavior,
     cancel_flag: Option<Arc<AtomicBool>>,
-    progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send>>,
-) -> Result<DriveItemSummary, String> {
+    progress: Option<ProgressCallback>,
+) -> Result<UploadOutcome, TransferError> {
+    let file = File::open(local_path)
+        .map_err(|e| TransferError::permanent(format!("failed to open file for upload: {e}")))?;
+    let total_len = file
+        .metadata()
+        .map_err(|e| TransferError::permanent(format!("failed to read file size: {e}")))?
+        .len();
+    let file_system_info = local_file_system_info(Path::new(local_path));
+    let mut outcome = simple_upload(
+        parent_id,
+        file_name,
+        BufReader::new(file),
+        total_len,
+        conflict_behavior,
+        cancel_flag,
+        progress,
+    )?;
+    if let Some(info) = file_system_info {
+        let summary = &mut outcome.item;
+        match patch_file_system_info(&summary.id, &info) {
+            Ok(()) => {
+                if info.last_modified_date_time.is_some() {
+                    summary.last_modified = info.last_modified_date_time;
+                }
+            }
+            Err(err) => {
+                eprintln!("[drive-upload] failed to keep timestamps for {}: {err}", summary.id)
+            }
+        }
+    }
+    Ok(outcome)
+}
+
+/// 单请求 PUT 上传，请求体边读边发，并按上行限速与取消标记控制。
+fn simple_upload<R: Read + Send + 'static>(
+    parent_id: Option<String>,
+    file_name: String,
+    content: R,
+    total_len: u64,
+    conflict_behavior: UploadConflictBehavior,
+    cancel_flag: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+) -> Result<UploadOutcome, TransferError> {
     if file_name.trim().is_empty() {
-        return Err("file name cannot be empty".to_string());
+        return Err(TransferError::permanent("file name cannot be empty"));
     }
-    if content.len() > SIMPLE_UPLOAD_MAX_BYTES {
-        return Err("file too large for simple upload; please use chunked upload".to_string());
+    if total_len > SIMPLE_UPLOAD_MAX_BYTES as u64 {
+        return Err(TransferError::permanent(
+            "file too large for simple upload; please use chunked upload",
+        ));
     }
 
-    let access_token = current_access_token()?;
-    let client = build_blocking_client(Duration::from_secs(120))?;
+    let access_token = current_access_token().map_err(TransferError::permanent)?;
+    let client =
+        build_blocking_client(Duration::from_secs(120)).map_err(TransferError::permanent)?;
 
     let encoded_name = utf8_percent_encode(file_name.trim(), NON_ALPHANUMERIC).to_string();
     let encoded_parent = parent_id
         .map(|id| id.trim().to_string())
         .filter(|id| !id.is_empty());
 
-    let conflict = if overwrite { "replace" } else { "rename" };
+    let conflict = conflict_behavior_param(conflict_behavior);
     let url = if let Some(id) = encoded_parent {
         format!(
             "{GRAPH_BASE}/me/drive/items/{id}:/{encoded_name}:/content?@microsoft.graph.conflictBehavior={conflict}"
@@ -72,9 +148,8 @@ pub(crate) fn upload_small_file_with_hooks(
         )
     };
 
-    let total_len = content.len() as u64;
     let reader = ProgressReader::new(
-        Cursor::new(content),
+        content,
         total_len,
         cancel_flag.clone(),
         progress,
@@ -85,35 +160,50 @@ pub(crate) fn upload_small_file_with_hooks(
         .header("Content-Type", "application/octet-stream")
         .body(reqwest::blocking::Body::sized(reader, total_len))
         .send()
-        .map_err(|e| format!("failed to upload file: {e}"))?;
+        .map_err(|e| {
+            if cancel_flag.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE)
+            } else {
+                TransferError::transient(format!("failed to upload file: {e}"))
+            }
+        })?;
 
-    if response.status().as_u16() == 401 {
-        return Err("access token rejected by Graph API; please sign in again".to_string());
+    let status = response.status();
+    if status.as_u16() == 401 {
+        return Err(TransferError::permanent(
+            "access token rejected by Graph API; please sign in again",
+        ));
+    }
+    if status.as_u16() == 409 {
+        return Err(TransferError::permanent(REMOTE_NAME_CONFLICT_MESSAGE));
     }
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "graph api returned HTTP {} while uploading",
-            response.status()
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("graph api returned HTTP {status} while uploading"),
         ));
     }
 
     let dto: DriveItemUploadResponse = response
         .json()
-        .map_err(|e| format!("failed to parse upload response: {e}"))?;
+        .map_err(|e| TransferError::permanent(format!("failed to parse upload response: {e}")))?;
 
-    Ok(dto.into())
+    Ok(dto.into_outcome(None))
 }
 
 /// 创建分片上传会话，返回预签名 URL 与过期时间。
+/// 传入本地时间戳时随会话 item 一并提交，上传完成后 OneDrive 直接采用。
 #[flutter_rust_bridge::frb(ignore)]
 pub(crate) fn create_upload_session(
     parent_id: Option<String>,
     file_name: &str,
-    overwrite: bool,
-) -> Result<UploadSessionResponse, String> {
-    let access_token = current_access_token()?;
-    let client = build_blocking_client(Duration::from_secs(30))?;
+    conflict_behavior: UploadConflictBehavior,
+    file_system_info: Option<&FileSystemInfo>,
+) -> Result<UploadSessionResponse, TransferError> {
+    let access_token = current_access_token().map_err(TransferError::permanent)?;
+    let client =
+        build_blocking_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
 
     let encoded_name = utf8_percent_encode(file_name.trim(), NON_ALPHANUMERIC).to_string();
     let encoded_parent = parent_id
@@ -121,7 +211,7 @@ pub(crate) fn create_upload_session(
         .map(|id| id.trim().to_string())
         .filter(|id| !id.is_empty());
 
-    let conflict = if overwrite { "replace" } else { "rename" };
+    let conflict = conflict_behavior_param(conflict_behavior);
     let url = if let Some(id) = encoded_parent {
         format!(
             "{GRAPH_BASE}/me/drive/items/{id}:/{encoded_name}:/createUploadSession"
@@ -135,6 +225,8 @@ pub(crate) fn create_upload_session(
         #[serde(rename = "@microsoft.graph.conflictBehavior")]
         conflict_behavior: &'a str,
         name: &'a str,
+        #[serde(rename = "fileSystemInfo", skip_serializing_if = "Option::is_none")]
+        file_system_info: Option<&'a FileSystemInfo>,
     }
     #[derive(serde::Serialize)]
     struct SessionRequest<'a> {
@@ -145,6 +237,7 @@ pub(crate) fn create_upload_session(
         item: SessionRequestItem {
             conflict_behavior: conflict,
             name: file_name,
+            file_system_info,
         },
     };
 
@@ -153,64 +246,148 @@ pub(crate) fn create_upload_session(
         .bearer_auth(access_token)
         .json(&body)
         .send()
-        .map_err(|e| format!("failed to create upload session: {e}"))?;
+        .map_err(|e| TransferError::transient(format!("failed to create upload session: {e}")))?;
 
-    if resp.status().as_u16() == 401 {
-        return Err("access token rejected by Graph API; please sign in again".to_string());
+    let status = resp.status();
+    if status.as_u16() == 401 {
+        return Err(TransferError::permanent(
+            "access token rejected by Graph API; please sign in again",
+        ));
+    }
+    if status.as_u16() == 409 {
+        return Err(TransferError::permanent(REMOTE_NAME_CONFLICT_MESSAGE));
     }
 
-    if !resp.status().is_success() {
-        return Err(format!(
-            "graph api returned HTTP {} while creating upload session",
-            resp.status()
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("graph api returned HTTP {status} while creating upload session"),
         ));
     }
 
     parse_upload_session_response(resp, "parse upload session", true)
+        .map_err(TransferError::permanent)
+}
+
+/// 映射为 Graph 的 `@microsoft.graph.conflictBehavior`；跳过相同文件的判断在上传前完成，
+/// 走到上传这一步说明内容不同，按覆盖处理。
+pub(crate) fn conflict_behavior_param(behavior: UploadConflictBehavior) -> &'static str {
+    match behavior {
+        UploadConflictBehavior::Fail => "fail",
+        UploadConflictBehavior::Replace | UploadConflictBehavior::SkipIfIdentical => "replace",
+        UploadConflictBehavior::Rename => "rename",
+    }
 }
 
 /// 获取 upload session 状态（恢复/处理 416 时使用）。
 #[flutter_rust_bridge::frb(ignore)]
-pub(crate) fn get_upload_session_status(upload_url: &str) -> Result<UploadSessionResponse, String> {
-    let client = build_blocking_client(Duration::from_secs(30))?;
+pub(crate) fn get_upload_session_status(
+    upload_url: &str,
+) -> Result<UploadSessionResponse, TransferError> {
+    let client =
+        build_blocking_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
     let resp = client
         .get(upload_url)
         .send()
-        .map_err(|e| format!("failed to query upload session: {e}"))?;
-    if resp.status().as_u16() == 404 {
-        return Err("upload session expired or not found".to_string());
+        .map_err(|e| TransferError::transient(format!("failed to query upload session: {e}")))?;
+    let status = resp.status();
+    if status.as_u16() == 404 {
+        return Err(TransferError::session_expired(UPLOAD_SESSION_EXPIRED_MESSAGE));
     }
-    if !resp.status().is_success() {
-        return Err(format!(
-            "failed to query upload session, http {}",
-            resp.status()
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("failed to query upload session, http {status}"),
         ));
     }
     parse_upload_session_response(resp, "parse upload session status", false)
+        .map_err(TransferError::permanent)
+}
+
+/// 会话过期时间更新回调，参数为毫秒时间戳。
+pub(crate) type SessionExpiryCallback = Box<dyn FnMut(i64) + Send>;
+
+/// 解析 Graph 返回的 `expirationDateTime`（RFC 3339）为毫秒时间戳。
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) fn parse_session_expiration(raw: Option<&str>) -> Option<i64> {
+    DateTime::parse_from_rfc3339(raw?.trim())
+        .ok()
+        .map(|time| time.timestamp_millis())
 }
 
 /// 分片上传大文件（读取本地路径），支持取消与进度回调。
+/// 分片大小按实测吞吐与错误率在 320 KiB 到 60 MiB 之间自适应；
+/// 失败的分片以缩小后的大小从同一偏移重传，缓冲区在分片之间复用。
+/// 服务端每收到一个分片都会顺延会话过期时间，通过 `on_session_expiry` 回报。
+/// 读取分片时顺带计算本地内容哈希；从断点续传时前段未经本次读取，不提供本地哈希。
 #[flutter_rust_bridge::frb(ignore)]
 pub(crate) fn upload_large_file_with_hooks(
     upload_url: String,
     local_path: &str,
     total_size: u64,
-    mut offset: u64,
+    offset: u64,
     cancel_flag: Arc<AtomicBool>,
-    mut progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send>>,
-) -> Result<DriveItemSummary, String> {
-    align_chunk_size()?;
+    progress: Option<ProgressCallback>,
+    on_session_expiry: Option<SessionExpiryCallback>,
+) -> Result<UploadOutcome, TransferError> {
     let mut file = File::open(local_path)
-        .map_err(|e| format!("failed to open file for upload: {e}"))?;
-    file.seek(SeekFrom::Start(offset))
-        .map_err(|e| format!("failed to seek file: {e}"))?;
-    let mut reader = BufReader::new(file);
+        .map_err(|e| TransferError::permanent(format!("failed to open file for upload: {e}")))?;
+    upload_chunks(
+        upload_url,
+        &mut file,
+        total_size,
+        offset,
+        cancel_flag,
+        progress,
+        on_session_expiry,
+    )
+}
+
+/// 把顺序读取的数据流（如网络下载）写入上传会话，不落地到磁盘；
+/// 数据流无法回退，只能从头开始上传，`total_size` 必须与流的实际长度一致。
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) fn upload_stream_with_hooks<R: Read>(
+    upload_url: String,
+    reader: R,
+    total_size: u64,
+    cancel_flag: Arc<AtomicBool>,
+    progress: Option<ProgressCallback>,
+    on_session_expiry: Option<SessionExpiryCallback>,
+) -> Result<UploadOutcome, TransferError> {
+    let mut source = StreamChunkSource::new(reader);
+    upload_chunks(
+        upload_url,
+        &mut source,
+        total_size,
+        0,
+        cancel_flag,
+        progress,
+        on_session_expiry,
+    )
+}
+
+/// 分片上传主循环，数据源可以是本地文件或顺序数据流。
+fn upload_chunks(
+    upload_url: String,
+    source: &mut dyn ChunkSource,
+    total_size: u64,
+    mut offset: u64,
+    cancel_flag: Arc<AtomicBool>,
+    mut progress: Option<ProgressCallback>,
+    mut on_session_expiry: Option<SessionExpiryCallback>,
+) -> Result<UploadOutcome, TransferError> {
+    let mut sizer = ChunkSizer::new();
+    let mut buffer = Arc::new(Vec::new());
+    let mut failures = 0;
+    // 只有从文件开头连续读到结尾时摘要才有效；`hashed_until` 之前的数据已喂入摘要。
+    let mut digest = (offset == 0).then(ContentDigest::new);
+    let mut hashed_until = 0u64;
 
     loop {
         if cancel_flag.load(Ordering::Relaxed) {
             // 尝试通知服务端取消会话，但即便失败也返回取消。
             let _ = cancel_upload_session(&upload_url);
-            return Err("upload cancelled".to_string());
+            return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
         }
 
         if offset >= total_size {
@@ -220,32 +397,36 @@ pub(crate) fn upload_large_file_with_hooks(
                 if let Some(cb) = progress.as_mut() {
                     cb(total_size, Some(total_size));
                 }
-                return Ok(item.into());
+                return Ok(item.into_outcome(finish_digest(digest, hashed_until, total_size)));
             }
         }
 
         let remaining = total_size.saturating_sub(offset);
-        let chunk_len = std::cmp::min(CHUNK_SIZE_BYTES, remaining) as usize;
-        let mut buffer = vec![0u8; chunk_len];
-        let n = reader
-            .read(&mut buffer)
-            .map_err(|e| format!("failed to read file chunk: {e}"))?;
-        if n == 0 {
-            return Err("unexpected EOF while reading file".to_string());
+        let chunk_len = sizer.chunk_size().min(remaining);
+        fill_chunk(source, &mut buffer, offset, chunk_len as usize)?;
+        let end = offset + chunk_len - 1;
+        // 重传或缩小分片时可能与已计入摘要的数据重叠，只喂入新的部分；出现空洞则放弃本地摘要。
+        if let Some(hasher) = digest.as_mut() {
+            if offset > hashed_until {
+                digest = None;
+            } else if end + 1 > hashed_until {
+                hasher.update(&buffer[(hashed_until - offset) as usize..]);
+                hashed_until = end + 1;
+            }
         }
-        buffer.truncate(n);
-        let end = offset + buffer.len() as u64 - 1;
-
-        match upload_chunk_with_retry(
-            &upload_url,
-            offset,
-            end,
-            total_size,
-            buffer,
-            &cancel_flag,
-        ) {
-            Ok(UploadChunkResult::Continue { next_offset, expire_at: _ }) => {
+        let started = Instant::now();
+
+        match upload_chunk(&upload_url, offset, end, total_size, &buffer, &cancel_flag) {
+            Ok(UploadChunkResult::Continue { next_offset, expire_at }) => {
+                sizer.record_success(chunk_len, started.elapsed());
+                failures = 0;
                 offset = next_offset;
+                if let (Some(cb), Some(at)) = (
+                    on_session_expiry.as_mut(),
+                    parse_session_expiration(expire_at.as_deref()),
+                ) {
+                    cb(at);
+                }
                 if let Some(cb) = progress.as_mut() {
                     cb(offset, Some(total_size));
                 }
@@ -254,122 +435,144 @@ pub(crate) fn upload_large_file_with_hooks(
                 if let Some(cb) = progress.as_mut() {
                     cb(total_size, Some(total_size));
                 }
-                return Ok(item);
+                return Ok(item.into_outcome(finish_digest(digest, hashed_until, total_size)));
             }
             Err(UploadChunkError::RangeMismatch(next_start)) => {
-                // 416/错位：重置游标后继续。
+                // 416/错位：重置游标后继续，下一轮按新偏移重新读取分片。
                 offset = next_start;
-                reader
-                    .seek(SeekFrom::Start(offset))
-                    .map_err(|e| format!("failed to seek after range mismatch: {e}"))?;
+            }
+            Err(UploadChunkError::Retryable(err)) => {
+                sizer.record_failure();
+                failures += 1;
+                if failures > MAX_RETRY {
+                    return Err(err);
+                }
+                let backoff = RETRY_BASE_DELAY_MS * 2u64.saturating_pow(failures as u32);
+                thread::sleep(Duration::from_millis(backoff));
             }
             Err(UploadChunkError::SessionExpired) => {
-                return Err("upload session expired; please retry".to_string());
+                return Err(TransferError::session_expired(UPLOAD_SESSION_EXPIRED_MESSAGE));
             }
             Err(UploadChunkError::Cancelled) => {
                 let _ = cancel_upload_session(&upload_url);
-                return Err("upload cancelled".to_string());
+                return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
             }
-            Err(UploadChunkError::Fatal(msg)) => {
-                return Err(msg);
+            Err(UploadChunkError::Fatal(err)) => {
+                return Err(err);
             }
         }
     }
 }
 
-fn align_chunk_size() -> Result<(), String> {
-    if CHUNK_SIZE_BYTES % CHUNK_ALIGNMENT != 0 {
-        return Err("CHUNK_SIZE_BYTES must align with 320KiB per Graph requirement".to_string());
-    }
-    Ok(())
-}
-
-/// 处理单个分片上传，并包含指数退避重试。
-fn upload_chunk_with_retry(
+/// 发送单个分片；请求体只读共享缓冲区，不复制分片数据。
+/// 网络错误与非预期的状态码返回 `Retryable`，由调用方缩小分片后退避重试。
+fn upload_chunk(
     upload_url: &str,
     start: u64,
     end: u64,
     total: u64,
-    body: Vec<u8>,
+    body: &Arc<Vec<u8>>,
     cancel_flag: &Arc<AtomicBool>,
 ) -> Result<UploadChunkResult, UploadChunkError> {
+    if cancel_flag.load(Ordering::Relaxed) {
+        return Err(UploadChunkError::Cancelled);
+    }
     let content_length = body.len() as u64;
     let content_range = format!("bytes {start}-{end}/{total}");
-
-    let mut attempt = 0;
-    let mut last_err = String::new();
-    while attempt <= MAX_RETRY {
-        if cancel_flag.load(Ordering::Relaxed) {
-            return Err(UploadChunkError::Cancelled);
-        }
-        let client = build_blocking_client(Duration::from_secs(120))
-            .map_err(|e| UploadChunkError::Fatal(format!("failed to build client: {e}")))?;
-        let send_body = body.clone();
-        let resp = client
-            .put(upload_url)
-            .header("Content-Length", content_length)
-            .header("Content-Range", &content_range)
-            .body(send_body)
-            .send();
-        match resp {
-            Ok(r) => {
-                let status = r.status();
-                if status.is_success() {
-                    if status.as_u16() == 201 || status.as_u16() == 200 {
-                        let dto: DriveItemUploadResponse = r
-                            .json()
-                            .map_err(|e| UploadChunkError::Fatal(format!("parse final response failed: {e}")))?;
-                        return Ok(UploadChunkResult::Completed { item: dto.into() });
-                    }
-                    // 202 Accepted: 继续上传
-                    let dto = parse_upload_session_response(
-                        r,
-                        "parse upload session status after chunk",
-                        false,
-                    )
-                    .map_err(UploadChunkError::Fatal)?;
-                    let next_offset =
-                        parse_next_start(&dto.next_expected_ranges).unwrap_or(end + 1);
-                    return Ok(UploadChunkResult::Continue {
-                        next_offset,
-                        expire_at: dto.expiration_date_time,
-                    });
-                }
-                match status.as_u16() {
-                    401 => return Err(UploadChunkError::Fatal("access token rejected by Graph API; please sign in again".to_string())),
-                    404 => return Err(UploadChunkError::SessionExpired),
-                    409 => return Err(UploadChunkError::Fatal("upload conflict: target file changed, please retry".to_string())),
-                    412 => return Err(UploadChunkError::Fatal("precondition failed while uploading; retry later".to_string())),
-                    416 => {
-                        if let Ok(status) = get_upload_session_status(upload_url) {
-                            let next = parse_next_start(&status.next_expected_ranges).unwrap_or(start);
-                            return Err(UploadChunkError::RangeMismatch(next));
-                        } else {
-                            return Err(UploadChunkError::RangeMismatch(start));
-                        }
-                    }
-                    _ => {
-                        last_err = format!("graph returned HTTP {status} for chunk {content_range}");
-                    }
-                }
-            }
-            Err(e) => {
-                last_err = format!("network error on upload chunk: {e}");
+    let client = build_blocking_client(Duration::from_secs(120))
+        .map_err(|e| {
+            UploadChunkError::Fatal(TransferError::permanent(format!(
+                "failed to build client: {e}"
+            )))
+        })?;
+    // 分片体同样经过 ProgressReader，以便按上行限速平滑发送。
+    let send_body = ProgressReader::new(
+        SharedChunkReader::new(Arc::clone(body)),
+        content_length,
+        Some(cancel_flag.clone()),
+        None,
+    );
+    let resp = client
+        .put(upload_url)
+        .header("Content-Length", content_length)
+        .header("Content-Range", &content_range)
+        .body(reqwest::blocking::Body::sized(send_body, content_length))
+        .send();
+    let r = match resp {
+        Ok(r) => r,
+        Err(e) => {
+            if cancel_flag.load(Ordering::Relaxed) {
+                return Err(UploadChunkError::Cancelled);
             }
+            return Err(UploadChunkError::Retryable(TransferError::transient(format!(
+                "network error on upload chunk: {e}"
+            ))));
         }
-
-        attempt += 1;
-        if attempt > MAX_RETRY {
-            break;
+    };
+    let status = r.status();
+    if status.is_success() {
+        if status.as_u16() == 201 || status.as_u16() == 200 {
+            let dto: DriveItemUploadResponse = r
+                .json()
+                .map_err(|e| {
+                    UploadChunkError::Fatal(TransferError::permanent(format!(
+                        "parse final response failed: {e}"
+                    )))
+                })?;
+            return Ok(UploadChunkResult::Completed { item: dto });
+        }
+        // 202 Accepted: 继续上传
+        let dto = parse_upload_session_response(
+            r,
+            "parse upload session status after chunk",
+            false,
+        )
+        .map_err(|e| UploadChunkError::Fatal(TransferError::permanent(e)))?;
+        let next_offset = parse_next_start(&dto.next_expected_ranges).unwrap_or(end + 1);
+        return Ok(UploadChunkResult::Continue {
+            next_offset,
+            expire_at: dto.expiration_date_time,
+        });
+    }
+    match status.as_u16() {
+        401 => Err(UploadChunkError::Fatal(TransferError::permanent(
+            "access token rejected by Graph API; please sign in again",
+        ))),
+        404 => Err(UploadChunkError::SessionExpired),
+        409 => Err(UploadChunkError::Fatal(TransferError::permanent(
+            "upload conflict: target file changed, please retry",
+        ))),
+        412 => Err(UploadChunkError::Fatal(TransferError::permanent(
+            "precondition failed while uploading; retry later",
+        ))),
+        416 => {
+            let next = get_upload_session_status(upload_url)
+                .ok()
+                .and_then(|status| parse_next_start(&status.next_expected_ranges))
+                .unwrap_or(start);
+            Err(UploadChunkError::RangeMismatch(next))
         }
-        let backoff = RETRY_BASE_DELAY_MS * 2u64.saturating_pow(attempt as u32);
-        thread::sleep(Duration::from_millis(backoff));
+        _ => Err(UploadChunkError::Retryable(TransferError::from_status(
+            status,
+            format!("graph returned HTTP {status} for chunk {content_range}"),
+        ))),
     }
+}
 
-    Err(UploadChunkError::Fatal(last_err))
+/// 摘要覆盖了整个文件时返回本地哈希。
+fn finish_digest(
+    digest: Option<ContentDigest>,
+    hashed_until: u64,
+    total_size: u64,
+) -> Option<ContentHashes> {
+    digest
+        .filter(|_| hashed_until == total_size)
+        .map(ContentDigest::finalize)
 }
 
-fn cancel_upload_session(upload_url: &str) -> Result<(), String> {
+/// 删除上传会话，释放服务端已暂存的分片；会话已过期（404）视为成功。
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) fn cancel_upload_session(upload_url: &str) -> Result<(), String> {
     let client = build_blocking_client(Duration::from_secs(10))?;
     let resp = client
         .delete(upload_url)
@@ -385,7 +588,8 @@ fn cancel_upload_session(upload_url: &str) -> Result<(), String> {
     }
 }
 
-fn parse_next_start(next_expected: &Option<Vec<String>>) -> Option<u64> {
+/// 取 nextExpectedRanges 首个区间的起点，区间形如 `start-end` 或 `start-`。
+pub(crate) fn parse_next_start(next_expected: &Option<Vec<String>>) -> Option<u64> {
     let raw = next_expected.as_ref()?.first()?;
     if let Some((start, _)) = raw.split_once('-') {
         return start.parse::<u64>().ok();
@@ -434,11 +638,10 @@ pub(crate) struct UploadSessionResponse {
 enum UploadChunkResult {
     Continue {
         next_offset: u64,
-        #[allow(dead_code)]
         expire_at: Option<String>,
     },
     Completed {
-        item: DriveItemSummary,
+        item: DriveItemUploadResponse,
     },
 }
 
@@ -446,7 +649,9 @@ enum UploadChunkError {
     Cancelled,
     SessionExpired,
     RangeMismatch(u64),
-    Fatal(String),
+    /// 先在分片层缩小重传，次数耗尽后按其中的失败类型交给任务级重试。
+    Retryable(TransferError),
+    Fatal(TransferError),
 }
 
 #[derive(Debug, Deserialize)]
@@ -466,6 +671,38 @@ pub(crate) struct DriveItemUploadResponse {
 pub(crate) struct UploadFileFacet {
     #[serde(rename = "mimeType")]
     pub mime_type: Option<String>,
+    pub hashes: Option<UploadFileHashes>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct UploadFileHashes {
+    pub quick_xor_hash: Option<String>,
+    pub sha1_hash: Option<String>,
+}
+
+/// 上传完成的结果：远端 item 与 Graph 返回的内容哈希，以及上传过程中顺带计算的本地哈希。
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct UploadOutcome {
+    pub item: DriveItemSummary,
+    pub remote_hashes: ContentHashes,
+    pub local_hashes: Option<ContentHashes>,
+}
+
+impl DriveItemUploadResponse {
+    pub(crate) fn into_outcome(mut self, local_hashes: Option<ContentHashes>) -> UploadOutcome {
+        let hashes = self.file.as_mut().and_then(|file| file.hashes.take());
+        let remote_hashes = ContentHashes {
+            quick_xor_hash: hashes.as_ref().and_then(|h| h.quick_xor_hash.clone()),
+            sha1_hash: hashes.and_then(|h| h.sha1_hash),
+        };
+        UploadOutcome {
+            item: self.into(),
+            remote_hashes,
+            local_hashes,
+        }
+    }
 }
 
 impl From<DriveItemUploadResponse> for DriveItemSummary {
@@ -483,13 +720,13 @@ impl From<DriveItemUploadResponse> for DriveItemSummary {
     }
 }
 
-/// 负责对上传请求体做进度回调与取消检测的 Reader。
+/// 负责对上传请求体做进度回调、取消检测与上行限速的 Reader。
 struct ProgressReader<R: Read> {
     inner: R,
     sent: u64,
     total: u64,
     cancel_flag: Option<Arc<AtomicBool>>,
-    progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send>>,
+    progress: Option<ProgressCallback>,
 }
 
 impl<R: Read> ProgressReader<R> {
@@ -497,7 +734,7 @@ impl<R: Read> ProgressReader<R> {
         inner: R,
         total: u64,
         cancel_flag: Option<Arc<AtomicBool>>,
-        progress: Option<Box<dyn FnMut(u64, Option<u64>) + Send>>,
+        progress: Option<ProgressCallback>,
     ) -> Self {
         Self {
             inner,
@@ -521,6 +758,11 @@ impl<R: Read> Read for ProgressReader<R> {
         }
         let read_bytes = self.inner.read(buf)?;
         if read_bytes > 0 {
+            throttle(
+                TransferDirection::Upload,
+                read_bytes,
+                self.cancel_flag.as_deref(),
+            );
             self.sent = self.sent.saturating_add(read_bytes as u64);
             if let Some(cb) = self.progress.as_mut() {
                 cb(self.sent, Some(self.total));
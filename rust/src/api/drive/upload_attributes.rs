@@ -0,0 +1,61 @@
+use super::{
+    client::{build_blocking_client, current_access_token},
+    GRAPH_BASE,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use serde_json::json;
+use std::{path::Path, time::Duration, time::SystemTime};
+
+/// Graph `fileSystemInfo`：客户端记录的创建/修改时间，OneDrive 以此作为文件的“修改日期”展示。
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FileSystemInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date_time: Option<String>,
+}
+
+/// 读取本地文件的创建/修改时间；平台不支持创建时间时仅带修改时间，两者都读不到时返回 `None`。
+pub(crate) fn local_file_system_info(path: &Path) -> Option<FileSystemInfo> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let created = metadata.created().ok().map(format_graph_timestamp);
+    let modified = metadata.modified().ok().map(format_graph_timestamp);
+    if created.is_none() && modified.is_none() {
+        return None;
+    }
+    Some(FileSystemInfo {
+        created_date_time: created,
+        last_modified_date_time: modified,
+    })
+}
+
+/// 简易上传没有携带元数据的入口，上传完成后单独 PATCH 写回本地时间戳。
+pub(crate) fn patch_file_system_info(item_id: &str, info: &FileSystemInfo) -> Result<(), String> {
+    let access_token = current_access_token()?;
+    let client = build_blocking_client(Duration::from_secs(30))?;
+    let response = client
+        .patch(format!("{GRAPH_BASE}/me/drive/items/{item_id}"))
+        .bearer_auth(access_token)
+        .header("Accept", "application/json")
+        .json(&json!({ "fileSystemInfo": info }))
+        .send()
+        .map_err(|e| format!("failed to update file timestamps: {e}"))?;
+
+    if response.status().as_u16() == 401 {
+        return Err("access token rejected by Graph API; please sign in again".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "graph api returned HTTP {} while updating file timestamps",
+            response.status()
+        ));
+    }
+    Ok(())
+}
+
+/// 以 UTC、毫秒精度输出 ISO 8601 时间戳（如 `2024-05-01T08:00:00.000Z`）。
+fn format_graph_timestamp(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Millis, true)
+}
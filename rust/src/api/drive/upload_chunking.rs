@@ -0,0 +1,274 @@
+use crate::retry::TransferError;
+use std::{
+    fs::File,
+    io::{ErrorKind, Read, Seek, SeekFrom},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Graph 要求分片大小为 320 KiB 的整数倍（最后一片除外）。
+const CHUNK_ALIGNMENT: u64 = 320 * 1024;
+const MIN_CHUNK_SIZE_BYTES: u64 = CHUNK_ALIGNMENT;
+/// Graph 单个分片的上限为 60 MiB（恰好是 320 KiB 的 192 倍）。
+const MAX_CHUNK_SIZE_BYTES: u64 = 60 * 1024 * 1024;
+// 起始保持 10MiB（32 * 320KiB），在测得吞吐前兼顾吞吐与重传开销。
+const INITIAL_CHUNK_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// 期望单个分片的传输耗时：越大请求开销越低，越小失败重传的代价越低。
+const TARGET_CHUNK_DURATION: Duration = Duration::from_secs(8);
+/// 错误率的指数平滑系数，最近几次尝试权重更高。
+const ERROR_RATE_SMOOTHING: f64 = 0.25;
+/// 错误率高于该值时只收缩不增长。
+const GROWTH_ERROR_RATE_LIMIT: f64 = 0.05;
+
+/// 根据实测吞吐与近期错误率调整分片大小：
+/// - 成功后按吞吐折算到目标耗时，单次最多翻倍或减半，避免剧烈抖动；
+/// - 失败后立即减半，让不稳定的链路每次重传的数据更少。
+pub(crate) struct ChunkSizer {
+    chunk_size: u64,
+    error_rate: f64,
+}
+
+impl ChunkSizer {
+    pub(crate) fn new() -> Self {
+        Self {
+            chunk_size: INITIAL_CHUNK_SIZE_BYTES,
+            error_rate: 0.0,
+        }
+    }
+
+    pub(crate) fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+
+    pub(crate) fn record_success(&mut self, bytes: u64, elapsed: Duration) {
+        self.error_rate *= 1.0 - ERROR_RATE_SMOOTHING;
+        let seconds = elapsed.as_secs_f64();
+        if bytes == 0 || seconds <= 0.0 {
+            return;
+        }
+        let throughput = bytes as f64 / seconds;
+        let target = throughput * TARGET_CHUNK_DURATION.as_secs_f64() * (1.0 - self.error_rate);
+        let mut upper = self.chunk_size.saturating_mul(2);
+        if self.error_rate > GROWTH_ERROR_RATE_LIMIT {
+            upper = self.chunk_size;
+        }
+        let next = (target as u64).clamp(self.chunk_size / 2, upper);
+        self.chunk_size = align_chunk_size(next);
+    }
+
+    pub(crate) fn record_failure(&mut self) {
+        self.error_rate = self.error_rate * (1.0 - ERROR_RATE_SMOOTHING) + ERROR_RATE_SMOOTHING;
+        self.chunk_size = align_chunk_size(self.chunk_size / 2);
+    }
+}
+
+/// 向下对齐到 320 KiB 并限定在 [MIN, MAX] 区间。
+fn align_chunk_size(size: u64) -> u64 {
+    let aligned = size / CHUNK_ALIGNMENT * CHUNK_ALIGNMENT;
+    aligned.clamp(MIN_CHUNK_SIZE_BYTES, MAX_CHUNK_SIZE_BYTES)
+}
+
+/// 分片数据来源：本地文件可任意定位，网络流只能顺序向前读取。
+pub(crate) trait ChunkSource {
+    /// 把 `[offset, offset + data.len())` 的数据读入 `data`。
+    /// 本地文件读取失败重试无用；网络流中断属于瞬时失败，重新开始即可。
+    fn read_chunk(&mut self, offset: u64, data: &mut [u8]) -> Result<(), TransferError>;
+}
+
+impl ChunkSource for File {
+    fn read_chunk(&mut self, offset: u64, data: &mut [u8]) -> Result<(), TransferError> {
+        self.seek(SeekFrom::Start(offset))
+            .map_err(|e| TransferError::permanent(format!("failed to seek file: {e}")))?;
+        self.read_exact(data).map_err(|e| {
+            TransferError::permanent(if e.kind() == ErrorKind::UnexpectedEof {
+                "unexpected EOF while reading file".to_string()
+            } else {
+                format!("failed to read file chunk: {e}")
+            })
+        })
+    }
+}
+
+/// 顺序读取的网络流：保留尚未确认上传的数据，分片重传或缩小时无需回退数据源。
+pub(crate) struct StreamChunkSource<R: Read> {
+    reader: R,
+    /// `pending[0]` 对应的流内偏移。
+    pending_offset: u64,
+    pending: Vec<u8>,
+}
+
+impl<R: Read> StreamChunkSource<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending_offset: 0,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<R: Read> ChunkSource for StreamChunkSource<R> {
+    fn read_chunk(&mut self, offset: u64, data: &mut [u8]) -> Result<(), TransferError> {
+        if offset < self.pending_offset {
+            return Err(TransferError::permanent(
+                "source stream cannot rewind to an acknowledged offset",
+            ));
+        }
+        // 请求的偏移之前的数据已被服务端接收，释放掉。
+        let acknowledged = ((offset - self.pending_offset) as usize).min(self.pending.len());
+        self.pending.drain(..acknowledged);
+        self.pending_offset += acknowledged as u64;
+        let skip = (offset - self.pending_offset) as usize;
+        let needed = skip + data.len();
+        if self.pending.len() < needed {
+            let start = self.pending.len();
+            self.pending.resize(needed, 0);
+            if let Err(e) = self.reader.read_exact(&mut self.pending[start..]) {
+                self.pending.truncate(start);
+                return Err(TransferError::transient(
+                    if e.kind() == ErrorKind::UnexpectedEof {
+                        "source stream ended before the expected size".to_string()
+                    } else {
+                        format!("failed to read source stream: {e}")
+                    },
+                ));
+            }
+        }
+        data.copy_from_slice(&self.pending[skip..needed]);
+        Ok(())
+    }
+}
+
+/// 将数据源 `[offset, offset + len)` 读入分片缓冲区并复用其容量；
+/// 上一个请求体仍持有缓冲区时（发送线程尚未释放）改为新分配，避免共享可变数据。
+pub(crate) fn fill_chunk(
+    source: &mut dyn ChunkSource,
+    buffer: &mut Arc<Vec<u8>>,
+    offset: u64,
+    len: usize,
+) -> Result<(), TransferError> {
+    if Arc::get_mut(buffer).is_none() {
+        *buffer = Arc::new(Vec::with_capacity(len));
+    }
+    let data = Arc::get_mut(buffer)
+        .ok_or_else(|| TransferError::permanent("chunk buffer is still in use"))?;
+    data.resize(len, 0);
+    source.read_chunk(offset, data)
+}
+
+/// 只读共享分片缓冲区的请求体，重试时无需复制分片数据。
+pub(crate) struct SharedChunkReader {
+    data: Arc<Vec<u8>>,
+    position: usize,
+}
+
+impl SharedChunkReader {
+    pub(crate) fn new(data: Arc<Vec<u8>>) -> Self {
+        Self { data, position: 0 }
+    }
+}
+
+impl Read for SharedChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = &self.data[self.position..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retry::FailureKind;
+
+    #[test]
+    fn sizer_stays_aligned_and_within_bounds() {
+        let mut sizer = ChunkSizer::new();
+        for _ in 0..20 {
+            sizer.record_failure();
+        }
+        assert_eq!(sizer.chunk_size(), MIN_CHUNK_SIZE_BYTES);
+        for _ in 0..40 {
+            sizer.record_success(sizer.chunk_size(), Duration::from_millis(1));
+        }
+        assert_eq!(sizer.chunk_size(), MAX_CHUNK_SIZE_BYTES);
+        assert_eq!(sizer.chunk_size() % CHUNK_ALIGNMENT, 0);
+    }
+
+    #[test]
+    fn sizer_changes_at_most_by_factor_of_two() {
+        let mut sizer = ChunkSizer::new();
+        let start = sizer.chunk_size();
+        sizer.record_success(start, Duration::from_millis(1));
+        assert_eq!(sizer.chunk_size(), start * 2);
+
+        let mut sizer = ChunkSizer::new();
+        sizer.record_success(start, Duration::from_secs(3600));
+        assert_eq!(sizer.chunk_size(), start / 2);
+    }
+
+    #[test]
+    fn sizer_converges_on_target_duration() {
+        let mut sizer = ChunkSizer::new();
+        // 1 MiB/s 的链路：目标 8 秒对应 8 MiB 左右
+        let bytes_per_second = 1024 * 1024;
+        for _ in 0..10 {
+            let size = sizer.chunk_size();
+            sizer.record_success(
+                size,
+                Duration::from_secs_f64(size as f64 / bytes_per_second as f64),
+            );
+        }
+        let expected = align_chunk_size(8 * 1024 * 1024);
+        assert_eq!(sizer.chunk_size(), expected);
+    }
+
+    #[test]
+    fn recent_failures_block_growth() {
+        let mut sizer = ChunkSizer::new();
+        sizer.record_failure();
+        let shrunk = sizer.chunk_size();
+        sizer.record_success(shrunk, Duration::from_millis(1));
+        assert_eq!(sizer.chunk_size(), shrunk);
+    }
+
+    fn read(
+        source: &mut dyn ChunkSource,
+        offset: u64,
+        len: usize,
+    ) -> Result<Vec<u8>, TransferError> {
+        let mut data = vec![0u8; len];
+        source.read_chunk(offset, &mut data).map(|_| data)
+    }
+
+    #[test]
+    fn stream_source_replays_unacknowledged_data() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let mut source = StreamChunkSource::new(bytes.as_slice());
+        assert_eq!(read(&mut source, 0, 40).unwrap(), &bytes[..40]);
+        // 分片失败后缩小重传：从同一偏移再次读取
+        assert_eq!(read(&mut source, 0, 20).unwrap(), &bytes[..20]);
+        assert_eq!(read(&mut source, 20, 50).unwrap(), &bytes[20..70]);
+        assert_eq!(read(&mut source, 70, 30).unwrap(), &bytes[70..]);
+    }
+
+    #[test]
+    fn stream_source_rejects_rewind_past_acknowledged_offset() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let mut source = StreamChunkSource::new(bytes.as_slice());
+        read(&mut source, 0, 40).unwrap();
+        read(&mut source, 40, 10).unwrap();
+        let err = read(&mut source, 10, 10).unwrap_err();
+        assert_eq!(err.kind, FailureKind::Permanent);
+    }
+
+    #[test]
+    fn truncated_stream_is_transient() {
+        let bytes = [1u8; 10];
+        let mut source = StreamChunkSource::new(&bytes[..]);
+        let err = read(&mut source, 0, 20).unwrap_err();
+        assert!(err.is_transient());
+    }
+}
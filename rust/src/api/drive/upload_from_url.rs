@@ -0,0 +1,126 @@
+use super::{
+    client::{build_blocking_client, build_streaming_client, current_access_token},
+    info::get_drive_overview,
+    models::UploadConflictBehavior,
+    upload::{conflict_behavior_param, REMOTE_NAME_CONFLICT_MESSAGE},
+    GRAPH_BASE,
+};
+use crate::retry::TransferError;
+use reqwest::{
+    blocking::Response,
+    header::{CONTENT_LENGTH, LOCATION},
+};
+use serde_json::json;
+use std::time::Duration;
+
+/// 交给 OneDrive 服务端拉取网络文件（`@microsoft.graph.sourceUrl`，仅个人版支持），返回 monitor 地址。
+/// 当前账户或该请求不支持服务端拉取时返回 `None`，由调用方改为本端流式上传。
+pub(crate) fn start_source_url_upload(
+    parent_id: Option<&str>,
+    file_name: &str,
+    source_url: &str,
+    conflict_behavior: UploadConflictBehavior,
+) -> Result<Option<String>, TransferError> {
+    let personal = get_drive_overview()
+        .map_err(TransferError::permanent)?
+        .drive_type
+        .is_some_and(|drive_type| drive_type.eq_ignore_ascii_case("personal"));
+    if !personal {
+        return Ok(None);
+    }
+    let access_token = current_access_token().map_err(TransferError::permanent)?;
+    let client =
+        build_blocking_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
+    let url = match parent_id.map(str::trim).filter(|id| !id.is_empty()) {
+        Some(id) => format!("{GRAPH_BASE}/me/drive/items/{id}/children"),
+        None => format!("{GRAPH_BASE}/me/drive/root/children"),
+    };
+    let body = json!({
+        "@microsoft.graph.sourceUrl": source_url,
+        "name": file_name,
+        "file": {},
+        "@microsoft.graph.conflictBehavior": conflict_behavior_param(conflict_behavior),
+    });
+    let response = client
+        .post(url)
+        .bearer_auth(access_token)
+        .header("Prefer", "respond-async")
+        .json(&body)
+        .send()
+        .map_err(|e| {
+            TransferError::transient(format!("network error while requesting url upload: {e}"))
+        })?;
+
+    let status = response.status();
+    match status.as_u16() {
+        202 => response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(|location| Some(location.to_string()))
+            .ok_or_else(|| {
+                TransferError::permanent("graph api did not return a monitor url for url upload")
+            }),
+        401 => Err(TransferError::permanent(
+            "access token rejected by Graph API; please sign in again",
+        )),
+        409 => Err(TransferError::permanent(REMOTE_NAME_CONFLICT_MESSAGE)),
+        400 | 403 | 501 => {
+            eprintln!("[drive-upload] url upload not accepted (HTTP {status}); streaming instead");
+            Ok(None)
+        }
+        _ => Err(TransferError::from_status(
+            status,
+            format!("graph api returned HTTP {status} while requesting url upload"),
+        )),
+    }
+}
+
+/// 网络文件的下载流与长度。
+pub(crate) struct SourceStream {
+    pub reader: Response,
+    pub size: u64,
+}
+
+/// 打开网络地址的下载流；上传会话需要预先知道总长度，来源未提供 Content-Length 时无法流式上传。
+pub(crate) fn open_source_url(source_url: &str) -> Result<SourceStream, TransferError> {
+    let client =
+        build_streaming_client(Duration::from_secs(30)).map_err(TransferError::permanent)?;
+    let response = client.get(source_url).send().map_err(|e| {
+        TransferError::transient(format!("network error while opening source url: {e}"))
+    })?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(TransferError::from_status(
+            status,
+            format!("source url returned HTTP {status}"),
+        ));
+    }
+    let size = content_length(&response).ok_or_else(|| {
+        TransferError::permanent("来源地址未提供文件大小（Content-Length），无法上传")
+    })?;
+    Ok(SourceStream {
+        reader: response,
+        size,
+    })
+}
+
+/// 通过 HEAD 请求探测网络文件大小，用于换算服务端拉取的进度；探测失败时返回 `None`。
+pub(crate) fn probe_source_size(source_url: &str) -> Option<u64> {
+    let client = build_blocking_client(Duration::from_secs(30)).ok()?;
+    let response = client.head(source_url).send().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    content_length(&response)
+}
+
+fn content_length(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
@@ -1,4 +1,7 @@
-use crate::api::drive::models::{UploadProgressUpdate, UploadQueueState};
+use crate::api::drive::models::{
+    TransferPriority, UploadConflictBehavior, UploadProgressUpdate, UploadQueueState,
+    UploadTaskEvent,
+};
 use crate::frb_generated::StreamSink;
 use crate::upload_manager::UploadManager;
 use flutter_rust_bridge::frb;
@@ -8,25 +11,97 @@ pub fn upload_queue_state() -> UploadQueueState {
     UploadManager::shared().snapshot()
 }
 
+/// 按本地路径入队上传，任意大小的文件都从磁盘流式读取；
+/// 不超过简易上传阈值的文件单请求上传，更大的文件走可续传的分片会话。
+/// `conflict_behavior` 优先于 `overwrite`；两者都未指定时使用设置中的默认冲突处理方式。
 #[frb]
 pub fn enqueue_upload_task(
     parent_id: Option<String>,
     file_name: String,
     local_path: String,
-    content: Vec<u8>,
     overwrite: bool,
+    priority: Option<TransferPriority>,
+    conflict_behavior: Option<UploadConflictBehavior>,
 ) -> Result<UploadQueueState, String> {
-    UploadManager::shared().enqueue_small_file(parent_id, file_name, local_path, content, overwrite)
+    UploadManager::shared().enqueue_file(
+        parent_id,
+        file_name,
+        local_path,
+        requested_conflict_behavior(overwrite, conflict_behavior),
+        priority,
+    )
 }
 
+/// 兼容旧调用：与 `enqueue_upload_task` 相同，由上传队列按文件大小选择上传方式。
 #[frb]
 pub fn enqueue_large_upload_task(
     parent_id: Option<String>,
     file_name: String,
     local_path: String,
     overwrite: bool,
+    priority: Option<TransferPriority>,
+    conflict_behavior: Option<UploadConflictBehavior>,
 ) -> Result<UploadQueueState, String> {
-    UploadManager::shared().enqueue_large_file(parent_id, file_name, local_path, overwrite)
+    UploadManager::shared().enqueue_file(
+        parent_id,
+        file_name,
+        local_path,
+        requested_conflict_behavior(overwrite, conflict_behavior),
+        priority,
+    )
+}
+
+/// 递归上传本地文件夹：在 OneDrive 上重建目录结构（已存在的文件夹会复用），
+/// 每个文件作为任务组的子任务排队上传。include/exclude 为 glob 规则，
+/// 不含 `/` 时匹配文件或目录名（如 `.git`、`target`、`*.tmp`），含 `/` 时匹配相对路径。
+#[frb]
+pub fn enqueue_upload_folder(
+    parent_id: Option<String>,
+    local_dir: String,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    conflict_behavior: Option<UploadConflictBehavior>,
+    priority: Option<TransferPriority>,
+) -> Result<UploadQueueState, String> {
+    UploadManager::shared().enqueue_folder(
+        parent_id,
+        local_dir,
+        include,
+        exclude,
+        conflict_behavior,
+        priority,
+    )
+}
+
+/// 从网络地址上传文件：个人版 OneDrive 由服务端直接拉取，其他账户由本端边下载边上传，
+/// 不在本地暂存；进度与本地上传一样在上传队列中展示。`file_name` 为空时取地址中的文件名。
+#[frb]
+pub fn enqueue_upload_from_url(
+    parent_id: Option<String>,
+    source_url: String,
+    file_name: Option<String>,
+    conflict_behavior: Option<UploadConflictBehavior>,
+    priority: Option<TransferPriority>,
+) -> Result<UploadQueueState, String> {
+    UploadManager::shared().enqueue_url(
+        parent_id,
+        source_url,
+        file_name,
+        conflict_behavior,
+        priority,
+    )
+}
+
+/// 取消文件夹上传任务组，未结束的子任务一并取消。
+#[frb]
+pub fn cancel_upload_group(group_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().cancel_group(&group_id)
+}
+
+/// 移除文件夹上传任务组及其全部子任务记录。
+#[frb]
+pub fn remove_upload_group(group_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().remove_group(&group_id)
 }
 
 #[frb]
@@ -39,11 +114,51 @@ pub fn cancel_upload_task(task_id: String) -> Result<UploadQueueState, String> {
     UploadManager::shared().cancel(&task_id)
 }
 
+/// 将等待中的上传任务前移一位。
+#[frb]
+pub fn move_upload_task_up(task_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().move_up(&task_id)
+}
+
+/// 将等待中的上传任务后移一位。
+#[frb]
+pub fn move_upload_task_down(task_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().move_down(&task_id)
+}
+
+/// 将等待中的上传任务移到队首，下一个空闲名额即开始上传。
+#[frb]
+pub fn move_upload_task_to_top(task_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().move_to_top(&task_id)
+}
+
+/// 修改未结束上传任务的优先级，等待中的任务会按新优先级重新排队。
+#[frb]
+pub fn set_upload_task_priority(
+    task_id: String,
+    priority: TransferPriority,
+) -> Result<UploadQueueState, String> {
+    UploadManager::shared().set_priority(&task_id, priority)
+}
+
 #[frb]
 pub fn clear_failed_upload_tasks() -> Result<UploadQueueState, String> {
     UploadManager::shared().clear_failed_tasks()
 }
 
+/// 按原参数重新上传全部失败或已取消的任务，本地文件已不存在等无法重试的任务保留在失败列表。
+#[frb]
+pub fn retry_failed_upload_tasks() -> Result<UploadQueueState, String> {
+    UploadManager::shared().retry_failed_tasks()
+}
+
+/// 按原参数重新上传单个失败或已取消的任务：目标目录、文件名、冲突处理方式与优先级均沿用入队时的设置，
+/// 上传会话仍有效时从断点续传，否则新建会话。本地文件已不存在或同名文件已在队列中时返回错误。
+#[frb]
+pub fn retry_upload_task(task_id: String) -> Result<UploadQueueState, String> {
+    UploadManager::shared().retry_task(&task_id)
+}
+
 #[frb]
 pub fn clear_upload_history() -> Result<UploadQueueState, String> {
     UploadManager::shared().clear_history()
@@ -60,3 +175,24 @@ pub fn upload_progress_stream(stream_sink: StreamSink<UploadProgressUpdate>) {
         }
     });
 }
+
+/// 推送上传任务的生命周期事件，UI 无需轮询队列快照即可感知状态变化。
+#[frb]
+pub fn upload_task_event_stream(stream_sink: StreamSink<UploadTaskEvent>) {
+    let rx = UploadManager::shared().subscribe_events();
+    std::thread::spawn(move || {
+        for event in rx {
+            if stream_sink.add(event).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// 兼容旧参数：overwrite=true 等同于覆盖，否则交由设置中的默认值决定。
+fn requested_conflict_behavior(
+    overwrite: bool,
+    conflict_behavior: Option<UploadConflictBehavior>,
+) -> Option<UploadConflictBehavior> {
+    conflict_behavior.or(overwrite.then_some(UploadConflictBehavior::Replace))
+}
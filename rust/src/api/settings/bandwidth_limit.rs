@@ -0,0 +1,20 @@
+use crate::api::drive::models::BandwidthSettings;
+use crate::bandwidth::BandwidthLimiter;
+use crate::settings::bandwidth_limit::{
+    get_bandwidth_settings as core_get_bandwidth_settings,
+    set_bandwidth_settings as core_set_bandwidth_settings,
+};
+
+/// FRB 对外接口：获取带宽限速设置。
+#[flutter_rust_bridge::frb]
+pub fn get_bandwidth_settings() -> Result<BandwidthSettings, String> {
+    core_get_bandwidth_settings()
+}
+
+/// FRB 对外接口：更新带宽限速设置，进行中的传输立即按新限速执行。
+#[flutter_rust_bridge::frb]
+pub fn set_bandwidth_settings(settings: BandwidthSettings) -> Result<BandwidthSettings, String> {
+    let updated = core_set_bandwidth_settings(settings)?;
+    BandwidthLimiter::shared().apply_settings(updated.clone());
+    Ok(updated)
+}
@@ -0,0 +1,16 @@
+use crate::settings::disk_space_reserve::{
+    get_disk_space_reserve_mib as core_get_disk_space_reserve_mib,
+    set_disk_space_reserve_mib as core_set_disk_space_reserve_mib,
+};
+
+/// FRB 对外接口：获取下载前需保留的本地磁盘空闲空间（MiB）。
+#[flutter_rust_bridge::frb]
+pub fn get_disk_space_reserve_mib() -> Result<u64, String> {
+    core_get_disk_space_reserve_mib()
+}
+
+/// FRB 对外接口：更新保留空间，下一个开始的下载任务即按新值检查。
+#[flutter_rust_bridge::frb]
+pub fn set_disk_space_reserve_mib(reserve_mib: u64) -> Result<u64, String> {
+    core_set_disk_space_reserve_mib(reserve_mib)
+}
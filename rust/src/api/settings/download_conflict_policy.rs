@@ -0,0 +1,19 @@
+use crate::api::drive::models::DownloadConflictPolicy;
+use crate::settings::download_conflict_policy::{
+    get_download_conflict_policy as core_get_download_conflict_policy,
+    set_download_conflict_policy as core_set_download_conflict_policy,
+};
+
+/// FRB 对外接口：获取默认的下载冲突策略。
+#[flutter_rust_bridge::frb]
+pub fn get_download_conflict_policy() -> Result<DownloadConflictPolicy, String> {
+    core_get_download_conflict_policy()
+}
+
+/// FRB 对外接口：更新默认的下载冲突策略，仅影响之后入队的任务。
+#[flutter_rust_bridge::frb]
+pub fn set_download_conflict_policy(
+    policy: DownloadConflictPolicy,
+) -> Result<DownloadConflictPolicy, String> {
+    core_set_download_conflict_policy(policy)
+}
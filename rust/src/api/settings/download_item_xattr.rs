@@ -0,0 +1,16 @@
+use crate::settings::download_item_xattr::{
+    get_download_item_xattr_enabled as core_get_download_item_xattr_enabled,
+    set_download_item_xattr_enabled as core_set_download_item_xattr_enabled,
+};
+
+/// FRB 对外接口：获取是否把 OneDrive item id 与 eTag 写入下载文件的扩展属性。
+#[flutter_rust_bridge::frb]
+pub fn get_download_item_xattr_enabled() -> Result<bool, String> {
+    core_get_download_item_xattr_enabled()
+}
+
+/// FRB 对外接口：开启或关闭扩展属性写入，对之后完成的下载生效。
+#[flutter_rust_bridge::frb]
+pub fn set_download_item_xattr_enabled(enabled: bool) -> Result<bool, String> {
+    core_set_download_item_xattr_enabled(enabled)
+}
@@ -0,0 +1,19 @@
+use crate::settings::global_transfer_limit::{
+    get_global_transfer_limit as core_get_global_transfer_limit,
+    set_global_transfer_limit as core_set_global_transfer_limit,
+};
+use crate::transfer_queue::GlobalTransferLimit;
+
+/// FRB 对外接口：获取上传与下载共享的总并发上限，`None` 表示不限制。
+#[flutter_rust_bridge::frb]
+pub fn get_global_transfer_limit() -> Result<Option<u32>, String> {
+    core_get_global_transfer_limit().map(|value| value.map(|limit| limit as u32))
+}
+
+/// FRB 对外接口：更新总并发上限并立即生效；调低时已在运行的任务不受影响。
+#[flutter_rust_bridge::frb]
+pub fn set_global_transfer_limit(limit: Option<u32>) -> Result<Option<u32>, String> {
+    let updated = core_set_global_transfer_limit(limit.map(|value| value as usize))?;
+    GlobalTransferLimit::shared().set_max(updated);
+    Ok(updated.map(|value| value as u32))
+}
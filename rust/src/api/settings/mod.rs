@@ -1,2 +1,13 @@
+pub mod bandwidth_limit;
+pub mod disk_space_reserve;
 pub mod download_concurrency;
+pub mod download_conflict_policy;
 pub mod download_directory;
+pub mod download_item_xattr;
+pub mod global_transfer_limit;
+pub mod notifications;
+pub mod post_download_hooks;
+pub mod retry_policy;
+pub mod upload_concurrency;
+pub mod upload_conflict_behavior;
+pub mod upload_integrity_reupload;
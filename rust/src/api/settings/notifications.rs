@@ -0,0 +1,22 @@
+use crate::api::drive::models::NotificationSettings;
+use crate::notifications::NotificationCenter;
+use crate::settings::notifications::{
+    get_notification_settings as core_get_notification_settings,
+    set_notification_settings as core_set_notification_settings,
+};
+
+/// FRB 对外接口：获取传输结束时的桌面通知设置。
+#[flutter_rust_bridge::frb]
+pub fn get_notification_settings() -> Result<NotificationSettings, String> {
+    core_get_notification_settings()
+}
+
+/// FRB 对外接口：更新桌面通知设置，对之后结束的传输生效。
+#[flutter_rust_bridge::frb]
+pub fn set_notification_settings(
+    settings: NotificationSettings,
+) -> Result<NotificationSettings, String> {
+    let updated = core_set_notification_settings(settings)?;
+    NotificationCenter::shared().apply_settings(updated.clone());
+    Ok(updated)
+}
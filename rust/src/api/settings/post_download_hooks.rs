@@ -0,0 +1,19 @@
+use crate::api::drive::models::PostDownloadHook;
+use crate::settings::post_download_hooks::{
+    get_post_download_hooks as core_get_post_download_hooks,
+    set_post_download_hooks as core_set_post_download_hooks,
+};
+
+/// FRB 对外接口：获取下载完成后自动执行的动作列表。
+#[flutter_rust_bridge::frb]
+pub fn get_post_download_hooks() -> Result<Vec<PostDownloadHook>, String> {
+    core_get_post_download_hooks()
+}
+
+/// FRB 对外接口：更新下载后动作列表，对之后完成的下载生效。
+#[flutter_rust_bridge::frb]
+pub fn set_post_download_hooks(
+    hooks: Vec<PostDownloadHook>,
+) -> Result<Vec<PostDownloadHook>, String> {
+    core_set_post_download_hooks(hooks)
+}
@@ -0,0 +1,19 @@
+use crate::api::drive::models::TransferRetryPolicy;
+use crate::settings::retry_policy::{
+    get_transfer_retry_policy as core_get_transfer_retry_policy,
+    set_transfer_retry_policy as core_set_transfer_retry_policy,
+};
+
+/// FRB 对外接口：获取传输失败后的自动重试策略。
+#[flutter_rust_bridge::frb]
+pub fn get_transfer_retry_policy() -> Result<TransferRetryPolicy, String> {
+    core_get_transfer_retry_policy()
+}
+
+/// FRB 对外接口：更新自动重试策略，下一次失败时即按新策略处理。
+#[flutter_rust_bridge::frb]
+pub fn set_transfer_retry_policy(
+    policy: TransferRetryPolicy,
+) -> Result<TransferRetryPolicy, String> {
+    core_set_transfer_retry_policy(policy)
+}
@@ -0,0 +1,19 @@
+use crate::settings::upload_concurrency::{
+    get_upload_concurrency as core_get_upload_concurrency,
+    set_upload_concurrency as core_set_upload_concurrency,
+};
+use crate::upload_manager::UploadManager;
+
+/// FRB 对外接口：获取当前并行上传数设置。
+#[flutter_rust_bridge::frb]
+pub fn get_upload_concurrency() -> Result<u32, String> {
+    core_get_upload_concurrency().map(|value| value as u32)
+}
+
+/// FRB 对外接口：更新并行上传数，并立即通知上传管理器生效。
+#[flutter_rust_bridge::frb]
+pub fn set_upload_concurrency(limit: u32) -> Result<u32, String> {
+    let updated = core_set_upload_concurrency(limit as usize)?;
+    UploadManager::shared().update_concurrency_limit(updated);
+    Ok(updated as u32)
+}
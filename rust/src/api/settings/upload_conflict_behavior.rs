@@ -0,0 +1,19 @@
+use crate::api::drive::models::UploadConflictBehavior;
+use crate::settings::upload_conflict_behavior::{
+    get_upload_conflict_behavior as core_get_upload_conflict_behavior,
+    set_upload_conflict_behavior as core_set_upload_conflict_behavior,
+};
+
+/// FRB 对外接口：获取默认的上传冲突处理方式。
+#[flutter_rust_bridge::frb]
+pub fn get_upload_conflict_behavior() -> Result<UploadConflictBehavior, String> {
+    core_get_upload_conflict_behavior()
+}
+
+/// FRB 对外接口：更新默认的上传冲突处理方式，仅影响之后入队的任务。
+#[flutter_rust_bridge::frb]
+pub fn set_upload_conflict_behavior(
+    behavior: UploadConflictBehavior,
+) -> Result<UploadConflictBehavior, String> {
+    core_set_upload_conflict_behavior(behavior)
+}
@@ -0,0 +1,16 @@
+use crate::settings::upload_integrity_reupload::{
+    get_upload_integrity_reupload_enabled as core_get_upload_integrity_reupload_enabled,
+    set_upload_integrity_reupload_enabled as core_set_upload_integrity_reupload_enabled,
+};
+
+/// FRB 对外接口：获取上传完整性校验失败时是否自动重新上传。
+#[flutter_rust_bridge::frb]
+pub fn get_upload_integrity_reupload_enabled() -> Result<bool, String> {
+    core_get_upload_integrity_reupload_enabled()
+}
+
+/// FRB 对外接口：开启或关闭校验失败后的自动重传，关闭时任务直接标记为校验失败。
+#[flutter_rust_bridge::frb]
+pub fn set_upload_integrity_reupload_enabled(enabled: bool) -> Result<bool, String> {
+    core_set_upload_integrity_reupload_enabled(enabled)
+}
@@ -0,0 +1,214 @@
+use super::token_bucket::TokenBucket;
+use crate::api::drive::models::{BandwidthSchedule, BandwidthSettings};
+use crate::lock::recover_lock;
+use crate::settings::bandwidth_limit::{default_bandwidth_settings, get_bandwidth_settings};
+use crate::time_window::{local_minute, minute_window_contains};
+use once_cell::sync::Lazy;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 全局限速器实例：上传、下载共用，设置变更后对进行中的传输立即生效。
+static BANDWIDTH_LIMITER: Lazy<BandwidthLimiter> = Lazy::new(BandwidthLimiter::new);
+/// 单次休眠的上限，保证取消与限速调整能及时被感知。
+const MAX_WAIT_SLICE: Duration = Duration::from_millis(100);
+
+/// 传输方向，决定除总限速外还要经过哪个方向的令牌桶。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferDirection {
+    Download,
+    Upload,
+}
+
+pub struct BandwidthLimiter {
+    state: Mutex<LimiterState>,
+}
+
+struct LimiterState {
+    settings: BandwidthSettings,
+    global: TokenBucket,
+    download: TokenBucket,
+    upload: TokenBucket,
+}
+
+/// 当前时刻实际生效的限速（字节/秒）。
+struct EffectiveLimits {
+    global: u64,
+    download: u64,
+    upload: u64,
+}
+
+impl BandwidthLimiter {
+    fn new() -> Self {
+        let settings = get_bandwidth_settings().unwrap_or_else(|err| {
+            eprintln!(
+                "[bandwidth] failed to load bandwidth settings, fallback to unlimited: {err}"
+            );
+            default_bandwidth_settings()
+        });
+        Self {
+            state: Mutex::new(LimiterState {
+                settings,
+                global: TokenBucket::new(0),
+                download: TokenBucket::new(0),
+                upload: TokenBucket::new(0),
+            }),
+        }
+    }
+
+    pub fn shared() -> &'static Self {
+        &BANDWIDTH_LIMITER
+    }
+
+    /// 替换限速设置；令牌桶速率在下一次取令牌时按当前时段刷新。
+    pub fn apply_settings(&self, settings: BandwidthSettings) {
+        recover_lock(&self.state).settings = settings;
+    }
+
+    /// 为已传输的 `bytes` 字节取令牌，必要时阻塞等待；取消标记置位时立即返回。
+    pub fn throttle(
+        &self,
+        direction: TransferDirection,
+        bytes: usize,
+        cancel_flag: Option<&AtomicBool>,
+    ) {
+        if bytes == 0 {
+            return;
+        }
+        loop {
+            if cancel_flag
+                .map(|flag| flag.load(Ordering::Relaxed))
+                .unwrap_or(false)
+            {
+                return;
+            }
+            let wait = {
+                let mut state = recover_lock(&self.state);
+                let limits = effective_limits(&state.settings, local_minute());
+                let now = Instant::now();
+                state.global.set_rate(limits.global);
+                state.download.set_rate(limits.download);
+                state.upload.set_rate(limits.upload);
+                let LimiterState {
+                    global,
+                    download,
+                    upload,
+                    ..
+                } = &mut *state;
+                let directional = match direction {
+                    TransferDirection::Download => download,
+                    TransferDirection::Upload => upload,
+                };
+                global.refill(now);
+                directional.refill(now);
+                let wait = global.wait_time().max(directional.wait_time());
+                if wait.is_zero() {
+                    global.consume(bytes as u64);
+                    directional.consume(bytes as u64);
+                    return;
+                }
+                wait
+            };
+            thread::sleep(wait.min(MAX_WAIT_SLICE));
+        }
+    }
+}
+
+/// 便捷入口：通过全局限速器为一次读写取令牌。
+pub fn throttle(direction: TransferDirection, bytes: usize, cancel_flag: Option<&AtomicBool>) {
+    BandwidthLimiter::shared().throttle(direction, bytes, cancel_flag);
+}
+
+/// 命中的第一条时段规则覆盖全部限速，未命中时使用基础设置。
+fn effective_limits(settings: &BandwidthSettings, minute: u32) -> EffectiveLimits {
+    let (global, download, upload) = settings
+        .schedules
+        .iter()
+        .find(|schedule| schedule_contains(schedule, minute))
+        .map(|s| {
+            (
+                s.global_kib_per_sec,
+                s.download_kib_per_sec,
+                s.upload_kib_per_sec,
+            )
+        })
+        .unwrap_or((
+            settings.global_kib_per_sec,
+            settings.download_kib_per_sec,
+            settings.upload_kib_per_sec,
+        ));
+    EffectiveLimits {
+        global: global.saturating_mul(1024),
+        download: download.saturating_mul(1024),
+        upload: upload.saturating_mul(1024),
+    }
+}
+
+fn schedule_contains(schedule: &BandwidthSchedule, minute: u32) -> bool {
+    minute_window_contains(schedule.start_minute, schedule.end_minute, minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(start_minute: u32, end_minute: u32, global: u64) -> BandwidthSchedule {
+        BandwidthSchedule {
+            start_minute,
+            end_minute,
+            global_kib_per_sec: global,
+            download_kib_per_sec: 0,
+            upload_kib_per_sec: 0,
+        }
+    }
+
+    #[test]
+    fn schedule_contains_same_day_window() {
+        let window = schedule(9 * 60, 18 * 60, 0);
+        assert!(!schedule_contains(&window, 9 * 60 - 1));
+        assert!(schedule_contains(&window, 9 * 60));
+        assert!(schedule_contains(&window, 18 * 60 - 1));
+        assert!(!schedule_contains(&window, 18 * 60));
+    }
+
+    #[test]
+    fn schedule_contains_window_across_midnight() {
+        let window = schedule(23 * 60, 7 * 60, 0);
+        assert!(schedule_contains(&window, 23 * 60));
+        assert!(schedule_contains(&window, 0));
+        assert!(schedule_contains(&window, 7 * 60 - 1));
+        assert!(!schedule_contains(&window, 7 * 60));
+        assert!(!schedule_contains(&window, 12 * 60));
+    }
+
+    #[test]
+    fn schedule_with_equal_bounds_covers_whole_day() {
+        let window = schedule(300, 300, 0);
+        assert!(schedule_contains(&window, 0));
+        assert!(schedule_contains(&window, 1439));
+    }
+
+    #[test]
+    fn first_matching_schedule_overrides_base_limits() {
+        let settings = BandwidthSettings {
+            global_kib_per_sec: 100,
+            download_kib_per_sec: 50,
+            upload_kib_per_sec: 20,
+            schedules: vec![schedule(0, 60, 1), schedule(0, 120, 2)],
+        };
+        let limits = effective_limits(&settings, 30);
+        assert_eq!(limits.global, 1024);
+        assert_eq!(limits.download, 0);
+        assert_eq!(effective_limits(&settings, 90).global, 2 * 1024);
+
+        let base = effective_limits(&settings, 600);
+        assert_eq!(base.global, 100 * 1024);
+        assert_eq!(base.download, 50 * 1024);
+        assert_eq!(base.upload, 20 * 1024);
+    }
+}
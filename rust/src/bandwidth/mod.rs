@@ -0,0 +1,5 @@
+pub mod limiter;
+pub mod token_bucket;
+
+pub use limiter::{throttle, BandwidthLimiter, TransferDirection};
+pub use token_bucket::TokenBucket;
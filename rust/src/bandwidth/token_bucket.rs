@@ -0,0 +1,105 @@
+use std::time::{Duration, Instant};
+
+/// 令牌桶：以固定速率补充令牌，容量为一秒的配额。
+/// 允许单次消费超过当前余额（记为欠账），后续请求需等欠账还清，
+/// 这样无论读缓冲多大，长期平均速率都不会超过限速。
+pub struct TokenBucket {
+    /// 每秒补充的字节数，0 表示不限速。
+    rate: u64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate,
+            tokens: rate as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// 调整速率；已有余额按新容量截断，欠账保留。
+    pub fn set_rate(&mut self, rate: u64) {
+        if self.rate == rate {
+            return;
+        }
+        self.refill(Instant::now());
+        self.rate = rate;
+        self.tokens = if rate == 0 {
+            0.0
+        } else {
+            self.tokens.min(rate as f64)
+        };
+    }
+
+    pub fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.last_refill = now;
+        if self.rate == 0 {
+            return;
+        }
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.rate as f64).min(self.rate as f64);
+    }
+
+    /// 距离可以再次消费还需等待的时间；不限速或余额为正时为零。
+    pub fn wait_time(&self) -> Duration {
+        if self.rate == 0 || self.tokens > 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64((1.0 - self.tokens) / self.rate as f64)
+    }
+
+    pub fn consume(&mut self, bytes: u64) {
+        if self.rate > 0 {
+            self.tokens -= bytes as f64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited_bucket_never_waits() {
+        let mut bucket = TokenBucket::new(0);
+        bucket.consume(10 * 1024 * 1024);
+        assert_eq!(bucket.wait_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn debt_must_be_repaid_before_next_consume() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1000);
+        bucket.last_refill = start;
+        bucket.consume(1500);
+        // 余额 -500，需补充到 1 个令牌以上
+        assert_eq!(bucket.wait_time(), Duration::from_millis(501));
+
+        bucket.refill(start + Duration::from_millis(400));
+        assert!(bucket.wait_time() > Duration::ZERO);
+        bucket.refill(start + Duration::from_millis(600));
+        assert_eq!(bucket.wait_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn refill_is_capped_at_one_second_of_rate() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1000);
+        bucket.last_refill = start;
+        bucket.consume(1000);
+        bucket.refill(start + Duration::from_secs(10));
+        assert_eq!(bucket.tokens, 1000.0);
+    }
+
+    #[test]
+    fn lowering_rate_truncates_balance() {
+        let mut bucket = TokenBucket::new(1000);
+        bucket.set_rate(100);
+        assert!(bucket.tokens <= 100.0);
+        bucket.set_rate(0);
+        assert_eq!(bucket.tokens, 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+use super::quick_xor::QuickXorHasher;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use sha1::{Digest, Sha1};
+
+/// 文件内容哈希，格式与 Graph `file.hashes` 一致；本地计算时两者都有，
+/// Graph 返回的可能只有其一（SHA-1 仅 OneDrive 个人版提供）。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentHashes {
+    pub quick_xor_hash: Option<String>,
+    pub sha1_hash: Option<String>,
+}
+
+impl ContentHashes {
+    /// 按双方都有的哈希比较，优先 quickXorHash；没有可比较的哈希时返回 `None`。
+    pub fn matches(&self, other: &ContentHashes) -> Option<bool> {
+        if let (Some(a), Some(b)) = (&self.quick_xor_hash, &other.quick_xor_hash) {
+            return Some(a == b);
+        }
+        if let (Some(a), Some(b)) = (&self.sha1_hash, &other.sha1_hash) {
+            return Some(a.eq_ignore_ascii_case(b));
+        }
+        None
+    }
+}
+
+/// 同时增量计算 quickXorHash 与 SHA-1，可在读取上传分片时顺带喂入数据。
+#[derive(Default)]
+pub struct ContentDigest {
+    quick_xor: QuickXorHasher,
+    sha1: Sha1,
+}
+
+impl ContentDigest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.quick_xor.update(bytes);
+        self.sha1.update(bytes);
+    }
+
+    pub fn finalize(self) -> ContentHashes {
+        ContentHashes {
+            quick_xor_hash: Some(encode_quick_xor(self.quick_xor)),
+            sha1_hash: Some(encode_sha1(self.sha1)),
+        }
+    }
+}
+
+pub(super) fn encode_quick_xor(hasher: QuickXorHasher) -> String {
+    STANDARD.encode(hasher.finalize())
+}
+
+pub(super) fn encode_sha1(hasher: Sha1) -> String {
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(quick_xor: Option<&str>, sha1: Option<&str>) -> ContentHashes {
+        ContentHashes {
+            quick_xor_hash: quick_xor.map(str::to_string),
+            sha1_hash: sha1.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn digest_matches_graph_formats() {
+        let mut digest = ContentDigest::new();
+        digest.update(b"a");
+        digest.update(b"bc");
+        assert_eq!(
+            digest.finalize(),
+            hashes(
+                Some("YRDDGAAAAAAAAAAAAwAAAAAAAAA="),
+                Some("A9993E364706816ABA3E25717850C26C9CD0D89D"),
+            )
+        );
+    }
+
+    #[test]
+    fn matches_prefers_quick_xor() {
+        let local = hashes(Some("AAA="), Some("ABCDEF"));
+        assert_eq!(
+            local.matches(&hashes(Some("AAA="), Some("000000"))),
+            Some(true)
+        );
+        assert_eq!(
+            local.matches(&hashes(Some("BBB="), Some("ABCDEF"))),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn matches_falls_back_to_sha1_ignoring_case() {
+        let local = hashes(Some("AAA="), Some("ABCDEF"));
+        assert_eq!(local.matches(&hashes(None, Some("abcdef"))), Some(true));
+        assert_eq!(local.matches(&hashes(None, Some("123456"))), Some(false));
+        assert_eq!(local.matches(&hashes(None, None)), None);
+    }
+}
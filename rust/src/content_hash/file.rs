@@ -0,0 +1,97 @@
+use super::digest::{encode_quick_xor, encode_sha1, ContentDigest, ContentHashes};
+use super::quick_xor::QuickXorHasher;
+use sha1::{Digest, Sha1};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const READ_BUFFER_BYTES: usize = 1024 * 1024;
+
+/// 与 Graph `file.hashes` 对应的哈希算法。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// `quickXorHash`，base64 编码。
+    QuickXor,
+    /// `sha1Hash`，大写十六进制，仅 OneDrive 个人版提供。
+    Sha1,
+}
+
+/// 流式计算本地文件哈希，输出格式与 Graph 返回值一致，便于直接比较。
+/// 大文件计算耗时较长，取消标记置位时返回错误。
+pub fn hash_local_file(
+    path: &Path,
+    algorithm: HashAlgorithm,
+    cancel_flag: Option<&AtomicBool>,
+) -> Result<String, String> {
+    let file = File::open(path).map_err(|e| format!("failed to open file for hashing: {e}"))?;
+    let mut reader = BufReader::new(file);
+    let mut buffer = vec![0u8; READ_BUFFER_BYTES];
+    let mut quick_xor = QuickXorHasher::new();
+    let mut sha1 = Sha1::new();
+    loop {
+        if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return Err("hashing cancelled".to_string());
+        }
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("failed to read file for hashing: {e}"))?;
+        if read == 0 {
+            break;
+        }
+        match algorithm {
+            HashAlgorithm::QuickXor => quick_xor.update(&buffer[..read]),
+            HashAlgorithm::Sha1 => sha1.update(&buffer[..read]),
+        }
+    }
+    Ok(match algorithm {
+        HashAlgorithm::QuickXor => encode_quick_xor(quick_xor),
+        HashAlgorithm::Sha1 => encode_sha1(sha1),
+    })
+}
+
+/// 一次读取同时计算 quickXorHash 与 SHA-1，用于上传完成后的完整性校验。
+pub fn digest_local_file(
+    path: &Path,
+    cancel_flag: Option<&AtomicBool>,
+) -> Result<ContentHashes, String> {
+    let file = File::open(path).map_err(|e| format!("failed to open file for hashing: {e}"))?;
+    let mut reader = BufReader::new(file);
+    let mut buffer = vec![0u8; READ_BUFFER_BYTES];
+    let mut digest = ContentDigest::new();
+    loop {
+        if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return Err("hashing cancelled".to_string());
+        }
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("failed to read file for hashing: {e}"))?;
+        if read == 0 {
+            break;
+        }
+        digest.update(&buffer[..read]);
+    }
+    Ok(digest.finalize())
+}
+
+/// 只读取文件开头与结尾各 `sample_bytes` 字节计算 quickXorHash，用于快速判断文件是否被改写；
+/// 不能替代完整哈希，中间部分的修改只能靠大小与修改时间发现。
+pub fn sample_local_file(path: &Path, sample_bytes: u64) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|e| format!("failed to open file for hashing: {e}"))?;
+    let size = file
+        .metadata()
+        .map_err(|e| format!("failed to read file size: {e}"))?
+        .len();
+    let mut hasher = QuickXorHasher::new();
+    let head_len = size.min(sample_bytes);
+    let tail_start = size.saturating_sub(sample_bytes).max(head_len);
+    for (start, len) in [(0, head_len), (tail_start, size - tail_start)] {
+        let mut buffer = vec![0u8; len as usize];
+        file.seek(SeekFrom::Start(start))
+            .map_err(|e| format!("failed to seek file for hashing: {e}"))?;
+        file.read_exact(&mut buffer)
+            .map_err(|e| format!("failed to read file for hashing: {e}"))?;
+        hasher.update(&buffer);
+    }
+    Ok(encode_quick_xor(hasher))
+}
@@ -0,0 +1,7 @@
+pub mod digest;
+pub mod file;
+pub mod quick_xor;
+
+pub use digest::{ContentDigest, ContentHashes};
+pub use file::{digest_local_file, hash_local_file, sample_local_file, HashAlgorithm};
+pub use quick_xor::QuickXorHasher;
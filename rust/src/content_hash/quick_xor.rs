@@ -0,0 +1,121 @@
+// OneDrive quickXorHash：160 位滚动异或，末尾混入数据长度，结果以 base64 表示。
+// 算法与微软公开的参考实现一致，OneDrive 个人版与商业版都会返回该哈希。
+
+const WIDTH_IN_BITS: usize = 160;
+const SHIFT: usize = 11;
+const BITS_IN_LAST_CELL: usize = 32;
+const CELL_COUNT: usize = (WIDTH_IN_BITS - 1) / 64 + 1;
+
+/// 增量计算 quickXorHash，可分块喂入数据。
+pub struct QuickXorHasher {
+    data: [u64; CELL_COUNT],
+    shift_so_far: usize,
+    length_so_far: u64,
+}
+
+impl QuickXorHasher {
+    pub fn new() -> Self {
+        Self {
+            data: [0; CELL_COUNT],
+            shift_so_far: 0,
+            length_so_far: 0,
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut vector_index = self.shift_so_far / 64;
+        let mut vector_offset = self.shift_so_far % 64;
+        let iterations = bytes.len().min(WIDTH_IN_BITS);
+        for i in 0..iterations {
+            let is_last_cell = vector_index == CELL_COUNT - 1;
+            let bits_in_cell = if is_last_cell { BITS_IN_LAST_CELL } else { 64 };
+            if vector_offset <= bits_in_cell - 8 {
+                for byte in bytes[i..].iter().step_by(WIDTH_IN_BITS) {
+                    self.data[vector_index] ^= u64::from(*byte) << vector_offset;
+                }
+            } else {
+                let next_index = if is_last_cell { 0 } else { vector_index + 1 };
+                let low = bits_in_cell - vector_offset;
+                let xored = bytes[i..]
+                    .iter()
+                    .step_by(WIDTH_IN_BITS)
+                    .fold(0u8, |acc, byte| acc ^ byte);
+                self.data[vector_index] ^= u64::from(xored) << vector_offset;
+                self.data[next_index] ^= u64::from(xored) >> low;
+            }
+            vector_offset += SHIFT;
+            while vector_offset >= bits_in_cell {
+                vector_index = if is_last_cell { 0 } else { vector_index + 1 };
+                vector_offset -= bits_in_cell;
+            }
+        }
+        self.shift_so_far =
+            (self.shift_so_far + SHIFT * (bytes.len() % WIDTH_IN_BITS)) % WIDTH_IN_BITS;
+        self.length_so_far += bytes.len() as u64;
+    }
+
+    /// 输出 20 字节摘要：各单元按小端拼接，最后 8 字节与数据总长度异或。
+    pub fn finalize(&self) -> [u8; WIDTH_IN_BITS / 8] {
+        let mut digest = [0u8; WIDTH_IN_BITS / 8];
+        for (index, cell) in self.data.iter().enumerate() {
+            let start = index * 8;
+            let end = (start + 8).min(digest.len());
+            digest[start..end].copy_from_slice(&cell.to_le_bytes()[..end - start]);
+        }
+        let length_offset = WIDTH_IN_BITS / 8 - 8;
+        for (index, byte) in self.length_so_far.to_le_bytes().iter().enumerate() {
+            digest[length_offset + index] ^= byte;
+        }
+        digest
+    }
+}
+
+impl Default for QuickXorHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    fn quick_xor_base64(bytes: &[u8]) -> String {
+        let mut hasher = QuickXorHasher::new();
+        hasher.update(bytes);
+        STANDARD.encode(hasher.finalize())
+    }
+
+    /// 跨越多个 160 字节周期、覆盖末尾单元跨界写入的样本。
+    fn sample(len: usize) -> Vec<u8> {
+        (0..len).map(|i| ((i * 31 + 7) % 256) as u8).collect()
+    }
+
+    #[test]
+    fn matches_reference_values() {
+        assert_eq!(quick_xor_base64(b""), "AAAAAAAAAAAAAAAAAAAAAAAAAAA=");
+        assert_eq!(quick_xor_base64(b"abc"), "YRDDGAAAAAAAAAAAAwAAAAAAAAA=");
+        assert_eq!(
+            quick_xor_base64(b"The quick brown fox jumps over the lazy dog"),
+            "bMSlbysmxJL6S75XwfMcQZOpcr4="
+        );
+        assert_eq!(
+            quick_xor_base64(&sample(1000)),
+            "X4X7cC7/cVgPZMrjju+fOUsa7aY="
+        );
+    }
+
+    #[test]
+    fn chunked_updates_match_single_update() {
+        let data = sample(5000);
+        let expected = quick_xor_base64(&data);
+        for chunk_size in [1, 7, 160, 161, 4096] {
+            let mut hasher = QuickXorHasher::new();
+            for chunk in data.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(STANDARD.encode(hasher.finalize()), expected, "{chunk_size}");
+        }
+    }
+}
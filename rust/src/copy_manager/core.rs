@@ -0,0 +1,334 @@
+// 复制任务管理：发起 Graph 异步复制，轮询 monitor 地址并广播进度，重启后继续跟踪未结束的任务。
+use super::storage::{CopyJobStore, SqliteCopyJobStore};
+use crate::api::drive::{
+    async_monitor::wait_for_async_job,
+    copy_item::start_copy,
+    details::get_drive_item_details,
+    models::{CopyJob, CopyJobStatus},
+};
+use crate::db::current_timestamp_millis;
+use crate::lock::recover_lock;
+use once_cell::sync::Lazy;
+use std::{
+    sync::{
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+        Arc, Mutex,
+    },
+    thread,
+};
+use uuid::Uuid;
+
+// 复制事件的缓冲大小，防止无界内存增长。
+const EVENT_CHANNEL_CAP: usize = 256;
+const COPY_FAILED_MESSAGE: &str = "复制失败";
+
+static COPY_MANAGER: Lazy<CopyManager> = Lazy::new(CopyManager::new);
+
+/// 复制任务管理器，复制本身由服务端执行，这里只负责跟踪与持久化。
+#[derive(Clone)]
+pub struct CopyManager {
+    jobs: Arc<Mutex<Vec<CopyJob>>>,
+    store: Arc<dyn CopyJobStore>,
+    subscribers: Arc<Mutex<Vec<SyncSender<CopyJob>>>>,
+}
+
+impl CopyManager {
+    /// 构造全局单例，读取持久化记录并继续跟踪未结束的任务。
+    fn new() -> Self {
+        let manager = Self::with_store(Arc::new(SqliteCopyJobStore::default()));
+        manager.restore_from_storage();
+        manager
+    }
+
+    fn with_store(store: Arc<dyn CopyJobStore>) -> Self {
+        Self {
+            jobs: Arc::new(Mutex::new(Vec::new())),
+            store,
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 获取可克隆的全局实例。
+    pub fn shared() -> Self {
+        COPY_MANAGER.clone()
+    }
+
+    /// 重启恢复：monitor 地址在服务端保留一段时间，未结束的任务继续轮询；
+    /// 地址已失效时按失败结束。
+    fn restore_from_storage(&self) {
+        for job in self.load_jobs() {
+            self.spawn_monitor(job.job_id, job.monitor_url);
+        }
+    }
+
+    /// 载入持久化的全部任务，返回仍需继续轮询的进行中任务。
+    fn load_jobs(&self) -> Vec<CopyJob> {
+        let jobs = self.store.load();
+        let pending = jobs
+            .iter()
+            .filter(|job| job.status == CopyJobStatus::InProgress)
+            .cloned()
+            .collect();
+        *recover_lock(&self.jobs) = jobs;
+        pending
+    }
+
+    /// 发起复制并开始跟踪，返回新建的任务；完成后任务的 `new_item_id` 为新项目的 id。
+    pub fn start(
+        &self,
+        item_id: String,
+        target_drive_id: Option<String>,
+        target_parent_id: Option<String>,
+        new_name: Option<String>,
+    ) -> Result<CopyJob, String> {
+        let item_id = item_id.trim().to_string();
+        if item_id.is_empty() {
+            return Err("drive item id is required".to_string());
+        }
+        if target_parent_id
+            .as_ref()
+            .is_some_and(|id| id.trim().is_empty())
+        {
+            return Err("target parent id cannot be empty string".to_string());
+        }
+        if new_name.as_ref().is_some_and(|name| name.trim().is_empty()) {
+            return Err("new name cannot be empty string".to_string());
+        }
+        let target_drive_id = target_drive_id.filter(|id| !id.trim().is_empty());
+        let item_name = match &new_name {
+            Some(name) => name.clone(),
+            None => get_drive_item_details(item_id.clone())?.name,
+        };
+        let monitor_url = start_copy(
+            &item_id,
+            target_drive_id.as_deref(),
+            target_parent_id.as_deref(),
+            new_name.as_deref(),
+        )?;
+        let job = CopyJob {
+            job_id: Uuid::new_v4().to_string(),
+            source_item_id: item_id,
+            item_name,
+            target_drive_id,
+            target_parent_id,
+            new_name,
+            monitor_url,
+            status: CopyJobStatus::InProgress,
+            percentage_complete: None,
+            new_item_id: None,
+            error_message: None,
+            started_at: current_timestamp_millis(),
+            completed_at: None,
+        };
+        recover_lock(&self.jobs).push(job.clone());
+        self.store.upsert(&job);
+        self.emit(&job);
+        self.spawn_monitor(job.job_id.clone(), job.monitor_url.clone());
+        Ok(job)
+    }
+
+    /// 全部复制任务，按发起时间排序。
+    pub fn jobs(&self) -> Vec<CopyJob> {
+        recover_lock(&self.jobs).clone()
+    }
+
+    /// 清除已结束的任务记录，进行中的任务保留。
+    pub fn clear_history(&self) -> Vec<CopyJob> {
+        recover_lock(&self.jobs).retain(|job| job.status == CopyJobStatus::InProgress);
+        self.store.clear_history();
+        self.jobs()
+    }
+
+    /// 订阅任务变化：发起、进度更新与结束时推送任务的最新状态。
+    pub fn subscribe(&self) -> Receiver<CopyJob> {
+        let (tx, rx) = mpsc::sync_channel(EVENT_CHANNEL_CAP);
+        recover_lock(&self.subscribers).push(tx);
+        rx
+    }
+
+    fn spawn_monitor(&self, job_id: String, monitor_url: String) {
+        let manager = self.clone();
+        thread::spawn(move || manager.monitor(&job_id, &monitor_url));
+    }
+
+    /// 轮询直到复制结束；网络抖动与服务端临时错误只延长等待，其余错误按失败结束。
+    fn monitor(&self, job_id: &str, monitor_url: &str) {
+        let result = wait_for_async_job(monitor_url, None, COPY_FAILED_MESSAGE, |percentage| {
+            self.update_progress(job_id, Some(percentage))
+        });
+        self.finish(job_id, result.map_err(String::from));
+    }
+
+    /// 百分比变化时更新并推送。
+    fn update_progress(&self, job_id: &str, percentage_complete: Option<f64>) {
+        let Some(percentage) = percentage_complete.map(|p| p.clamp(0.0, 100.0)) else {
+            return;
+        };
+        let mut jobs = recover_lock(&self.jobs);
+        let Some(job) = jobs.iter_mut().find(|job| job.job_id == job_id) else {
+            return;
+        };
+        if job.percentage_complete == Some(percentage) {
+            return;
+        }
+        job.percentage_complete = Some(percentage);
+        let job = job.clone();
+        drop(jobs);
+        self.store.upsert(&job);
+        self.emit(&job);
+    }
+
+    /// 写入终态：成功时记录新项目 id，失败时记录错误信息。
+    fn finish(&self, job_id: &str, result: Result<Option<String>, String>) {
+        let mut jobs = recover_lock(&self.jobs);
+        let Some(job) = jobs.iter_mut().find(|job| job.job_id == job_id) else {
+            return;
+        };
+        match result {
+            Ok(new_item_id) => {
+                job.status = CopyJobStatus::Completed;
+                job.percentage_complete = Some(100.0);
+                job.new_item_id = new_item_id;
+            }
+            Err(err) => {
+                job.status = CopyJobStatus::Failed;
+                job.error_message = Some(err);
+            }
+        }
+        job.completed_at = Some(current_timestamp_millis());
+        let job = job.clone();
+        drop(jobs);
+        self.store.upsert(&job);
+        self.emit(&job);
+    }
+
+    fn emit(&self, job: &CopyJob) {
+        let mut subs = recover_lock(&self.subscribers);
+        subs.retain_mut(|sender| match sender.try_send(job.clone()) {
+            Ok(_) => true,
+            Err(TrySendError::Full(_)) => {
+                eprintln!("[copy-manager] subscriber is lagging; dropping update");
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 内存实现，记录写入以便断言持久化内容。
+    #[derive(Default)]
+    struct MemoryStore {
+        jobs: Mutex<Vec<CopyJob>>,
+    }
+
+    impl CopyJobStore for MemoryStore {
+        fn load(&self) -> Vec<CopyJob> {
+            recover_lock(&self.jobs).clone()
+        }
+
+        fn upsert(&self, job: &CopyJob) {
+            let mut jobs = recover_lock(&self.jobs);
+            match jobs
+                .iter_mut()
+                .find(|existing| existing.job_id == job.job_id)
+            {
+                Some(existing) => *existing = job.clone(),
+                None => jobs.push(job.clone()),
+            }
+        }
+
+        fn remove(&self, job_id: &str) {
+            recover_lock(&self.jobs).retain(|job| job.job_id != job_id);
+        }
+
+        fn clear_history(&self) {
+            recover_lock(&self.jobs).retain(|job| job.status == CopyJobStatus::InProgress);
+        }
+    }
+
+    fn job(job_id: &str, status: CopyJobStatus) -> CopyJob {
+        CopyJob {
+            job_id: job_id.to_string(),
+            source_item_id: "source".to_string(),
+            item_name: "report.pdf".to_string(),
+            target_drive_id: None,
+            target_parent_id: Some("parent".to_string()),
+            new_name: None,
+            monitor_url: "https://example.com/monitor".to_string(),
+            status,
+            percentage_complete: None,
+            new_item_id: None,
+            error_message: None,
+            started_at: 100,
+            completed_at: None,
+        }
+    }
+
+    fn manager_with(jobs: Vec<CopyJob>) -> (CopyManager, Arc<MemoryStore>) {
+        let store = Arc::new(MemoryStore::default());
+        for job in &jobs {
+            store.upsert(job);
+        }
+        let manager = CopyManager::with_store(store.clone());
+        manager.load_jobs();
+        (manager, store)
+    }
+
+    #[test]
+    fn finish_records_new_item_id_and_persists() {
+        let (manager, store) = manager_with(vec![job("a", CopyJobStatus::InProgress)]);
+        let events = manager.subscribe();
+        manager.finish("a", Ok(Some("new-item".to_string())));
+
+        let finished = &manager.jobs()[0];
+        assert_eq!(finished.status, CopyJobStatus::Completed);
+        assert_eq!(finished.new_item_id.as_deref(), Some("new-item"));
+        assert_eq!(finished.percentage_complete, Some(100.0));
+        assert!(finished.completed_at.is_some());
+        assert_eq!(store.load()[0].new_item_id.as_deref(), Some("new-item"));
+        assert_eq!(events.try_recv().unwrap().status, CopyJobStatus::Completed);
+    }
+
+    #[test]
+    fn finish_records_error_on_failure() {
+        let (manager, store) = manager_with(vec![job("a", CopyJobStatus::InProgress)]);
+        manager.finish("a", Err("复制失败".to_string()));
+
+        let failed = &store.load()[0];
+        assert_eq!(failed.status, CopyJobStatus::Failed);
+        assert_eq!(failed.error_message.as_deref(), Some("复制失败"));
+        assert_eq!(failed.new_item_id, None);
+    }
+
+    #[test]
+    fn restart_resumes_only_in_progress_jobs() {
+        let store = Arc::new(MemoryStore::default());
+        store.upsert(&job("running", CopyJobStatus::InProgress));
+        store.upsert(&job("done", CopyJobStatus::Completed));
+        store.upsert(&job("failed", CopyJobStatus::Failed));
+        let manager = CopyManager::with_store(store);
+
+        let pending: Vec<String> = manager.load_jobs().into_iter().map(|j| j.job_id).collect();
+        assert_eq!(pending, vec!["running"]);
+        assert_eq!(manager.jobs().len(), 3);
+
+        manager.clear_history();
+        let remaining: Vec<String> = manager.jobs().into_iter().map(|j| j.job_id).collect();
+        assert_eq!(remaining, vec!["running"]);
+    }
+
+    #[test]
+    fn progress_is_clamped_and_deduplicated() {
+        let (manager, _) = manager_with(vec![job("a", CopyJobStatus::InProgress)]);
+        let events = manager.subscribe();
+        manager.update_progress("a", Some(150.0));
+        manager.update_progress("a", Some(100.0));
+        assert_eq!(manager.jobs()[0].percentage_complete, Some(100.0));
+        assert!(events.try_recv().is_ok());
+        assert!(events.try_recv().is_err());
+    }
+}
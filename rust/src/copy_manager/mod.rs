@@ -0,0 +1,5 @@
+pub mod core;
+pub mod storage;
+
+pub use core::CopyManager;
+pub use storage::{CopyJobStore, SqliteCopyJobStore};
@@ -0,0 +1,144 @@
+use crate::api::drive::models::{CopyJob, CopyJobStatus};
+use crate::db::{
+    clear_finished_copy_jobs, delete_copy_job, load_copy_jobs, upsert_copy_job, CopyJobRecord,
+};
+
+/// 复制任务持久化接口，方便未来替换存储实现或编写单测。
+pub trait CopyJobStore: Send + Sync {
+    fn load(&self) -> Vec<CopyJob>;
+    fn upsert(&self, job: &CopyJob);
+    fn remove(&self, job_id: &str);
+    fn clear_history(&self);
+}
+
+/// 默认的 SQLite 实现。
+#[flutter_rust_bridge::frb(ignore)]
+pub struct SqliteCopyJobStore {}
+
+impl SqliteCopyJobStore {
+    pub fn new() -> Self {
+        SqliteCopyJobStore {}
+    }
+}
+
+impl Default for SqliteCopyJobStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CopyJobStore for SqliteCopyJobStore {
+    fn load(&self) -> Vec<CopyJob> {
+        load_copy_jobs()
+            .map(|records| records.into_iter().map(job_from_record).collect())
+            .unwrap_or_default()
+    }
+
+    fn upsert(&self, job: &CopyJob) {
+        if let Err(err) = upsert_copy_job(&record_from_job(job)) {
+            eprintln!("[copy-store] failed to upsert job {}: {err}", job.job_id);
+        }
+    }
+
+    fn remove(&self, job_id: &str) {
+        if let Err(err) = delete_copy_job(job_id) {
+            eprintln!("[copy-store] failed to delete job {job_id}: {err}");
+        }
+    }
+
+    fn clear_history(&self) {
+        let active_statuses = [status_to_i64(CopyJobStatus::InProgress)];
+        if let Err(err) = clear_finished_copy_jobs(&active_statuses) {
+            eprintln!("[copy-store] failed to clear copy history: {err}");
+        }
+    }
+}
+
+fn record_from_job(job: &CopyJob) -> CopyJobRecord {
+    CopyJobRecord {
+        job_id: job.job_id.clone(),
+        source_item_id: job.source_item_id.clone(),
+        item_name: job.item_name.clone(),
+        target_drive_id: job.target_drive_id.clone(),
+        target_parent_id: job.target_parent_id.clone(),
+        new_name: job.new_name.clone(),
+        monitor_url: job.monitor_url.clone(),
+        status: status_to_i64(job.status),
+        percentage_complete: job.percentage_complete,
+        new_item_id: job.new_item_id.clone(),
+        error_message: job.error_message.clone(),
+        started_at: job.started_at,
+        completed_at: job.completed_at,
+        updated_at_millis: crate::db::current_timestamp_millis(),
+    }
+}
+
+fn job_from_record(record: CopyJobRecord) -> CopyJob {
+    CopyJob {
+        job_id: record.job_id,
+        source_item_id: record.source_item_id,
+        item_name: record.item_name,
+        target_drive_id: record.target_drive_id,
+        target_parent_id: record.target_parent_id,
+        new_name: record.new_name,
+        monitor_url: record.monitor_url,
+        status: status_from_i64(record.status),
+        percentage_complete: record.percentage_complete,
+        new_item_id: record.new_item_id,
+        error_message: record.error_message,
+        started_at: record.started_at,
+        completed_at: record.completed_at,
+    }
+}
+
+fn status_to_i64(status: CopyJobStatus) -> i64 {
+    match status {
+        CopyJobStatus::InProgress => 0,
+        CopyJobStatus::Completed => 1,
+        CopyJobStatus::Failed => 2,
+    }
+}
+
+fn status_from_i64(value: i64) -> CopyJobStatus {
+    match value {
+        1 => CopyJobStatus::Completed,
+        2 => CopyJobStatus::Failed,
+        _ => CopyJobStatus::InProgress,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job_survives_record_conversion() {
+        let job = CopyJob {
+            job_id: "job".to_string(),
+            source_item_id: "source".to_string(),
+            item_name: "report.pdf".to_string(),
+            target_drive_id: Some("drive".to_string()),
+            target_parent_id: Some("parent".to_string()),
+            new_name: Some("copy.pdf".to_string()),
+            monitor_url: "https://example.com/monitor".to_string(),
+            status: CopyJobStatus::Completed,
+            percentage_complete: Some(100.0),
+            new_item_id: Some("new-item".to_string()),
+            error_message: None,
+            started_at: 100,
+            completed_at: Some(200),
+        };
+        let restored = job_from_record(record_from_job(&job));
+        assert_eq!(restored.new_item_id.as_deref(), Some("new-item"));
+        assert_eq!(restored.status, CopyJobStatus::Completed);
+        assert_eq!(restored.target_drive_id, job.target_drive_id);
+        assert_eq!(restored.completed_at, Some(200));
+        for status in [
+            CopyJobStatus::InProgress,
+            CopyJobStatus::Completed,
+            CopyJobStatus::Failed,
+        ] {
+            assert_eq!(status_from_i64(status_to_i64(status)), status);
+        }
+    }
+}
@@ -5,7 +5,6 @@ use super::{current_timestamp_millis, with_connection, StorageResult};
 
 /// OAuth 令牌持久化模块：集中管理 auth_tokens 表的建表语句与 CRUD。
 /// 由于桌面端可能需要跨多次启动复用 token，所以统一走 sqlite。
-
 pub(crate) const AUTH_TABLE_SCHEMA: &str = "
 CREATE TABLE IF NOT EXISTS auth_tokens (
     id INTEGER PRIMARY KEY CHECK (id = 1),
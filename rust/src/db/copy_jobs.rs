@@ -0,0 +1,243 @@
+use rusqlite::{params, params_from_iter, Connection, Row};
+
+use super::{with_connection, StorageResult};
+
+pub(crate) const COPY_JOB_TABLE_SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS copy_jobs (
+    job_id TEXT PRIMARY KEY,
+    source_item_id TEXT NOT NULL,
+    item_name TEXT NOT NULL,
+    target_drive_id TEXT,
+    target_parent_id TEXT,
+    new_name TEXT,
+    monitor_url TEXT NOT NULL,
+    status INTEGER NOT NULL,
+    percentage_complete REAL,
+    new_item_id TEXT,
+    error_message TEXT,
+    started_at INTEGER NOT NULL,
+    completed_at INTEGER,
+    updated_at_millis INTEGER NOT NULL
+);";
+
+#[derive(Debug, Clone)]
+pub struct CopyJobRecord {
+    pub job_id: String,
+    pub source_item_id: String,
+    pub item_name: String,
+    pub target_drive_id: Option<String>,
+    pub target_parent_id: Option<String>,
+    pub new_name: Option<String>,
+    pub monitor_url: String,
+    pub status: i64,
+    pub percentage_complete: Option<f64>,
+    pub new_item_id: Option<String>,
+    pub error_message: Option<String>,
+    pub started_at: i64,
+    pub completed_at: Option<i64>,
+    pub updated_at_millis: i64,
+}
+
+pub fn upsert_copy_job(record: &CopyJobRecord) -> StorageResult<()> {
+    with_connection(|conn| write_copy_job(conn, record))
+}
+
+fn write_copy_job(conn: &Connection, record: &CopyJobRecord) -> StorageResult<()> {
+    conn.execute(
+        "INSERT INTO copy_jobs (
+            job_id,
+            source_item_id,
+            item_name,
+            target_drive_id,
+            target_parent_id,
+            new_name,
+            monitor_url,
+            status,
+            percentage_complete,
+            new_item_id,
+            error_message,
+            started_at,
+            completed_at,
+            updated_at_millis
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(job_id) DO UPDATE SET
+            source_item_id = excluded.source_item_id,
+            item_name = excluded.item_name,
+            target_drive_id = excluded.target_drive_id,
+            target_parent_id = excluded.target_parent_id,
+            new_name = excluded.new_name,
+            monitor_url = excluded.monitor_url,
+            status = excluded.status,
+            percentage_complete = excluded.percentage_complete,
+            new_item_id = excluded.new_item_id,
+            error_message = excluded.error_message,
+            started_at = excluded.started_at,
+            completed_at = excluded.completed_at,
+            updated_at_millis = excluded.updated_at_millis",
+        params![
+            record.job_id,
+            record.source_item_id,
+            record.item_name,
+            record.target_drive_id,
+            record.target_parent_id,
+            record.new_name,
+            record.monitor_url,
+            record.status,
+            record.percentage_complete,
+            record.new_item_id,
+            record.error_message,
+            record.started_at,
+            record.completed_at,
+            record.updated_at_millis,
+        ],
+    )
+    .map_err(|e| format!("failed to upsert copy job: {e}"))?;
+    Ok(())
+}
+
+pub fn load_copy_jobs() -> StorageResult<Vec<CopyJobRecord>> {
+    with_connection(read_copy_jobs)
+}
+
+fn read_copy_jobs(conn: &Connection) -> StorageResult<Vec<CopyJobRecord>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT
+                job_id,
+                source_item_id,
+                item_name,
+                target_drive_id,
+                target_parent_id,
+                new_name,
+                monitor_url,
+                status,
+                percentage_complete,
+                new_item_id,
+                error_message,
+                started_at,
+                completed_at,
+                updated_at_millis
+            FROM copy_jobs
+            ORDER BY started_at ASC",
+        )
+        .map_err(|e| format!("failed to prepare copy job query: {e}"))?;
+    let rows = stmt
+        .query_map([], map_copy_job)
+        .map_err(|e| format!("failed to query copy jobs: {e}"))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("failed to parse copy job row: {e}"))?;
+    Ok(rows)
+}
+
+pub fn delete_copy_job(job_id: &str) -> StorageResult<()> {
+    with_connection(|conn| {
+        conn.execute("DELETE FROM copy_jobs WHERE job_id = ?", params![job_id])
+            .map_err(|e| format!("failed to delete copy job {job_id}: {e}"))?;
+        Ok(())
+    })
+}
+
+/// 删除所有不处于 `active_statuses` 中的复制任务记录。
+pub fn clear_finished_copy_jobs(active_statuses: &[i64]) -> StorageResult<()> {
+    with_connection(|conn| delete_finished_copy_jobs(conn, active_statuses))
+}
+
+fn delete_finished_copy_jobs(conn: &Connection, active_statuses: &[i64]) -> StorageResult<()> {
+    let placeholders = vec!["?"; active_statuses.len()].join(", ");
+    conn.execute(
+        &format!("DELETE FROM copy_jobs WHERE status NOT IN ({placeholders})"),
+        params_from_iter(active_statuses),
+    )
+    .map_err(|e| format!("failed to clear copy jobs: {e}"))?;
+    Ok(())
+}
+
+fn map_copy_job(row: &Row) -> rusqlite::Result<CopyJobRecord> {
+    Ok(CopyJobRecord {
+        job_id: row.get(0)?,
+        source_item_id: row.get(1)?,
+        item_name: row.get(2)?,
+        target_drive_id: row.get(3)?,
+        target_parent_id: row.get(4)?,
+        new_name: row.get(5)?,
+        monitor_url: row.get(6)?,
+        status: row.get(7)?,
+        percentage_complete: row.get(8)?,
+        new_item_id: row.get(9)?,
+        error_message: row.get(10)?,
+        started_at: row.get(11)?,
+        completed_at: row.get(12)?,
+        updated_at_millis: row.get(13)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(COPY_JOB_TABLE_SCHEMA).unwrap();
+        conn
+    }
+
+    fn record(job_id: &str, status: i64, started_at: i64) -> CopyJobRecord {
+        CopyJobRecord {
+            job_id: job_id.to_string(),
+            source_item_id: "source".to_string(),
+            item_name: "report.pdf".to_string(),
+            target_drive_id: Some("drive".to_string()),
+            target_parent_id: None,
+            new_name: Some("report copy.pdf".to_string()),
+            monitor_url: "https://example.com/monitor".to_string(),
+            status,
+            percentage_complete: Some(42.5),
+            new_item_id: None,
+            error_message: None,
+            started_at,
+            completed_at: None,
+            updated_at_millis: started_at,
+        }
+    }
+
+    #[test]
+    fn records_round_trip_and_update_in_place() {
+        let conn = connection();
+        write_copy_job(&conn, &record("b", 0, 200)).unwrap();
+        let mut first = record("a", 0, 100);
+        write_copy_job(&conn, &first).unwrap();
+        first.status = 1;
+        first.new_item_id = Some("new-item".to_string());
+        first.completed_at = Some(300);
+        write_copy_job(&conn, &first).unwrap();
+
+        let records = read_copy_jobs(&conn).unwrap();
+        assert_eq!(records.len(), 2);
+        // 按发起时间排序
+        let loaded = &records[0];
+        assert_eq!(loaded.job_id, "a");
+        assert_eq!(loaded.status, 1);
+        assert_eq!(loaded.new_item_id.as_deref(), Some("new-item"));
+        assert_eq!(loaded.completed_at, Some(300));
+        assert_eq!(loaded.target_drive_id.as_deref(), Some("drive"));
+        assert_eq!(loaded.target_parent_id, None);
+        assert_eq!(loaded.percentage_complete, Some(42.5));
+    }
+
+    #[test]
+    fn clearing_keeps_active_jobs() {
+        let conn = connection();
+        write_copy_job(&conn, &record("running", 0, 100)).unwrap();
+        write_copy_job(&conn, &record("done", 1, 200)).unwrap();
+        write_copy_job(&conn, &record("failed", 2, 300)).unwrap();
+        delete_finished_copy_jobs(&conn, &[0]).unwrap();
+
+        let ids: Vec<String> = read_copy_jobs(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|record| record.job_id)
+            .collect();
+        assert_eq!(ids, vec!["running"]);
+    }
+}
@@ -1,13 +1,13 @@
-use rusqlite::{params, Row};
+use rusqlite::{params, params_from_iter, Connection, Row};
 
 use super::{with_connection, StorageResult};
 
 /// 下载任务持久化模块：负责 download_tasks 表结构与增删改查。
 /// 在应用重启后，可借此恢复队列状态，实现断点续传级别的体验。
-
 pub(crate) const DOWNLOAD_TABLE_SCHEMA: &str = "
 CREATE TABLE IF NOT EXISTS download_tasks (
-    item_id TEXT PRIMARY KEY,
+    task_id TEXT PRIMARY KEY,
+    item_id TEXT NOT NULL,
     item_name TEXT NOT NULL,
     size INTEGER,
     is_folder INTEGER NOT NULL,
@@ -22,11 +22,19 @@ CREATE TABLE IF NOT EXISTS download_tasks (
     size_label INTEGER,
     bytes_downloaded INTEGER,
     error_message TEXT,
-    updated_at_millis INTEGER NOT NULL
+    updated_at_millis INTEGER NOT NULL,
+    target_dir TEXT,
+    conflict_policy INTEGER,
+    conflict_outcome INTEGER,
+    retry_count INTEGER,
+    priority INTEGER,
+    failure_reason INTEGER,
+    hook_outcomes TEXT
 );";
 
 #[derive(Debug, Clone)]
 pub struct DownloadTaskRecord {
+    pub task_id: String,
     pub item_id: String,
     pub item_name: String,
     pub size: Option<i64>,
@@ -43,12 +51,21 @@ pub struct DownloadTaskRecord {
     pub bytes_downloaded: Option<i64>,
     pub error_message: Option<String>,
     pub updated_at_millis: i64,
+    pub target_dir: Option<String>,
+    pub conflict_policy: Option<i64>,
+    pub conflict_outcome: Option<i64>,
+    pub retry_count: Option<i64>,
+    pub priority: Option<i64>,
+    pub failure_reason: Option<i64>,
+    /// 下载后动作的执行结果，以 JSON 数组保存。
+    pub hook_outcomes: Option<String>,
 }
 
 pub fn upsert_download_task(record: &DownloadTaskRecord) -> StorageResult<()> {
     with_connection(|conn| {
         conn.execute(
             "INSERT INTO download_tasks (
+                task_id,
                 item_id,
                 item_name,
                 size,
@@ -64,10 +81,18 @@ pub fn upsert_download_task(record: &DownloadTaskRecord) -> StorageResult<()> {
                 size_label,
                 bytes_downloaded,
                 error_message,
-                updated_at_millis
+                updated_at_millis,
+                target_dir,
+                conflict_policy,
+                conflict_outcome,
+                retry_count,
+                priority,
+                failure_reason,
+                hook_outcomes
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ON CONFLICT(item_id) DO UPDATE SET
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(task_id) DO UPDATE SET
+                item_id = excluded.item_id,
                 item_name = excluded.item_name,
                 size = excluded.size,
                 is_folder = excluded.is_folder,
@@ -82,8 +107,16 @@ pub fn upsert_download_task(record: &DownloadTaskRecord) -> StorageResult<()> {
                 size_label = excluded.size_label,
                 bytes_downloaded = excluded.bytes_downloaded,
                 error_message = excluded.error_message,
-                updated_at_millis = excluded.updated_at_millis",
+                updated_at_millis = excluded.updated_at_millis,
+                target_dir = excluded.target_dir,
+                conflict_policy = excluded.conflict_policy,
+                conflict_outcome = excluded.conflict_outcome,
+                retry_count = excluded.retry_count,
+                priority = excluded.priority,
+                failure_reason = excluded.failure_reason,
+                hook_outcomes = excluded.hook_outcomes",
             params![
+                record.task_id,
                 record.item_id,
                 record.item_name,
                 record.size,
@@ -100,6 +133,13 @@ pub fn upsert_download_task(record: &DownloadTaskRecord) -> StorageResult<()> {
                 record.bytes_downloaded,
                 record.error_message,
                 record.updated_at_millis,
+                record.target_dir,
+                record.conflict_policy,
+                record.conflict_outcome,
+                record.retry_count,
+                record.priority,
+                record.failure_reason,
+                record.hook_outcomes,
             ],
         )
         .map_err(|e| format!("failed to upsert download task: {e}"))?;
@@ -112,6 +152,7 @@ pub fn load_download_tasks() -> StorageResult<Vec<DownloadTaskRecord>> {
         let mut stmt = conn
             .prepare(
                 "SELECT
+                    task_id,
                     item_id,
                     item_name,
                     size,
@@ -127,13 +168,20 @@ pub fn load_download_tasks() -> StorageResult<Vec<DownloadTaskRecord>> {
                     size_label,
                     bytes_downloaded,
                     error_message,
-                    updated_at_millis
+                    updated_at_millis,
+                    target_dir,
+                    conflict_policy,
+                    conflict_outcome,
+                    retry_count,
+                    priority,
+                    failure_reason,
+                    hook_outcomes
                 FROM download_tasks
                 ORDER BY started_at ASC",
             )
             .map_err(|e| format!("failed to prepare download task query: {e}"))?;
         let rows = stmt
-            .query_map([], |row| map_download_task(row))
+            .query_map([], map_download_task)
             .map_err(|e| format!("failed to query download tasks: {e}"))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("failed to parse download task row: {e}"))?;
@@ -141,22 +189,24 @@ pub fn load_download_tasks() -> StorageResult<Vec<DownloadTaskRecord>> {
     })
 }
 
-pub fn delete_download_task(item_id: &str) -> StorageResult<()> {
+pub fn delete_download_task(task_id: &str) -> StorageResult<()> {
     with_connection(|conn| {
         conn.execute(
-            "DELETE FROM download_tasks WHERE item_id = ?",
-            params![item_id],
+            "DELETE FROM download_tasks WHERE task_id = ?",
+            params![task_id],
         )
-        .map_err(|e| format!("failed to delete download task {item_id}: {e}"))?;
+        .map_err(|e| format!("failed to delete download task {task_id}: {e}"))?;
         Ok(())
     })
 }
 
-pub fn clear_finished_download_tasks(active_status: i64) -> StorageResult<()> {
+/// 删除所有不处于 `active_statuses` 中的任务记录（即已结束的历史）。
+pub fn clear_finished_download_tasks(active_statuses: &[i64]) -> StorageResult<()> {
     with_connection(|conn| {
+        let placeholders = vec!["?"; active_statuses.len()].join(", ");
         conn.execute(
-            "DELETE FROM download_tasks WHERE status != ?",
-            params![active_status],
+            &format!("DELETE FROM download_tasks WHERE status NOT IN ({placeholders})"),
+            params_from_iter(active_statuses),
         )
         .map_err(|e| format!("failed to clear download history: {e}"))?;
         Ok(())
@@ -165,21 +215,154 @@ pub fn clear_finished_download_tasks(active_status: i64) -> StorageResult<()> {
 
 fn map_download_task(row: &Row) -> rusqlite::Result<DownloadTaskRecord> {
     Ok(DownloadTaskRecord {
-        item_id: row.get(0)?,
-        item_name: row.get(1)?,
-        size: row.get(2)?,
-        is_folder: row.get::<_, i64>(3)? != 0,
-        child_count: row.get(4)?,
-        mime_type: row.get(5)?,
-        last_modified: row.get(6)?,
-        thumbnail_url: row.get(7)?,
-        status: row.get(8)?,
-        started_at: row.get(9)?,
-        completed_at: row.get(10)?,
-        saved_path: row.get(11)?,
-        size_label: row.get(12)?,
-        bytes_downloaded: row.get(13)?,
-        error_message: row.get(14)?,
-        updated_at_millis: row.get(15)?,
+        task_id: row.get(0)?,
+        item_id: row.get(1)?,
+        item_name: row.get(2)?,
+        size: row.get(3)?,
+        is_folder: row.get::<_, i64>(4)? != 0,
+        child_count: row.get(5)?,
+        mime_type: row.get(6)?,
+        last_modified: row.get(7)?,
+        thumbnail_url: row.get(8)?,
+        status: row.get(9)?,
+        started_at: row.get(10)?,
+        completed_at: row.get(11)?,
+        saved_path: row.get(12)?,
+        size_label: row.get(13)?,
+        bytes_downloaded: row.get(14)?,
+        error_message: row.get(15)?,
+        updated_at_millis: row.get(16)?,
+        target_dir: row.get(17)?,
+        conflict_policy: row.get(18)?,
+        conflict_outcome: row.get(19)?,
+        retry_count: row.get(20)?,
+        priority: row.get(21)?,
+        failure_reason: row.get(22)?,
+        hook_outcomes: row.get(23)?,
     })
 }
+
+/// 旧版本以 item_id 作为主键，同一文件只能保留一条记录。
+/// 检测到旧表结构时重建为以 task_id 为主键的新表，历史记录沿用 item_id 作为 task_id。
+pub(crate) fn migrate_to_task_id_key(conn: &Connection) -> StorageResult<()> {
+    let has_task_id = conn
+        .prepare("SELECT 1 FROM pragma_table_info('download_tasks') WHERE name = 'task_id'")
+        .and_then(|mut stmt| stmt.exists([]))
+        .map_err(|e| format!("failed to inspect download_tasks schema: {e}"))?;
+    if has_task_id {
+        return Ok(());
+    }
+    let columns = "item_id, item_name, size, is_folder, child_count, mime_type, last_modified, \
+        thumbnail_url, status, started_at, completed_at, saved_path, size_label, bytes_downloaded, \
+        error_message, updated_at_millis, target_dir, conflict_policy, conflict_outcome, retry_count";
+    let sql = format!(
+        "BEGIN;
+        ALTER TABLE download_tasks RENAME TO download_tasks_legacy;
+        {DOWNLOAD_TABLE_SCHEMA}
+        INSERT INTO download_tasks (task_id, {columns})
+            SELECT item_id, {columns} FROM download_tasks_legacy;
+        DROP TABLE download_tasks_legacy;
+        COMMIT;"
+    );
+    conn.execute_batch(&sql).map_err(|e| {
+        let _ = conn.execute_batch("ROLLBACK;");
+        format!("failed to migrate download_tasks to task_id key: {e}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_SCHEMA: &str = "
+    CREATE TABLE download_tasks (
+        item_id TEXT PRIMARY KEY,
+        item_name TEXT NOT NULL,
+        size INTEGER,
+        is_folder INTEGER NOT NULL,
+        child_count INTEGER,
+        mime_type TEXT,
+        last_modified TEXT,
+        thumbnail_url TEXT,
+        status INTEGER NOT NULL,
+        started_at INTEGER NOT NULL,
+        completed_at INTEGER,
+        saved_path TEXT,
+        size_label INTEGER,
+        bytes_downloaded INTEGER,
+        error_message TEXT,
+        updated_at_millis INTEGER NOT NULL,
+        target_dir TEXT,
+        conflict_policy INTEGER,
+        conflict_outcome INTEGER,
+        retry_count INTEGER
+    );";
+
+    fn legacy_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(LEGACY_SCHEMA).unwrap();
+        conn.execute(
+            "INSERT INTO download_tasks (item_id, item_name, is_folder, status, started_at, \
+             updated_at_millis, saved_path, retry_count) \
+             VALUES ('item-1', 'a.txt', 0, 2, 100, 200, '/tmp/a.txt', 3)",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn legacy_rows_keep_item_id_as_task_id() {
+        let conn = legacy_connection();
+        migrate_to_task_id_key(&conn).unwrap();
+
+        let records = conn
+            .prepare("SELECT * FROM download_tasks")
+            .unwrap()
+            .query_map([], map_download_task)
+            .unwrap()
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.task_id, "item-1");
+        assert_eq!(record.item_id, "item-1");
+        assert_eq!(record.saved_path.as_deref(), Some("/tmp/a.txt"));
+        assert_eq!(record.retry_count, Some(3));
+        assert_eq!(record.priority, None);
+    }
+
+    #[test]
+    fn migrated_table_allows_repeated_item_ids() {
+        let conn = legacy_connection();
+        migrate_to_task_id_key(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO download_tasks (task_id, item_id, item_name, is_folder, status, \
+             started_at, updated_at_millis) VALUES ('task-2', 'item-1', 'a.txt', 0, 0, 300, 300)",
+            [],
+        )
+        .unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM download_tasks WHERE item_id = 'item-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn migration_is_noop_on_current_schema() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(DOWNLOAD_TABLE_SCHEMA).unwrap();
+        migrate_to_task_id_key(&conn).unwrap();
+        migrate_to_task_id_key(&conn).unwrap();
+        let legacy_exists = conn
+            .prepare("SELECT 1 FROM sqlite_master WHERE name = 'download_tasks_legacy'")
+            .unwrap()
+            .exists([])
+            .unwrap();
+        assert!(!legacy_exists);
+    }
+}
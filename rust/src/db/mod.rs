@@ -1,22 +1,33 @@
 mod auth;
+mod copy_jobs;
 mod download_tasks;
 mod settings;
+mod upload_groups;
 mod upload_tasks;
 
+use crate::lock::recover_lock;
 use directories::ProjectDirs;
 use rusqlite::{Connection, Error as SqliteError};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use auth::{
     build_record, clear_auth_record, load_auth_record, upsert_auth_record, AuthTokenRecord,
 };
+pub use copy_jobs::{
+    clear_finished_copy_jobs, delete_copy_job, load_copy_jobs, upsert_copy_job, CopyJobRecord,
+};
 pub use download_tasks::{
     clear_finished_download_tasks, delete_download_task, load_download_tasks, upsert_download_task,
     DownloadTaskRecord,
 };
 pub use settings::{get_setting, set_setting};
+pub use upload_groups::{
+    clear_finished_upload_groups, delete_upload_group, load_upload_groups, upsert_upload_group,
+    UploadGroupRecord,
+};
 pub use upload_tasks::{
     clear_finished_upload_tasks, delete_upload_task, load_upload_tasks, upsert_upload_task,
     UploadTaskRecord,
@@ -24,7 +35,6 @@ pub use upload_tasks::{
 
 /// DB 模块：提供统一的 sqlite 连接管理，同时 re-export 领域级 API。
 /// 目前支持 auth_tokens 与 download_tasks，两者共用同一数据库文件，便于部署。
-
 const QUALIFIER: &str = "com";
 const ORGANIZATION: &str = "Skydrivex";
 const APPLICATION: &str = "Skydrivex";
@@ -66,7 +76,53 @@ fn open_connection() -> StorageResult<Connection> {
     Ok(conn)
 }
 
+/// 一步结构迁移。每一步都必须可以重复执行（建表使用 IF NOT EXISTS、加列忽略已存在的列），
+/// 以兼容引入版本号之前每次打开连接都会补齐结构的旧数据库。
+type Migration = fn(&Connection) -> StorageResult<()>;
+
+/// 按顺序排列的迁移步骤，`PRAGMA user_version` 记录已执行的步数。
+/// 结构变更只在末尾追加新的步骤，已发布的步骤不再修改或调整顺序。
+const MIGRATIONS: &[Migration] = &[
+    create_base_tables,
+    add_download_conflict_columns,
+    add_retry_count_columns,
+    migrate_download_task_key,
+    add_priority_columns,
+    add_failure_reason_columns,
+    add_hook_outcomes_column,
+    create_upload_groups,
+    add_upload_conflict_columns,
+    add_session_expiry_column,
+    add_source_snapshot_columns,
+    add_source_url_column,
+    create_copy_jobs_table,
+];
+
+/// 串行化同一进程内的迁移，避免多个线程同时打开首个连接时重复执行。
+static MIGRATION_LOCK: Mutex<()> = Mutex::new(());
+
+/// 只执行数据库尚未应用的迁移步骤；已是最新版本时只读取一次版本号。
 fn apply_migrations(conn: &Connection) -> StorageResult<()> {
+    if schema_version(conn)? < MIGRATIONS.len() {
+        let _guard = recover_lock(&MIGRATION_LOCK);
+        // 等待锁期间其他连接可能已完成迁移，重新读取版本号。
+        let applied = schema_version(conn)?;
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(applied) {
+            migration(conn)?;
+            conn.pragma_update(None, "user_version", (index + 1) as i64)
+                .map_err(|e| format!("failed to record schema version {}: {e}", index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+fn schema_version(conn: &Connection) -> StorageResult<usize> {
+    conn.pragma_query_value(None, "user_version", |row| row.get::<_, i64>(0))
+        .map(|version| version.max(0) as usize)
+        .map_err(|e| format!("failed to read schema version: {e}"))
+}
+
+fn create_base_tables(conn: &Connection) -> StorageResult<()> {
     conn.execute_batch(auth::AUTH_TABLE_SCHEMA)
         .map_err(|e| format!("failed to initialize auth_tokens schema: {e}"))?;
     conn.execute_batch(download_tasks::DOWNLOAD_TABLE_SCHEMA)
@@ -75,8 +131,67 @@ fn apply_migrations(conn: &Connection) -> StorageResult<()> {
         .map_err(|e| format!("failed to initialize upload_tasks schema: {e}"))?;
     conn.execute_batch(settings::SETTINGS_TABLE_SCHEMA)
         .map_err(|e| format!("failed to initialize settings schema: {e}"))?;
-    ensure_column(conn, "download_tasks", "bytes_downloaded", "INTEGER")?;
-    Ok(())
+    ensure_column(conn, "download_tasks", "bytes_downloaded", "INTEGER")
+}
+
+fn add_download_conflict_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "download_tasks", "target_dir", "TEXT")?;
+    ensure_column(conn, "download_tasks", "conflict_policy", "INTEGER")?;
+    ensure_column(conn, "download_tasks", "conflict_outcome", "INTEGER")
+}
+
+fn add_retry_count_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "download_tasks", "retry_count", "INTEGER")?;
+    ensure_column(conn, "upload_tasks", "retry_count", "INTEGER")
+}
+
+/// 旧版以 item_id 为主键，按最新表结构重建为 task_id 主键；此后新增的列需在这一步之后补齐。
+fn migrate_download_task_key(conn: &Connection) -> StorageResult<()> {
+    download_tasks::migrate_to_task_id_key(conn)
+}
+
+fn add_priority_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "download_tasks", "priority", "INTEGER")?;
+    ensure_column(conn, "upload_tasks", "priority", "INTEGER")
+}
+
+fn add_failure_reason_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "download_tasks", "failure_reason", "INTEGER")?;
+    ensure_column(conn, "upload_tasks", "failure_reason", "INTEGER")
+}
+
+fn add_hook_outcomes_column(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "download_tasks", "hook_outcomes", "TEXT")
+}
+
+fn create_upload_groups(conn: &Connection) -> StorageResult<()> {
+    conn.execute_batch(upload_groups::UPLOAD_GROUP_TABLE_SCHEMA)
+        .map_err(|e| format!("failed to initialize upload_groups schema: {e}"))?;
+    ensure_column(conn, "upload_tasks", "group_id", "TEXT")
+}
+
+fn add_upload_conflict_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "upload_tasks", "conflict_behavior", "INTEGER")?;
+    ensure_column(conn, "upload_tasks", "remote_name", "TEXT")?;
+    ensure_column(conn, "upload_tasks", "skipped_identical", "INTEGER")
+}
+
+fn add_session_expiry_column(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "upload_tasks", "session_expires_at", "INTEGER")
+}
+
+fn add_source_snapshot_columns(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "upload_tasks", "source_modified_at", "INTEGER")?;
+    ensure_column(conn, "upload_tasks", "source_fingerprint", "TEXT")
+}
+
+fn add_source_url_column(conn: &Connection) -> StorageResult<()> {
+    ensure_column(conn, "upload_tasks", "source_url", "TEXT")
+}
+
+fn create_copy_jobs_table(conn: &Connection) -> StorageResult<()> {
+    conn.execute_batch(copy_jobs::COPY_JOB_TABLE_SCHEMA)
+        .map_err(|e| format!("failed to initialize copy_jobs schema: {e}"))
 }
 
 fn ensure_column(
@@ -102,3 +217,50 @@ fn database_path() -> StorageResult<PathBuf> {
         .ok_or_else(|| "failed to resolve application data directory".to_string())?;
     Ok(dirs.data_dir().join(DB_FILE_NAME))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_database_runs_every_step_once() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_migrations(&conn).unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), MIGRATIONS.len());
+        // 已是最新版本时再次打开不会重复执行
+        apply_migrations(&conn).unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), MIGRATIONS.len());
+    }
+
+    #[test]
+    fn unversioned_database_is_brought_up_to_date() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE download_tasks (
+                item_id TEXT PRIMARY KEY,
+                item_name TEXT NOT NULL,
+                size INTEGER,
+                is_folder INTEGER NOT NULL,
+                child_count INTEGER,
+                mime_type TEXT,
+                last_modified TEXT,
+                thumbnail_url TEXT,
+                status INTEGER NOT NULL,
+                started_at INTEGER NOT NULL,
+                completed_at INTEGER,
+                saved_path TEXT,
+                size_label INTEGER,
+                error_message TEXT,
+                updated_at_millis INTEGER NOT NULL
+            );",
+        )
+        .unwrap();
+        apply_migrations(&conn).unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), MIGRATIONS.len());
+        let has_task_id = conn
+            .prepare("SELECT 1 FROM pragma_table_info('download_tasks') WHERE name = 'task_id'")
+            .and_then(|mut stmt| stmt.exists([]))
+            .unwrap();
+        assert!(has_task_id);
+    }
+}
@@ -0,0 +1,150 @@
+use rusqlite::{params, params_from_iter, Row};
+
+use super::{with_connection, StorageResult};
+
+pub(crate) const UPLOAD_GROUP_TABLE_SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS upload_groups (
+    group_id TEXT PRIMARY KEY,
+    local_root TEXT NOT NULL,
+    folder_name TEXT NOT NULL,
+    parent_id TEXT,
+    status INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    completed_at INTEGER,
+    error_message TEXT,
+    total_files INTEGER NOT NULL,
+    total_bytes INTEGER NOT NULL,
+    updated_at_millis INTEGER NOT NULL
+);";
+
+/// 文件夹上传任务组记录；完成/失败数量由子任务实时汇总，不在此持久化。
+#[derive(Debug, Clone)]
+pub struct UploadGroupRecord {
+    pub group_id: String,
+    pub local_root: String,
+    pub folder_name: String,
+    pub parent_id: Option<String>,
+    pub status: i64,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+    pub error_message: Option<String>,
+    pub total_files: i64,
+    pub total_bytes: i64,
+    pub updated_at_millis: i64,
+}
+
+pub fn upsert_upload_group(record: &UploadGroupRecord) -> StorageResult<()> {
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO upload_groups (
+                group_id,
+                local_root,
+                folder_name,
+                parent_id,
+                status,
+                created_at,
+                completed_at,
+                error_message,
+                total_files,
+                total_bytes,
+                updated_at_millis
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(group_id) DO UPDATE SET
+                local_root = excluded.local_root,
+                folder_name = excluded.folder_name,
+                parent_id = excluded.parent_id,
+                status = excluded.status,
+                created_at = excluded.created_at,
+                completed_at = excluded.completed_at,
+                error_message = excluded.error_message,
+                total_files = excluded.total_files,
+                total_bytes = excluded.total_bytes,
+                updated_at_millis = excluded.updated_at_millis",
+            params![
+                record.group_id,
+                record.local_root,
+                record.folder_name,
+                record.parent_id,
+                record.status,
+                record.created_at,
+                record.completed_at,
+                record.error_message,
+                record.total_files,
+                record.total_bytes,
+                record.updated_at_millis,
+            ],
+        )
+        .map_err(|e| format!("failed to upsert upload group: {e}"))?;
+        Ok(())
+    })
+}
+
+pub fn load_upload_groups() -> StorageResult<Vec<UploadGroupRecord>> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT
+                    group_id,
+                    local_root,
+                    folder_name,
+                    parent_id,
+                    status,
+                    created_at,
+                    completed_at,
+                    error_message,
+                    total_files,
+                    total_bytes,
+                    updated_at_millis
+                FROM upload_groups
+                ORDER BY created_at ASC",
+            )
+            .map_err(|e| format!("failed to prepare upload group query: {e}"))?;
+        let rows = stmt
+            .query_map([], map_upload_group)
+            .map_err(|e| format!("failed to query upload groups: {e}"))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("failed to parse upload group row: {e}"))?;
+        Ok(rows)
+    })
+}
+
+pub fn delete_upload_group(group_id: &str) -> StorageResult<()> {
+    with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM upload_groups WHERE group_id = ?",
+            params![group_id],
+        )
+        .map_err(|e| format!("failed to delete upload group {group_id}: {e}"))?;
+        Ok(())
+    })
+}
+
+/// 删除所有不处于 `active_statuses` 中的任务组记录。
+pub fn clear_finished_upload_groups(active_statuses: &[i64]) -> StorageResult<()> {
+    with_connection(|conn| {
+        let placeholders = vec!["?"; active_statuses.len()].join(", ");
+        conn.execute(
+            &format!("DELETE FROM upload_groups WHERE status NOT IN ({placeholders})"),
+            params_from_iter(active_statuses),
+        )
+        .map_err(|e| format!("failed to clear upload groups: {e}"))?;
+        Ok(())
+    })
+}
+
+fn map_upload_group(row: &Row) -> rusqlite::Result<UploadGroupRecord> {
+    Ok(UploadGroupRecord {
+        group_id: row.get(0)?,
+        local_root: row.get(1)?,
+        folder_name: row.get(2)?,
+        parent_id: row.get(3)?,
+        status: row.get(4)?,
+        created_at: row.get(5)?,
+        completed_at: row.get(6)?,
+        error_message: row.get(7)?,
+        total_files: row.get(8)?,
+        total_bytes: row.get(9)?,
+        updated_at_millis: row.get(10)?,
+    })
+}
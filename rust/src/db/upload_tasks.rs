@@ -1,4 +1,4 @@
-use rusqlite::{params, Row};
+use rusqlite::{params, params_from_iter, Row};
 
 use super::{with_connection, StorageResult};
 
@@ -17,7 +17,18 @@ CREATE TABLE IF NOT EXISTS upload_tasks (
     bytes_uploaded INTEGER,
     error_message TEXT,
     session_url TEXT,
-    updated_at_millis INTEGER NOT NULL
+    updated_at_millis INTEGER NOT NULL,
+    retry_count INTEGER,
+    priority INTEGER,
+    failure_reason INTEGER,
+    group_id TEXT,
+    conflict_behavior INTEGER,
+    remote_name TEXT,
+    skipped_identical INTEGER,
+    session_expires_at INTEGER,
+    source_modified_at INTEGER,
+    source_fingerprint TEXT,
+    source_url TEXT
 );";
 
 #[derive(Debug, Clone)]
@@ -36,6 +47,17 @@ pub struct UploadTaskRecord {
     pub error_message: Option<String>,
     pub session_url: Option<String>,
     pub updated_at_millis: i64,
+    pub retry_count: Option<i64>,
+    pub priority: Option<i64>,
+    pub failure_reason: Option<i64>,
+    pub group_id: Option<String>,
+    pub conflict_behavior: Option<i64>,
+    pub remote_name: Option<String>,
+    pub skipped_identical: Option<i64>,
+    pub session_expires_at: Option<i64>,
+    pub source_modified_at: Option<i64>,
+    pub source_fingerprint: Option<String>,
+    pub source_url: Option<String>,
 }
 
 pub fn upsert_upload_task(record: &UploadTaskRecord) -> StorageResult<()> {
@@ -55,9 +77,20 @@ pub fn upsert_upload_task(record: &UploadTaskRecord) -> StorageResult<()> {
                 bytes_uploaded,
                 error_message,
                 session_url,
-                updated_at_millis
+                updated_at_millis,
+                retry_count,
+                priority,
+                failure_reason,
+                group_id,
+                conflict_behavior,
+                remote_name,
+                skipped_identical,
+                session_expires_at,
+                source_modified_at,
+                source_fingerprint,
+                source_url
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(task_id) DO UPDATE SET
                 file_name = excluded.file_name,
                 local_path = excluded.local_path,
@@ -71,7 +104,18 @@ pub fn upsert_upload_task(record: &UploadTaskRecord) -> StorageResult<()> {
                 bytes_uploaded = excluded.bytes_uploaded,
                 error_message = excluded.error_message,
                 session_url = excluded.session_url,
-                updated_at_millis = excluded.updated_at_millis",
+                updated_at_millis = excluded.updated_at_millis,
+                retry_count = excluded.retry_count,
+                priority = excluded.priority,
+                failure_reason = excluded.failure_reason,
+                group_id = excluded.group_id,
+                conflict_behavior = excluded.conflict_behavior,
+                remote_name = excluded.remote_name,
+                skipped_identical = excluded.skipped_identical,
+                session_expires_at = excluded.session_expires_at,
+                source_modified_at = excluded.source_modified_at,
+                source_fingerprint = excluded.source_fingerprint,
+                source_url = excluded.source_url",
             params![
                 record.task_id,
                 record.file_name,
@@ -87,6 +131,17 @@ pub fn upsert_upload_task(record: &UploadTaskRecord) -> StorageResult<()> {
                 record.error_message,
                 record.session_url,
                 record.updated_at_millis,
+                record.retry_count,
+                record.priority,
+                record.failure_reason,
+                record.group_id,
+                record.conflict_behavior,
+                record.remote_name,
+                record.skipped_identical,
+                record.session_expires_at,
+                record.source_modified_at,
+                record.source_fingerprint,
+                record.source_url,
             ],
         )
         .map_err(|e| format!("failed to upsert upload task: {e}"))?;
@@ -112,13 +167,24 @@ pub fn load_upload_tasks() -> StorageResult<Vec<UploadTaskRecord>> {
                     bytes_uploaded,
                     error_message,
                     session_url,
-                    updated_at_millis
+                    updated_at_millis,
+                    retry_count,
+                    priority,
+                    failure_reason,
+                    group_id,
+                    conflict_behavior,
+                    remote_name,
+                    skipped_identical,
+                    session_expires_at,
+                    source_modified_at,
+                    source_fingerprint,
+                    source_url
                 FROM upload_tasks
                 ORDER BY started_at ASC",
             )
             .map_err(|e| format!("failed to prepare upload task query: {e}"))?;
         let rows = stmt
-            .query_map([], |row| map_upload_task(row))
+            .query_map([], map_upload_task)
             .map_err(|e| format!("failed to query upload tasks: {e}"))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("failed to parse upload task row: {e}"))?;
@@ -137,11 +203,13 @@ pub fn delete_upload_task(task_id: &str) -> StorageResult<()> {
     })
 }
 
-pub fn clear_finished_upload_tasks(active_status: i64) -> StorageResult<()> {
+/// 删除所有不处于 `active_statuses` 中的任务记录（即已结束的历史）。
+pub fn clear_finished_upload_tasks(active_statuses: &[i64]) -> StorageResult<()> {
     with_connection(|conn| {
+        let placeholders = vec!["?"; active_statuses.len()].join(", ");
         conn.execute(
-            "DELETE FROM upload_tasks WHERE status != ?",
-            params![active_status],
+            &format!("DELETE FROM upload_tasks WHERE status NOT IN ({placeholders})"),
+            params_from_iter(active_statuses),
         )
         .map_err(|e| format!("failed to clear upload history: {e}"))?;
         Ok(())
@@ -164,5 +232,16 @@ fn map_upload_task(row: &Row) -> rusqlite::Result<UploadTaskRecord> {
         error_message: row.get(11)?,
         session_url: row.get(12)?,
         updated_at_millis: row.get(13)?,
+        retry_count: row.get(14)?,
+        priority: row.get(15)?,
+        failure_reason: row.get(16)?,
+        group_id: row.get(17)?,
+        conflict_behavior: row.get(18)?,
+        remote_name: row.get(19)?,
+        skipped_identical: row.get(20)?,
+        session_expires_at: row.get(21)?,
+        source_modified_at: row.get(22)?,
+        source_fingerprint: row.get(23)?,
+        source_url: row.get(24)?,
     })
 }
@@ -1,30 +1,45 @@
 use super::storage::{DownloadStore, SqliteDownloadStore};
 use crate::api::drive::{
-    download::download_drive_item_with_progress,
+    download::{download_drive_item_with_progress, ProgressCallback},
     models::{
-        DownloadProgressUpdate, DownloadQueueState, DownloadStatus, DownloadTask,
-        DriveDownloadResult, DriveItemSummary,
+        DownloadConflictOutcome, DownloadConflictPolicy, DownloadProgressUpdate,
+        DownloadQueueState, DownloadStatus, DownloadTask, DownloadTaskEvent, DriveDownloadResult,
+        DriveItemSummary, PostDownloadHookOutcome, TransferEventKind, TransferFailureReason,
+        TransferPriority, TransferRetryPolicy,
     },
 };
-use crate::settings::download_concurrency::{
-    default_download_concurrency, get_download_concurrency,
+use crate::bandwidth::TransferDirection;
+use crate::db::current_timestamp_millis;
+use crate::lock::recover_lock;
+use crate::notifications::NotificationCenter;
+use crate::post_transfer::{open_path, reveal_path, run_post_download_hooks, should_run_hooks};
+use crate::preflight::{check_local_space, PreflightFailure};
+use crate::retry::{backoff_delay, load_retry_policy, wait_or_cancel, TransferError};
+use crate::settings::{
+    download_concurrency::{default_download_concurrency, get_download_concurrency},
+    download_conflict_policy::{default_download_conflict_policy, get_download_conflict_policy},
+    post_download_hooks::{default_post_download_hooks, get_post_download_hooks},
 };
+use crate::transfer_queue::TransferScheduler;
 use once_cell::sync::Lazy;
 use std::{
     collections::{HashMap, HashSet},
     sync::{
         atomic::{AtomicBool, Ordering},
         mpsc::{self, Receiver, SyncSender, TrySendError},
-        Arc, Condvar, Mutex, MutexGuard,
+        Arc, Mutex,
     },
     thread,
-    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant},
 };
+use uuid::Uuid;
 
 /// 全局下载管理器实例：避免多次初始化，同时方便在 FRB 桥接层与其他模块之间共享。
 static DOWNLOAD_MANAGER: Lazy<DownloadManager> = Lazy::new(DownloadManager::new);
 /// 进度广播的有界缓冲大小（每个订阅者），避免无界内存增长。
 const PROGRESS_CHANNEL_CAP: usize = 64;
+/// 生命周期事件的缓冲大小；事件频率远低于进度，留足余量避免丢失终态事件。
+const EVENT_CHANNEL_CAP: usize = 256;
 /// 每次持久化前至少累计的字节增量，避免频繁写入 SQLite。
 const PROGRESS_PERSIST_BYTES_THRESHOLD: u64 = 256 * 1024;
 /// 持久化节流的最小时间间隔，保证长时间空闲也能写入。
@@ -33,6 +48,9 @@ const PROGRESS_PERSIST_INTERVAL: Duration = Duration::from_secs(1);
 const SPEED_SAMPLE_MIN_INTERVAL: Duration = Duration::from_millis(300);
 
 const INTERRUPTED_DOWNLOAD_MESSAGE: &str = "应用已关闭或异常退出，下载被中断，请重新下载";
+const CANCELLED_DOWNLOAD_MESSAGE: &str = "下载已取消";
+const CONFLICT_PENDING_MESSAGE: &str = "目标位置已存在同名文件，等待选择处理方式";
+const NOT_QUEUED_MESSAGE: &str = "任务不在等待队列中，无法调整顺序";
 
 /// 核心状态机：负责调度、下载线程管理、速度计算与事件广播。
 #[derive(Clone)]
@@ -45,10 +63,12 @@ pub struct DownloadManager {
     persist_markers: Arc<Mutex<HashMap<String, PersistMarker>>>,
     /// 订阅者列表，需要保护避免在 send 阶段被并发修改
     subscribers: Arc<Mutex<Vec<SyncSender<DownloadProgressUpdate>>>>,
+    /// 生命周期事件订阅者
+    event_subscribers: Arc<Mutex<Vec<SyncSender<DownloadTaskEvent>>>>,
     /// 每个任务的取消令牌
     cancel_tokens: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
-    /// 控制同时进行的下载数量，避免无限制创建线程
-    concurrency_guard: Arc<Semaphore>,
+    /// 按队列顺序分配下载名额，控制同时进行的下载数量
+    scheduler: Arc<TransferScheduler>,
 }
 
 /// 内部状态快照，仅在 rust 内部使用，避免 FRB 生成多余绑定。
@@ -86,8 +106,9 @@ impl DownloadManager {
             progress_meters: Arc::new(Mutex::new(HashMap::new())),
             persist_markers: Arc::new(Mutex::new(HashMap::new())),
             subscribers: Arc::new(Mutex::new(Vec::new())),
+            event_subscribers: Arc::new(Mutex::new(Vec::new())),
             cancel_tokens: Arc::new(Mutex::new(HashMap::new())),
-            concurrency_guard: Arc::new(Semaphore::new(max_concurrency)),
+            scheduler: TransferScheduler::with_global_limit(max_concurrency),
         };
         manager.restore_from_storage();
         manager
@@ -105,9 +126,9 @@ impl DownloadManager {
         let mut failed = Vec::new();
         for mut task in records {
             match task.status {
-                DownloadStatus::InProgress => {
+                DownloadStatus::InProgress | DownloadStatus::Queued => {
                     task.status = DownloadStatus::Failed;
-                    task.completed_at = Some(current_timestamp());
+                    task.completed_at = Some(current_timestamp_millis());
                     if task.error_message.is_none() {
                         task.error_message = Some(INTERRUPTED_DOWNLOAD_MESSAGE.to_string());
                     }
@@ -129,11 +150,15 @@ impl DownloadManager {
     }
 
     /// 入队并启动下载线程，线程中会负责周期性推送进度。
+    /// - `conflict_policy` 为空时使用设置中的默认冲突策略。
+    /// - `priority` 为空时按普通优先级排队。
+    /// - 同一文件可下载到不同目录，仅拒绝同一目标目录下仍在进行的重复任务。
     pub fn enqueue(
         &self,
         item: DriveItemSummary,
         target_dir: String,
-        overwrite: bool,
+        conflict_policy: Option<DownloadConflictPolicy>,
+        priority: Option<TransferPriority>,
     ) -> Result<DownloadQueueState, String> {
         if item.id.trim().is_empty() {
             return Err("drive item id is required".to_string());
@@ -141,60 +166,329 @@ impl DownloadManager {
         if target_dir.trim().is_empty() {
             return Err("target directory is required".to_string());
         }
+        let conflict_policy = match conflict_policy {
+            Some(policy) => policy,
+            None => get_download_conflict_policy().unwrap_or_else(|err| {
+                eprintln!(
+                    "[download-manager] failed to load conflict policy: {err}; fallback to default"
+                );
+                default_download_conflict_policy()
+            }),
+        };
 
         let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if state.active.iter().any(|task| task.item.id == item.id) {
+        if state
+            .active
+            .iter()
+            .any(|task| task.item.id == item.id && task.target_dir == target_dir)
+        {
             return Err("该文件已在下载队列中".to_string());
         }
-        state.completed.retain(|task| task.item.id != item.id);
-        state.failed.retain(|task| task.item.id != item.id);
 
         let task = DownloadTask {
+            task_id: Uuid::new_v4().to_string(),
             item: item.clone(),
-            status: DownloadStatus::InProgress,
-            started_at: current_timestamp(),
+            status: DownloadStatus::Queued,
+            started_at: current_timestamp_millis(),
             completed_at: None,
             saved_path: None,
             size_label: item.size,
             bytes_downloaded: Some(0),
             error_message: None,
+            target_dir,
+            conflict_policy,
+            conflict_outcome: None,
+            retry_count: 0,
+            priority: priority.unwrap_or_default(),
+            failure_reason: None,
+            hook_outcomes: Vec::new(),
         };
         state.active.push(task.clone());
         drop(state);
         self.store.upsert(&task);
+        self.spawn_worker(task);
+
+        Ok(self.snapshot())
+    }
 
+    /// 将 active 中的任务放入等待队列并启动下载线程；线程按队列顺序获得名额后才真正开始，
+    /// 失败时按重试策略退避，再重新排队重试。
+    fn spawn_worker(&self, task: DownloadTask) {
         let cancel_token = Arc::new(AtomicBool::new(false));
-        self.register_cancel_token(&item.id, cancel_token.clone());
+        self.register_cancel_token(&task.task_id, cancel_token.clone());
+        self.scheduler.enqueue(&task.task_id, task.priority);
+        self.sync_active_order();
+        self.emit_event(TransferEventKind::Queued, &task);
 
         let manager = self.clone();
-        let item_id = item.id.clone();
         thread::spawn(move || {
-            // 获取并发许可，超限时会阻塞在此，避免无限制创建线程
-            let _permit = manager.concurrency_guard.acquire();
-            let progress_manager = manager.clone();
-            let progress_item_id = item_id.clone();
-            let progress_callback: Option<Box<dyn FnMut(u64, Option<u64>) + Send>> =
-                Some(Box::new(move |downloaded: u64, expected: Option<u64>| {
-                    progress_manager.report_progress(&progress_item_id, downloaded, expected);
-                }));
-            let result = download_drive_item_with_progress(
-                item_id.clone(),
-                target_dir,
-                overwrite,
-                progress_callback,
-                Some(cancel_token.clone()),
-            );
-            match result {
-                Ok(done) => manager.mark_success(&item_id, done),
-                Err(err) => manager.mark_failure(&item_id, err),
+            let task_id = task.task_id;
+            let retry_policy = load_retry_policy();
+            loop {
+                let result = {
+                    // 排到队首且有空闲名额才会返回；退避等待期间不占用名额
+                    let permit = manager.scheduler.acquire(&task_id);
+                    if permit.is_none() || cancel_token.load(Ordering::Relaxed) {
+                        return manager.mark_failure(
+                            &task_id,
+                            CANCELLED_DOWNLOAD_MESSAGE.to_string(),
+                            None,
+                        );
+                    }
+                    if let Err(failure) = manager.preflight(&task_id) {
+                        return manager.mark_failure(
+                            &task_id,
+                            failure.message,
+                            Some(failure.reason),
+                        );
+                    }
+                    manager.mark_started(&task_id);
+                    let progress_manager = manager.clone();
+                    let progress_task_id = task_id.clone();
+                    let progress_callback: Option<ProgressCallback> =
+                        Some(Box::new(move |downloaded: u64, expected: Option<u64>| {
+                            progress_manager.report_progress(
+                                &progress_task_id,
+                                downloaded,
+                                expected,
+                            );
+                        }));
+                    let result = download_drive_item_with_progress(
+                        task.item.id.clone(),
+                        task.target_dir.clone(),
+                        task.conflict_policy.clone(),
+                        progress_callback,
+                        Some(cancel_token.clone()),
+                    );
+                    // 传输结束立即归还名额，下载后动作（解压、移动等）不占用并发槽位
+                    drop(permit);
+                    result
+                };
+                match result {
+                    Ok(mut done) => {
+                        let hook_outcomes = manager.apply_post_download_hooks(&mut done);
+                        return manager.mark_success(&task_id, done, hook_outcomes);
+                    }
+                    Err(err) => {
+                        if manager.wait_for_retry(&task_id, &err, &retry_policy, &cancel_token) {
+                            manager.requeue(&task_id);
+                            continue;
+                        }
+                        if cancel_token.load(Ordering::Relaxed) {
+                            return manager.mark_failure(
+                                &task_id,
+                                CANCELLED_DOWNLOAD_MESSAGE.to_string(),
+                                None,
+                            );
+                        }
+                        let reason = err.failure_reason();
+                        return manager.mark_failure(&task_id, err.message, reason);
+                    }
+                }
             }
         });
+    }
+
+    /// 将 failed 中的任务原地重新开始（沿用 task_id），返回是否找到该任务。
+    fn restart_failed_task(
+        &self,
+        task_id: &str,
+        conflict_policy: Option<DownloadConflictPolicy>,
+    ) -> bool {
+        let mut state = recover_lock(&self.state);
+        let Some(position) = state.failed.iter().position(|t| t.task_id == task_id) else {
+            return false;
+        };
+        let mut task = state.failed.remove(position);
+        task.status = DownloadStatus::Queued;
+        task.started_at = current_timestamp_millis();
+        task.completed_at = None;
+        task.saved_path = None;
+        task.bytes_downloaded = Some(0);
+        task.error_message = None;
+        task.conflict_outcome = None;
+        task.retry_count = 0;
+        task.failure_reason = None;
+        task.hook_outcomes = Vec::new();
+        if let Some(policy) = conflict_policy {
+            task.conflict_policy = policy;
+        }
+        state.active.push(task.clone());
+        drop(state);
+        self.store.upsert(&task);
+        self.spawn_worker(task);
+        true
+    }
+
+    /// 开始写入前确认目标磁盘能容纳整个文件；大小未知时跳过检查。
+    fn preflight(&self, task_id: &str) -> Result<(), PreflightFailure> {
+        let target = {
+            let state = recover_lock(&self.state);
+            state
+                .active
+                .iter()
+                .find(|t| t.task_id == task_id)
+                .and_then(|t| Some((t.target_dir.clone(), t.size_label.or(t.item.size)?)))
+        };
+        match target {
+            Some((target_dir, size)) => check_local_space(&target_dir, size),
+            None => Ok(()),
+        }
+    }
+
+    /// 获得下载名额后将任务切换为进行中。
+    fn mark_started(&self, task_id: &str) {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return;
+        };
+        task.status = DownloadStatus::InProgress;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.sync_active_order();
+        self.emit_event(TransferEventKind::Started, &task);
+    }
+
+    /// 退避结束后重新排队，按任务当前优先级插入等待队列。
+    fn requeue(&self, task_id: &str) {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return;
+        };
+        task.status = DownloadStatus::Queued;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.scheduler.enqueue(task_id, task.priority);
+        self.sync_active_order();
+        self.emit_event(TransferEventKind::Queued, &task);
+    }
+
+    /// 按调度顺序整理 active：进行中的任务在前，等待中的任务按队列顺序排在其后。
+    fn sync_active_order(&self) {
+        let queued = self.scheduler.queued_ids();
+        let mut state = recover_lock(&self.state);
+        state
+            .active
+            .sort_by_key(|task| queued.iter().position(|id| *id == task.task_id));
+    }
+
+    /// 判断失败是否可自动重试；可重试时累加计数、写入提示并完成退避等待。
+    /// 返回 false 表示应当直接标记失败（永久失败、次数耗尽或等待中被取消）。
+    fn wait_for_retry(
+        &self,
+        task_id: &str,
+        err: &TransferError,
+        policy: &TransferRetryPolicy,
+        cancel_token: &AtomicBool,
+    ) -> bool {
+        if !err.is_transient() {
+            return false;
+        }
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return false;
+        };
+        if task.retry_count >= policy.max_retries {
+            return false;
+        }
+        task.retry_count += 1;
+        let delay = backoff_delay(policy, task.retry_count);
+        // 保留已下载的字节数：临时文件会留在磁盘上，重试时从该偏移用 Range 请求续传。
+        task.error_message = Some(format!(
+            "{err}（{} 秒后第 {} 次重试）",
+            delay.as_secs().max(1),
+            task.retry_count
+        ));
+        let task = task.clone();
+        drop(state);
+        eprintln!(
+            "[download-manager] retry {} for {task_id} in {:?}: {err}",
+            task.retry_count, delay
+        );
+        self.store.upsert(&task);
+        self.clear_progress_meter(task_id);
+        self.emit_progress_snapshot(&task, task.bytes_downloaded.unwrap_or(0), task.size_label);
+        self.emit_event(TransferEventKind::Retrying, &task);
+        wait_or_cancel(delay, cancel_token)
+    }
 
+    /// 将所有失败任务原地重新开始（等待冲突决定的任务除外），沿用各自的目标目录与冲突策略。
+    pub fn retry_failed_tasks(&self) -> Result<DownloadQueueState, String> {
+        let failed_ids: Vec<String> = {
+            let state = recover_lock(&self.state);
+            state
+                .failed
+                .iter()
+                .filter(|task| {
+                    task.conflict_outcome != Some(DownloadConflictOutcome::AwaitingDecision)
+                        && !state.active.iter().any(|active| {
+                            active.item.id == task.item.id && active.target_dir == task.target_dir
+                        })
+                })
+                .map(|task| task.task_id.clone())
+                .collect()
+        };
+        for task_id in failed_ids {
+            self.restart_failed_task(&task_id, None);
+        }
+        Ok(self.snapshot())
+    }
+
+    /// 对等待用户决定的冲突任务应用所选策略，并以同一 task_id 重新开始下载。
+    pub fn resolve_conflict(
+        &self,
+        task_id: &str,
+        policy: DownloadConflictPolicy,
+    ) -> Result<DownloadQueueState, String> {
+        if policy == DownloadConflictPolicy::Ask {
+            return Err("请选择具体的冲突处理方式".to_string());
+        }
+        let pending = {
+            let state = recover_lock(&self.state);
+            state.failed.iter().any(|task| {
+                task.task_id == task_id
+                    && task.conflict_outcome == Some(DownloadConflictOutcome::AwaitingDecision)
+            })
+        };
+        if !pending || !self.restart_failed_task(task_id, Some(policy)) {
+            return Err("未找到等待处理冲突的下载任务".to_string());
+        }
         Ok(self.snapshot())
     }
 
+    /// 执行用户配置的下载后动作；动作可能移动文件，因此会回写结果中的保存路径。
+    fn apply_post_download_hooks(
+        &self,
+        result: &mut DriveDownloadResult,
+    ) -> Vec<PostDownloadHookOutcome> {
+        if !should_run_hooks(&result.conflict_outcome) {
+            return Vec::new();
+        }
+        let hooks = get_post_download_hooks().unwrap_or_else(|err| {
+            eprintln!("[download-manager] failed to load post download hooks: {err}");
+            default_post_download_hooks()
+        });
+        if hooks.is_empty() {
+            return Vec::new();
+        }
+        let (saved_path, outcomes) = run_post_download_hooks(&result.saved_path, &hooks);
+        result.saved_path = saved_path;
+        outcomes
+    }
+
     /// 下载成功后迁移任务到 completed，并更新存储/推送终态事件。
-    fn mark_success(&self, item_id: &str, result: DriveDownloadResult) {
+    fn mark_success(
+        &self,
+        task_id: &str,
+        result: DriveDownloadResult,
+        hook_outcomes: Vec<PostDownloadHookOutcome>,
+    ) {
+        if result.conflict_outcome == DownloadConflictOutcome::AwaitingDecision {
+            self.mark_conflict_pending(task_id, result);
+            return;
+        }
         let mut state = match self.state.lock() {
             Ok(guard) => guard,
             Err(poison) => {
@@ -204,32 +498,65 @@ impl DownloadManager {
         };
 
         let mut updated_task = None;
-        if let Some(position) = state.active.iter().position(|t| t.item.id == item_id) {
+        if let Some(position) = state.active.iter().position(|t| t.task_id == task_id) {
             let mut task = state.active.remove(position);
             task.status = DownloadStatus::Completed;
-            task.completed_at = Some(current_timestamp());
+            task.completed_at = Some(current_timestamp_millis());
             task.saved_path = Some(result.saved_path.clone());
             task.size_label = task.size_label.or(result.expected_size);
             task.bytes_downloaded = Some(result.bytes_downloaded);
             task.error_message = None;
+            task.conflict_outcome = Some(result.conflict_outcome.clone());
+            task.hook_outcomes = hook_outcomes;
             state.completed.insert(0, task.clone());
             updated_task = Some(task);
         }
         drop(state);
         if let Some(task) = updated_task {
             self.store.upsert(&task);
-            self.clear_progress_meter(item_id);
+            self.clear_progress_meter(task_id);
             self.emit_progress_snapshot(
-                item_id,
+                &task,
                 task.bytes_downloaded.unwrap_or(result.bytes_downloaded),
                 task.size_label.or(result.expected_size),
             );
-            self.clear_cancel_token(item_id);
+            self.clear_cancel_token(task_id);
+            self.emit_event(TransferEventKind::Completed, &task);
         }
     }
 
-    /// 下载失败时迁移任务到 failed，并保留错误信息。
-    fn mark_failure(&self, item_id: &str, err_msg: String) {
+    /// 目标文件已存在且策略为 Ask：任务暂存到 failed，等待用户调用 `resolve_conflict`。
+    fn mark_conflict_pending(&self, task_id: &str, result: DriveDownloadResult) {
+        let mut state = recover_lock(&self.state);
+        let mut updated_task = None;
+        if let Some(position) = state.active.iter().position(|t| t.task_id == task_id) {
+            let mut task = state.active.remove(position);
+            task.status = DownloadStatus::Failed;
+            task.completed_at = Some(current_timestamp_millis());
+            task.saved_path = Some(result.saved_path);
+            task.size_label = task.size_label.or(result.expected_size);
+            task.error_message = Some(CONFLICT_PENDING_MESSAGE.to_string());
+            task.conflict_outcome = Some(DownloadConflictOutcome::AwaitingDecision);
+            state.failed.insert(0, task.clone());
+            updated_task = Some(task);
+        }
+        drop(state);
+        if let Some(task) = updated_task {
+            self.store.upsert(&task);
+            self.clear_progress_meter(task_id);
+            self.emit_progress_snapshot(&task, 0, task.size_label);
+            self.clear_cancel_token(task_id);
+            self.emit_event(TransferEventKind::AwaitingDecision, &task);
+        }
+    }
+
+    /// 下载失败时迁移任务到 failed，并保留错误信息；预检失败时同时记录失败类型。
+    fn mark_failure(
+        &self,
+        task_id: &str,
+        err_msg: String,
+        failure_reason: Option<TransferFailureReason>,
+    ) {
         let mut state = match self.state.lock() {
             Ok(guard) => guard,
             Err(poison) => {
@@ -239,63 +566,119 @@ impl DownloadManager {
         };
 
         let mut updated_task = None;
-        if let Some(position) = state.active.iter().position(|t| t.item.id == item_id) {
+        if let Some(position) = state.active.iter().position(|t| t.task_id == task_id) {
             let mut task = state.active.remove(position);
             task.status = DownloadStatus::Failed;
-            task.completed_at = Some(current_timestamp());
+            task.completed_at = Some(current_timestamp_millis());
             task.error_message = Some(err_msg.clone());
+            task.failure_reason = failure_reason;
             state.failed.insert(0, task.clone());
             updated_task = Some(task);
         } else {
-            state.failed.retain(|task| task.item.id != item_id);
+            state.failed.retain(|task| task.task_id != task_id);
         }
         drop(state);
         if let Some(task) = updated_task {
             self.store.upsert(&task);
-            self.clear_progress_meter(item_id);
-            self.emit_progress_snapshot(
-                item_id,
-                task.bytes_downloaded.unwrap_or(0),
-                task.size_label,
-            );
-            self.clear_cancel_token(item_id);
+            self.clear_progress_meter(task_id);
+            self.emit_progress_snapshot(&task, task.bytes_downloaded.unwrap_or(0), task.size_label);
+            self.clear_cancel_token(task_id);
+            let kind = if err_msg == CANCELLED_DOWNLOAD_MESSAGE {
+                TransferEventKind::Cancelled
+            } else {
+                TransferEventKind::Failed
+            };
+            self.emit_event(kind, &task);
         }
     }
 
     /// 移除任意状态的任务，用于用户手动清理条目。
-    pub fn remove(&self, item_id: &str) -> Result<DownloadQueueState, String> {
-        let _ = self.signal_cancel(item_id);
+    pub fn remove(&self, task_id: &str) -> Result<DownloadQueueState, String> {
+        let _ = self.signal_cancel(task_id);
         let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        state.active.retain(|task| task.item.id != item_id);
-        state.completed.retain(|task| task.item.id != item_id);
-        state.failed.retain(|task| task.item.id != item_id);
+        let removed = take_task(&mut state.active, task_id)
+            .or_else(|| take_task(&mut state.completed, task_id))
+            .or_else(|| take_task(&mut state.failed, task_id));
         let snapshot = (*state).clone();
         drop(state);
-        self.store.remove(item_id);
-        self.clear_progress_meter(item_id);
+        self.store.remove(task_id);
+        self.clear_progress_meter(task_id);
+        if let Some(task) = removed {
+            self.emit_event(TransferEventKind::Removed, &task);
+        }
         Ok(snapshot.into())
     }
 
     /// 清除 completed/failed 历史记录；active 队列保持不变。
     pub fn clear_history(&self) -> Result<DownloadQueueState, String> {
         let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        state.completed.clear();
-        state.failed.clear();
+        let mut removed = std::mem::take(&mut state.completed);
+        removed.append(&mut state.failed);
         let snapshot = (*state).clone();
         drop(state);
         self.store.clear_history();
         self.prune_inactive_trackers(&snapshot.active);
+        for task in &removed {
+            self.emit_event(TransferEventKind::Removed, task);
+        }
         Ok(snapshot.into())
     }
 
     /// 更新同时下载的最大数量，从设置项或用户调整处调用。
     pub fn update_concurrency_limit(&self, new_limit: usize) {
-        self.concurrency_guard.set_max(new_limit.max(1));
+        self.scheduler.set_max(new_limit.max(1));
+    }
+
+    /// 将等待中的任务前移一位。
+    pub fn move_up(&self, task_id: &str) -> Result<DownloadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_up)
+    }
+
+    /// 将等待中的任务后移一位。
+    pub fn move_down(&self, task_id: &str) -> Result<DownloadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_down)
+    }
+
+    /// 将等待中的任务移到队首，下一个空闲名额即分配给它。
+    pub fn move_to_top(&self, task_id: &str) -> Result<DownloadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_to_top)
+    }
+
+    fn reorder(
+        &self,
+        task_id: &str,
+        apply: fn(&TransferScheduler, &str) -> bool,
+    ) -> Result<DownloadQueueState, String> {
+        if !apply(&self.scheduler, task_id) {
+            return Err(NOT_QUEUED_MESSAGE.to_string());
+        }
+        self.sync_active_order();
+        Ok(self.snapshot())
+    }
+
+    /// 修改未结束任务的优先级：等待中的任务按新优先级重新排队，
+    /// 进行中的任务在下一次重试排队时生效。
+    pub fn set_priority(
+        &self,
+        task_id: &str,
+        priority: TransferPriority,
+    ) -> Result<DownloadQueueState, String> {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return Err("未找到对应的下载任务或任务已结束".to_string());
+        };
+        task.priority = priority;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.scheduler.set_priority(task_id, priority);
+        self.sync_active_order();
+        Ok(self.snapshot())
     }
 
     /// 标记指定任务为取消状态，下载线程会在下一次轮询时终止。
-    pub fn cancel(&self, item_id: &str) -> Result<DownloadQueueState, String> {
-        if self.signal_cancel(item_id) {
+    pub fn cancel(&self, task_id: &str) -> Result<DownloadQueueState, String> {
+        if self.signal_cancel(task_id) {
             Ok(self.snapshot())
         } else {
             Err("未找到对应的下载任务或任务已结束".to_string())
@@ -308,20 +691,28 @@ impl DownloadManager {
         if state.failed.is_empty() {
             return Ok((*state).clone().into());
         }
-        let failed_ids: Vec<String> = state
-            .failed
-            .iter()
-            .map(|task| task.item.id.clone())
-            .collect();
-        state.failed.clear();
+        let removed = std::mem::take(&mut state.failed);
         let snapshot = (*state).clone();
         drop(state);
-        for id in failed_ids {
-            self.store.remove(&id);
+        for task in &removed {
+            self.store.remove(&task.task_id);
+            self.emit_event(TransferEventKind::Removed, task);
         }
         Ok(snapshot.into())
     }
 
+    /// 已完成任务的本地保存路径；任务不存在、未完成或被跳过时返回错误。
+    pub fn completed_path(&self, task_id: &str) -> Result<std::path::PathBuf, String> {
+        let state = recover_lock(&self.state);
+        state
+            .completed
+            .iter()
+            .find(|task| task.task_id == task_id)
+            .and_then(|task| task.saved_path.clone())
+            .map(std::path::PathBuf::from)
+            .ok_or_else(|| "未找到已完成的下载任务".to_string())
+    }
+
     /// 返回当前状态的浅拷贝，供 FRB 直接转成 Dart 结构。
     pub fn snapshot(&self) -> DownloadQueueState {
         self.state
@@ -332,7 +723,7 @@ impl DownloadManager {
     }
 
     /// 下载线程调用的进度回调：更新内存+持久化，并向订阅者广播增量。
-    fn report_progress(&self, item_id: &str, bytes_downloaded: u64, expected_size: Option<u64>) {
+    fn report_progress(&self, task_id: &str, bytes_downloaded: u64, expected_size: Option<u64>) {
         let mut state = match self.state.lock() {
             Ok(guard) => guard,
             Err(poison) => {
@@ -342,7 +733,7 @@ impl DownloadManager {
         };
 
         let mut updated_task = None;
-        if let Some(task) = state.active.iter_mut().find(|t| t.item.id == item_id) {
+        if let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) {
             task.bytes_downloaded = Some(bytes_downloaded);
             if expected_size.is_some() {
                 task.size_label = expected_size;
@@ -351,35 +742,36 @@ impl DownloadManager {
         }
         drop(state);
         if let Some(task) = updated_task {
-            if self.should_persist_progress(item_id, bytes_downloaded) {
+            if self.should_persist_progress(task_id, bytes_downloaded) {
                 self.store.upsert(&task);
             }
-            self.emit_progress_snapshot(item_id, bytes_downloaded, task.size_label);
+            self.emit_progress_snapshot(&task, bytes_downloaded, task.size_label);
         }
     }
 
     fn emit_progress_snapshot(
         &self,
-        item_id: &str,
+        task: &DownloadTask,
         bytes_downloaded: u64,
         expected_size: Option<u64>,
     ) {
-        let speed = self.compute_speed_bps(item_id, bytes_downloaded);
+        let speed = self.compute_speed_bps(&task.task_id, bytes_downloaded);
         let update = DownloadProgressUpdate {
-            item_id: item_id.to_string(),
+            task_id: task.task_id.clone(),
+            item_id: task.item.id.clone(),
             bytes_downloaded,
             expected_size,
             speed_bps: speed,
-            timestamp_millis: current_timestamp(),
+            timestamp_millis: current_timestamp_millis(),
         };
         self.broadcast_update(update);
     }
 
-    fn compute_speed_bps(&self, item_id: &str, bytes_downloaded: u64) -> Option<f64> {
+    fn compute_speed_bps(&self, task_id: &str, bytes_downloaded: u64) -> Option<f64> {
         let now = Instant::now();
         let mut meters = recover_lock(&self.progress_meters);
         let entry = meters
-            .entry(item_id.to_string())
+            .entry(task_id.to_string())
             .or_insert_with(|| ProgressTick {
                 bytes_downloaded,
                 instant: now,
@@ -394,17 +786,17 @@ impl DownloadManager {
         Some(delta_bytes as f64 / elapsed.as_secs_f64())
     }
 
-    fn clear_progress_meter(&self, item_id: &str) {
+    fn clear_progress_meter(&self, task_id: &str) {
         let mut meters = recover_lock(&self.progress_meters);
-        meters.remove(item_id);
+        meters.remove(task_id);
         drop(meters);
-        self.clear_persist_marker(item_id);
+        self.clear_persist_marker(task_id);
     }
 
     fn prune_inactive_trackers(&self, active_tasks: &[DownloadTask]) {
         let active_ids: HashSet<String> = active_tasks
             .iter()
-            .map(|task| task.item.id.clone())
+            .map(|task| task.task_id.clone())
             .collect();
         let mut meters = recover_lock(&self.progress_meters);
         meters.retain(|id, _| active_ids.contains(id));
@@ -413,30 +805,32 @@ impl DownloadManager {
         markers.retain(|id, _| active_ids.contains(id));
     }
 
-    fn register_cancel_token(&self, item_id: &str, token: Arc<AtomicBool>) {
+    fn register_cancel_token(&self, task_id: &str, token: Arc<AtomicBool>) {
         let mut tokens = recover_lock(&self.cancel_tokens);
-        tokens.insert(item_id.to_string(), token);
+        tokens.insert(task_id.to_string(), token);
     }
 
-    fn clear_cancel_token(&self, item_id: &str) {
+    fn clear_cancel_token(&self, task_id: &str) {
         let mut tokens = recover_lock(&self.cancel_tokens);
-        tokens.remove(item_id);
+        tokens.remove(task_id);
     }
 
-    fn signal_cancel(&self, item_id: &str) -> bool {
+    fn signal_cancel(&self, task_id: &str) -> bool {
+        // 仍在排队的任务直接移出队列，等待中的线程会立即结束
+        self.scheduler.remove(task_id);
         let tokens = recover_lock(&self.cancel_tokens);
-        if let Some(token) = tokens.get(item_id) {
+        if let Some(token) = tokens.get(task_id) {
             token.store(true, Ordering::Relaxed);
             return true;
         }
         false
     }
 
-    fn should_persist_progress(&self, item_id: &str, bytes_downloaded: u64) -> bool {
+    fn should_persist_progress(&self, task_id: &str, bytes_downloaded: u64) -> bool {
         let now = Instant::now();
         let mut inserted = false;
         let mut markers = recover_lock(&self.persist_markers);
-        let entry = markers.entry(item_id.to_string()).or_insert_with(|| {
+        let entry = markers.entry(task_id.to_string()).or_insert_with(|| {
             inserted = true;
             PersistMarker {
                 bytes_downloaded,
@@ -460,9 +854,9 @@ impl DownloadManager {
         }
     }
 
-    fn clear_persist_marker(&self, item_id: &str) {
+    fn clear_persist_marker(&self, task_id: &str) {
         let mut markers = recover_lock(&self.persist_markers);
-        markers.remove(item_id);
+        markers.remove(task_id);
     }
 
     /// 提供一个新的 channel 接收器，用于持续消费进度事件。
@@ -477,17 +871,50 @@ impl DownloadManager {
         for task in &state.active {
             if let Some(bytes) = task.bytes_downloaded {
                 let _ = tx.try_send(DownloadProgressUpdate {
+                    task_id: task.task_id.clone(),
                     item_id: task.item.id.clone(),
                     bytes_downloaded: bytes,
                     expected_size: task.size_label,
                     speed_bps: None,
-                    timestamp_millis: current_timestamp(),
+                    timestamp_millis: current_timestamp_millis(),
                 });
             }
         }
         rx
     }
 
+    /// 订阅任务生命周期事件（排队、开始、重试、完成、失败、取消、移除）。
+    pub fn subscribe_events(&self) -> Receiver<DownloadTaskEvent> {
+        let (tx, rx) = mpsc::sync_channel(EVENT_CHANNEL_CAP);
+        recover_lock(&self.event_subscribers).push(tx);
+        rx
+    }
+
+    fn emit_event(&self, kind: TransferEventKind, task: &DownloadTask) {
+        let queue_drained = recover_lock(&self.state).active.is_empty();
+        NotificationCenter::shared().record(
+            TransferDirection::Download,
+            kind,
+            &task.item.name,
+            task.error_message.as_deref(),
+            queue_drained,
+        );
+        let event = DownloadTaskEvent {
+            kind,
+            task: task.clone(),
+            timestamp_millis: current_timestamp_millis(),
+        };
+        let mut subs = recover_lock(&self.event_subscribers);
+        subs.retain_mut(|sender| match sender.try_send(event.clone()) {
+            Ok(_) => true,
+            Err(TrySendError::Full(_)) => {
+                eprintln!("[download-manager] event subscriber is lagging; dropping event");
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+    }
+
     fn broadcast_update(&self, update: DownloadProgressUpdate) {
         let mut subs = recover_lock(&self.subscribers);
         subs.retain_mut(|sender| match sender.try_send(update.clone()) {
@@ -501,76 +928,10 @@ impl DownloadManager {
     }
 }
 
-fn recover_lock<'a, T>(mutex: &'a Mutex<T>) -> MutexGuard<'a, T> {
-    match mutex.lock() {
-        Ok(guard) => guard,
-        // 遇到中毒锁时直接取出内部数据继续运行，避免整个下载管理器瘫痪
-        Err(poison) => poison.into_inner(),
-    }
-}
-
-/// 简易信号量，用 Mutex+Condvar 实现固定并发控制。
-struct Semaphore {
-    state: Mutex<SemaphoreState>,
-    cvar: Condvar,
-}
-
-struct SemaphoreState {
-    available: usize,
-    max: usize,
-}
-
-impl Semaphore {
-    fn new(max: usize) -> Self {
-        Self {
-            state: Mutex::new(SemaphoreState {
-                available: max,
-                max,
-            }),
-            cvar: Condvar::new(),
-        }
-    }
-
-    /// 获取一个许可，必要时阻塞至有空闲。
-    fn acquire(&self) -> SemaphorePermit<'_> {
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        while state.available == 0 {
-            state = self.cvar.wait(state).unwrap_or_else(|p| p.into_inner());
-        }
-        state.available -= 1;
-        SemaphorePermit { semaphore: self }
-    }
-
-    fn release(&self) {
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if state.available < state.max {
-            state.available += 1;
-            self.cvar.notify_one();
-        }
-    }
-
-    fn set_max(&self, new_max: usize) {
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        let in_use = state.max.saturating_sub(state.available);
-        state.max = new_max;
-        state.available = if new_max > in_use {
-            new_max - in_use
-        } else {
-            0
-        };
-        self.cvar.notify_all();
-    }
-}
-
-/// RAII 许可，Drop 时自动释放。
-struct SemaphorePermit<'a> {
-    semaphore: &'a Semaphore,
-}
-
-impl<'a> Drop for SemaphorePermit<'a> {
-    fn drop(&mut self) {
-        self.semaphore.release();
-    }
+/// 从列表中取出指定任务，返回被移除的任务。
+fn take_task(tasks: &mut Vec<DownloadTask>, task_id: &str) -> Option<DownloadTask> {
+    let position = tasks.iter().position(|task| task.task_id == task_id)?;
+    Some(tasks.remove(position))
 }
 
 impl From<InnerState> for DownloadQueueState {
@@ -583,13 +944,6 @@ impl From<InnerState> for DownloadQueueState {
     }
 }
 
-fn current_timestamp() -> i64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_millis() as i64)
-        .unwrap_or_default()
-}
-
 pub fn download_queue_state() -> DownloadQueueState {
     DownloadManager::shared().snapshot()
 }
@@ -597,17 +951,25 @@ pub fn download_queue_state() -> DownloadQueueState {
 pub fn enqueue_download_task(
     item: DriveItemSummary,
     target_dir: String,
-    overwrite: bool,
+    conflict_policy: Option<DownloadConflictPolicy>,
+    priority: Option<TransferPriority>,
+) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().enqueue(item, target_dir, conflict_policy, priority)
+}
+
+pub fn resolve_download_conflict(
+    task_id: &str,
+    policy: DownloadConflictPolicy,
 ) -> Result<DownloadQueueState, String> {
-    DownloadManager::shared().enqueue(item, target_dir, overwrite)
+    DownloadManager::shared().resolve_conflict(task_id, policy)
 }
 
-pub fn remove_download_task(item_id: &str) -> Result<DownloadQueueState, String> {
-    DownloadManager::shared().remove(item_id)
+pub fn remove_download_task(task_id: &str) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().remove(task_id)
 }
 
-pub fn cancel_download_task(item_id: &str) -> Result<DownloadQueueState, String> {
-    DownloadManager::shared().cancel(item_id)
+pub fn cancel_download_task(task_id: &str) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().cancel(task_id)
 }
 
 pub fn clear_download_history() -> Result<DownloadQueueState, String> {
@@ -618,6 +980,43 @@ pub fn clear_failed_download_tasks() -> Result<DownloadQueueState, String> {
     DownloadManager::shared().clear_failed_tasks()
 }
 
+pub fn retry_failed_download_tasks() -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().retry_failed_tasks()
+}
+
+pub fn move_download_task_up(task_id: &str) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().move_up(task_id)
+}
+
+pub fn move_download_task_down(task_id: &str) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().move_down(task_id)
+}
+
+pub fn move_download_task_to_top(task_id: &str) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().move_to_top(task_id)
+}
+
+pub fn set_download_task_priority(
+    task_id: &str,
+    priority: TransferPriority,
+) -> Result<DownloadQueueState, String> {
+    DownloadManager::shared().set_priority(task_id, priority)
+}
+
 pub fn subscribe_progress() -> Receiver<DownloadProgressUpdate> {
     DownloadManager::shared().subscribe_progress()
 }
+
+pub fn subscribe_events() -> Receiver<DownloadTaskEvent> {
+    DownloadManager::shared().subscribe_events()
+}
+
+/// 使用系统默认程序打开已下载的文件。
+pub fn open_downloaded_file(task_id: &str) -> Result<(), String> {
+    open_path(&DownloadManager::shared().completed_path(task_id)?)
+}
+
+/// 在文件管理器中定位已下载的文件。
+pub fn reveal_downloaded_file(task_id: &str) -> Result<(), String> {
+    reveal_path(&DownloadManager::shared().completed_path(task_id)?)
+}
@@ -3,7 +3,9 @@ pub mod storage;
 
 pub use core::{
     cancel_download_task, clear_download_history, clear_failed_download_tasks,
-    download_queue_state, enqueue_download_task, remove_download_task, subscribe_progress,
-    DownloadManager,
+    download_queue_state, enqueue_download_task, move_download_task_down,
+    move_download_task_to_top, move_download_task_up, open_downloaded_file, remove_download_task,
+    resolve_download_conflict, retry_failed_download_tasks, reveal_downloaded_file,
+    set_download_task_priority, subscribe_events, subscribe_progress, DownloadManager,
 };
 pub use storage::{DownloadStore, SqliteDownloadStore};
@@ -1,14 +1,19 @@
-use crate::api::drive::models::{DownloadStatus, DownloadTask, DriveItemSummary};
+use crate::api::drive::models::{
+    DownloadConflictOutcome, DownloadConflictPolicy, DownloadStatus, DownloadTask,
+    DriveItemSummary, PostDownloadHookKind, PostDownloadHookOutcome, TransferFailureReason,
+    TransferPriority,
+};
 use crate::db::{
     clear_finished_download_tasks, delete_download_task, load_download_tasks, upsert_download_task,
     DownloadTaskRecord,
 };
+use serde::{Deserialize, Serialize};
 
 /// 定义持久化接口，方便未来替换存储实现或编写单测。
 pub trait DownloadStore: Send + Sync {
     fn load(&self) -> Vec<DownloadTask>;
     fn upsert(&self, task: &DownloadTask);
-    fn remove(&self, item_id: &str);
+    fn remove(&self, task_id: &str);
     fn clear_history(&self);
 }
 
@@ -41,21 +46,25 @@ impl DownloadStore for SqliteDownloadStore {
         if let Err(err) = upsert_download_task(&record_from_task(task)) {
             eprintln!(
                 "[download-store] failed to upsert task {}: {err}",
-                task.item.id
+                task.task_id
             );
         }
     }
 
     /// 删除任意状态的任务记录。
-    fn remove(&self, item_id: &str) {
-        if let Err(err) = delete_download_task(item_id) {
-            eprintln!("[download-store] failed to delete task {item_id}: {err}");
+    fn remove(&self, task_id: &str) {
+        if let Err(err) = delete_download_task(task_id) {
+            eprintln!("[download-store] failed to delete task {task_id}: {err}");
         }
     }
 
     /// 清理历史记录，保留 active 船票给上层状态机使用。
     fn clear_history(&self) {
-        if let Err(err) = clear_finished_download_tasks(DownloadStatus::InProgress as i64) {
+        let active_statuses = [
+            status_to_i64(&DownloadStatus::InProgress),
+            status_to_i64(&DownloadStatus::Queued),
+        ];
+        if let Err(err) = clear_finished_download_tasks(&active_statuses) {
             eprintln!("[download-store] failed to clear download history: {err}");
         }
     }
@@ -64,6 +73,7 @@ impl DownloadStore for SqliteDownloadStore {
 /// 将运行时任务转换成数据库记录；统一在此处理类型与符号转换。
 fn record_from_task(task: &DownloadTask) -> DownloadTaskRecord {
     DownloadTaskRecord {
+        task_id: task.task_id.clone(),
         item_id: task.item.id.clone(),
         item_name: task.item.name.clone(),
         size: task.item.size.and_then(|v| v.try_into().ok()),
@@ -80,11 +90,28 @@ fn record_from_task(task: &DownloadTask) -> DownloadTaskRecord {
         bytes_downloaded: task.bytes_downloaded.and_then(|v| v.try_into().ok()),
         error_message: task.error_message.clone(),
         updated_at_millis: crate::db::current_timestamp_millis(),
+        target_dir: Some(task.target_dir.clone()),
+        conflict_policy: Some(policy_to_i64(&task.conflict_policy)),
+        conflict_outcome: task.conflict_outcome.as_ref().map(outcome_to_i64),
+        retry_count: Some(task.retry_count as i64),
+        priority: Some(priority_to_i64(task.priority)),
+        failure_reason: task.failure_reason.map(failure_reason_to_i64),
+        hook_outcomes: encode_hook_outcomes(&task.hook_outcomes),
     }
 }
 
 fn task_from_record(record: DownloadTaskRecord) -> DownloadTask {
+    // 旧版本记录没有 target_dir，尽量从保存路径推断所在目录。
+    let target_dir = record.target_dir.clone().unwrap_or_else(|| {
+        record
+            .saved_path
+            .as_deref()
+            .and_then(|path| std::path::Path::new(path).parent())
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
     DownloadTask {
+        task_id: record.task_id,
         item: DriveItemSummary {
             id: record.item_id,
             name: record.item_name,
@@ -112,6 +139,23 @@ fn task_from_record(record: DownloadTaskRecord) -> DownloadTask {
             }
         }),
         error_message: record.error_message,
+        target_dir,
+        conflict_policy: record
+            .conflict_policy
+            .map(policy_from_i64)
+            .unwrap_or(DownloadConflictPolicy::Ask),
+        conflict_outcome: record.conflict_outcome.map(outcome_from_i64),
+        retry_count: record
+            .retry_count
+            .and_then(|v| v.try_into().ok())
+            .unwrap_or(0),
+        priority: record.priority.map(priority_from_i64).unwrap_or_default(),
+        failure_reason: record.failure_reason.and_then(failure_reason_from_i64),
+        hook_outcomes: record
+            .hook_outcomes
+            .as_deref()
+            .map(decode_hook_outcomes)
+            .unwrap_or_default(),
     }
 }
 
@@ -120,6 +164,7 @@ fn status_to_i64(status: &DownloadStatus) -> i64 {
         DownloadStatus::InProgress => 0,
         DownloadStatus::Completed => 1,
         DownloadStatus::Failed => 2,
+        DownloadStatus::Queued => 3,
     }
 }
 
@@ -127,6 +172,145 @@ fn status_from_i64(value: i64) -> DownloadStatus {
     match value {
         1 => DownloadStatus::Completed,
         2 => DownloadStatus::Failed,
+        3 => DownloadStatus::Queued,
         _ => DownloadStatus::InProgress,
     }
 }
+
+fn policy_to_i64(policy: &DownloadConflictPolicy) -> i64 {
+    match policy {
+        DownloadConflictPolicy::Overwrite => 0,
+        DownloadConflictPolicy::Skip => 1,
+        DownloadConflictPolicy::KeepBoth => 2,
+        DownloadConflictPolicy::OverwriteIfNewer => 3,
+        DownloadConflictPolicy::Ask => 4,
+    }
+}
+
+fn policy_from_i64(value: i64) -> DownloadConflictPolicy {
+    match value {
+        0 => DownloadConflictPolicy::Overwrite,
+        1 => DownloadConflictPolicy::Skip,
+        2 => DownloadConflictPolicy::KeepBoth,
+        3 => DownloadConflictPolicy::OverwriteIfNewer,
+        _ => DownloadConflictPolicy::Ask,
+    }
+}
+
+fn outcome_to_i64(outcome: &DownloadConflictOutcome) -> i64 {
+    match outcome {
+        DownloadConflictOutcome::NoConflict => 0,
+        DownloadConflictOutcome::Overwritten => 1,
+        DownloadConflictOutcome::Skipped => 2,
+        DownloadConflictOutcome::KeptBoth => 3,
+        DownloadConflictOutcome::AwaitingDecision => 4,
+    }
+}
+
+fn outcome_from_i64(value: i64) -> DownloadConflictOutcome {
+    match value {
+        1 => DownloadConflictOutcome::Overwritten,
+        2 => DownloadConflictOutcome::Skipped,
+        3 => DownloadConflictOutcome::KeptBoth,
+        4 => DownloadConflictOutcome::AwaitingDecision,
+        _ => DownloadConflictOutcome::NoConflict,
+    }
+}
+
+fn priority_to_i64(priority: TransferPriority) -> i64 {
+    match priority {
+        TransferPriority::Low => 0,
+        TransferPriority::Normal => 1,
+        TransferPriority::High => 2,
+    }
+}
+
+fn priority_from_i64(value: i64) -> TransferPriority {
+    match value {
+        0 => TransferPriority::Low,
+        2 => TransferPriority::High,
+        _ => TransferPriority::Normal,
+    }
+}
+
+fn failure_reason_to_i64(reason: TransferFailureReason) -> i64 {
+    match reason {
+        TransferFailureReason::InsufficientDiskSpace => 0,
+        TransferFailureReason::InsufficientQuota => 1,
+        TransferFailureReason::IntegrityMismatch => 2,
+        TransferFailureReason::SourceChanged => 3,
+        TransferFailureReason::AccessDenied => 4,
+        TransferFailureReason::NotFound => 5,
+        TransferFailureReason::RetriesExhausted => 6,
+    }
+}
+
+fn failure_reason_from_i64(value: i64) -> Option<TransferFailureReason> {
+    match value {
+        0 => Some(TransferFailureReason::InsufficientDiskSpace),
+        1 => Some(TransferFailureReason::InsufficientQuota),
+        2 => Some(TransferFailureReason::IntegrityMismatch),
+        3 => Some(TransferFailureReason::SourceChanged),
+        4 => Some(TransferFailureReason::AccessDenied),
+        5 => Some(TransferFailureReason::NotFound),
+        6 => Some(TransferFailureReason::RetriesExhausted),
+        _ => None,
+    }
+}
+
+/// 动作结果的持久化形式；消息可能包含任意字符，因此使用 JSON 而非分隔符拼接。
+#[derive(Serialize, Deserialize)]
+struct HookOutcomeRecord {
+    kind: i64,
+    success: bool,
+    message: String,
+}
+
+fn encode_hook_outcomes(outcomes: &[PostDownloadHookOutcome]) -> Option<String> {
+    if outcomes.is_empty() {
+        return None;
+    }
+    let records: Vec<HookOutcomeRecord> = outcomes
+        .iter()
+        .map(|outcome| HookOutcomeRecord {
+            kind: hook_kind_to_i64(outcome.kind),
+            success: outcome.success,
+            message: outcome.message.clone(),
+        })
+        .collect();
+    serde_json::to_string(&records).ok()
+}
+
+fn decode_hook_outcomes(raw: &str) -> Vec<PostDownloadHookOutcome> {
+    let records: Vec<HookOutcomeRecord> = serde_json::from_str(raw).unwrap_or_else(|err| {
+        eprintln!("[download-store] failed to parse hook outcomes: {err}");
+        Vec::new()
+    });
+    records
+        .into_iter()
+        .filter_map(|record| {
+            Some(PostDownloadHookOutcome {
+                kind: hook_kind_from_i64(record.kind)?,
+                success: record.success,
+                message: record.message,
+            })
+        })
+        .collect()
+}
+
+fn hook_kind_to_i64(kind: PostDownloadHookKind) -> i64 {
+    match kind {
+        PostDownloadHookKind::RunCommand => 0,
+        PostDownloadHookKind::ExtractArchive => 1,
+        PostDownloadHookKind::MoveByType => 2,
+    }
+}
+
+fn hook_kind_from_i64(value: i64) -> Option<PostDownloadHookKind> {
+    match value {
+        0 => Some(PostDownloadHookKind::RunCommand),
+        1 => Some(PostDownloadHookKind::ExtractArchive),
+        2 => Some(PostDownloadHookKind::MoveByType),
+        _ => None,
+    }
+}
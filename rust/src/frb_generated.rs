@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1430268093;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 2032451979;
 
 // Section: executor
 
@@ -82,7 +82,7 @@ fn wire__crate__api__auth__auth__authenticate_via_browser_impl(
         },
     )
 }
-fn wire__crate__api__drive__download_manager__cancel_download_task_impl(
+fn wire__crate__api__drive__models__bandwidth_settings_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -90,7 +90,7 @@ fn wire__crate__api__drive__download_manager__cancel_download_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cancel_download_task",
+            debug_name: "bandwidth_settings_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -104,19 +104,19 @@ fn wire__crate__api__drive__download_manager__cancel_download_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::drive::download_manager::cancel_download_task(api_item_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::models::BandwidthSettings::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__cancel_upload_task_impl(
+fn wire__crate__api__drive__download_manager__cancel_download_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -124,7 +124,7 @@ fn wire__crate__api__drive__upload_manager__cancel_upload_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cancel_upload_task",
+            debug_name: "cancel_download_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -143,14 +143,14 @@ fn wire__crate__api__drive__upload_manager__cancel_upload_task_impl(
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::drive::upload_manager::cancel_upload_task(api_task_id)?;
+                        crate::api::drive::download_manager::cancel_download_task(api_task_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__clear_download_history_impl(
+fn wire__crate__api__drive__upload_manager__cancel_upload_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -158,7 +158,7 @@ fn wire__crate__api__drive__download_manager__clear_download_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_download_history",
+            debug_name: "cancel_upload_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -172,17 +172,19 @@ fn wire__crate__api__drive__download_manager__clear_download_history_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::download_manager::clear_download_history()?;
+                    let output_ok =
+                        crate::api::drive::upload_manager::cancel_upload_group(api_group_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(
+fn wire__crate__api__drive__upload_manager__cancel_upload_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -190,7 +192,7 @@ fn wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_failed_download_tasks",
+            debug_name: "cancel_upload_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -204,18 +206,19 @@ fn wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::drive::download_manager::clear_failed_download_tasks()?;
+                        crate::api::drive::upload_manager::cancel_upload_task(api_task_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(
+fn wire__crate__api__drive__download_manager__clear_download_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -223,7 +226,7 @@ fn wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_failed_upload_tasks",
+            debug_name: "clear_download_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -240,14 +243,14 @@ fn wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::upload_manager::clear_failed_upload_tasks()?;
+                    let output_ok = crate::api::drive::download_manager::clear_download_history()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__auth__auth__clear_persisted_auth_state_impl(
+fn wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -255,7 +258,7 @@ fn wire__crate__api__auth__auth__clear_persisted_auth_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_persisted_auth_state",
+            debug_name: "clear_failed_download_tasks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -272,14 +275,15 @@ fn wire__crate__api__auth__auth__clear_persisted_auth_state_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::auth::auth::clear_persisted_auth_state()?;
+                    let output_ok =
+                        crate::api::drive::download_manager::clear_failed_download_tasks()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__clear_upload_history_impl(
+fn wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -287,7 +291,7 @@ fn wire__crate__api__drive__upload_manager__clear_upload_history_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_upload_history",
+            debug_name: "clear_failed_upload_tasks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -304,14 +308,14 @@ fn wire__crate__api__drive__upload_manager__clear_upload_history_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::upload_manager::clear_upload_history()?;
+                    let output_ok = crate::api::drive::upload_manager::clear_failed_upload_tasks()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__share__create_share_link_impl(
+fn wire__crate__api__drive__copy_manager__clear_finished_copy_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -319,7 +323,7 @@ fn wire__crate__api__drive__share__create_share_link_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_share_link",
+            debug_name: "clear_finished_copy_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -333,25 +337,11 @@ fn wire__crate__api__drive__share__create_share_link_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
-            let api_link_type =
-                <crate::api::drive::models::LinkType>::sse_decode(&mut deserializer);
-            let api_scope = <crate::api::drive::models::LinkScope>::sse_decode(&mut deserializer);
-            let api_password = <Option<String>>::sse_decode(&mut deserializer);
-            let api_expiration_date_time = <Option<String>>::sse_decode(&mut deserializer);
-            let api_retain_inherited_permissions = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_recipients = <Option<Vec<String>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::share::create_share_link(
-                        api_item_id,
-                        api_link_type,
-                        api_scope,
-                        api_password,
-                        api_expiration_date_time,
-                        api_retain_inherited_permissions,
-                        api_recipients,
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::copy_manager::clear_finished_copy_jobs(),
                     )?;
                     Ok(output_ok)
                 })())
@@ -359,7 +349,7 @@ fn wire__crate__api__drive__share__create_share_link_impl(
         },
     )
 }
-fn wire__crate__api__drive__delete__delete_drive_item_impl(
+fn wire__crate__api__auth__auth__clear_persisted_auth_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -367,7 +357,7 @@ fn wire__crate__api__drive__delete__delete_drive_item_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_drive_item",
+            debug_name: "clear_persisted_auth_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -381,24 +371,17 @@ fn wire__crate__api__drive__delete__delete_drive_item_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
-            let api_if_match = <Option<String>>::sse_decode(&mut deserializer);
-            let api_bypass_locks = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::delete::delete_drive_item(
-                        api_item_id,
-                        api_if_match,
-                        api_bypass_locks,
-                    )?;
+                    let output_ok = crate::api::auth::auth::clear_persisted_auth_state()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download__download_drive_item_impl(
+fn wire__crate__api__drive__upload_manager__clear_upload_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -406,7 +389,7 @@ fn wire__crate__api__drive__download__download_drive_item_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_drive_item",
+            debug_name: "clear_upload_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -420,24 +403,17 @@ fn wire__crate__api__drive__download__download_drive_item_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
-            let api_target_dir = <String>::sse_decode(&mut deserializer);
-            let api_overwrite = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::download::download_drive_item(
-                        api_item_id,
-                        api_target_dir,
-                        api_overwrite,
-                    )?;
+                    let output_ok = crate::api::drive::upload_manager::clear_upload_history()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__download_progress_stream_impl(
+fn wire__crate__api__drive__copy_manager__copy_drive_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -445,7 +421,7 @@ fn wire__crate__api__drive__download_manager__download_progress_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_progress_stream",
+            debug_name: "copy_drive_item",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -459,25 +435,26 @@ fn wire__crate__api__drive__download_manager__download_progress_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stream_sink = <StreamSink<
-                crate::api::drive::models::DownloadProgressUpdate,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_target_drive_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_target_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_new_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::drive::download_manager::download_progress_stream(
-                            api_stream_sink,
-                        );
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::copy_manager::copy_drive_item(
+                        api_item_id,
+                        api_target_drive_id,
+                        api_target_parent_id,
+                        api_new_name,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__download_queue_state_impl(
+fn wire__crate__api__drive__copy_manager__copy_job_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -485,7 +462,7 @@ fn wire__crate__api__drive__download_manager__download_queue_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_queue_state",
+            debug_name: "copy_job_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -499,19 +476,23 @@ fn wire__crate__api__drive__download_manager__download_queue_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_sink = <StreamSink<
+                crate::api::drive::models::CopyJob,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::drive::download_manager::download_queue_state(),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::drive::copy_manager::copy_job_stream(api_stream_sink);
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__models__download_queue_state_default_impl(
+fn wire__crate__api__drive__copy_manager__copy_jobs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -519,7 +500,7 @@ fn wire__crate__api__drive__models__download_queue_state_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_queue_state_default",
+            debug_name: "copy_jobs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -536,16 +517,15 @@ fn wire__crate__api__drive__models__download_queue_state_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::drive::models::DownloadQueueState::default(),
-                    )?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::drive::copy_manager::copy_jobs())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__enqueue_download_task_impl(
+fn wire__crate__api__drive__create_folder__create_drive_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -553,7 +533,7 @@ fn wire__crate__api__drive__download_manager__enqueue_download_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "enqueue_download_task",
+            debug_name: "create_drive_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -567,17 +547,17 @@ fn wire__crate__api__drive__download_manager__enqueue_download_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item =
-                <crate::api::drive::models::DriveItemSummary>::sse_decode(&mut deserializer);
-            let api_target_dir = <String>::sse_decode(&mut deserializer);
-            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_conflict_behavior =
+                <crate::api::drive::models::FolderConflictBehavior>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::download_manager::enqueue_download_task(
-                        api_item,
-                        api_target_dir,
-                        api_overwrite,
+                    let output_ok = crate::api::drive::create_folder::create_drive_folder(
+                        api_parent_id,
+                        api_name,
+                        api_conflict_behavior,
                     )?;
                     Ok(output_ok)
                 })())
@@ -585,7 +565,7 @@ fn wire__crate__api__drive__download_manager__enqueue_download_task_impl(
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
+fn wire__crate__api__drive__share__create_share_link_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -593,7 +573,7 @@ fn wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "enqueue_large_upload_task",
+            debug_name: "create_share_link",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -607,18 +587,25 @@ fn wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_file_name = <String>::sse_decode(&mut deserializer);
-            let api_local_path = <String>::sse_decode(&mut deserializer);
-            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_link_type =
+                <crate::api::drive::models::LinkType>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::drive::models::LinkScope>::sse_decode(&mut deserializer);
+            let api_password = <Option<String>>::sse_decode(&mut deserializer);
+            let api_expiration_date_time = <Option<String>>::sse_decode(&mut deserializer);
+            let api_retain_inherited_permissions = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_recipients = <Option<Vec<String>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::upload_manager::enqueue_large_upload_task(
-                        api_parent_id,
-                        api_file_name,
-                        api_local_path,
-                        api_overwrite,
+                    let output_ok = crate::api::drive::share::create_share_link(
+                        api_item_id,
+                        api_link_type,
+                        api_scope,
+                        api_password,
+                        api_expiration_date_time,
+                        api_retain_inherited_permissions,
+                        api_recipients,
                     )?;
                     Ok(output_ok)
                 })())
@@ -626,7 +613,7 @@ fn wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
+fn wire__crate__api__drive__delete__delete_drive_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -634,7 +621,7 @@ fn wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "enqueue_upload_task",
+            debug_name: "delete_drive_item",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -648,20 +635,16 @@ fn wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_file_name = <String>::sse_decode(&mut deserializer);
-            let api_local_path = <String>::sse_decode(&mut deserializer);
-            let api_content = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_if_match = <Option<String>>::sse_decode(&mut deserializer);
+            let api_bypass_locks = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::upload_manager::enqueue_upload_task(
-                        api_parent_id,
-                        api_file_name,
-                        api_local_path,
-                        api_content,
-                        api_overwrite,
+                    let output_ok = crate::api::drive::delete::delete_drive_item(
+                        api_item_id,
+                        api_if_match,
+                        api_bypass_locks,
                     )?;
                     Ok(output_ok)
                 })())
@@ -669,7 +652,7 @@ fn wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
         },
     )
 }
-fn wire__crate__api__settings__download_concurrency__get_download_concurrency_impl(
+fn wire__crate__api__drive__download__download_drive_item_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -677,7 +660,7 @@ fn wire__crate__api__settings__download_concurrency__get_download_concurrency_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_download_concurrency",
+            debug_name: "download_drive_item",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -691,18 +674,24 @@ fn wire__crate__api__settings__download_concurrency__get_download_concurrency_im
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_target_dir = <String>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::settings::download_concurrency::get_download_concurrency()?;
+                    let output_ok = crate::api::drive::download::download_drive_item(
+                        api_item_id,
+                        api_target_dir,
+                        api_overwrite,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__settings__download_directory__get_download_directory_impl(
+fn wire__crate__api__drive__download_manager__download_progress_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -710,7 +699,7 @@ fn wire__crate__api__settings__download_directory__get_download_directory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_download_directory",
+            debug_name: "download_progress_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -724,18 +713,25 @@ fn wire__crate__api__settings__download_directory__get_download_directory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_sink = <StreamSink<
+                crate::api::drive::models::DownloadProgressUpdate,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::settings::download_directory::get_download_directory()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::drive::download_manager::download_progress_stream(
+                            api_stream_sink,
+                        );
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__details__get_drive_item_details_impl(
+fn wire__crate__api__drive__download_manager__download_queue_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -743,7 +739,7 @@ fn wire__crate__api__drive__details__get_drive_item_details_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_drive_item_details",
+            debug_name: "download_queue_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -757,19 +753,19 @@ fn wire__crate__api__drive__details__get_drive_item_details_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::drive::details::get_drive_item_details(api_item_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::download_manager::download_queue_state(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__info__get_drive_overview_impl(
+fn wire__crate__api__drive__models__download_queue_state_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -777,7 +773,7 @@ fn wire__crate__api__drive__info__get_drive_overview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_drive_overview",
+            debug_name: "download_queue_state_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -793,15 +789,17 @@ fn wire__crate__api__drive__info__get_drive_overview_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::info::get_drive_overview()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::models::DownloadQueueState::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__share__get_share_capabilities_impl(
+fn wire__crate__api__drive__download_manager__download_task_event_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -809,7 +807,7 @@ fn wire__crate__api__drive__share__get_share_capabilities_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_share_capabilities",
+            debug_name: "download_task_event_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -823,26 +821,35 @@ fn wire__crate__api__drive__share__get_share_capabilities_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_sink = <StreamSink<
+                crate::api::drive::models::DownloadTaskEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::share::get_share_capabilities()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::drive::download_manager::download_task_event_stream(
+                            api_stream_sink,
+                        );
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__drive__download_manager__enqueue_download_task_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "enqueue_download_task",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -854,16 +861,32 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_item =
+                <crate::api::drive::models::DriveItemSummary>::sse_decode(&mut deserializer);
+            let api_target_dir = <String>::sse_decode(&mut deserializer);
+            let api_conflict_policy =
+                <Option<crate::api::drive::models::DownloadConflictPolicy>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_priority = <Option<crate::api::drive::models::TransferPriority>>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::download_manager::enqueue_download_task(
+                        api_item,
+                        api_target_dir,
+                        api_conflict_policy,
+                        api_priority,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -871,7 +894,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "enqueue_large_upload_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -885,19 +908,35 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            let api_local_path = <String>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            let api_priority = <Option<crate::api::drive::models::TransferPriority>>::sse_decode(
+                &mut deserializer,
+            );
+            let api_conflict_behavior =
+                <Option<crate::api::drive::models::UploadConflictBehavior>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::upload_manager::enqueue_large_upload_task(
+                        api_parent_id,
+                        api_file_name,
+                        api_local_path,
+                        api_overwrite,
+                        api_priority,
+                        api_conflict_behavior,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__list__list_drive_children_impl(
+fn wire__crate__api__drive__upload_manager__enqueue_upload_folder_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -905,7 +944,7 @@ fn wire__crate__api__drive__list__list_drive_children_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_drive_children",
+            debug_name: "enqueue_upload_folder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -919,16 +958,27 @@ fn wire__crate__api__drive__list__list_drive_children_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_folder_path = <Option<String>>::sse_decode(&mut deserializer);
-            let api_next_link = <Option<String>>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_local_dir = <String>::sse_decode(&mut deserializer);
+            let api_include = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_exclude = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_conflict_behavior =
+                <Option<crate::api::drive::models::UploadConflictBehavior>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_priority = <Option<crate::api::drive::models::TransferPriority>>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::list::list_drive_children(
-                        api_folder_id,
-                        api_folder_path,
-                        api_next_link,
+                    let output_ok = crate::api::drive::upload_manager::enqueue_upload_folder(
+                        api_parent_id,
+                        api_local_dir,
+                        api_include,
+                        api_exclude,
+                        api_conflict_behavior,
+                        api_priority,
                     )?;
                     Ok(output_ok)
                 })())
@@ -936,7 +986,7 @@ fn wire__crate__api__drive__list__list_drive_children_impl(
         },
     )
 }
-fn wire__crate__api__auth__auth__load_persisted_auth_state_impl(
+fn wire__crate__api__drive__upload_manager__enqueue_upload_from_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -944,7 +994,7 @@ fn wire__crate__api__auth__auth__load_persisted_auth_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_persisted_auth_state",
+            debug_name: "enqueue_upload_from_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -958,17 +1008,33 @@ fn wire__crate__api__auth__auth__load_persisted_auth_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_source_url = <String>::sse_decode(&mut deserializer);
+            let api_file_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_conflict_behavior =
+                <Option<crate::api::drive::models::UploadConflictBehavior>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_priority = <Option<crate::api::drive::models::TransferPriority>>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::auth::auth::load_persisted_auth_state()?;
+                    let output_ok = crate::api::drive::upload_manager::enqueue_upload_from_url(
+                        api_parent_id,
+                        api_source_url,
+                        api_file_name,
+                        api_conflict_behavior,
+                        api_priority,
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__move_item__move_drive_item_impl(
+fn wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -976,7 +1042,7 @@ fn wire__crate__api__drive__move_item__move_drive_item_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "move_drive_item",
+            debug_name: "enqueue_upload_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -990,18 +1056,27 @@ fn wire__crate__api__drive__move_item__move_drive_item_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
-            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_new_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_if_match = <Option<String>>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            let api_local_path = <String>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            let api_priority = <Option<crate::api::drive::models::TransferPriority>>::sse_decode(
+                &mut deserializer,
+            );
+            let api_conflict_behavior =
+                <Option<crate::api::drive::models::UploadConflictBehavior>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::move_item::move_drive_item(
-                        api_item_id,
-                        api_new_parent_id,
-                        api_new_name,
-                        api_if_match,
+                    let output_ok = crate::api::drive::upload_manager::enqueue_upload_task(
+                        api_parent_id,
+                        api_file_name,
+                        api_local_path,
+                        api_overwrite,
+                        api_priority,
+                        api_conflict_behavior,
                     )?;
                     Ok(output_ok)
                 })())
@@ -1009,7 +1084,7 @@ fn wire__crate__api__drive__move_item__move_drive_item_impl(
         },
     )
 }
-fn wire__crate__api__auth__auth__persist_auth_state_impl(
+fn wire__crate__api__drive__create_folder__ensure_drive_folder_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1017,7 +1092,44 @@ fn wire__crate__api__auth__auth__persist_auth_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "persist_auth_state",
+            debug_name: "ensure_drive_folder_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::create_folder::ensure_drive_folder_path(
+                        api_parent_id,
+                        api_path,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__bandwidth_limit__get_bandwidth_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_bandwidth_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1031,20 +1143,18 @@ fn wire__crate__api__auth__auth__persist_auth_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_client_id = <String>::sse_decode(&mut deserializer);
-            let api_tokens = <crate::api::auth::auth::AuthTokens>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::auth::auth::persist_auth_state(api_client_id, api_tokens)?;
+                        crate::api::settings::bandwidth_limit::get_bandwidth_settings()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__auth__refresh__refresh_tokens_impl(
+fn wire__crate__api__settings__disk_space_reserve__get_disk_space_reserve_mib_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1052,7 +1162,7 @@ fn wire__crate__api__auth__refresh__refresh_tokens_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "refresh_tokens",
+            debug_name: "get_disk_space_reserve_mib",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1069,14 +1179,15 @@ fn wire__crate__api__auth__refresh__refresh_tokens_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::auth::refresh::refresh_tokens()?;
+                    let output_ok =
+                        crate::api::settings::disk_space_reserve::get_disk_space_reserve_mib()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__download_manager__remove_download_task_impl(
+fn wire__crate__api__settings__download_concurrency__get_download_concurrency_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1084,7 +1195,7 @@ fn wire__crate__api__drive__download_manager__remove_download_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_download_task",
+            debug_name: "get_download_concurrency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1098,19 +1209,31 @@ fn wire__crate__api__drive__download_manager__remove_download_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::drive::download_manager::remove_download_task(api_item_id)?;
+                        crate::api::settings::download_concurrency::get_download_concurrency()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__remove_upload_task_impl(
+fn wire__crate__api__settings__download_conflict_policy__get_download_conflict_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "get_download_conflict_policy", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::download_conflict_policy::get_download_conflict_policy()?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__settings__download_directory__get_download_directory_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1118,7 +1241,7 @@ fn wire__crate__api__drive__upload_manager__remove_upload_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_upload_task",
+            debug_name: "get_download_directory",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1132,19 +1255,18 @@ fn wire__crate__api__drive__upload_manager__remove_upload_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_task_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::drive::upload_manager::remove_upload_task(api_task_id)?;
+                        crate::api::settings::download_directory::get_download_directory()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__settings__download_concurrency__set_download_concurrency_impl(
+fn wire__crate__api__settings__download_item_xattr__get_download_item_xattr_enabled_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1152,7 +1274,7 @@ fn wire__crate__api__settings__download_concurrency__set_download_concurrency_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_download_concurrency",
+            debug_name: "get_download_item_xattr_enabled",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1166,13 +1288,11 @@ fn wire__crate__api__settings__download_concurrency__set_download_concurrency_im
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::settings::download_concurrency::set_download_concurrency(
-                            api_limit,
+                        crate::api::settings::download_item_xattr::get_download_item_xattr_enabled(
                         )?;
                     Ok(output_ok)
                 })())
@@ -1180,7 +1300,7 @@ fn wire__crate__api__settings__download_concurrency__set_download_concurrency_im
         },
     )
 }
-fn wire__crate__api__settings__download_directory__set_download_directory_impl(
+fn wire__crate__api__drive__details__get_drive_item_details_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1188,7 +1308,7 @@ fn wire__crate__api__settings__download_directory__set_download_directory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_download_directory",
+            debug_name: "get_drive_item_details",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1202,19 +1322,19 @@ fn wire__crate__api__settings__download_directory__set_download_directory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        crate::api::settings::download_directory::set_download_directory(api_path)?;
+                        crate::api::drive::details::get_drive_item_details(api_item_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__upload_progress_stream_impl(
+fn wire__crate__api__drive__info__get_drive_overview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1222,7 +1342,7 @@ fn wire__crate__api__drive__upload_manager__upload_progress_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_progress_stream",
+            debug_name: "get_drive_overview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1236,23 +1356,17 @@ fn wire__crate__api__drive__upload_manager__upload_progress_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stream_sink = <StreamSink<
-                crate::api::drive::models::UploadProgressUpdate,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::drive::upload_manager::upload_progress_stream(api_stream_sink);
-                    })?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::info::get_drive_overview()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload_manager__upload_queue_state_impl(
+fn wire__crate__api__settings__global_transfer_limit__get_global_transfer_limit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1260,7 +1374,7 @@ fn wire__crate__api__drive__upload_manager__upload_queue_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_queue_state",
+            debug_name: "get_global_transfer_limit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1276,17 +1390,16 @@ fn wire__crate__api__drive__upload_manager__upload_queue_state_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::drive::upload_manager::upload_queue_state(),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::global_transfer_limit::get_global_transfer_limit()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__models__upload_queue_state_default_impl(
+fn wire__crate__api__settings__notifications__get_notification_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1294,7 +1407,7 @@ fn wire__crate__api__drive__models__upload_queue_state_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_queue_state_default",
+            debug_name: "get_notification_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1310,17 +1423,16 @@ fn wire__crate__api__drive__models__upload_queue_state_default_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::drive::models::UploadQueueState::default(),
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::notifications::get_notification_settings()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__drive__upload__upload_small_file_impl(
+fn wire__crate__api__settings__post_download_hooks__get_post_download_hooks_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1328,7 +1440,7 @@ fn wire__crate__api__drive__upload__upload_small_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_small_file",
+            debug_name: "get_post_download_hooks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1342,52 +1454,1657 @@ fn wire__crate__api__drive__upload__upload_small_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_file_name = <String>::sse_decode(&mut deserializer);
-            let api_content = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_overwrite = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::drive::upload::upload_small_file(
-                        api_parent_id,
-                        api_file_name,
-                        api_content,
-                        api_overwrite,
-                    )?;
+                    let output_ok =
+                        crate::api::settings::post_download_hooks::get_post_download_hooks()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::drive::models::DownloadProgressUpdate,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__drive__share__get_share_capabilities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_share_capabilities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::share::get_share_capabilities()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode
-    for StreamSink<
-        crate::api::drive::models::UploadProgressUpdate,
+fn wire__crate__api__settings__retry_policy__get_transfer_retry_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_transfer_retry_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::retry_policy::get_transfer_retry_policy()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__upload_concurrency__get_upload_concurrency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_upload_concurrency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::upload_concurrency::get_upload_concurrency()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__upload_conflict_behavior__get_upload_conflict_behavior_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "get_upload_conflict_behavior", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::upload_conflict_behavior::get_upload_conflict_behavior()?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__settings__upload_integrity_reupload__get_upload_integrity_reupload_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "get_upload_integrity_reupload_enabled", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::upload_integrity_reupload::get_upload_integrity_reupload_enabled()?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__list__list_drive_children_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_drive_children",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_folder_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_folder_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_next_link = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::list::list_drive_children(
+                        api_folder_id,
+                        api_folder_path,
+                        api_next_link,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__auth__auth__load_persisted_auth_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_persisted_auth_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::auth::auth::load_persisted_auth_state()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__move_download_task_down_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_download_task_down",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::move_download_task_down(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__move_download_task_to_top_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_download_task_to_top",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::download_manager::move_download_task_to_top(
+                        api_task_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__move_download_task_up_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_download_task_up",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::move_download_task_up(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__move_item__move_drive_item_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_drive_item",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_item_id = <String>::sse_decode(&mut deserializer);
+            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_new_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_if_match = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::move_item::move_drive_item(
+                        api_item_id,
+                        api_new_parent_id,
+                        api_new_name,
+                        api_if_match,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__move_upload_task_down_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_upload_task_down",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::move_upload_task_down(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__move_upload_task_to_top_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_upload_task_to_top",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::move_upload_task_to_top(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__move_upload_task_up_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_upload_task_up",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::move_upload_task_up(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__open_downloaded_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_downloaded_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::open_downloaded_file(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__auth__auth__persist_auth_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "persist_auth_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_client_id = <String>::sse_decode(&mut deserializer);
+            let api_tokens = <crate::api::auth::auth::AuthTokens>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::auth::auth::persist_auth_state(api_client_id, api_tokens)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__auth__refresh__refresh_tokens_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_tokens",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::auth::refresh::refresh_tokens()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__remove_download_task_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_download_task",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::remove_download_task(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__remove_upload_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_upload_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::remove_upload_group(api_group_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__remove_upload_task_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_upload_task",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::remove_upload_task(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__resolve_download_conflict_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_download_conflict",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::api::drive::models::DownloadConflictPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::download_manager::resolve_download_conflict(
+                        api_task_id,
+                        api_policy,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__retry_failed_download_tasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_failed_download_tasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::retry_failed_download_tasks()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__retry_failed_upload_tasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_failed_upload_tasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::upload_manager::retry_failed_upload_tasks()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__retry_upload_task_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_upload_task",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::upload_manager::retry_upload_task(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__reveal_downloaded_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reveal_downloaded_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::reveal_downloaded_file(api_task_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__bandwidth_limit__set_bandwidth_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bandwidth_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::drive::models::BandwidthSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::settings::bandwidth_limit::set_bandwidth_settings(
+                        api_settings,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__disk_space_reserve__set_disk_space_reserve_mib_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_disk_space_reserve_mib",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reserve_mib = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::disk_space_reserve::set_disk_space_reserve_mib(
+                            api_reserve_mib,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__download_concurrency__set_download_concurrency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_concurrency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::download_concurrency::set_download_concurrency(
+                            api_limit,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__download_conflict_policy__set_download_conflict_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "set_download_conflict_policy", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_policy = <crate::api::drive::models::DownloadConflictPolicy>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::download_conflict_policy::set_download_conflict_policy(api_policy)?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__settings__download_directory__set_download_directory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_directory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::download_directory::set_download_directory(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__download_item_xattr__set_download_item_xattr_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_item_xattr_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::download_item_xattr::set_download_item_xattr_enabled(
+                            api_enabled,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__download_manager__set_download_task_priority_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_download_task_priority",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_priority =
+                <crate::api::drive::models::TransferPriority>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::drive::download_manager::set_download_task_priority(
+                            api_task_id,
+                            api_priority,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__global_transfer_limit__set_global_transfer_limit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_global_transfer_limit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::global_transfer_limit::set_global_transfer_limit(
+                            api_limit,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__notifications__set_notification_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_notification_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::drive::models::NotificationSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::settings::notifications::set_notification_settings(
+                        api_settings,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__post_download_hooks__set_post_download_hooks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_post_download_hooks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_hooks =
+                <Vec<crate::api::drive::models::PostDownloadHook>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::post_download_hooks::set_post_download_hooks(
+                            api_hooks,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__retry_policy__set_transfer_retry_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_transfer_retry_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy =
+                <crate::api::drive::models::TransferRetryPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::retry_policy::set_transfer_retry_policy(api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__upload_concurrency__set_upload_concurrency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_upload_concurrency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::settings::upload_concurrency::set_upload_concurrency(
+                            api_limit,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__settings__upload_conflict_behavior__set_upload_conflict_behavior_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "set_upload_conflict_behavior", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_behavior = <crate::api::drive::models::UploadConflictBehavior>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::upload_conflict_behavior::set_upload_conflict_behavior(api_behavior)?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__settings__upload_integrity_reupload__set_upload_integrity_reupload_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(flutter_rust_bridge::for_generated::TaskInfo { debug_name: "set_upload_integrity_reupload_enabled", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || {
+let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_enabled = <bool>::sse_decode(&mut deserializer);
+deserializer.end();
+move |context| { transform_result_sse::<_, String>((move || { let output_ok = crate::api::settings::upload_integrity_reupload::set_upload_integrity_reupload_enabled(api_enabled)?; Ok(output_ok) })()) }
+})
+}
+fn wire__crate__api__drive__upload_manager__set_upload_task_priority_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_upload_task_priority",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_task_id = <String>::sse_decode(&mut deserializer);
+            let api_priority =
+                <crate::api::drive::models::TransferPriority>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::upload_manager::set_upload_task_priority(
+                        api_task_id,
+                        api_priority,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__models__transfer_priority_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transfer_priority_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::models::TransferPriority::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__upload_progress_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_progress_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_sink = <StreamSink<
+                crate::api::drive::models::UploadProgressUpdate,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::drive::upload_manager::upload_progress_stream(api_stream_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__upload_queue_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_queue_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::upload_manager::upload_queue_state(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__models__upload_queue_state_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_queue_state_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::drive::models::UploadQueueState::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload__upload_small_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_small_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_file_name = <String>::sse_decode(&mut deserializer);
+            let api_local_path = <String>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::drive::upload::upload_small_file(
+                        api_parent_id,
+                        api_file_name,
+                        api_local_path,
+                        api_overwrite,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__drive__upload_manager__upload_task_event_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_task_event_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_stream_sink = <StreamSink<
+                crate::api::drive::models::UploadTaskEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::drive::upload_manager::upload_task_event_stream(
+                            api_stream_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::drive::models::CopyJob, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::drive::models::DownloadProgressUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::drive::models::DownloadTaskEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::drive::models::UploadProgressUpdate,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::drive::models::UploadTaskEvent,
         flutter_rust_bridge::for_generated::SseCodec,
     >
 {
@@ -1426,6 +3143,41 @@ impl SseDecode for crate::api::auth::auth::AuthTokens {
     }
 }
 
+impl SseDecode for crate::api::drive::models::BandwidthSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMinute = <u32>::sse_decode(deserializer);
+        let mut var_endMinute = <u32>::sse_decode(deserializer);
+        let mut var_globalKibPerSec = <u64>::sse_decode(deserializer);
+        let mut var_downloadKibPerSec = <u64>::sse_decode(deserializer);
+        let mut var_uploadKibPerSec = <u64>::sse_decode(deserializer);
+        return crate::api::drive::models::BandwidthSchedule {
+            start_minute: var_startMinute,
+            end_minute: var_endMinute,
+            global_kib_per_sec: var_globalKibPerSec,
+            download_kib_per_sec: var_downloadKibPerSec,
+            upload_kib_per_sec: var_uploadKibPerSec,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::BandwidthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_globalKibPerSec = <u64>::sse_decode(deserializer);
+        let mut var_downloadKibPerSec = <u64>::sse_decode(deserializer);
+        let mut var_uploadKibPerSec = <u64>::sse_decode(deserializer);
+        let mut var_schedules =
+            <Vec<crate::api::drive::models::BandwidthSchedule>>::sse_decode(deserializer);
+        return crate::api::drive::models::BandwidthSettings {
+            global_kib_per_sec: var_globalKibPerSec,
+            download_kib_per_sec: var_downloadKibPerSec,
+            upload_kib_per_sec: var_uploadKibPerSec,
+            schedules: var_schedules,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1433,15 +3185,94 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::drive::models::CopyJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jobId = <String>::sse_decode(deserializer);
+        let mut var_sourceItemId = <String>::sse_decode(deserializer);
+        let mut var_itemName = <String>::sse_decode(deserializer);
+        let mut var_targetDriveId = <Option<String>>::sse_decode(deserializer);
+        let mut var_targetParentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_newName = <Option<String>>::sse_decode(deserializer);
+        let mut var_monitorUrl = <String>::sse_decode(deserializer);
+        let mut var_status = <crate::api::drive::models::CopyJobStatus>::sse_decode(deserializer);
+        let mut var_percentageComplete = <Option<f64>>::sse_decode(deserializer);
+        let mut var_newItemId = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_completedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::drive::models::CopyJob {
+            job_id: var_jobId,
+            source_item_id: var_sourceItemId,
+            item_name: var_itemName,
+            target_drive_id: var_targetDriveId,
+            target_parent_id: var_targetParentId,
+            new_name: var_newName,
+            monitor_url: var_monitorUrl,
+            status: var_status,
+            percentage_complete: var_percentageComplete,
+            new_item_id: var_newItemId,
+            error_message: var_errorMessage,
+            started_at: var_startedAt,
+            completed_at: var_completedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::CopyJobStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::CopyJobStatus::InProgress,
+            1 => crate::api::drive::models::CopyJobStatus::Completed,
+            2 => crate::api::drive::models::CopyJobStatus::Failed,
+            _ => unreachable!("Invalid variant for CopyJobStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::DownloadConflictOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::DownloadConflictOutcome::NoConflict,
+            1 => crate::api::drive::models::DownloadConflictOutcome::Overwritten,
+            2 => crate::api::drive::models::DownloadConflictOutcome::Skipped,
+            3 => crate::api::drive::models::DownloadConflictOutcome::KeptBoth,
+            4 => crate::api::drive::models::DownloadConflictOutcome::AwaitingDecision,
+            _ => unreachable!("Invalid variant for DownloadConflictOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::DownloadConflictPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::DownloadConflictPolicy::Overwrite,
+            1 => crate::api::drive::models::DownloadConflictPolicy::Skip,
+            2 => crate::api::drive::models::DownloadConflictPolicy::KeepBoth,
+            3 => crate::api::drive::models::DownloadConflictPolicy::OverwriteIfNewer,
+            4 => crate::api::drive::models::DownloadConflictPolicy::Ask,
+            _ => unreachable!("Invalid variant for DownloadConflictPolicy: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::drive::models::DownloadProgressUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taskId = <String>::sse_decode(deserializer);
         let mut var_itemId = <String>::sse_decode(deserializer);
         let mut var_bytesDownloaded = <u64>::sse_decode(deserializer);
         let mut var_expectedSize = <Option<u64>>::sse_decode(deserializer);
         let mut var_speedBps = <Option<f64>>::sse_decode(deserializer);
         let mut var_timestampMillis = <i64>::sse_decode(deserializer);
         return crate::api::drive::models::DownloadProgressUpdate {
+            task_id: var_taskId,
             item_id: var_itemId,
             bytes_downloaded: var_bytesDownloaded,
             expected_size: var_expectedSize,
@@ -1476,6 +3307,7 @@ impl SseDecode for crate::api::drive::models::DownloadStatus {
             0 => crate::api::drive::models::DownloadStatus::InProgress,
             1 => crate::api::drive::models::DownloadStatus::Completed,
             2 => crate::api::drive::models::DownloadStatus::Failed,
+            3 => crate::api::drive::models::DownloadStatus::Queued,
             _ => unreachable!("Invalid variant for DownloadStatus: {}", inner),
         };
     }
@@ -1484,6 +3316,7 @@ impl SseDecode for crate::api::drive::models::DownloadStatus {
 impl SseDecode for crate::api::drive::models::DownloadTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taskId = <String>::sse_decode(deserializer);
         let mut var_item = <crate::api::drive::models::DriveItemSummary>::sse_decode(deserializer);
         let mut var_status = <crate::api::drive::models::DownloadStatus>::sse_decode(deserializer);
         let mut var_startedAt = <i64>::sse_decode(deserializer);
@@ -1492,7 +3325,20 @@ impl SseDecode for crate::api::drive::models::DownloadTask {
         let mut var_sizeLabel = <Option<u64>>::sse_decode(deserializer);
         let mut var_bytesDownloaded = <Option<u64>>::sse_decode(deserializer);
         let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        let mut var_targetDir = <String>::sse_decode(deserializer);
+        let mut var_conflictPolicy =
+            <crate::api::drive::models::DownloadConflictPolicy>::sse_decode(deserializer);
+        let mut var_conflictOutcome =
+            <Option<crate::api::drive::models::DownloadConflictOutcome>>::sse_decode(deserializer);
+        let mut var_retryCount = <u32>::sse_decode(deserializer);
+        let mut var_priority =
+            <crate::api::drive::models::TransferPriority>::sse_decode(deserializer);
+        let mut var_failureReason =
+            <Option<crate::api::drive::models::TransferFailureReason>>::sse_decode(deserializer);
+        let mut var_hookOutcomes =
+            <Vec<crate::api::drive::models::PostDownloadHookOutcome>>::sse_decode(deserializer);
         return crate::api::drive::models::DownloadTask {
+            task_id: var_taskId,
             item: var_item,
             status: var_status,
             started_at: var_startedAt,
@@ -1501,6 +3347,27 @@ impl SseDecode for crate::api::drive::models::DownloadTask {
             size_label: var_sizeLabel,
             bytes_downloaded: var_bytesDownloaded,
             error_message: var_errorMessage,
+            target_dir: var_targetDir,
+            conflict_policy: var_conflictPolicy,
+            conflict_outcome: var_conflictOutcome,
+            retry_count: var_retryCount,
+            priority: var_priority,
+            failure_reason: var_failureReason,
+            hook_outcomes: var_hookOutcomes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::DownloadTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::drive::models::TransferEventKind>::sse_decode(deserializer);
+        let mut var_task = <crate::api::drive::models::DownloadTask>::sse_decode(deserializer);
+        let mut var_timestampMillis = <i64>::sse_decode(deserializer);
+        return crate::api::drive::models::DownloadTaskEvent {
+            kind: var_kind,
+            task: var_task,
+            timestamp_millis: var_timestampMillis,
         };
     }
 }
@@ -1512,11 +3379,14 @@ impl SseDecode for crate::api::drive::models::DriveDownloadResult {
         let mut var_savedPath = <String>::sse_decode(deserializer);
         let mut var_bytesDownloaded = <u64>::sse_decode(deserializer);
         let mut var_expectedSize = <Option<u64>>::sse_decode(deserializer);
+        let mut var_conflictOutcome =
+            <crate::api::drive::models::DownloadConflictOutcome>::sse_decode(deserializer);
         return crate::api::drive::models::DriveDownloadResult {
             file_name: var_fileName,
             saved_path: var_savedPath,
             bytes_downloaded: var_bytesDownloaded,
             expected_size: var_expectedSize,
+            conflict_outcome: var_conflictOutcome,
         };
     }
 }
@@ -1653,6 +3523,20 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::api::drive::models::FolderConflictBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::FolderConflictBehavior::Fail,
+            1 => crate::api::drive::models::FolderConflictBehavior::Rename,
+            2 => crate::api::drive::models::FolderConflictBehavior::Replace,
+            3 => crate::api::drive::models::FolderConflictBehavior::UseExisting,
+            _ => unreachable!("Invalid variant for FolderConflictBehavior: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1705,41 +3589,111 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::drive::models::BandwidthSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::drive::models::BandwidthSchedule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::drive::models::CopyJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::drive::models::CopyJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::drive::models::DownloadTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::drive::models::DownloadTask>::sse_decode(
-                deserializer,
-            ));
+            ans_.push(<crate::api::drive::models::DownloadTask>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::drive::models::DriveItemSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::drive::models::DriveItemSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::drive::models::PostDownloadHook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::drive::models::PostDownloadHook>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::drive::models::PostDownloadHookOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::drive::models::PostDownloadHookOutcome>::sse_decode(deserializer),
+            );
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::drive::models::DriveItemSummary> {
+impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::drive::models::DriveItemSummary>::sse_decode(
-                deserializer,
-            ));
+            ans_.push(<u8>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::api::drive::models::UploadGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::api::drive::models::UploadGroup>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
@@ -1759,6 +3713,28 @@ impl SseDecode for Vec<crate::api::drive::models::UploadTask> {
     }
 }
 
+impl SseDecode for crate::api::drive::models::NotificationSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_notifyOnTaskCompleted = <bool>::sse_decode(deserializer);
+        let mut var_notifyOnTaskFailed = <bool>::sse_decode(deserializer);
+        let mut var_notifyOnBatchFinished = <bool>::sse_decode(deserializer);
+        let mut var_quietHoursEnabled = <bool>::sse_decode(deserializer);
+        let mut var_quietHoursStartMinute = <u32>::sse_decode(deserializer);
+        let mut var_quietHoursEndMinute = <u32>::sse_decode(deserializer);
+        return crate::api::drive::models::NotificationSettings {
+            enabled: var_enabled,
+            notify_on_task_completed: var_notifyOnTaskCompleted,
+            notify_on_task_failed: var_notifyOnTaskFailed,
+            notify_on_batch_finished: var_notifyOnBatchFinished,
+            quiet_hours_enabled: var_quietHoursEnabled,
+            quiet_hours_start_minute: var_quietHoursStartMinute,
+            quiet_hours_end_minute: var_quietHoursEndMinute,
+        };
+    }
+}
+
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1781,6 +3757,32 @@ impl SseDecode for Option<bool> {
     }
 }
 
+impl SseDecode for Option<crate::api::drive::models::DownloadConflictOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::drive::models::DownloadConflictOutcome>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::drive::models::DownloadConflictPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::drive::models::DownloadConflictPolicy>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::drive::models::DriveOwner> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1842,6 +3844,43 @@ impl SseDecode for Option<crate::api::auth::auth::StoredAuthState> {
     }
 }
 
+impl SseDecode for Option<crate::api::drive::models::TransferFailureReason> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::drive::models::TransferFailureReason>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::drive::models::TransferPriority> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::drive::models::TransferPriority>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1853,6 +3892,19 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for Option<crate::api::drive::models::UploadConflictBehavior> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::drive::models::UploadConflictBehavior>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1864,6 +3916,55 @@ impl SseDecode for Option<Vec<String>> {
     }
 }
 
+impl SseDecode for crate::api::drive::models::PostDownloadHook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::drive::models::PostDownloadHookKind>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_extensions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_program = <Option<String>>::sse_decode(deserializer);
+        let mut var_args = <Vec<String>>::sse_decode(deserializer);
+        let mut var_subfolder = <Option<String>>::sse_decode(deserializer);
+        return crate::api::drive::models::PostDownloadHook {
+            kind: var_kind,
+            enabled: var_enabled,
+            extensions: var_extensions,
+            program: var_program,
+            args: var_args,
+            subfolder: var_subfolder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::PostDownloadHookKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::PostDownloadHookKind::RunCommand,
+            1 => crate::api::drive::models::PostDownloadHookKind::ExtractArchive,
+            2 => crate::api::drive::models::PostDownloadHookKind::MoveByType,
+            _ => unreachable!("Invalid variant for PostDownloadHookKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::PostDownloadHookOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::drive::models::PostDownloadHookKind>::sse_decode(deserializer);
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::drive::models::PostDownloadHookOutcome {
+            kind: var_kind,
+            success: var_success,
+            message: var_message,
+        };
+    }
+}
+
 impl SseDecode for crate::api::drive::models::ShareCapabilities {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1918,6 +4019,68 @@ impl SseDecode for crate::api::auth::auth::StoredAuthState {
     }
 }
 
+impl SseDecode for crate::api::drive::models::TransferEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::TransferEventKind::Queued,
+            1 => crate::api::drive::models::TransferEventKind::Started,
+            2 => crate::api::drive::models::TransferEventKind::Retrying,
+            3 => crate::api::drive::models::TransferEventKind::Completed,
+            4 => crate::api::drive::models::TransferEventKind::Failed,
+            5 => crate::api::drive::models::TransferEventKind::Cancelled,
+            6 => crate::api::drive::models::TransferEventKind::AwaitingDecision,
+            7 => crate::api::drive::models::TransferEventKind::Removed,
+            _ => unreachable!("Invalid variant for TransferEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::TransferFailureReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::TransferFailureReason::InsufficientDiskSpace,
+            1 => crate::api::drive::models::TransferFailureReason::InsufficientQuota,
+            2 => crate::api::drive::models::TransferFailureReason::IntegrityMismatch,
+            3 => crate::api::drive::models::TransferFailureReason::SourceChanged,
+            4 => crate::api::drive::models::TransferFailureReason::AccessDenied,
+            5 => crate::api::drive::models::TransferFailureReason::NotFound,
+            6 => crate::api::drive::models::TransferFailureReason::RetriesExhausted,
+            _ => unreachable!("Invalid variant for TransferFailureReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::TransferPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::TransferPriority::Low,
+            1 => crate::api::drive::models::TransferPriority::Normal,
+            2 => crate::api::drive::models::TransferPriority::High,
+            _ => unreachable!("Invalid variant for TransferPriority: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::TransferRetryPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_initialDelayMs = <u64>::sse_decode(deserializer);
+        let mut var_maxDelayMs = <u64>::sse_decode(deserializer);
+        return crate::api::drive::models::TransferRetryPolicy {
+            max_retries: var_maxRetries,
+            initial_delay_ms: var_initialDelayMs,
+            max_delay_ms: var_maxDelayMs,
+        };
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1944,6 +4107,71 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
+impl SseDecode for crate::api::drive::models::UploadConflictBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::UploadConflictBehavior::Fail,
+            1 => crate::api::drive::models::UploadConflictBehavior::Replace,
+            2 => crate::api::drive::models::UploadConflictBehavior::Rename,
+            3 => crate::api::drive::models::UploadConflictBehavior::SkipIfIdentical,
+            _ => unreachable!("Invalid variant for UploadConflictBehavior: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::UploadGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_groupId = <String>::sse_decode(deserializer);
+        let mut var_localRoot = <String>::sse_decode(deserializer);
+        let mut var_folderName = <String>::sse_decode(deserializer);
+        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_status =
+            <crate::api::drive::models::UploadGroupStatus>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_completedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
+        let mut var_totalFiles = <u32>::sse_decode(deserializer);
+        let mut var_completedFiles = <u32>::sse_decode(deserializer);
+        let mut var_failedFiles = <u32>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_uploadedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::drive::models::UploadGroup {
+            group_id: var_groupId,
+            local_root: var_localRoot,
+            folder_name: var_folderName,
+            parent_id: var_parentId,
+            status: var_status,
+            created_at: var_createdAt,
+            completed_at: var_completedAt,
+            error_message: var_errorMessage,
+            total_files: var_totalFiles,
+            completed_files: var_completedFiles,
+            failed_files: var_failedFiles,
+            total_bytes: var_totalBytes,
+            uploaded_bytes: var_uploadedBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::UploadGroupStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::drive::models::UploadGroupStatus::Preparing,
+            1 => crate::api::drive::models::UploadGroupStatus::Uploading,
+            2 => crate::api::drive::models::UploadGroupStatus::Completed,
+            3 => crate::api::drive::models::UploadGroupStatus::PartiallyFailed,
+            4 => crate::api::drive::models::UploadGroupStatus::Failed,
+            5 => crate::api::drive::models::UploadGroupStatus::Cancelled,
+            _ => unreachable!("Invalid variant for UploadGroupStatus: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::drive::models::UploadProgressUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1969,10 +4197,13 @@ impl SseDecode for crate::api::drive::models::UploadQueueState {
         let mut var_completed =
             <Vec<crate::api::drive::models::UploadTask>>::sse_decode(deserializer);
         let mut var_failed = <Vec<crate::api::drive::models::UploadTask>>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<crate::api::drive::models::UploadGroup>>::sse_decode(deserializer);
         return crate::api::drive::models::UploadQueueState {
             active: var_active,
             completed: var_completed,
             failed: var_failed,
+            groups: var_groups,
         };
     }
 }
@@ -1986,6 +4217,7 @@ impl SseDecode for crate::api::drive::models::UploadStatus {
             1 => crate::api::drive::models::UploadStatus::Completed,
             2 => crate::api::drive::models::UploadStatus::Failed,
             3 => crate::api::drive::models::UploadStatus::Cancelled,
+            4 => crate::api::drive::models::UploadStatus::Queued,
             _ => unreachable!("Invalid variant for UploadStatus: {}", inner),
         };
     }
@@ -2007,6 +4239,20 @@ impl SseDecode for crate::api::drive::models::UploadTask {
         let mut var_bytesUploaded = <Option<u64>>::sse_decode(deserializer);
         let mut var_errorMessage = <Option<String>>::sse_decode(deserializer);
         let mut var_sessionUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_sessionExpiresAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_retryCount = <u32>::sse_decode(deserializer);
+        let mut var_priority =
+            <crate::api::drive::models::TransferPriority>::sse_decode(deserializer);
+        let mut var_failureReason =
+            <Option<crate::api::drive::models::TransferFailureReason>>::sse_decode(deserializer);
+        let mut var_groupId = <Option<String>>::sse_decode(deserializer);
+        let mut var_conflictBehavior =
+            <crate::api::drive::models::UploadConflictBehavior>::sse_decode(deserializer);
+        let mut var_remoteName = <Option<String>>::sse_decode(deserializer);
+        let mut var_skippedIdentical = <bool>::sse_decode(deserializer);
+        let mut var_sourceModifiedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_sourceFingerprint = <Option<String>>::sse_decode(deserializer);
+        let mut var_sourceUrl = <Option<String>>::sse_decode(deserializer);
         return crate::api::drive::models::UploadTask {
             task_id: var_taskId,
             file_name: var_fileName,
@@ -2021,6 +4267,31 @@ impl SseDecode for crate::api::drive::models::UploadTask {
             bytes_uploaded: var_bytesUploaded,
             error_message: var_errorMessage,
             session_url: var_sessionUrl,
+            session_expires_at: var_sessionExpiresAt,
+            retry_count: var_retryCount,
+            priority: var_priority,
+            failure_reason: var_failureReason,
+            group_id: var_groupId,
+            conflict_behavior: var_conflictBehavior,
+            remote_name: var_remoteName,
+            skipped_identical: var_skippedIdentical,
+            source_modified_at: var_sourceModifiedAt,
+            source_fingerprint: var_sourceFingerprint,
+            source_url: var_sourceUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::api::drive::models::UploadTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::drive::models::TransferEventKind>::sse_decode(deserializer);
+        let mut var_task = <crate::api::drive::models::UploadTask>::sse_decode(deserializer);
+        let mut var_timestampMillis = <i64>::sse_decode(deserializer);
+        return crate::api::drive::models::UploadTaskEvent {
+            kind: var_kind,
+            task: var_task,
+            timestamp_millis: var_timestampMillis,
         };
     }
 }
@@ -2034,213 +4305,94 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        1 => wire__crate__api__auth__auth__authenticate_via_browser_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__drive__download_manager__cancel_download_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__drive__upload_manager__cancel_upload_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__drive__download_manager__clear_download_history_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__auth__auth__clear_persisted_auth_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__drive__upload_manager__clear_upload_history_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__drive__share__create_share_link_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__drive__delete__delete_drive_item_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__drive__download__download_drive_item_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__drive__download_manager__download_progress_stream_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__drive__download_manager__download_queue_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__drive__models__download_queue_state_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        15 => wire__crate__api__drive__download_manager__enqueue_download_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        16 => wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__api__settings__download_concurrency__get_download_concurrency_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        19 => wire__crate__api__settings__download_directory__get_download_directory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__drive__details__get_drive_item_details_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        21 => wire__crate__api__drive__info__get_drive_overview_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__drive__share__get_share_capabilities_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        24 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__drive__list__list_drive_children_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        26 => wire__crate__api__auth__auth__load_persisted_auth_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        27 => wire__crate__api__drive__move_item__move_drive_item_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        28 => {
-            wire__crate__api__auth__auth__persist_auth_state_impl(port, ptr, rust_vec_len, data_len)
-        }
-        29 => {
-            wire__crate__api__auth__refresh__refresh_tokens_impl(port, ptr, rust_vec_len, data_len)
-        }
-        30 => wire__crate__api__drive__download_manager__remove_download_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        31 => wire__crate__api__drive__upload_manager__remove_upload_task_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        32 => wire__crate__api__settings__download_concurrency__set_download_concurrency_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        33 => wire__crate__api__settings__download_directory__set_download_directory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        34 => wire__crate__api__drive__upload_manager__upload_progress_stream_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        35 => wire__crate__api__drive__upload_manager__upload_queue_state_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        36 => wire__crate__api__drive__models__upload_queue_state_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        37 => wire__crate__api__drive__upload__upload_small_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
-    }
+1 => wire__crate__api__auth__auth__authenticate_via_browser_impl(port, ptr, rust_vec_len, data_len),
+2 => wire__crate__api__drive__models__bandwidth_settings_default_impl(port, ptr, rust_vec_len, data_len),
+3 => wire__crate__api__drive__download_manager__cancel_download_task_impl(port, ptr, rust_vec_len, data_len),
+4 => wire__crate__api__drive__upload_manager__cancel_upload_group_impl(port, ptr, rust_vec_len, data_len),
+5 => wire__crate__api__drive__upload_manager__cancel_upload_task_impl(port, ptr, rust_vec_len, data_len),
+6 => wire__crate__api__drive__download_manager__clear_download_history_impl(port, ptr, rust_vec_len, data_len),
+7 => wire__crate__api__drive__download_manager__clear_failed_download_tasks_impl(port, ptr, rust_vec_len, data_len),
+8 => wire__crate__api__drive__upload_manager__clear_failed_upload_tasks_impl(port, ptr, rust_vec_len, data_len),
+9 => wire__crate__api__drive__copy_manager__clear_finished_copy_jobs_impl(port, ptr, rust_vec_len, data_len),
+10 => wire__crate__api__auth__auth__clear_persisted_auth_state_impl(port, ptr, rust_vec_len, data_len),
+11 => wire__crate__api__drive__upload_manager__clear_upload_history_impl(port, ptr, rust_vec_len, data_len),
+12 => wire__crate__api__drive__copy_manager__copy_drive_item_impl(port, ptr, rust_vec_len, data_len),
+13 => wire__crate__api__drive__copy_manager__copy_job_stream_impl(port, ptr, rust_vec_len, data_len),
+14 => wire__crate__api__drive__copy_manager__copy_jobs_impl(port, ptr, rust_vec_len, data_len),
+15 => wire__crate__api__drive__create_folder__create_drive_folder_impl(port, ptr, rust_vec_len, data_len),
+16 => wire__crate__api__drive__share__create_share_link_impl(port, ptr, rust_vec_len, data_len),
+17 => wire__crate__api__drive__delete__delete_drive_item_impl(port, ptr, rust_vec_len, data_len),
+18 => wire__crate__api__drive__download__download_drive_item_impl(port, ptr, rust_vec_len, data_len),
+19 => wire__crate__api__drive__download_manager__download_progress_stream_impl(port, ptr, rust_vec_len, data_len),
+20 => wire__crate__api__drive__download_manager__download_queue_state_impl(port, ptr, rust_vec_len, data_len),
+21 => wire__crate__api__drive__models__download_queue_state_default_impl(port, ptr, rust_vec_len, data_len),
+22 => wire__crate__api__drive__download_manager__download_task_event_stream_impl(port, ptr, rust_vec_len, data_len),
+23 => wire__crate__api__drive__download_manager__enqueue_download_task_impl(port, ptr, rust_vec_len, data_len),
+24 => wire__crate__api__drive__upload_manager__enqueue_large_upload_task_impl(port, ptr, rust_vec_len, data_len),
+25 => wire__crate__api__drive__upload_manager__enqueue_upload_folder_impl(port, ptr, rust_vec_len, data_len),
+26 => wire__crate__api__drive__upload_manager__enqueue_upload_from_url_impl(port, ptr, rust_vec_len, data_len),
+27 => wire__crate__api__drive__upload_manager__enqueue_upload_task_impl(port, ptr, rust_vec_len, data_len),
+28 => wire__crate__api__drive__create_folder__ensure_drive_folder_path_impl(port, ptr, rust_vec_len, data_len),
+29 => wire__crate__api__settings__bandwidth_limit__get_bandwidth_settings_impl(port, ptr, rust_vec_len, data_len),
+30 => wire__crate__api__settings__disk_space_reserve__get_disk_space_reserve_mib_impl(port, ptr, rust_vec_len, data_len),
+31 => wire__crate__api__settings__download_concurrency__get_download_concurrency_impl(port, ptr, rust_vec_len, data_len),
+32 => wire__crate__api__settings__download_conflict_policy__get_download_conflict_policy_impl(port, ptr, rust_vec_len, data_len),
+33 => wire__crate__api__settings__download_directory__get_download_directory_impl(port, ptr, rust_vec_len, data_len),
+34 => wire__crate__api__settings__download_item_xattr__get_download_item_xattr_enabled_impl(port, ptr, rust_vec_len, data_len),
+35 => wire__crate__api__drive__details__get_drive_item_details_impl(port, ptr, rust_vec_len, data_len),
+36 => wire__crate__api__drive__info__get_drive_overview_impl(port, ptr, rust_vec_len, data_len),
+37 => wire__crate__api__settings__global_transfer_limit__get_global_transfer_limit_impl(port, ptr, rust_vec_len, data_len),
+38 => wire__crate__api__settings__notifications__get_notification_settings_impl(port, ptr, rust_vec_len, data_len),
+39 => wire__crate__api__settings__post_download_hooks__get_post_download_hooks_impl(port, ptr, rust_vec_len, data_len),
+40 => wire__crate__api__drive__share__get_share_capabilities_impl(port, ptr, rust_vec_len, data_len),
+41 => wire__crate__api__settings__retry_policy__get_transfer_retry_policy_impl(port, ptr, rust_vec_len, data_len),
+42 => wire__crate__api__settings__upload_concurrency__get_upload_concurrency_impl(port, ptr, rust_vec_len, data_len),
+43 => wire__crate__api__settings__upload_conflict_behavior__get_upload_conflict_behavior_impl(port, ptr, rust_vec_len, data_len),
+44 => wire__crate__api__settings__upload_integrity_reupload__get_upload_integrity_reupload_enabled_impl(port, ptr, rust_vec_len, data_len),
+46 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+47 => wire__crate__api__drive__list__list_drive_children_impl(port, ptr, rust_vec_len, data_len),
+48 => wire__crate__api__auth__auth__load_persisted_auth_state_impl(port, ptr, rust_vec_len, data_len),
+49 => wire__crate__api__drive__download_manager__move_download_task_down_impl(port, ptr, rust_vec_len, data_len),
+50 => wire__crate__api__drive__download_manager__move_download_task_to_top_impl(port, ptr, rust_vec_len, data_len),
+51 => wire__crate__api__drive__download_manager__move_download_task_up_impl(port, ptr, rust_vec_len, data_len),
+52 => wire__crate__api__drive__move_item__move_drive_item_impl(port, ptr, rust_vec_len, data_len),
+53 => wire__crate__api__drive__upload_manager__move_upload_task_down_impl(port, ptr, rust_vec_len, data_len),
+54 => wire__crate__api__drive__upload_manager__move_upload_task_to_top_impl(port, ptr, rust_vec_len, data_len),
+55 => wire__crate__api__drive__upload_manager__move_upload_task_up_impl(port, ptr, rust_vec_len, data_len),
+56 => wire__crate__api__drive__download_manager__open_downloaded_file_impl(port, ptr, rust_vec_len, data_len),
+57 => wire__crate__api__auth__auth__persist_auth_state_impl(port, ptr, rust_vec_len, data_len),
+58 => wire__crate__api__auth__refresh__refresh_tokens_impl(port, ptr, rust_vec_len, data_len),
+59 => wire__crate__api__drive__download_manager__remove_download_task_impl(port, ptr, rust_vec_len, data_len),
+60 => wire__crate__api__drive__upload_manager__remove_upload_group_impl(port, ptr, rust_vec_len, data_len),
+61 => wire__crate__api__drive__upload_manager__remove_upload_task_impl(port, ptr, rust_vec_len, data_len),
+62 => wire__crate__api__drive__download_manager__resolve_download_conflict_impl(port, ptr, rust_vec_len, data_len),
+63 => wire__crate__api__drive__download_manager__retry_failed_download_tasks_impl(port, ptr, rust_vec_len, data_len),
+64 => wire__crate__api__drive__upload_manager__retry_failed_upload_tasks_impl(port, ptr, rust_vec_len, data_len),
+65 => wire__crate__api__drive__upload_manager__retry_upload_task_impl(port, ptr, rust_vec_len, data_len),
+66 => wire__crate__api__drive__download_manager__reveal_downloaded_file_impl(port, ptr, rust_vec_len, data_len),
+67 => wire__crate__api__settings__bandwidth_limit__set_bandwidth_settings_impl(port, ptr, rust_vec_len, data_len),
+68 => wire__crate__api__settings__disk_space_reserve__set_disk_space_reserve_mib_impl(port, ptr, rust_vec_len, data_len),
+69 => wire__crate__api__settings__download_concurrency__set_download_concurrency_impl(port, ptr, rust_vec_len, data_len),
+70 => wire__crate__api__settings__download_conflict_policy__set_download_conflict_policy_impl(port, ptr, rust_vec_len, data_len),
+71 => wire__crate__api__settings__download_directory__set_download_directory_impl(port, ptr, rust_vec_len, data_len),
+72 => wire__crate__api__settings__download_item_xattr__set_download_item_xattr_enabled_impl(port, ptr, rust_vec_len, data_len),
+73 => wire__crate__api__drive__download_manager__set_download_task_priority_impl(port, ptr, rust_vec_len, data_len),
+74 => wire__crate__api__settings__global_transfer_limit__set_global_transfer_limit_impl(port, ptr, rust_vec_len, data_len),
+75 => wire__crate__api__settings__notifications__set_notification_settings_impl(port, ptr, rust_vec_len, data_len),
+76 => wire__crate__api__settings__post_download_hooks__set_post_download_hooks_impl(port, ptr, rust_vec_len, data_len),
+77 => wire__crate__api__settings__retry_policy__set_transfer_retry_policy_impl(port, ptr, rust_vec_len, data_len),
+78 => wire__crate__api__settings__upload_concurrency__set_upload_concurrency_impl(port, ptr, rust_vec_len, data_len),
+79 => wire__crate__api__settings__upload_conflict_behavior__set_upload_conflict_behavior_impl(port, ptr, rust_vec_len, data_len),
+80 => wire__crate__api__settings__upload_integrity_reupload__set_upload_integrity_reupload_enabled_impl(port, ptr, rust_vec_len, data_len),
+81 => wire__crate__api__drive__upload_manager__set_upload_task_priority_impl(port, ptr, rust_vec_len, data_len),
+82 => wire__crate__api__drive__models__transfer_priority_default_impl(port, ptr, rust_vec_len, data_len),
+83 => wire__crate__api__drive__upload_manager__upload_progress_stream_impl(port, ptr, rust_vec_len, data_len),
+84 => wire__crate__api__drive__upload_manager__upload_queue_state_impl(port, ptr, rust_vec_len, data_len),
+85 => wire__crate__api__drive__models__upload_queue_state_default_impl(port, ptr, rust_vec_len, data_len),
+86 => wire__crate__api__drive__upload__upload_small_file_impl(port, ptr, rust_vec_len, data_len),
+87 => wire__crate__api__drive__upload_manager__upload_task_event_stream_impl(port, ptr, rust_vec_len, data_len),
+_ => unreachable!(),
+}
 }
 
 fn pde_ffi_dispatcher_sync_impl(
@@ -2251,7 +4403,7 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        23 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -2273,13 +4425,162 @@ impl flutter_rust_bridge::IntoDart for crate::api::auth::auth::AuthTokens {
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::auth::auth::AuthTokens
+    for crate::api::auth::auth::AuthTokens
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::auth::auth::AuthTokens>
+    for crate::api::auth::auth::AuthTokens
+{
+    fn into_into_dart(self) -> crate::api::auth::auth::AuthTokens {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::BandwidthSchedule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_minute.into_into_dart().into_dart(),
+            self.end_minute.into_into_dart().into_dart(),
+            self.global_kib_per_sec.into_into_dart().into_dart(),
+            self.download_kib_per_sec.into_into_dart().into_dart(),
+            self.upload_kib_per_sec.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::BandwidthSchedule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::BandwidthSchedule>
+    for crate::api::drive::models::BandwidthSchedule
+{
+    fn into_into_dart(self) -> crate::api::drive::models::BandwidthSchedule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::BandwidthSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.global_kib_per_sec.into_into_dart().into_dart(),
+            self.download_kib_per_sec.into_into_dart().into_dart(),
+            self.upload_kib_per_sec.into_into_dart().into_dart(),
+            self.schedules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::BandwidthSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::BandwidthSettings>
+    for crate::api::drive::models::BandwidthSettings
+{
+    fn into_into_dart(self) -> crate::api::drive::models::BandwidthSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::CopyJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.job_id.into_into_dart().into_dart(),
+            self.source_item_id.into_into_dart().into_dart(),
+            self.item_name.into_into_dart().into_dart(),
+            self.target_drive_id.into_into_dart().into_dart(),
+            self.target_parent_id.into_into_dart().into_dart(),
+            self.new_name.into_into_dart().into_dart(),
+            self.monitor_url.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.percentage_complete.into_into_dart().into_dart(),
+            self.new_item_id.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.completed_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::CopyJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::CopyJob>
+    for crate::api::drive::models::CopyJob
+{
+    fn into_into_dart(self) -> crate::api::drive::models::CopyJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::CopyJobStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::InProgress => 0.into_dart(),
+            Self::Completed => 1.into_dart(),
+            Self::Failed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::CopyJobStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::CopyJobStatus>
+    for crate::api::drive::models::CopyJobStatus
+{
+    fn into_into_dart(self) -> crate::api::drive::models::CopyJobStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadConflictOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NoConflict => 0.into_dart(),
+            Self::Overwritten => 1.into_dart(),
+            Self::Skipped => 2.into_dart(),
+            Self::KeptBoth => 3.into_dart(),
+            Self::AwaitingDecision => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DownloadConflictOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DownloadConflictOutcome>
+    for crate::api::drive::models::DownloadConflictOutcome
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DownloadConflictOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadConflictPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Overwrite => 0.into_dart(),
+            Self::Skip => 1.into_dart(),
+            Self::KeepBoth => 2.into_dart(),
+            Self::OverwriteIfNewer => 3.into_dart(),
+            Self::Ask => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DownloadConflictPolicy
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::auth::auth::AuthTokens>
-    for crate::api::auth::auth::AuthTokens
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DownloadConflictPolicy>
+    for crate::api::drive::models::DownloadConflictPolicy
 {
-    fn into_into_dart(self) -> crate::api::auth::auth::AuthTokens {
+    fn into_into_dart(self) -> crate::api::drive::models::DownloadConflictPolicy {
         self
     }
 }
@@ -2287,6 +4588,7 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::auth::auth::AuthTokens>
 impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadProgressUpdate {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
+            self.task_id.into_into_dart().into_dart(),
             self.item_id.into_into_dart().into_dart(),
             self.bytes_downloaded.into_into_dart().into_dart(),
             self.expected_size.into_into_dart().into_dart(),
@@ -2336,6 +4638,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadStatus
             Self::InProgress => 0.into_dart(),
             Self::Completed => 1.into_dart(),
             Self::Failed => 2.into_dart(),
+            Self::Queued => 3.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -2355,6 +4658,7 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DownloadStatus
 impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadTask {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
+            self.task_id.into_into_dart().into_dart(),
             self.item.into_into_dart().into_dart(),
             self.status.into_into_dart().into_dart(),
             self.started_at.into_into_dart().into_dart(),
@@ -2363,6 +4667,13 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadTask {
             self.size_label.into_into_dart().into_dart(),
             self.bytes_downloaded.into_into_dart().into_dart(),
             self.error_message.into_into_dart().into_dart(),
+            self.target_dir.into_into_dart().into_dart(),
+            self.conflict_policy.into_into_dart().into_dart(),
+            self.conflict_outcome.into_into_dart().into_dart(),
+            self.retry_count.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.failure_reason.into_into_dart().into_dart(),
+            self.hook_outcomes.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2379,6 +4690,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DownloadTask>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DownloadTaskEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.task.into_into_dart().into_dart(),
+            self.timestamp_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DownloadTaskEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DownloadTaskEvent>
+    for crate::api::drive::models::DownloadTaskEvent
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DownloadTaskEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveDownloadResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -2386,6 +4719,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveDownloadR
             self.saved_path.into_into_dart().into_dart(),
             self.bytes_downloaded.into_into_dart().into_dart(),
             self.expected_size.into_into_dart().into_dart(),
+            self.conflict_outcome.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2414,257 +4748,552 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveInfo {
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DriveInfo
+    for crate::api::drive::models::DriveInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveInfo>
+    for crate::api::drive::models::DriveInfo
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DriveInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveItemDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.is_folder.into_into_dart().into_dart(),
+            self.child_count.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.last_modified_at.into_into_dart().into_dart(),
+            self.file_system_created_at.into_into_dart().into_dart(),
+            self.file_system_modified_at.into_into_dart().into_dart(),
+            self.web_url.into_into_dart().into_dart(),
+            self.download_url.into_into_dart().into_dart(),
+            self.etag.into_into_dart().into_dart(),
+            self.ctag.into_into_dart().into_dart(),
+            self.parent_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DriveItemDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveItemDetails>
+    for crate::api::drive::models::DriveItemDetails
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DriveItemDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveItemSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.is_folder.into_into_dart().into_dart(),
+            self.child_count.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.last_modified.into_into_dart().into_dart(),
+            self.thumbnail_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DriveItemSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveItemSummary>
+    for crate::api::drive::models::DriveItemSummary
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DriveItemSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveOwner {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.display_name.into_into_dart().into_dart(),
+            self.user_principal_name.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DriveOwner
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveOwner>
+    for crate::api::drive::models::DriveOwner
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DriveOwner {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DrivePage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.items.into_into_dart().into_dart(),
+            self.next_link.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DrivePage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DrivePage>
+    for crate::api::drive::models::DrivePage
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DrivePage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveQuota {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.used.into_into_dart().into_dart(),
+            self.remaining.into_into_dart().into_dart(),
+            self.deleted.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::DriveQuota
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveQuota>
+    for crate::api::drive::models::DriveQuota
+{
+    fn into_into_dart(self) -> crate::api::drive::models::DriveQuota {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::FolderConflictBehavior {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fail => 0.into_dart(),
+            Self::Rename => 1.into_dart(),
+            Self::Replace => 2.into_dart(),
+            Self::UseExisting => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::FolderConflictBehavior
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::FolderConflictBehavior>
+    for crate::api::drive::models::FolderConflictBehavior
+{
+    fn into_into_dart(self) -> crate::api::drive::models::FolderConflictBehavior {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::LinkScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Anonymous => 0.into_dart(),
+            Self::Organization => 1.into_dart(),
+            Self::Users => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::LinkScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::LinkScope>
+    for crate::api::drive::models::LinkScope
+{
+    fn into_into_dart(self) -> crate::api::drive::models::LinkScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::LinkType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::View => 0.into_dart(),
+            Self::Edit => 1.into_dart(),
+            Self::Embed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::LinkType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::LinkType>
+    for crate::api::drive::models::LinkType
+{
+    fn into_into_dart(self) -> crate::api::drive::models::LinkType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::NotificationSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.notify_on_task_completed.into_into_dart().into_dart(),
+            self.notify_on_task_failed.into_into_dart().into_dart(),
+            self.notify_on_batch_finished.into_into_dart().into_dart(),
+            self.quiet_hours_enabled.into_into_dart().into_dart(),
+            self.quiet_hours_start_minute.into_into_dart().into_dart(),
+            self.quiet_hours_end_minute.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::NotificationSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::NotificationSettings>
+    for crate::api::drive::models::NotificationSettings
+{
+    fn into_into_dart(self) -> crate::api::drive::models::NotificationSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::PostDownloadHook {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.extensions.into_into_dart().into_dart(),
+            self.program.into_into_dart().into_dart(),
+            self.args.into_into_dart().into_dart(),
+            self.subfolder.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::PostDownloadHook
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::PostDownloadHook>
+    for crate::api::drive::models::PostDownloadHook
+{
+    fn into_into_dart(self) -> crate::api::drive::models::PostDownloadHook {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::PostDownloadHookKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::RunCommand => 0.into_dart(),
+            Self::ExtractArchive => 1.into_dart(),
+            Self::MoveByType => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::PostDownloadHookKind
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveInfo>
-    for crate::api::drive::models::DriveInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::PostDownloadHookKind>
+    for crate::api::drive::models::PostDownloadHookKind
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DriveInfo {
+    fn into_into_dart(self) -> crate::api::drive::models::PostDownloadHookKind {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveItemDetails {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::PostDownloadHookOutcome {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.mime_type.into_into_dart().into_dart(),
-            self.is_folder.into_into_dart().into_dart(),
-            self.child_count.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.last_modified_at.into_into_dart().into_dart(),
-            self.file_system_created_at.into_into_dart().into_dart(),
-            self.file_system_modified_at.into_into_dart().into_dart(),
-            self.web_url.into_into_dart().into_dart(),
-            self.download_url.into_into_dart().into_dart(),
-            self.etag.into_into_dart().into_dart(),
-            self.ctag.into_into_dart().into_dart(),
-            self.parent_path.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.success.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DriveItemDetails
+    for crate::api::drive::models::PostDownloadHookOutcome
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveItemDetails>
-    for crate::api::drive::models::DriveItemDetails
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::PostDownloadHookOutcome>
+    for crate::api::drive::models::PostDownloadHookOutcome
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DriveItemDetails {
+    fn into_into_dart(self) -> crate::api::drive::models::PostDownloadHookOutcome {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveItemSummary {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::ShareCapabilities {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.is_folder.into_into_dart().into_dart(),
-            self.child_count.into_into_dart().into_dart(),
-            self.mime_type.into_into_dart().into_dart(),
-            self.last_modified.into_into_dart().into_dart(),
-            self.thumbnail_url.into_into_dart().into_dart(),
+            self.drive_type.into_into_dart().into_dart(),
+            self.can_embed_link.into_into_dart().into_dart(),
+            self.can_org_scope_link.into_into_dart().into_dart(),
+            self.can_password.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DriveItemSummary
+    for crate::api::drive::models::ShareCapabilities
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveItemSummary>
-    for crate::api::drive::models::DriveItemSummary
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::ShareCapabilities>
+    for crate::api::drive::models::ShareCapabilities
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DriveItemSummary {
+    fn into_into_dart(self) -> crate::api::drive::models::ShareCapabilities {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveOwner {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::ShareLinkResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.display_name.into_into_dart().into_dart(),
-            self.user_principal_name.into_into_dart().into_dart(),
-            self.id.into_into_dart().into_dart(),
+            self.link_type.into_into_dart().into_dart(),
+            self.scope.into_into_dart().into_dart(),
+            self.web_url.into_into_dart().into_dart(),
+            self.web_html.into_into_dart().into_dart(),
+            self.permission_id.into_into_dart().into_dart(),
+            self.share_id.into_into_dart().into_dart(),
+            self.roles.into_into_dart().into_dart(),
+            self.password_protected.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DriveOwner
+    for crate::api::drive::models::ShareLinkResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveOwner>
-    for crate::api::drive::models::DriveOwner
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::ShareLinkResult>
+    for crate::api::drive::models::ShareLinkResult
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DriveOwner {
+    fn into_into_dart(self) -> crate::api::drive::models::ShareLinkResult {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DrivePage {
+impl flutter_rust_bridge::IntoDart for crate::api::auth::auth::StoredAuthState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.items.into_into_dart().into_dart(),
-            self.next_link.into_into_dart().into_dart(),
+            self.client_id.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
+            self.updated_at_millis.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DrivePage
+    for crate::api::auth::auth::StoredAuthState
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DrivePage>
-    for crate::api::drive::models::DrivePage
+impl flutter_rust_bridge::IntoIntoDart<crate::api::auth::auth::StoredAuthState>
+    for crate::api::auth::auth::StoredAuthState
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DrivePage {
+    fn into_into_dart(self) -> crate::api::auth::auth::StoredAuthState {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::DriveQuota {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::TransferEventKind {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total.into_into_dart().into_dart(),
-            self.used.into_into_dart().into_dart(),
-            self.remaining.into_into_dart().into_dart(),
-            self.deleted.into_into_dart().into_dart(),
-            self.state.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Queued => 0.into_dart(),
+            Self::Started => 1.into_dart(),
+            Self::Retrying => 2.into_dart(),
+            Self::Completed => 3.into_dart(),
+            Self::Failed => 4.into_dart(),
+            Self::Cancelled => 5.into_dart(),
+            Self::AwaitingDecision => 6.into_dart(),
+            Self::Removed => 7.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::DriveQuota
+    for crate::api::drive::models::TransferEventKind
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::DriveQuota>
-    for crate::api::drive::models::DriveQuota
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::TransferEventKind>
+    for crate::api::drive::models::TransferEventKind
 {
-    fn into_into_dart(self) -> crate::api::drive::models::DriveQuota {
+    fn into_into_dart(self) -> crate::api::drive::models::TransferEventKind {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::LinkScope {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::TransferFailureReason {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::Anonymous => 0.into_dart(),
-            Self::Organization => 1.into_dart(),
-            Self::Users => 2.into_dart(),
+            Self::InsufficientDiskSpace => 0.into_dart(),
+            Self::InsufficientQuota => 1.into_dart(),
+            Self::IntegrityMismatch => 2.into_dart(),
+            Self::SourceChanged => 3.into_dart(),
+            Self::AccessDenied => 4.into_dart(),
+            Self::NotFound => 5.into_dart(),
+            Self::RetriesExhausted => 6.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::LinkScope
+    for crate::api::drive::models::TransferFailureReason
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::LinkScope>
-    for crate::api::drive::models::LinkScope
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::TransferFailureReason>
+    for crate::api::drive::models::TransferFailureReason
 {
-    fn into_into_dart(self) -> crate::api::drive::models::LinkScope {
+    fn into_into_dart(self) -> crate::api::drive::models::TransferFailureReason {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::LinkType {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::TransferPriority {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            Self::View => 0.into_dart(),
-            Self::Edit => 1.into_dart(),
-            Self::Embed => 2.into_dart(),
+            Self::Low => 0.into_dart(),
+            Self::Normal => 1.into_dart(),
+            Self::High => 2.into_dart(),
             _ => unreachable!(),
         }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::LinkType
+    for crate::api::drive::models::TransferPriority
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::LinkType>
-    for crate::api::drive::models::LinkType
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::TransferPriority>
+    for crate::api::drive::models::TransferPriority
 {
-    fn into_into_dart(self) -> crate::api::drive::models::LinkType {
+    fn into_into_dart(self) -> crate::api::drive::models::TransferPriority {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::ShareCapabilities {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::TransferRetryPolicy {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.drive_type.into_into_dart().into_dart(),
-            self.can_embed_link.into_into_dart().into_dart(),
-            self.can_org_scope_link.into_into_dart().into_dart(),
-            self.can_password.into_into_dart().into_dart(),
+            self.max_retries.into_into_dart().into_dart(),
+            self.initial_delay_ms.into_into_dart().into_dart(),
+            self.max_delay_ms.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::ShareCapabilities
+    for crate::api::drive::models::TransferRetryPolicy
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::ShareCapabilities>
-    for crate::api::drive::models::ShareCapabilities
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::TransferRetryPolicy>
+    for crate::api::drive::models::TransferRetryPolicy
 {
-    fn into_into_dart(self) -> crate::api::drive::models::ShareCapabilities {
+    fn into_into_dart(self) -> crate::api::drive::models::TransferRetryPolicy {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::drive::models::ShareLinkResult {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadConflictBehavior {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.link_type.into_into_dart().into_dart(),
-            self.scope.into_into_dart().into_dart(),
-            self.web_url.into_into_dart().into_dart(),
-            self.web_html.into_into_dart().into_dart(),
-            self.permission_id.into_into_dart().into_dart(),
-            self.share_id.into_into_dart().into_dart(),
-            self.roles.into_into_dart().into_dart(),
-            self.password_protected.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+        match self {
+            Self::Fail => 0.into_dart(),
+            Self::Replace => 1.into_dart(),
+            Self::Rename => 2.into_dart(),
+            Self::SkipIfIdentical => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::drive::models::ShareLinkResult
+    for crate::api::drive::models::UploadConflictBehavior
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::ShareLinkResult>
-    for crate::api::drive::models::ShareLinkResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::UploadConflictBehavior>
+    for crate::api::drive::models::UploadConflictBehavior
 {
-    fn into_into_dart(self) -> crate::api::drive::models::ShareLinkResult {
+    fn into_into_dart(self) -> crate::api::drive::models::UploadConflictBehavior {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::auth::auth::StoredAuthState {
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadGroup {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.client_id.into_into_dart().into_dart(),
-            self.tokens.into_into_dart().into_dart(),
-            self.updated_at_millis.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.local_root.into_into_dart().into_dart(),
+            self.folder_name.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.completed_at.into_into_dart().into_dart(),
+            self.error_message.into_into_dart().into_dart(),
+            self.total_files.into_into_dart().into_dart(),
+            self.completed_files.into_into_dart().into_dart(),
+            self.failed_files.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.uploaded_bytes.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::auth::auth::StoredAuthState
+    for crate::api::drive::models::UploadGroup
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::auth::auth::StoredAuthState>
-    for crate::api::auth::auth::StoredAuthState
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::UploadGroup>
+    for crate::api::drive::models::UploadGroup
 {
-    fn into_into_dart(self) -> crate::api::auth::auth::StoredAuthState {
+    fn into_into_dart(self) -> crate::api::drive::models::UploadGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadGroupStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Preparing => 0.into_dart(),
+            Self::Uploading => 1.into_dart(),
+            Self::Completed => 2.into_dart(),
+            Self::PartiallyFailed => 3.into_dart(),
+            Self::Failed => 4.into_dart(),
+            Self::Cancelled => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::UploadGroupStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::UploadGroupStatus>
+    for crate::api::drive::models::UploadGroupStatus
+{
+    fn into_into_dart(self) -> crate::api::drive::models::UploadGroupStatus {
         self
     }
 }
@@ -2699,6 +5328,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadQueueSta
             self.active.into_into_dart().into_dart(),
             self.completed.into_into_dart().into_dart(),
             self.failed.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2722,6 +5352,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadStatus {
             Self::Completed => 1.into_dart(),
             Self::Failed => 2.into_dart(),
             Self::Cancelled => 3.into_dart(),
+            Self::Queued => 4.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -2754,6 +5385,17 @@ impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadTask {
             self.bytes_uploaded.into_into_dart().into_dart(),
             self.error_message.into_into_dart().into_dart(),
             self.session_url.into_into_dart().into_dart(),
+            self.session_expires_at.into_into_dart().into_dart(),
+            self.retry_count.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.failure_reason.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.conflict_behavior.into_into_dart().into_dart(),
+            self.remote_name.into_into_dart().into_dart(),
+            self.skipped_identical.into_into_dart().into_dart(),
+            self.source_modified_at.into_into_dart().into_dart(),
+            self.source_fingerprint.into_into_dart().into_dart(),
+            self.source_url.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2769,6 +5411,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::UploadTask>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::drive::models::UploadTaskEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.task.into_into_dart().into_dart(),
+            self.timestamp_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::drive::models::UploadTaskEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::drive::models::UploadTaskEvent>
+    for crate::api::drive::models::UploadTaskEvent
+{
+    fn into_into_dart(self) -> crate::api::drive::models::UploadTaskEvent {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2777,6 +5441,15 @@ impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     }
 }
 
+impl SseEncode
+    for StreamSink<crate::api::drive::models::CopyJob, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::drive::models::DownloadProgressUpdate,
@@ -2789,6 +5462,18 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::drive::models::DownloadTaskEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::drive::models::UploadProgressUpdate,
@@ -2801,6 +5486,18 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::drive::models::UploadTaskEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2820,16 +5517,112 @@ impl SseEncode for crate::api::auth::auth::AuthTokens {
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::drive::models::BandwidthSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start_minute, serializer);
+        <u32>::sse_encode(self.end_minute, serializer);
+        <u64>::sse_encode(self.global_kib_per_sec, serializer);
+        <u64>::sse_encode(self.download_kib_per_sec, serializer);
+        <u64>::sse_encode(self.upload_kib_per_sec, serializer);
+    }
+}
+
+impl SseEncode for crate::api::drive::models::BandwidthSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.global_kib_per_sec, serializer);
+        <u64>::sse_encode(self.download_kib_per_sec, serializer);
+        <u64>::sse_encode(self.upload_kib_per_sec, serializer);
+        <Vec<crate::api::drive::models::BandwidthSchedule>>::sse_encode(self.schedules, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::drive::models::CopyJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.job_id, serializer);
+        <String>::sse_encode(self.source_item_id, serializer);
+        <String>::sse_encode(self.item_name, serializer);
+        <Option<String>>::sse_encode(self.target_drive_id, serializer);
+        <Option<String>>::sse_encode(self.target_parent_id, serializer);
+        <Option<String>>::sse_encode(self.new_name, serializer);
+        <String>::sse_encode(self.monitor_url, serializer);
+        <crate::api::drive::models::CopyJobStatus>::sse_encode(self.status, serializer);
+        <Option<f64>>::sse_encode(self.percentage_complete, serializer);
+        <Option<String>>::sse_encode(self.new_item_id, serializer);
+        <Option<String>>::sse_encode(self.error_message, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <Option<i64>>::sse_encode(self.completed_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::drive::models::CopyJobStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::CopyJobStatus::InProgress => 0,
+                crate::api::drive::models::CopyJobStatus::Completed => 1,
+                crate::api::drive::models::CopyJobStatus::Failed => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::DownloadConflictOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::DownloadConflictOutcome::NoConflict => 0,
+                crate::api::drive::models::DownloadConflictOutcome::Overwritten => 1,
+                crate::api::drive::models::DownloadConflictOutcome::Skipped => 2,
+                crate::api::drive::models::DownloadConflictOutcome::KeptBoth => 3,
+                crate::api::drive::models::DownloadConflictOutcome::AwaitingDecision => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::DownloadConflictPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::DownloadConflictPolicy::Overwrite => 0,
+                crate::api::drive::models::DownloadConflictPolicy::Skip => 1,
+                crate::api::drive::models::DownloadConflictPolicy::KeepBoth => 2,
+                crate::api::drive::models::DownloadConflictPolicy::OverwriteIfNewer => 3,
+                crate::api::drive::models::DownloadConflictPolicy::Ask => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
 impl SseEncode for crate::api::drive::models::DownloadProgressUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.task_id, serializer);
         <String>::sse_encode(self.item_id, serializer);
         <u64>::sse_encode(self.bytes_downloaded, serializer);
         <Option<u64>>::sse_encode(self.expected_size, serializer);
@@ -2855,6 +5648,7 @@ impl SseEncode for crate::api::drive::models::DownloadStatus {
                 crate::api::drive::models::DownloadStatus::InProgress => 0,
                 crate::api::drive::models::DownloadStatus::Completed => 1,
                 crate::api::drive::models::DownloadStatus::Failed => 2,
+                crate::api::drive::models::DownloadStatus::Queued => 3,
                 _ => {
                     unimplemented!("");
                 }
@@ -2867,6 +5661,7 @@ impl SseEncode for crate::api::drive::models::DownloadStatus {
 impl SseEncode for crate::api::drive::models::DownloadTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.task_id, serializer);
         <crate::api::drive::models::DriveItemSummary>::sse_encode(self.item, serializer);
         <crate::api::drive::models::DownloadStatus>::sse_encode(self.status, serializer);
         <i64>::sse_encode(self.started_at, serializer);
@@ -2875,6 +5670,34 @@ impl SseEncode for crate::api::drive::models::DownloadTask {
         <Option<u64>>::sse_encode(self.size_label, serializer);
         <Option<u64>>::sse_encode(self.bytes_downloaded, serializer);
         <Option<String>>::sse_encode(self.error_message, serializer);
+        <String>::sse_encode(self.target_dir, serializer);
+        <crate::api::drive::models::DownloadConflictPolicy>::sse_encode(
+            self.conflict_policy,
+            serializer,
+        );
+        <Option<crate::api::drive::models::DownloadConflictOutcome>>::sse_encode(
+            self.conflict_outcome,
+            serializer,
+        );
+        <u32>::sse_encode(self.retry_count, serializer);
+        <crate::api::drive::models::TransferPriority>::sse_encode(self.priority, serializer);
+        <Option<crate::api::drive::models::TransferFailureReason>>::sse_encode(
+            self.failure_reason,
+            serializer,
+        );
+        <Vec<crate::api::drive::models::PostDownloadHookOutcome>>::sse_encode(
+            self.hook_outcomes,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::DownloadTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::drive::models::TransferEventKind>::sse_encode(self.kind, serializer);
+        <crate::api::drive::models::DownloadTask>::sse_encode(self.task, serializer);
+        <i64>::sse_encode(self.timestamp_millis, serializer);
     }
 }
 
@@ -2885,6 +5708,10 @@ impl SseEncode for crate::api::drive::models::DriveDownloadResult {
         <String>::sse_encode(self.saved_path, serializer);
         <u64>::sse_encode(self.bytes_downloaded, serializer);
         <Option<u64>>::sse_encode(self.expected_size, serializer);
+        <crate::api::drive::models::DownloadConflictOutcome>::sse_encode(
+            self.conflict_outcome,
+            serializer,
+        );
     }
 }
 
@@ -2968,6 +5795,24 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::drive::models::FolderConflictBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::FolderConflictBehavior::Fail => 0,
+                crate::api::drive::models::FolderConflictBehavior::Rename => 1,
+                crate::api::drive::models::FolderConflictBehavior::Replace => 2,
+                crate::api::drive::models::FolderConflictBehavior::UseExisting => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3026,6 +5871,26 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::drive::models::BandwidthSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::drive::models::BandwidthSchedule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::drive::models::CopyJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::drive::models::CopyJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::drive::models::DownloadTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3046,6 +5911,26 @@ impl SseEncode for Vec<crate::api::drive::models::DriveItemSummary> {
     }
 }
 
+impl SseEncode for Vec<crate::api::drive::models::PostDownloadHook> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::drive::models::PostDownloadHook>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::drive::models::PostDownloadHookOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::drive::models::PostDownloadHookOutcome>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3056,6 +5941,16 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::drive::models::UploadGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::drive::models::UploadGroup>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::drive::models::UploadTask> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3066,6 +5961,19 @@ impl SseEncode for Vec<crate::api::drive::models::UploadTask> {
     }
 }
 
+impl SseEncode for crate::api::drive::models::NotificationSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <bool>::sse_encode(self.notify_on_task_completed, serializer);
+        <bool>::sse_encode(self.notify_on_task_failed, serializer);
+        <bool>::sse_encode(self.notify_on_batch_finished, serializer);
+        <bool>::sse_encode(self.quiet_hours_enabled, serializer);
+        <u32>::sse_encode(self.quiet_hours_start_minute, serializer);
+        <u32>::sse_encode(self.quiet_hours_end_minute, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3086,6 +5994,26 @@ impl SseEncode for Option<bool> {
     }
 }
 
+impl SseEncode for Option<crate::api::drive::models::DownloadConflictOutcome> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::drive::models::DownloadConflictOutcome>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::drive::models::DownloadConflictPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::drive::models::DownloadConflictPolicy>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::drive::models::DriveOwner> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3136,6 +6064,36 @@ impl SseEncode for Option<crate::api::auth::auth::StoredAuthState> {
     }
 }
 
+impl SseEncode for Option<crate::api::drive::models::TransferFailureReason> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::drive::models::TransferFailureReason>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::drive::models::TransferPriority> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::drive::models::TransferPriority>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3146,6 +6104,16 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<crate::api::drive::models::UploadConflictBehavior> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::drive::models::UploadConflictBehavior>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3156,6 +6124,44 @@ impl SseEncode for Option<Vec<String>> {
     }
 }
 
+impl SseEncode for crate::api::drive::models::PostDownloadHook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::drive::models::PostDownloadHookKind>::sse_encode(self.kind, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <Vec<String>>::sse_encode(self.extensions, serializer);
+        <Option<String>>::sse_encode(self.program, serializer);
+        <Vec<String>>::sse_encode(self.args, serializer);
+        <Option<String>>::sse_encode(self.subfolder, serializer);
+    }
+}
+
+impl SseEncode for crate::api::drive::models::PostDownloadHookKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::PostDownloadHookKind::RunCommand => 0,
+                crate::api::drive::models::PostDownloadHookKind::ExtractArchive => 1,
+                crate::api::drive::models::PostDownloadHookKind::MoveByType => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::PostDownloadHookOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::drive::models::PostDownloadHookKind>::sse_encode(self.kind, serializer);
+        <bool>::sse_encode(self.success, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
 impl SseEncode for crate::api::drive::models::ShareCapabilities {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3189,6 +6195,75 @@ impl SseEncode for crate::api::auth::auth::StoredAuthState {
     }
 }
 
+impl SseEncode for crate::api::drive::models::TransferEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::TransferEventKind::Queued => 0,
+                crate::api::drive::models::TransferEventKind::Started => 1,
+                crate::api::drive::models::TransferEventKind::Retrying => 2,
+                crate::api::drive::models::TransferEventKind::Completed => 3,
+                crate::api::drive::models::TransferEventKind::Failed => 4,
+                crate::api::drive::models::TransferEventKind::Cancelled => 5,
+                crate::api::drive::models::TransferEventKind::AwaitingDecision => 6,
+                crate::api::drive::models::TransferEventKind::Removed => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::TransferFailureReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::TransferFailureReason::InsufficientDiskSpace => 0,
+                crate::api::drive::models::TransferFailureReason::InsufficientQuota => 1,
+                crate::api::drive::models::TransferFailureReason::IntegrityMismatch => 2,
+                crate::api::drive::models::TransferFailureReason::SourceChanged => 3,
+                crate::api::drive::models::TransferFailureReason::AccessDenied => 4,
+                crate::api::drive::models::TransferFailureReason::NotFound => 5,
+                crate::api::drive::models::TransferFailureReason::RetriesExhausted => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::TransferPriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::TransferPriority::Low => 0,
+                crate::api::drive::models::TransferPriority::Normal => 1,
+                crate::api::drive::models::TransferPriority::High => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::TransferRetryPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_retries, serializer);
+        <u64>::sse_encode(self.initial_delay_ms, serializer);
+        <u64>::sse_encode(self.max_delay_ms, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3215,6 +6290,63 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::drive::models::UploadConflictBehavior {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::UploadConflictBehavior::Fail => 0,
+                crate::api::drive::models::UploadConflictBehavior::Replace => 1,
+                crate::api::drive::models::UploadConflictBehavior::Rename => 2,
+                crate::api::drive::models::UploadConflictBehavior::SkipIfIdentical => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::drive::models::UploadGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.group_id, serializer);
+        <String>::sse_encode(self.local_root, serializer);
+        <String>::sse_encode(self.folder_name, serializer);
+        <Option<String>>::sse_encode(self.parent_id, serializer);
+        <crate::api::drive::models::UploadGroupStatus>::sse_encode(self.status, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Option<i64>>::sse_encode(self.completed_at, serializer);
+        <Option<String>>::sse_encode(self.error_message, serializer);
+        <u32>::sse_encode(self.total_files, serializer);
+        <u32>::sse_encode(self.completed_files, serializer);
+        <u32>::sse_encode(self.failed_files, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.uploaded_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::drive::models::UploadGroupStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::drive::models::UploadGroupStatus::Preparing => 0,
+                crate::api::drive::models::UploadGroupStatus::Uploading => 1,
+                crate::api::drive::models::UploadGroupStatus::Completed => 2,
+                crate::api::drive::models::UploadGroupStatus::PartiallyFailed => 3,
+                crate::api::drive::models::UploadGroupStatus::Failed => 4,
+                crate::api::drive::models::UploadGroupStatus::Cancelled => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::drive::models::UploadProgressUpdate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3232,6 +6364,7 @@ impl SseEncode for crate::api::drive::models::UploadQueueState {
         <Vec<crate::api::drive::models::UploadTask>>::sse_encode(self.active, serializer);
         <Vec<crate::api::drive::models::UploadTask>>::sse_encode(self.completed, serializer);
         <Vec<crate::api::drive::models::UploadTask>>::sse_encode(self.failed, serializer);
+        <Vec<crate::api::drive::models::UploadGroup>>::sse_encode(self.groups, serializer);
     }
 }
 
@@ -3244,6 +6377,7 @@ impl SseEncode for crate::api::drive::models::UploadStatus {
                 crate::api::drive::models::UploadStatus::Completed => 1,
                 crate::api::drive::models::UploadStatus::Failed => 2,
                 crate::api::drive::models::UploadStatus::Cancelled => 3,
+                crate::api::drive::models::UploadStatus::Queued => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -3269,6 +6403,32 @@ impl SseEncode for crate::api::drive::models::UploadTask {
         <Option<u64>>::sse_encode(self.bytes_uploaded, serializer);
         <Option<String>>::sse_encode(self.error_message, serializer);
         <Option<String>>::sse_encode(self.session_url, serializer);
+        <Option<i64>>::sse_encode(self.session_expires_at, serializer);
+        <u32>::sse_encode(self.retry_count, serializer);
+        <crate::api::drive::models::TransferPriority>::sse_encode(self.priority, serializer);
+        <Option<crate::api::drive::models::TransferFailureReason>>::sse_encode(
+            self.failure_reason,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.group_id, serializer);
+        <crate::api::drive::models::UploadConflictBehavior>::sse_encode(
+            self.conflict_behavior,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.remote_name, serializer);
+        <bool>::sse_encode(self.skipped_identical, serializer);
+        <Option<i64>>::sse_encode(self.source_modified_at, serializer);
+        <Option<String>>::sse_encode(self.source_fingerprint, serializer);
+        <Option<String>>::sse_encode(self.source_url, serializer);
+    }
+}
+
+impl SseEncode for crate::api::drive::models::UploadTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::drive::models::TransferEventKind>::sse_encode(self.kind, serializer);
+        <crate::api::drive::models::UploadTask>::sse_encode(self.task, serializer);
+        <i64>::sse_encode(self.timestamp_millis, serializer);
     }
 }
 
@@ -1,6 +1,16 @@
 pub mod api;
+pub mod bandwidth;
+pub mod content_hash;
+pub mod copy_manager;
 pub mod db;
 pub mod download_manager;
 mod frb_generated;
+mod lock;
+pub mod notifications;
+pub mod post_transfer;
+pub mod preflight;
+pub mod retry;
 pub mod settings;
+mod time_window;
+pub mod transfer_queue;
 pub mod upload_manager;
@@ -0,0 +1,10 @@
+// 各管理器共享的锁工具。
+use std::sync::{Mutex, MutexGuard};
+
+/// 获取互斥锁；遇到中毒锁时直接取出内部数据继续运行，避免某个线程 panic 后整个管理器瘫痪。
+pub(crate) fn recover_lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poison) => poison.into_inner(),
+    }
+}
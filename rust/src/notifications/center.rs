@@ -0,0 +1,146 @@
+use super::sender::{spawn_sender, DesktopNotification};
+use crate::api::drive::models::{NotificationSettings, TransferEventKind};
+use crate::bandwidth::TransferDirection;
+use crate::lock::recover_lock;
+use crate::settings::notifications::{default_notification_settings, get_notification_settings};
+use crate::time_window::{local_minute, minute_window_contains};
+use once_cell::sync::Lazy;
+use std::sync::{
+    mpsc::{SyncSender, TrySendError},
+    Mutex,
+};
+
+static NOTIFICATION_CENTER: Lazy<NotificationCenter> = Lazy::new(NotificationCenter::new);
+
+/// 根据传输管理器上报的生命周期事件决定是否弹出桌面通知。
+/// 每个方向独立统计当前批次：从队列非空到再次清空之间结束的任务视为同一批。
+pub struct NotificationCenter {
+    /// 设置缓存，避免每个事件都查询数据库；设置变更时由 `apply_settings` 刷新。
+    settings: Mutex<NotificationSettings>,
+    batches: Mutex<BatchState>,
+    sender: SyncSender<DesktopNotification>,
+}
+
+#[derive(Default)]
+struct BatchState {
+    download: BatchStats,
+    upload: BatchStats,
+}
+
+#[derive(Clone, Copy, Default)]
+struct BatchStats {
+    completed: u32,
+    failed: u32,
+}
+
+impl NotificationCenter {
+    fn new() -> Self {
+        let settings = get_notification_settings().unwrap_or_else(|err| {
+            eprintln!("[notifications] failed to load settings: {err}; fallback to default");
+            default_notification_settings()
+        });
+        Self {
+            settings: Mutex::new(settings),
+            batches: Mutex::new(BatchState::default()),
+            sender: spawn_sender(),
+        }
+    }
+
+    pub fn shared() -> &'static Self {
+        &NOTIFICATION_CENTER
+    }
+
+    /// 替换通知设置，对之后结束的传输生效。
+    pub fn apply_settings(&self, settings: NotificationSettings) {
+        *recover_lock(&self.settings) = settings;
+    }
+
+    /// 记录一次任务事件。`queue_drained` 表示事件发生后该方向已没有排队或进行中的任务，
+    /// 此时结算本批次并按设置发送汇总通知。免打扰时段内只统计、不通知。
+    pub fn record(
+        &self,
+        direction: TransferDirection,
+        kind: TransferEventKind,
+        file_name: &str,
+        error_message: Option<&str>,
+        queue_drained: bool,
+    ) {
+        let finished_batch = {
+            let mut batches = recover_lock(&self.batches);
+            let batch = match direction {
+                TransferDirection::Download => &mut batches.download,
+                TransferDirection::Upload => &mut batches.upload,
+            };
+            match kind {
+                TransferEventKind::Completed => batch.completed += 1,
+                TransferEventKind::Failed => batch.failed += 1,
+                TransferEventKind::Cancelled
+                | TransferEventKind::AwaitingDecision
+                | TransferEventKind::Removed => {}
+                TransferEventKind::Queued
+                | TransferEventKind::Started
+                | TransferEventKind::Retrying => return,
+            }
+            queue_drained.then(|| std::mem::take(batch))
+        };
+
+        let settings = recover_lock(&self.settings).clone();
+        if !settings.enabled || in_quiet_hours(&settings, local_minute()) {
+            return;
+        }
+        let label = direction_label(direction);
+        match kind {
+            TransferEventKind::Completed if settings.notify_on_task_completed => {
+                self.post(format!("{label}完成"), file_name.to_string());
+            }
+            TransferEventKind::Failed if settings.notify_on_task_failed => {
+                let body = match error_message {
+                    Some(err) => format!("{file_name}：{err}"),
+                    None => file_name.to_string(),
+                };
+                self.post(format!("{label}失败"), body);
+            }
+            _ => {}
+        }
+        if let Some(batch) = finished_batch {
+            // 只有一个任务的批次已由单任务通知覆盖，不再重复汇总。
+            if settings.notify_on_batch_finished && batch.completed + batch.failed > 1 {
+                let summary = if batch.failed == 0 {
+                    format!("{label}队列已全部完成")
+                } else {
+                    format!("{label}队列已结束")
+                };
+                let body = format!("成功 {} 个，失败 {} 个", batch.completed, batch.failed);
+                self.post(summary, body);
+            }
+        }
+    }
+
+    fn post(&self, summary: String, body: String) {
+        match self.sender.try_send(DesktopNotification { summary, body }) {
+            Ok(_) => {}
+            Err(TrySendError::Full(_)) => {
+                eprintln!("[notifications] sender is busy; dropping notification");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                eprintln!("[notifications] sender thread has exited");
+            }
+        }
+    }
+}
+
+fn direction_label(direction: TransferDirection) -> &'static str {
+    match direction {
+        TransferDirection::Download => "下载",
+        TransferDirection::Upload => "上传",
+    }
+}
+
+fn in_quiet_hours(settings: &NotificationSettings, minute: u32) -> bool {
+    settings.quiet_hours_enabled
+        && minute_window_contains(
+            settings.quiet_hours_start_minute,
+            settings.quiet_hours_end_minute,
+            minute,
+        )
+}
@@ -0,0 +1,5 @@
+pub mod center;
+pub mod sender;
+
+pub use center::NotificationCenter;
+pub use sender::DesktopNotification;
@@ -0,0 +1,53 @@
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+use notify_rust::Notification;
+use std::{
+    sync::mpsc::{self, SyncSender},
+    thread,
+};
+
+/// 通知发送队列的缓冲大小；积压时直接丢弃，避免拖慢传输线程。
+const NOTIFICATION_CHANNEL_CAP: usize = 32;
+/// 与 Linux 桌面文件、macOS 产品名保持一致，便于通知中心按应用归类。
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+const APP_NAME: &str = "skydrivex";
+
+/// 一条待发送的桌面通知。
+#[derive(Clone, Debug)]
+pub struct DesktopNotification {
+    pub summary: String,
+    pub body: String,
+}
+
+/// 启动通知发送线程：D-Bus（Linux）或系统通知中心的调用可能阻塞，
+/// 统一放在独立线程中串行发送。
+pub(crate) fn spawn_sender() -> SyncSender<DesktopNotification> {
+    let (tx, rx) = mpsc::sync_channel::<DesktopNotification>(NOTIFICATION_CHANNEL_CAP);
+    thread::spawn(move || {
+        for notification in rx {
+            if let Err(err) = show(&notification) {
+                eprintln!(
+                    "[notifications] failed to show \"{}\": {err}",
+                    notification.summary
+                );
+            }
+        }
+    });
+    tx
+}
+
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+fn show(notification: &DesktopNotification) -> Result<(), String> {
+    Notification::new()
+        .appname(APP_NAME)
+        .summary(&notification.summary)
+        .body(&notification.body)
+        .show()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// 移动端等没有桌面通知中心的平台直接忽略。
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+fn show(_notification: &DesktopNotification) -> Result<(), String> {
+    Ok(())
+}
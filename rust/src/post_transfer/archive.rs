@@ -0,0 +1,216 @@
+use crate::api::drive::download::next_available_path;
+use crate::preflight::check_local_space;
+use std::{
+    fs::{self, File},
+    io::{self, Read, Seek},
+    path::{Path, PathBuf},
+};
+use zip::ZipArchive;
+
+/// 单个压缩包最多解压的条目数量。
+const MAX_ZIP_ENTRIES: usize = 10_000;
+/// 单个压缩包解压后的总大小上限（16 GiB）。
+const MAX_ZIP_UNCOMPRESSED_BYTES: u64 = 16 * 1024 * 1024 * 1024;
+
+/// 将 zip 压缩包解压到同目录下以文件名（不含扩展名）命名的新文件夹，
+/// 文件夹已存在时追加 " (1)" 等后缀，返回解压目录与写出的文件数量。
+/// 条目路径越出解压目录（zip slip）时跳过该条目；同名条目重复出现时追加 " (1)" 等后缀，不覆盖先写出的文件。
+/// 解压前按条目声明的大小检查数量/总量上限与磁盘剩余空间（含保留空间），
+/// 写入时也不允许条目超出声明大小，防止压缩炸弹写满磁盘。
+pub fn extract_zip(archive_path: &Path) -> Result<(PathBuf, usize), String> {
+    let file = File::open(archive_path)
+        .map_err(|e| format!("无法打开压缩包 {}: {e}", archive_path.display()))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("无法读取压缩包: {e}"))?;
+    let parent = archive_path.parent().unwrap_or_else(|| Path::new("."));
+    let stem = archive_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "archive".to_string());
+    let total_size =
+        declared_uncompressed_size(&mut archive, MAX_ZIP_ENTRIES, MAX_ZIP_UNCOMPRESSED_BYTES)?;
+    check_local_space(&parent.to_string_lossy(), total_size).map_err(|failure| failure.message)?;
+    let destination = next_available_path(parent, &stem);
+    fs::create_dir_all(&destination)
+        .map_err(|e| format!("无法创建解压目录 {}: {e}", destination.display()))?;
+    let extracted = extract_entries(&mut archive, &destination)?;
+    Ok((destination, extracted))
+}
+
+/// 将全部条目写入解压目录，返回写出的文件数量。
+fn extract_entries<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    destination: &Path,
+) -> Result<usize, String> {
+    let mut extracted = 0;
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| format!("无法读取压缩包条目: {e}"))?;
+        let Some(relative) = entry.enclosed_name() else {
+            eprintln!(
+                "[post-transfer] skip unsafe zip entry {} in {}",
+                entry.name(),
+                destination.display()
+            );
+            continue;
+        };
+        let mut output = destination.join(relative);
+        if entry.is_dir() {
+            fs::create_dir_all(&output)
+                .map_err(|e| format!("无法创建目录 {}: {e}", output.display()))?;
+            continue;
+        }
+        if let Some(dir) = output.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("无法创建目录 {}: {e}", dir.display()))?;
+        }
+        // 解压目录是新建的，已存在说明压缩包内有重名条目。
+        if output.exists() {
+            let dir = output.parent().unwrap_or(destination).to_path_buf();
+            let file_name = output
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            output = next_available_path(&dir, &file_name);
+        }
+        let declared = entry.size();
+        let mut writer =
+            File::create(&output).map_err(|e| format!("无法写入 {}: {e}", output.display()))?;
+        let written = io::copy(
+            &mut (&mut entry).take(declared.saturating_add(1)),
+            &mut writer,
+        )
+        .map_err(|e| format!("解压 {} 失败: {e}", output.display()))?;
+        if written > declared {
+            drop(writer);
+            let _ = fs::remove_file(&output);
+            return Err(format!(
+                "压缩包条目 {} 的实际大小超过声明大小，已停止解压",
+                entry.name()
+            ));
+        }
+        extracted += 1;
+    }
+    Ok(extracted)
+}
+
+/// 汇总全部条目声明的解压后大小，条目数量或总大小超出上限时拒绝解压。
+fn declared_uncompressed_size<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    max_entries: usize,
+    max_bytes: u64,
+) -> Result<u64, String> {
+    if archive.len() > max_entries {
+        return Err(format!(
+            "压缩包条目过多（{} 个，上限 {max_entries} 个），已跳过解压",
+            archive.len()
+        ));
+    }
+    let mut total: u64 = 0;
+    for index in 0..archive.len() {
+        let entry = archive
+            .by_index_raw(index)
+            .map_err(|e| format!("无法读取压缩包条目: {e}"))?;
+        total = total.saturating_add(entry.size());
+        if total > max_bytes {
+            return Err(format!(
+                "压缩包解压后超过 {} MiB 上限，已跳过解压",
+                max_bytes / (1024 * 1024)
+            ));
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("skydrivex-archive-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, content) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn open(bytes: Vec<u8>) -> ZipArchive<Cursor<Vec<u8>>> {
+        ZipArchive::new(Cursor::new(bytes)).unwrap()
+    }
+
+    #[test]
+    fn unsafe_entries_are_skipped() {
+        let dir = temp_dir("slip");
+        let destination = dir.join("out");
+        fs::create_dir_all(&destination).unwrap();
+        let bytes = build_zip(&[("../evil.txt", b"evil"), ("docs/a.txt", b"hello")]);
+
+        let extracted = extract_entries(&mut open(bytes), &destination).unwrap();
+        assert_eq!(extracted, 1);
+        assert_eq!(fs::read(destination.join("docs/a.txt")).unwrap(), b"hello");
+        assert!(!dir.join("evil.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn duplicate_entries_do_not_overwrite() {
+        let dir = temp_dir("duplicate");
+        // 名称不同但解析到同一路径的条目，在大小写不敏感的文件系统上同样会出现。
+        let bytes = build_zip(&[("a.txt", b"first"), ("./a.txt", b"second")]);
+
+        let extracted = extract_entries(&mut open(bytes), &dir).unwrap();
+        assert_eq!(extracted, 2);
+        assert_eq!(fs::read(dir.join("a.txt")).unwrap(), b"first");
+        assert_eq!(fs::read(dir.join("a (1).txt")).unwrap(), b"second");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn entry_larger_than_declared_size_is_rejected() {
+        let dir = temp_dir("bomb");
+        let mut bytes = build_zip(&[("bomb.bin", &[7u8; 64])]);
+        // 把本地头与中央目录中的解压后大小都改成 8 字节，模拟谎报大小的条目。
+        patch_uncompressed_size(&mut bytes, 64, 8);
+
+        let err = extract_entries(&mut open(bytes), &dir).unwrap_err();
+        assert!(err.contains("bomb.bin"), "{err}");
+        assert!(!dir.join("bomb.bin").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn limits_reject_large_or_crowded_archives() {
+        let bytes = build_zip(&[("a.txt", &[0u8; 100]), ("b.txt", &[0u8; 100])]);
+        assert_eq!(
+            declared_uncompressed_size(&mut open(bytes.clone()), 2, 200),
+            Ok(200)
+        );
+        assert!(declared_uncompressed_size(&mut open(bytes.clone()), 1, 200).is_err());
+        assert!(declared_uncompressed_size(&mut open(bytes), 2, 199).is_err());
+    }
+
+    /// 本地文件头在偏移 22、中央目录头在偏移 24 处记录解压后大小。
+    fn patch_uncompressed_size(bytes: &mut [u8], actual: u32, declared: u32) {
+        for (signature, offset) in [(0x04034b50u32, 22), (0x02014b50u32, 24)] {
+            let signature = signature.to_le_bytes();
+            let position = bytes
+                .windows(4)
+                .position(|window| window == signature)
+                .unwrap();
+            let field = position + offset;
+            assert_eq!(bytes[field..field + 4], actual.to_le_bytes());
+            bytes[field..field + 4].copy_from_slice(&declared.to_le_bytes());
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use super::archive::extract_zip;
+use crate::api::drive::download::next_available_path;
+use crate::api::drive::models::{
+    DownloadConflictOutcome, PostDownloadHook, PostDownloadHookKind, PostDownloadHookOutcome,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 用户命令的最长运行时间，超时后强制结束，避免占住下载线程。
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const PATH_PLACEHOLDER: &str = "{path}";
+
+/// 冲突处理为跳过或等待决定时，本地文件不是本次下载写入的，不执行下载后动作。
+pub fn should_run_hooks(outcome: &DownloadConflictOutcome) -> bool {
+    !matches!(
+        outcome,
+        DownloadConflictOutcome::Skipped | DownloadConflictOutcome::AwaitingDecision
+    )
+}
+
+/// 按顺序执行已启用且扩展名匹配的下载后动作，返回文件的最终路径与各动作结果。
+/// 单个动作失败只记录结果，不影响后续动作与下载任务本身的完成状态。
+pub fn run_post_download_hooks(
+    saved_path: &str,
+    hooks: &[PostDownloadHook],
+) -> (String, Vec<PostDownloadHookOutcome>) {
+    let mut current = PathBuf::from(saved_path);
+    let mut outcomes = Vec::new();
+    for hook in hooks.iter().filter(|hook| hook.enabled) {
+        if !matches_extension(hook, &current) {
+            continue;
+        }
+        let result = match hook.kind {
+            PostDownloadHookKind::RunCommand => run_command(hook, &current),
+            PostDownloadHookKind::ExtractArchive => {
+                if !has_extension(&current, "zip") {
+                    continue;
+                }
+                extract_zip(&current)
+                    .map(|(dir, count)| format!("已解压 {count} 个文件到 {}", dir.display()))
+            }
+            PostDownloadHookKind::MoveByType => move_to_subfolder(hook, &current).map(|moved| {
+                let message = format!("已移动到 {}", moved.display());
+                current = moved;
+                message
+            }),
+        };
+        let (success, message) = match result {
+            Ok(message) => (true, message),
+            Err(err) => {
+                eprintln!(
+                    "[post-transfer] {:?} hook failed for {}: {err}",
+                    hook.kind,
+                    current.display()
+                );
+                (false, err)
+            }
+        };
+        outcomes.push(PostDownloadHookOutcome {
+            kind: hook.kind,
+            success,
+            message,
+        });
+    }
+    (current.to_string_lossy().into_owned(), outcomes)
+}
+
+fn matches_extension(hook: &PostDownloadHook, path: &Path) -> bool {
+    hook.extensions.is_empty() || hook.extensions.iter().any(|ext| has_extension(path, ext))
+}
+
+fn has_extension(path: &Path, expected: &str) -> bool {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().eq_ignore_ascii_case(expected))
+        .unwrap_or(false)
+}
+
+/// 直接启动程序而不经过 shell，参数中的 `{path}` 替换为文件路径。
+fn run_command(hook: &PostDownloadHook, path: &Path) -> Result<String, String> {
+    let program = hook
+        .program
+        .as_deref()
+        .ok_or_else(|| "未配置要执行的程序".to_string())?;
+    let path_str = path.to_string_lossy();
+    let mut args: Vec<String> = hook
+        .args
+        .iter()
+        .map(|arg| arg.replace(PATH_PLACEHOLDER, &path_str))
+        .collect();
+    if !hook.args.iter().any(|arg| arg.contains(PATH_PLACEHOLDER)) {
+        args.push(path_str.into_owned());
+    }
+    let mut child = Command::new(program)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("无法启动 {program}: {e}"))?;
+    let deadline = Instant::now() + COMMAND_TIMEOUT;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return Ok(format!("{program} 执行成功")),
+            Ok(Some(status)) => return Err(format!("{program} 执行失败：{status}")),
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "{program} 运行超过 {} 秒，已强制结束",
+                    COMMAND_TIMEOUT.as_secs()
+                ));
+            }
+            Ok(None) => thread::sleep(COMMAND_POLL_INTERVAL),
+            Err(err) => return Err(format!("等待 {program} 结束失败: {err}")),
+        }
+    }
+}
+
+/// 移动到文件所在目录下的子文件夹，目标已存在同名文件时追加 " (1)" 等后缀。
+fn move_to_subfolder(hook: &PostDownloadHook, path: &Path) -> Result<PathBuf, String> {
+    let subfolder = hook
+        .subfolder
+        .as_deref()
+        .ok_or_else(|| "未配置目标子文件夹".to_string())?;
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| format!("无效的文件路径：{}", path.display()))?;
+    let target_dir = parent.join(subfolder);
+    fs::create_dir_all(&target_dir)
+        .map_err(|e| format!("无法创建目录 {}: {e}", target_dir.display()))?;
+    let mut target = target_dir.join(&file_name);
+    if target.exists() {
+        target = next_available_path(&target_dir, &file_name);
+    }
+    fs::rename(path, &target).map_err(|e| format!("无法移动到 {}: {e}", target.display()))?;
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("skydrivex-hooks-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn hook(kind: PostDownloadHookKind) -> PostDownloadHook {
+        PostDownloadHook {
+            kind,
+            enabled: true,
+            extensions: Vec::new(),
+            program: None,
+            args: Vec::new(),
+            subfolder: None,
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_command_substitutes_path_placeholder() {
+        let dir = temp_dir("placeholder");
+        let file = dir.join("report.pdf");
+        let mut command = hook(PostDownloadHookKind::RunCommand);
+        command.program = Some("sh".to_string());
+        command.args = vec![
+            "-c".to_string(),
+            "printf %s \"$1\" > \"$1.seen\"".to_string(),
+            "sh".to_string(),
+            "{path}".to_string(),
+        ];
+        run_command(&command, &file).unwrap();
+        let seen = fs::read_to_string(dir.join("report.pdf.seen")).unwrap();
+        assert_eq!(seen, file.to_string_lossy());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_command_appends_path_without_placeholder() {
+        let dir = temp_dir("append");
+        let file = dir.join("report.pdf");
+        let mut command = hook(PostDownloadHookKind::RunCommand);
+        command.program = Some("sh".to_string());
+        command.args = vec![
+            "-c".to_string(),
+            "test \"$1\" = \"$2\"".to_string(),
+            "sh".to_string(),
+            file.to_string_lossy().into_owned(),
+        ];
+        assert!(run_command(&command, &file).is_ok());
+        command.args[3] = "other".to_string();
+        assert!(run_command(&command, &file).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn move_by_type_keeps_existing_file() {
+        let dir = temp_dir("move");
+        let file = dir.join("photo.JPG");
+        fs::write(&file, b"new").unwrap();
+        fs::create_dir_all(dir.join("images")).unwrap();
+        fs::write(dir.join("images/photo.JPG"), b"old").unwrap();
+        let mut mover = hook(PostDownloadHookKind::MoveByType);
+        mover.extensions = vec!["jpg".to_string()];
+        mover.subfolder = Some("images".to_string());
+
+        let (final_path, outcomes) = run_post_download_hooks(&file.to_string_lossy(), &[mover]);
+        assert!(outcomes[0].success, "{}", outcomes[0].message);
+        assert_eq!(PathBuf::from(&final_path), dir.join("images/photo (1).JPG"));
+        assert_eq!(fs::read(dir.join("images/photo.JPG")).unwrap(), b"old");
+        assert_eq!(fs::read(&final_path).unwrap(), b"new");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn hooks_skip_disabled_and_unmatched_extensions() {
+        let mut disabled = hook(PostDownloadHookKind::MoveByType);
+        disabled.enabled = false;
+        let mut other_type = hook(PostDownloadHookKind::MoveByType);
+        other_type.extensions = vec!["mp4".to_string()];
+        let (final_path, outcomes) =
+            run_post_download_hooks("/nonexistent/photo.jpg", &[disabled, other_type]);
+        assert_eq!(final_path, "/nonexistent/photo.jpg");
+        assert!(outcomes.is_empty());
+        assert!(!should_run_hooks(&DownloadConflictOutcome::Skipped));
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+};
+
+/// 使用系统默认程序打开文件或文件夹。
+pub fn open_path(path: &Path) -> Result<(), String> {
+    ensure_exists(path)?;
+    #[cfg(target_os = "windows")]
+    let command = {
+        let mut command = Command::new("explorer");
+        command.arg(path);
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let command = {
+        let mut command = Command::new("open");
+        command.arg(path);
+        command
+    };
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(path);
+        command
+    };
+    spawn_detached(command)
+}
+
+/// 在文件管理器中显示文件并选中它。
+/// Linux 优先通过 D-Bus 调用 `org.freedesktop.FileManager1.ShowItems`，
+/// 文件管理器不支持该接口时退回到打开所在目录。
+pub fn reveal_path(path: &Path) -> Result<(), String> {
+    ensure_exists(path)?;
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        let mut command = Command::new("explorer");
+        // explorer 自行解析 `/select,` 参数，需原样传入带引号的路径
+        command.raw_arg(format!("/select,\"{}\"", path.display()));
+        spawn_detached(command)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = Command::new("open");
+        command.arg("-R").arg(path);
+        spawn_detached(command)
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        if show_items_via_dbus(path) {
+            return Ok(());
+        }
+        let parent = path.parent().unwrap_or(path);
+        open_path(parent)
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn show_items_via_dbus(path: &Path) -> bool {
+    let Ok(uri) = url::Url::from_file_path(path) else {
+        return false;
+    };
+    Command::new("dbus-send")
+        .args([
+            "--session",
+            "--print-reply",
+            "--dest=org.freedesktop.FileManager1",
+            "--type=method_call",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+        ])
+        .arg(format!("array:string:{uri}"))
+        .arg("string:")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+fn ensure_exists(path: &Path) -> Result<(), String> {
+    if path.exists() {
+        Ok(())
+    } else {
+        Err(format!("文件不存在：{}", path.display()))
+    }
+}
+
+/// 启动外部程序后不等待其退出；由后台线程回收子进程，避免遗留僵尸进程。
+fn spawn_detached(mut command: Command) -> Result<(), String> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("无法启动系统程序: {e}"))?;
+    thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
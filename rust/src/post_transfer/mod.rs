@@ -0,0 +1,7 @@
+pub mod archive;
+pub mod hooks;
+pub mod launcher;
+
+pub use archive::extract_zip;
+pub use hooks::{run_post_download_hooks, should_run_hooks};
+pub use launcher::{open_path, reveal_path};
@@ -0,0 +1,42 @@
+use super::failure::{format_bytes, PreflightFailure, INSUFFICIENT_DISK_SPACE_MESSAGE};
+use crate::api::drive::models::TransferFailureReason;
+use crate::settings::disk_space_reserve::{
+    default_disk_space_reserve_mib, get_disk_space_reserve_mib,
+};
+use std::path::Path;
+
+/// 检查目标目录所在磁盘能否容纳 `required_bytes`，并额外保留设置中的空闲空间。
+/// - 目标目录尚未创建时，沿父目录向上查找已存在的路径来确定所在磁盘。
+/// - 无法读取磁盘信息时放行，交由实际写入阶段报告错误。
+pub fn check_local_space(target_dir: &str, required_bytes: u64) -> Result<(), PreflightFailure> {
+    let Some(existing) = Path::new(target_dir).ancestors().find(|path| path.exists()) else {
+        return Ok(());
+    };
+    let available = match fs2::available_space(existing) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            eprintln!(
+                "[preflight] failed to query free space for {}: {err}",
+                existing.display()
+            );
+            return Ok(());
+        }
+    };
+    let reserve_mib = get_disk_space_reserve_mib().unwrap_or_else(|err| {
+        eprintln!("[preflight] failed to load disk space reserve: {err}; fallback to default");
+        default_disk_space_reserve_mib()
+    });
+    let reserve = reserve_mib.saturating_mul(1024 * 1024);
+    if available >= required_bytes.saturating_add(reserve) {
+        return Ok(());
+    }
+    Err(PreflightFailure {
+        reason: TransferFailureReason::InsufficientDiskSpace,
+        message: format!(
+            "{INSUFFICIENT_DISK_SPACE_MESSAGE}：需要 {}（另需保留 {}），当前可用 {}",
+            format_bytes(required_bytes),
+            format_bytes(reserve),
+            format_bytes(available)
+        ),
+    })
+}
@@ -0,0 +1,29 @@
+use crate::api::drive::models::TransferFailureReason;
+
+/// 本地磁盘空间不足时的错误前缀。
+pub const INSUFFICIENT_DISK_SPACE_MESSAGE: &str = "本地磁盘空间不足";
+/// OneDrive 配额不足时的错误前缀。
+pub const INSUFFICIENT_QUOTA_MESSAGE: &str = "OneDrive 剩余空间不足";
+
+/// 预检未通过：`reason` 供界面区分失败类型，`message` 直接展示给用户。
+#[derive(Clone, Debug)]
+pub struct PreflightFailure {
+    pub reason: TransferFailureReason,
+    pub message: String,
+}
+
+/// 将字节数格式化为便于阅读的 KiB/MiB/GiB 文本。
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
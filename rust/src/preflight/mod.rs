@@ -0,0 +1,7 @@
+pub mod disk_space;
+pub mod failure;
+pub mod quota;
+
+pub use disk_space::check_local_space;
+pub use failure::{PreflightFailure, INSUFFICIENT_DISK_SPACE_MESSAGE, INSUFFICIENT_QUOTA_MESSAGE};
+pub use quota::check_remote_quota;
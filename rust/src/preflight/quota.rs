@@ -0,0 +1,28 @@
+use super::failure::{format_bytes, PreflightFailure, INSUFFICIENT_QUOTA_MESSAGE};
+use crate::api::drive::{info::get_drive_overview, models::TransferFailureReason};
+
+/// 检查 OneDrive 剩余配额能否容纳 `pending_bytes`（本任务与其他进行中上传的剩余字节）。
+/// 配额查询失败或 Graph 未返回 remaining 时放行，避免网络抖动阻塞上传。
+pub fn check_remote_quota(pending_bytes: u64) -> Result<(), PreflightFailure> {
+    let overview = match get_drive_overview() {
+        Ok(overview) => overview,
+        Err(err) => {
+            eprintln!("[preflight] failed to fetch drive quota: {err}; skip quota check");
+            return Ok(());
+        }
+    };
+    let Some(remaining) = overview.quota.and_then(|quota| quota.remaining) else {
+        return Ok(());
+    };
+    if pending_bytes <= remaining {
+        return Ok(());
+    }
+    Err(PreflightFailure {
+        reason: TransferFailureReason::InsufficientQuota,
+        message: format!(
+            "{INSUFFICIENT_QUOTA_MESSAGE}：待上传 {}，剩余 {}",
+            format_bytes(pending_bytes),
+            format_bytes(remaining)
+        ),
+    })
+}
@@ -0,0 +1,89 @@
+use crate::api::drive::models::TransferRetryPolicy;
+use crate::settings::retry_policy::{default_transfer_retry_policy, get_transfer_retry_policy};
+use rand::Rng;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// 等待期间检查取消标记的间隔。
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 读取重试策略，失败时回退默认值，保证传输线程不会因设置表异常而中断。
+pub fn load_retry_policy() -> TransferRetryPolicy {
+    get_transfer_retry_policy().unwrap_or_else(|err| {
+        eprintln!("[retry] failed to load retry policy: {err}; fallback to default");
+        default_transfer_retry_policy()
+    })
+}
+
+/// 第 `attempt` 次重试（从 1 开始）前的等待时长，叠加 ±20% 抖动避免多个任务同时重连。
+pub fn backoff_delay(policy: &TransferRetryPolicy, attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(20);
+    let base = policy
+        .initial_delay_ms
+        .saturating_mul(1u64 << exponent)
+        .min(policy.max_delay_ms);
+    let jitter = rand::thread_rng().gen_range(0.8..=1.2);
+    Duration::from_millis((base as f64 * jitter) as u64)
+}
+
+/// 分段休眠指定时长；期间被取消时提前返回 false。
+pub fn wait_or_cancel(delay: Duration, cancel_flag: &AtomicBool) -> bool {
+    let deadline = Instant::now() + delay;
+    loop {
+        if cancel_flag.load(Ordering::Relaxed) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep((deadline - now).min(CANCEL_POLL_INTERVAL));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> TransferRetryPolicy {
+        TransferRetryPolicy {
+            max_retries: 5,
+            initial_delay_ms: 1_000,
+            max_delay_ms: 10_000,
+        }
+    }
+
+    fn assert_within_jitter(delay: Duration, base_ms: u64) {
+        let ms = delay.as_millis() as u64;
+        assert!(
+            ms >= base_ms * 8 / 10 && ms <= base_ms * 12 / 10,
+            "{ms}ms is outside ±20% of {base_ms}ms"
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_per_attempt() {
+        let policy = policy();
+        assert_within_jitter(backoff_delay(&policy, 1), 1_000);
+        assert_within_jitter(backoff_delay(&policy, 2), 2_000);
+        assert_within_jitter(backoff_delay(&policy, 3), 4_000);
+    }
+
+    #[test]
+    fn backoff_is_capped_by_max_delay() {
+        let policy = policy();
+        assert_within_jitter(backoff_delay(&policy, 5), 10_000);
+        assert_within_jitter(backoff_delay(&policy, u32::MAX), 10_000);
+    }
+
+    #[test]
+    fn wait_or_cancel_returns_early_when_cancelled() {
+        let cancelled = AtomicBool::new(true);
+        assert!(!wait_or_cancel(Duration::from_secs(60), &cancelled));
+        let running = AtomicBool::new(false);
+        assert!(wait_or_cancel(Duration::ZERO, &running));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::api::drive::models::TransferFailureReason;
+use reqwest::StatusCode;
+use std::fmt;
+
+/// 失败类型：决定任务是否值得自动重试。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+    /// 网络抖动、服务端 5xx/429、下载链接过期等，稍后重试大概率成功。
+    Transient,
+    /// 文件不存在、无权限、磁盘已满等，重试也无济于事。
+    Permanent,
+    /// 用户主动取消。
+    Cancelled,
+    /// 上传会话已过期或被服务端清理，原会话无法继续，需新建会话从头上传。
+    SessionExpired,
+}
+
+/// 预签名下载链接被拒绝（401/403/410）时的错误前缀；重新获取元数据即可拿到新链接。
+pub const DOWNLOAD_URL_EXPIRED_MESSAGE: &str = "下载链接已过期或失效";
+
+/// 传输路径上的失败：`kind` 在出错处按状态码或错误来源确定，调度器据此决定是否重试，
+/// `reason` 供界面给出针对性提示，`message` 只用于展示，改写文案不影响任何判断。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferError {
+    pub kind: FailureKind,
+    pub reason: Option<TransferFailureReason>,
+    pub message: String,
+}
+
+impl TransferError {
+    pub fn new(kind: FailureKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            reason: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_reason(mut self, reason: TransferFailureReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// 连接失败、读取响应中断等，重试大概率成功。
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::Transient, message)
+    }
+
+    /// 本地文件读写失败、授权失效、参数错误等，重试无济于事。
+    pub fn permanent(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::Permanent, message)
+    }
+
+    pub fn cancelled(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::Cancelled, message)
+    }
+
+    pub fn session_expired(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::SessionExpired, message)
+    }
+
+    /// 按 HTTP 状态码确定失败类型：408、429 与 5xx 可重试，其余视为永久失败；
+    /// 无权限与项目不存在会附带对应的失败原因。
+    pub fn from_status(status: StatusCode, message: impl Into<String>) -> Self {
+        let code = status.as_u16();
+        let kind = if code == 408 || code == 429 || status.is_server_error() {
+            FailureKind::Transient
+        } else {
+            FailureKind::Permanent
+        };
+        let error = Self::new(kind, message);
+        match code {
+            401 | 403 => error.with_reason(TransferFailureReason::AccessDenied),
+            404 | 410 => error.with_reason(TransferFailureReason::NotFound),
+            _ => error,
+        }
+    }
+
+    pub fn is_transient(&self) -> bool {
+        self.kind == FailureKind::Transient
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == FailureKind::Cancelled
+    }
+
+    pub fn is_session_expired(&self) -> bool {
+        self.kind == FailureKind::SessionExpired
+    }
+
+    /// 任务最终失败时记录的原因：出错处给出的原因优先；可重试的失败走到终态说明重试次数已耗尽。
+    pub fn failure_reason(&self) -> Option<TransferFailureReason> {
+        self.reason.or_else(|| {
+            self.is_transient()
+                .then_some(TransferFailureReason::RetriesExhausted)
+        })
+    }
+}
+
+impl fmt::Display for TransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// 对 Flutter 暴露的接口仍以文本返回错误。
+impl From<TransferError> for String {
+    fn from(value: TransferError) -> Self {
+        value.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retryable_statuses_are_transient() {
+        for code in [408, 429, 500, 502, 503, 504] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert!(
+                TransferError::from_status(status, "x").is_transient(),
+                "{code} should be transient"
+            );
+        }
+    }
+
+    #[test]
+    fn client_errors_are_permanent() {
+        for code in [400, 401, 403, 404, 409, 413] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert_eq!(
+                TransferError::from_status(status, "x").kind,
+                FailureKind::Permanent,
+                "{code} should be permanent"
+            );
+        }
+    }
+
+    #[test]
+    fn status_maps_to_failure_reason() {
+        let reason =
+            |code| TransferError::from_status(StatusCode::from_u16(code).unwrap(), "x").reason;
+        assert_eq!(reason(403), Some(TransferFailureReason::AccessDenied));
+        assert_eq!(reason(404), Some(TransferFailureReason::NotFound));
+        assert_eq!(reason(503), None);
+        assert_eq!(TransferError::permanent("x").reason, None);
+        assert_eq!(
+            TransferError::transient("x").failure_reason(),
+            Some(TransferFailureReason::RetriesExhausted)
+        );
+        assert_eq!(
+            TransferError::permanent("x")
+                .with_reason(TransferFailureReason::IntegrityMismatch)
+                .failure_reason(),
+            Some(TransferFailureReason::IntegrityMismatch)
+        );
+    }
+
+    #[test]
+    fn message_does_not_affect_kind() {
+        let err = TransferError::permanent("网络连接超时 timeout");
+        assert!(!err.is_transient());
+        assert!(TransferError::cancelled("x").is_cancelled());
+        let expired = TransferError::session_expired("upload session expired");
+        assert!(expired.is_session_expired() && !expired.is_transient());
+        assert!(!TransferError::permanent("upload session expired").is_session_expired());
+        assert_eq!(String::from(err.clone()), err.to_string());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod backoff;
+pub mod classify;
+
+pub use backoff::{backoff_delay, load_retry_policy, wait_or_cancel};
+pub use classify::{FailureKind, TransferError, DOWNLOAD_URL_EXPIRED_MESSAGE};
@@ -0,0 +1,159 @@
+use crate::api::drive::models::{BandwidthSchedule, BandwidthSettings};
+use crate::db;
+
+const GLOBAL_LIMIT_KEY: &str = "bandwidth_global_kib_per_sec";
+const DOWNLOAD_LIMIT_KEY: &str = "bandwidth_download_kib_per_sec";
+const UPLOAD_LIMIT_KEY: &str = "bandwidth_upload_kib_per_sec";
+const SCHEDULES_KEY: &str = "bandwidth_schedules";
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// 读取带宽限速设置；缺失的键视为不限速，错误时透传。
+pub fn get_bandwidth_settings() -> Result<BandwidthSettings, String> {
+    let schedules = match db::get_setting(SCHEDULES_KEY)? {
+        Some(raw) => parse_schedules(&raw)?,
+        None => Vec::new(),
+    };
+    Ok(BandwidthSettings {
+        global_kib_per_sec: read_limit(GLOBAL_LIMIT_KEY)?,
+        download_kib_per_sec: read_limit(DOWNLOAD_LIMIT_KEY)?,
+        upload_kib_per_sec: read_limit(UPLOAD_LIMIT_KEY)?,
+        schedules,
+    })
+}
+
+/// 校验并写入带宽限速设置。
+pub fn set_bandwidth_settings(settings: BandwidthSettings) -> Result<BandwidthSettings, String> {
+    for schedule in &settings.schedules {
+        if schedule.start_minute >= MINUTES_PER_DAY || schedule.end_minute >= MINUTES_PER_DAY {
+            return Err(format!(
+                "bandwidth schedule minutes must be between 0 and {}",
+                MINUTES_PER_DAY - 1
+            ));
+        }
+    }
+    db::set_setting(GLOBAL_LIMIT_KEY, &settings.global_kib_per_sec.to_string())?;
+    db::set_setting(
+        DOWNLOAD_LIMIT_KEY,
+        &settings.download_kib_per_sec.to_string(),
+    )?;
+    db::set_setting(UPLOAD_LIMIT_KEY, &settings.upload_kib_per_sec.to_string())?;
+    db::set_setting(SCHEDULES_KEY, &format_schedules(&settings.schedules))?;
+    Ok(settings)
+}
+
+/// 默认不限速、无时段规则。
+pub fn default_bandwidth_settings() -> BandwidthSettings {
+    BandwidthSettings::default()
+}
+
+fn read_limit(key: &str) -> Result<u64, String> {
+    match db::get_setting(key)? {
+        Some(value) => value
+            .parse::<u64>()
+            .map_err(|e| format!("invalid bandwidth limit value for {key}: {e}")),
+        None => Ok(0),
+    }
+}
+
+/// 时段以 `start-end:global,download,upload` 表示，多条之间以 `;` 分隔。
+fn format_schedules(schedules: &[BandwidthSchedule]) -> String {
+    schedules
+        .iter()
+        .map(|s| {
+            format!(
+                "{}-{}:{},{},{}",
+                s.start_minute,
+                s.end_minute,
+                s.global_kib_per_sec,
+                s.download_kib_per_sec,
+                s.upload_kib_per_sec
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn parse_schedules(raw: &str) -> Result<Vec<BandwidthSchedule>, String> {
+    raw.split(';')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(parse_schedule)
+        .collect()
+}
+
+fn parse_schedule(entry: &str) -> Result<BandwidthSchedule, String> {
+    let invalid = || format!("invalid bandwidth schedule entry: {entry}");
+    let (window, limits) = entry.trim().split_once(':').ok_or_else(invalid)?;
+    let (start, end) = window.split_once('-').ok_or_else(invalid)?;
+    let limits = limits
+        .split(',')
+        .map(|value| value.parse::<u64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [global, download, upload] = limits[..] else {
+        return Err(invalid());
+    };
+    Ok(BandwidthSchedule {
+        start_minute: start.parse().map_err(|_| invalid())?,
+        end_minute: end.parse().map_err(|_| invalid())?,
+        global_kib_per_sec: global,
+        download_kib_per_sec: download,
+        upload_kib_per_sec: upload,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_schedules_accepts_multiple_entries() {
+        let schedules = parse_schedules("540-1080:100,50,20; 1380-420:0,0,0;").unwrap();
+        assert_eq!(
+            schedules,
+            vec![
+                BandwidthSchedule {
+                    start_minute: 540,
+                    end_minute: 1080,
+                    global_kib_per_sec: 100,
+                    download_kib_per_sec: 50,
+                    upload_kib_per_sec: 20,
+                },
+                BandwidthSchedule {
+                    start_minute: 1380,
+                    end_minute: 420,
+                    global_kib_per_sec: 0,
+                    download_kib_per_sec: 0,
+                    upload_kib_per_sec: 0,
+                },
+            ]
+        );
+        assert!(parse_schedules("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_schedules_rejects_malformed_entries() {
+        for raw in [
+            "540:100,50,20",
+            "540-1080",
+            "540-1080:100,50",
+            "540-1080:100,50,20,10",
+            "a-1080:1,2,3",
+            "540-1080:1,-2,3",
+        ] {
+            assert!(parse_schedules(raw).is_err(), "{raw} should be rejected");
+        }
+    }
+
+    #[test]
+    fn format_schedules_round_trips() {
+        let schedules = vec![BandwidthSchedule {
+            start_minute: 0,
+            end_minute: 1439,
+            global_kib_per_sec: 1,
+            download_kib_per_sec: 2,
+            upload_kib_per_sec: 3,
+        }];
+        let raw = format_schedules(&schedules);
+        assert_eq!(raw, "0-1439:1,2,3");
+        assert_eq!(parse_schedules(&raw).unwrap(), schedules);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::db;
+
+const RESERVE_KEY: &str = "disk_space_reserve_mib";
+/// 保留空间上限（64 GiB），防止误填导致所有下载都被拒绝。
+pub const MAX_DISK_SPACE_RESERVE_MIB: u64 = 64 * 1024;
+const DEFAULT_DISK_SPACE_RESERVE_MIB: u64 = 512;
+
+/// 读取下载时需为本地磁盘保留的空闲空间（MiB）；缺失时返回默认值。
+pub fn get_disk_space_reserve_mib() -> Result<u64, String> {
+    if let Some(value) = db::get_setting(RESERVE_KEY)? {
+        let parsed = value
+            .parse::<u64>()
+            .map_err(|e| format!("invalid disk space reserve value: {e}"))?;
+        return Ok(parsed.min(MAX_DISK_SPACE_RESERVE_MIB));
+    }
+    Ok(DEFAULT_DISK_SPACE_RESERVE_MIB)
+}
+
+/// 写入保留空间，0 表示仅要求剩余空间足够容纳文件本身。
+pub fn set_disk_space_reserve_mib(value: u64) -> Result<u64, String> {
+    if value > MAX_DISK_SPACE_RESERVE_MIB {
+        return Err(format!(
+            "disk space reserve must not exceed {MAX_DISK_SPACE_RESERVE_MIB} MiB"
+        ));
+    }
+    db::set_setting(RESERVE_KEY, &value.to_string())?;
+    Ok(value)
+}
+
+/// 默认保留空间（MiB）。
+pub fn default_disk_space_reserve_mib() -> u64 {
+    DEFAULT_DISK_SPACE_RESERVE_MIB
+}
@@ -15,7 +15,7 @@ pub fn get_download_concurrency() -> Result<usize, String> {
 
 /// 写入并校验并行下载数，限定在 [MIN, MAX] 区间。
 pub fn set_download_concurrency(value: usize) -> Result<usize, String> {
-    if value < MIN_DOWNLOAD_CONCURRENCY || value > MAX_DOWNLOAD_CONCURRENCY {
+    if !(MIN_DOWNLOAD_CONCURRENCY..=MAX_DOWNLOAD_CONCURRENCY).contains(&value) {
         return Err(format!(
             "download concurrency must be between {} and {}",
             MIN_DOWNLOAD_CONCURRENCY, MAX_DOWNLOAD_CONCURRENCY
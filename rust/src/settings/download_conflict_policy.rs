@@ -0,0 +1,47 @@
+use crate::api::drive::models::DownloadConflictPolicy;
+use crate::db;
+
+const CONFLICT_POLICY_KEY: &str = "download_conflict_policy";
+const DEFAULT_CONFLICT_POLICY: DownloadConflictPolicy = DownloadConflictPolicy::Ask;
+
+/// 读取默认的下载冲突策略；缺失时返回默认值，错误时透传。
+pub fn get_download_conflict_policy() -> Result<DownloadConflictPolicy, String> {
+    if let Some(value) = db::get_setting(CONFLICT_POLICY_KEY)? {
+        return policy_from_str(&value);
+    }
+    Ok(DEFAULT_CONFLICT_POLICY)
+}
+
+/// 写入默认的下载冲突策略。
+pub fn set_download_conflict_policy(
+    policy: DownloadConflictPolicy,
+) -> Result<DownloadConflictPolicy, String> {
+    db::set_setting(CONFLICT_POLICY_KEY, policy_to_str(&policy))?;
+    Ok(policy)
+}
+
+/// 默认的下载冲突策略：与旧行为一致，不主动覆盖已有文件。
+pub fn default_download_conflict_policy() -> DownloadConflictPolicy {
+    DEFAULT_CONFLICT_POLICY
+}
+
+fn policy_to_str(policy: &DownloadConflictPolicy) -> &'static str {
+    match policy {
+        DownloadConflictPolicy::Overwrite => "overwrite",
+        DownloadConflictPolicy::Skip => "skip",
+        DownloadConflictPolicy::KeepBoth => "keep_both",
+        DownloadConflictPolicy::OverwriteIfNewer => "overwrite_if_newer",
+        DownloadConflictPolicy::Ask => "ask",
+    }
+}
+
+fn policy_from_str(raw: &str) -> Result<DownloadConflictPolicy, String> {
+    match raw {
+        "overwrite" => Ok(DownloadConflictPolicy::Overwrite),
+        "skip" => Ok(DownloadConflictPolicy::Skip),
+        "keep_both" => Ok(DownloadConflictPolicy::KeepBoth),
+        "overwrite_if_newer" => Ok(DownloadConflictPolicy::OverwriteIfNewer),
+        "ask" => Ok(DownloadConflictPolicy::Ask),
+        other => Err(format!("invalid download conflict policy value: {other}")),
+    }
+}
@@ -0,0 +1,22 @@
+use crate::db;
+
+const ITEM_XATTR_KEY: &str = "download_write_item_xattr";
+const DEFAULT_ITEM_XATTR_ENABLED: bool = false;
+
+/// 读取是否在下载完成后把 OneDrive item id 与 eTag 写入文件扩展属性。
+pub fn get_download_item_xattr_enabled() -> Result<bool, String> {
+    if let Some(value) = db::get_setting(ITEM_XATTR_KEY)? {
+        return Ok(value == "1");
+    }
+    Ok(DEFAULT_ITEM_XATTR_ENABLED)
+}
+
+pub fn set_download_item_xattr_enabled(enabled: bool) -> Result<bool, String> {
+    db::set_setting(ITEM_XATTR_KEY, if enabled { "1" } else { "0" })?;
+    Ok(enabled)
+}
+
+/// 默认关闭，避免在不支持扩展属性的文件系统上产生额外告警。
+pub fn default_download_item_xattr_enabled() -> bool {
+    DEFAULT_ITEM_XATTR_ENABLED
+}
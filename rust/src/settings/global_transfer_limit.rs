@@ -0,0 +1,47 @@
+use crate::db;
+
+use super::download_concurrency::MAX_DOWNLOAD_CONCURRENCY;
+use super::upload_concurrency::MAX_UPLOAD_CONCURRENCY;
+
+const GLOBAL_LIMIT_KEY: &str = "global_transfer_max_concurrency";
+// 设置表中以 0 表示不限制，此时上传与下载各自只受自身并行数约束。
+const UNLIMITED_VALUE: usize = 0;
+pub const MIN_GLOBAL_TRANSFER_LIMIT: usize = 1;
+pub const MAX_GLOBAL_TRANSFER_LIMIT: usize = MAX_DOWNLOAD_CONCURRENCY + MAX_UPLOAD_CONCURRENCY;
+
+/// 读取上传与下载共享的总并发上限；未设置或不限制时返回 `None`。
+pub fn get_global_transfer_limit() -> Result<Option<usize>, String> {
+    let Some(value) = db::get_setting(GLOBAL_LIMIT_KEY)? else {
+        return Ok(None);
+    };
+    let parsed = value
+        .parse::<usize>()
+        .map_err(|e| format!("invalid global transfer limit value: {e}"))?;
+    if parsed == UNLIMITED_VALUE {
+        return Ok(None);
+    }
+    Ok(Some(parsed.clamp(
+        MIN_GLOBAL_TRANSFER_LIMIT,
+        MAX_GLOBAL_TRANSFER_LIMIT,
+    )))
+}
+
+/// 写入总并发上限，`None` 表示不限制。
+pub fn set_global_transfer_limit(value: Option<usize>) -> Result<Option<usize>, String> {
+    if let Some(limit) = value {
+        if !(MIN_GLOBAL_TRANSFER_LIMIT..=MAX_GLOBAL_TRANSFER_LIMIT).contains(&limit) {
+            return Err(format!(
+                "global transfer limit must be between {} and {}",
+                MIN_GLOBAL_TRANSFER_LIMIT, MAX_GLOBAL_TRANSFER_LIMIT
+            ));
+        }
+    }
+    let value_str = value.unwrap_or(UNLIMITED_VALUE).to_string();
+    db::set_setting(GLOBAL_LIMIT_KEY, &value_str)?;
+    Ok(value)
+}
+
+/// 默认不限制总并发数。
+pub fn default_global_transfer_limit() -> Option<usize> {
+    None
+}
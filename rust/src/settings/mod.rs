@@ -1,10 +1,61 @@
+pub mod bandwidth_limit;
+pub mod disk_space_reserve;
 pub mod download_concurrency;
+pub mod download_conflict_policy;
 pub mod download_directory;
+pub mod download_item_xattr;
+pub mod global_transfer_limit;
+pub mod notifications;
+pub mod post_download_hooks;
+pub mod retry_policy;
+pub mod upload_concurrency;
+pub mod upload_conflict_behavior;
+pub mod upload_integrity_reupload;
 
+pub use bandwidth_limit::{
+    default_bandwidth_settings, get_bandwidth_settings, set_bandwidth_settings,
+};
+pub use disk_space_reserve::{
+    default_disk_space_reserve_mib, get_disk_space_reserve_mib, set_disk_space_reserve_mib,
+    MAX_DISK_SPACE_RESERVE_MIB,
+};
 pub use download_concurrency::{
     default_download_concurrency, get_download_concurrency, set_download_concurrency,
     MAX_DOWNLOAD_CONCURRENCY, MIN_DOWNLOAD_CONCURRENCY,
 };
+pub use download_conflict_policy::{
+    default_download_conflict_policy, get_download_conflict_policy, set_download_conflict_policy,
+};
 pub use download_directory::{
     default_download_directory, get_download_directory, set_download_directory,
 };
+pub use download_item_xattr::{
+    default_download_item_xattr_enabled, get_download_item_xattr_enabled,
+    set_download_item_xattr_enabled,
+};
+pub use global_transfer_limit::{
+    default_global_transfer_limit, get_global_transfer_limit, set_global_transfer_limit,
+    MAX_GLOBAL_TRANSFER_LIMIT, MIN_GLOBAL_TRANSFER_LIMIT,
+};
+pub use notifications::{
+    default_notification_settings, get_notification_settings, set_notification_settings,
+};
+pub use post_download_hooks::{
+    default_post_download_hooks, get_post_download_hooks, set_post_download_hooks,
+    MAX_POST_DOWNLOAD_HOOKS,
+};
+pub use retry_policy::{
+    default_transfer_retry_policy, get_transfer_retry_policy, set_transfer_retry_policy,
+    MAX_TRANSFER_RETRIES,
+};
+pub use upload_concurrency::{
+    default_upload_concurrency, get_upload_concurrency, set_upload_concurrency,
+    MAX_UPLOAD_CONCURRENCY, MIN_UPLOAD_CONCURRENCY,
+};
+pub use upload_conflict_behavior::{
+    default_upload_conflict_behavior, get_upload_conflict_behavior, set_upload_conflict_behavior,
+};
+pub use upload_integrity_reupload::{
+    default_upload_integrity_reupload_enabled, get_upload_integrity_reupload_enabled,
+    set_upload_integrity_reupload_enabled,
+};
@@ -0,0 +1,94 @@
+use crate::api::drive::models::NotificationSettings;
+use crate::db;
+
+use super::bandwidth_limit::MINUTES_PER_DAY;
+
+const ENABLED_KEY: &str = "notifications_enabled";
+const TASK_COMPLETED_KEY: &str = "notifications_task_completed";
+const TASK_FAILED_KEY: &str = "notifications_task_failed";
+const BATCH_FINISHED_KEY: &str = "notifications_batch_finished";
+const QUIET_HOURS_ENABLED_KEY: &str = "notifications_quiet_hours_enabled";
+const QUIET_HOURS_START_KEY: &str = "notifications_quiet_hours_start_minute";
+const QUIET_HOURS_END_KEY: &str = "notifications_quiet_hours_end_minute";
+// 默认免打扰时段：22:00 - 08:00（仅在开启免打扰后生效）。
+const DEFAULT_QUIET_HOURS_START: u32 = 22 * 60;
+const DEFAULT_QUIET_HOURS_END: u32 = 8 * 60;
+
+/// 读取桌面通知设置；缺失的键使用默认值，错误时透传。
+pub fn get_notification_settings() -> Result<NotificationSettings, String> {
+    let defaults = default_notification_settings();
+    Ok(NotificationSettings {
+        enabled: read_flag(ENABLED_KEY, defaults.enabled)?,
+        notify_on_task_completed: read_flag(TASK_COMPLETED_KEY, defaults.notify_on_task_completed)?,
+        notify_on_task_failed: read_flag(TASK_FAILED_KEY, defaults.notify_on_task_failed)?,
+        notify_on_batch_finished: read_flag(BATCH_FINISHED_KEY, defaults.notify_on_batch_finished)?,
+        quiet_hours_enabled: read_flag(QUIET_HOURS_ENABLED_KEY, defaults.quiet_hours_enabled)?,
+        quiet_hours_start_minute: read_minute(
+            QUIET_HOURS_START_KEY,
+            defaults.quiet_hours_start_minute,
+        )?,
+        quiet_hours_end_minute: read_minute(QUIET_HOURS_END_KEY, defaults.quiet_hours_end_minute)?,
+    })
+}
+
+/// 校验并写入桌面通知设置。
+pub fn set_notification_settings(
+    settings: NotificationSettings,
+) -> Result<NotificationSettings, String> {
+    if settings.quiet_hours_start_minute >= MINUTES_PER_DAY
+        || settings.quiet_hours_end_minute >= MINUTES_PER_DAY
+    {
+        return Err(format!(
+            "quiet hours minutes must be between 0 and {}",
+            MINUTES_PER_DAY - 1
+        ));
+    }
+    write_flag(ENABLED_KEY, settings.enabled)?;
+    write_flag(TASK_COMPLETED_KEY, settings.notify_on_task_completed)?;
+    write_flag(TASK_FAILED_KEY, settings.notify_on_task_failed)?;
+    write_flag(BATCH_FINISHED_KEY, settings.notify_on_batch_finished)?;
+    write_flag(QUIET_HOURS_ENABLED_KEY, settings.quiet_hours_enabled)?;
+    db::set_setting(
+        QUIET_HOURS_START_KEY,
+        &settings.quiet_hours_start_minute.to_string(),
+    )?;
+    db::set_setting(
+        QUIET_HOURS_END_KEY,
+        &settings.quiet_hours_end_minute.to_string(),
+    )?;
+    Ok(settings)
+}
+
+/// 默认仅通知失败与批次结束，单个任务完成不打扰；免打扰默认关闭。
+pub fn default_notification_settings() -> NotificationSettings {
+    NotificationSettings {
+        enabled: true,
+        notify_on_task_completed: false,
+        notify_on_task_failed: true,
+        notify_on_batch_finished: true,
+        quiet_hours_enabled: false,
+        quiet_hours_start_minute: DEFAULT_QUIET_HOURS_START,
+        quiet_hours_end_minute: DEFAULT_QUIET_HOURS_END,
+    }
+}
+
+fn read_flag(key: &str, default: bool) -> Result<bool, String> {
+    Ok(match db::get_setting(key)? {
+        Some(value) => value == "1",
+        None => default,
+    })
+}
+
+fn write_flag(key: &str, value: bool) -> Result<(), String> {
+    db::set_setting(key, if value { "1" } else { "0" })
+}
+
+fn read_minute(key: &str, default: u32) -> Result<u32, String> {
+    match db::get_setting(key)? {
+        Some(value) => value
+            .parse::<u32>()
+            .map(|minute| minute.min(MINUTES_PER_DAY - 1))
+            .map_err(|e| format!("invalid minute value for {key}: {e}")),
+        None => Ok(default),
+    }
+}
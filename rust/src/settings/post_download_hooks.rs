@@ -0,0 +1,132 @@
+use crate::api::drive::models::{PostDownloadHook, PostDownloadHookKind};
+use crate::db;
+use serde::{Deserialize, Serialize};
+
+const HOOKS_KEY: &str = "post_download_hooks";
+pub const MAX_POST_DOWNLOAD_HOOKS: usize = 16;
+
+/// 持久化形式；命令与参数可能包含任意字符，因此整体以 JSON 保存。
+#[derive(Serialize, Deserialize)]
+struct HookRecord {
+    kind: String,
+    enabled: bool,
+    #[serde(default)]
+    extensions: Vec<String>,
+    #[serde(default)]
+    program: Option<String>,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    subfolder: Option<String>,
+}
+
+/// 读取下载后动作列表；未配置时为空，错误时透传。
+pub fn get_post_download_hooks() -> Result<Vec<PostDownloadHook>, String> {
+    let Some(raw) = db::get_setting(HOOKS_KEY)? else {
+        return Ok(default_post_download_hooks());
+    };
+    let records: Vec<HookRecord> = serde_json::from_str(&raw)
+        .map_err(|e| format!("invalid post download hooks value: {e}"))?;
+    records.into_iter().map(hook_from_record).collect()
+}
+
+/// 校验并写入下载后动作列表，扩展名统一转为小写并去掉前导点。
+pub fn set_post_download_hooks(
+    hooks: Vec<PostDownloadHook>,
+) -> Result<Vec<PostDownloadHook>, String> {
+    if hooks.len() > MAX_POST_DOWNLOAD_HOOKS {
+        return Err(format!(
+            "at most {MAX_POST_DOWNLOAD_HOOKS} post download hooks are allowed"
+        ));
+    }
+    let hooks = hooks
+        .into_iter()
+        .map(normalize_hook)
+        .collect::<Result<Vec<_>, _>>()?;
+    let records: Vec<HookRecord> = hooks.iter().map(record_from_hook).collect();
+    let raw = serde_json::to_string(&records)
+        .map_err(|e| format!("failed to encode post download hooks: {e}"))?;
+    db::set_setting(HOOKS_KEY, &raw)?;
+    Ok(hooks)
+}
+
+/// 默认不执行任何下载后动作。
+pub fn default_post_download_hooks() -> Vec<PostDownloadHook> {
+    Vec::new()
+}
+
+fn normalize_hook(mut hook: PostDownloadHook) -> Result<PostDownloadHook, String> {
+    hook.extensions = hook
+        .extensions
+        .iter()
+        .map(|ext| ext.trim().trim_start_matches('.').to_lowercase())
+        .filter(|ext| !ext.is_empty())
+        .collect();
+    hook.program = hook
+        .program
+        .map(|program| program.trim().to_string())
+        .filter(|program| !program.is_empty());
+    hook.subfolder = hook
+        .subfolder
+        .map(|subfolder| subfolder.trim().to_string())
+        .filter(|subfolder| !subfolder.is_empty());
+    match hook.kind {
+        PostDownloadHookKind::RunCommand if hook.program.is_none() => {
+            Err("run command hook requires a program".to_string())
+        }
+        PostDownloadHookKind::MoveByType => match hook.subfolder.as_deref() {
+            None => Err("move by type hook requires a subfolder".to_string()),
+            Some(subfolder) if !is_plain_subfolder(subfolder) => Err(format!(
+                "subfolder must be a relative path without '..': {subfolder}"
+            )),
+            Some(_) => Ok(hook),
+        },
+        _ => Ok(hook),
+    }
+}
+
+/// 子文件夹只能位于文件所在目录之内，拒绝绝对路径与上级目录引用。
+fn is_plain_subfolder(subfolder: &str) -> bool {
+    std::path::Path::new(subfolder)
+        .components()
+        .all(|component| matches!(component, std::path::Component::Normal(_)))
+}
+
+fn record_from_hook(hook: &PostDownloadHook) -> HookRecord {
+    HookRecord {
+        kind: kind_to_str(hook.kind).to_string(),
+        enabled: hook.enabled,
+        extensions: hook.extensions.clone(),
+        program: hook.program.clone(),
+        args: hook.args.clone(),
+        subfolder: hook.subfolder.clone(),
+    }
+}
+
+fn hook_from_record(record: HookRecord) -> Result<PostDownloadHook, String> {
+    Ok(PostDownloadHook {
+        kind: kind_from_str(&record.kind)?,
+        enabled: record.enabled,
+        extensions: record.extensions,
+        program: record.program,
+        args: record.args,
+        subfolder: record.subfolder,
+    })
+}
+
+fn kind_to_str(kind: PostDownloadHookKind) -> &'static str {
+    match kind {
+        PostDownloadHookKind::RunCommand => "run_command",
+        PostDownloadHookKind::ExtractArchive => "extract_archive",
+        PostDownloadHookKind::MoveByType => "move_by_type",
+    }
+}
+
+fn kind_from_str(value: &str) -> Result<PostDownloadHookKind, String> {
+    match value {
+        "run_command" => Ok(PostDownloadHookKind::RunCommand),
+        "extract_archive" => Ok(PostDownloadHookKind::ExtractArchive),
+        "move_by_type" => Ok(PostDownloadHookKind::MoveByType),
+        other => Err(format!("unknown post download hook kind: {other}")),
+    }
+}
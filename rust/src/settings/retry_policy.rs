@@ -0,0 +1,75 @@
+use crate::api::drive::models::TransferRetryPolicy;
+use crate::db;
+
+const MAX_RETRIES_KEY: &str = "transfer_retry_max";
+const INITIAL_DELAY_KEY: &str = "transfer_retry_initial_delay_ms";
+const MAX_DELAY_KEY: &str = "transfer_retry_max_delay_ms";
+pub const MAX_TRANSFER_RETRIES: u32 = 10;
+pub const MIN_RETRY_DELAY_MS: u64 = 100;
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_INITIAL_DELAY_MS: u64 = 2_000;
+const DEFAULT_MAX_DELAY_MS: u64 = 60_000;
+
+/// 读取自动重试策略；缺失的键使用默认值，错误时透传。
+pub fn get_transfer_retry_policy() -> Result<TransferRetryPolicy, String> {
+    let defaults = default_transfer_retry_policy();
+    let max_retries = match db::get_setting(MAX_RETRIES_KEY)? {
+        Some(value) => parse_value::<u32>(&value)?.min(MAX_TRANSFER_RETRIES),
+        None => defaults.max_retries,
+    };
+    let initial_delay_ms = match db::get_setting(INITIAL_DELAY_KEY)? {
+        Some(value) => parse_value::<u64>(&value)?.max(MIN_RETRY_DELAY_MS),
+        None => defaults.initial_delay_ms,
+    };
+    let max_delay_ms = match db::get_setting(MAX_DELAY_KEY)? {
+        Some(value) => parse_value::<u64>(&value)?.max(initial_delay_ms),
+        None => defaults.max_delay_ms.max(initial_delay_ms),
+    };
+    Ok(TransferRetryPolicy {
+        max_retries,
+        initial_delay_ms,
+        max_delay_ms,
+    })
+}
+
+/// 校验并写入自动重试策略。
+pub fn set_transfer_retry_policy(
+    policy: TransferRetryPolicy,
+) -> Result<TransferRetryPolicy, String> {
+    if policy.max_retries > MAX_TRANSFER_RETRIES {
+        return Err(format!(
+            "max retries must be at most {}",
+            MAX_TRANSFER_RETRIES
+        ));
+    }
+    if policy.initial_delay_ms < MIN_RETRY_DELAY_MS {
+        return Err(format!(
+            "initial retry delay must be at least {} ms",
+            MIN_RETRY_DELAY_MS
+        ));
+    }
+    if policy.max_delay_ms < policy.initial_delay_ms {
+        return Err("max retry delay must not be less than the initial delay".to_string());
+    }
+    db::set_setting(MAX_RETRIES_KEY, &policy.max_retries.to_string())?;
+    db::set_setting(INITIAL_DELAY_KEY, &policy.initial_delay_ms.to_string())?;
+    db::set_setting(MAX_DELAY_KEY, &policy.max_delay_ms.to_string())?;
+    Ok(policy)
+}
+
+/// 默认策略：最多重试 3 次，从 2 秒开始指数退避，上限 1 分钟。
+pub fn default_transfer_retry_policy() -> TransferRetryPolicy {
+    TransferRetryPolicy {
+        max_retries: DEFAULT_MAX_RETRIES,
+        initial_delay_ms: DEFAULT_INITIAL_DELAY_MS,
+        max_delay_ms: DEFAULT_MAX_DELAY_MS,
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(raw: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    raw.parse::<T>()
+        .map_err(|e| format!("invalid retry policy value: {e}"))
+}
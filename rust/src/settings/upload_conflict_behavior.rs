@@ -0,0 +1,45 @@
+use crate::api::drive::models::UploadConflictBehavior;
+use crate::db;
+
+const CONFLICT_BEHAVIOR_KEY: &str = "upload_conflict_behavior";
+const DEFAULT_CONFLICT_BEHAVIOR: UploadConflictBehavior = UploadConflictBehavior::Rename;
+
+/// 读取默认的上传冲突处理方式；缺失时返回默认值，错误时透传。
+pub fn get_upload_conflict_behavior() -> Result<UploadConflictBehavior, String> {
+    if let Some(value) = db::get_setting(CONFLICT_BEHAVIOR_KEY)? {
+        return behavior_from_str(&value);
+    }
+    Ok(DEFAULT_CONFLICT_BEHAVIOR)
+}
+
+/// 写入默认的上传冲突处理方式。
+pub fn set_upload_conflict_behavior(
+    behavior: UploadConflictBehavior,
+) -> Result<UploadConflictBehavior, String> {
+    db::set_setting(CONFLICT_BEHAVIOR_KEY, behavior_to_str(behavior))?;
+    Ok(behavior)
+}
+
+/// 默认的上传冲突处理方式：与旧版 overwrite=false 一致，由 OneDrive 自动重命名。
+pub fn default_upload_conflict_behavior() -> UploadConflictBehavior {
+    DEFAULT_CONFLICT_BEHAVIOR
+}
+
+fn behavior_to_str(behavior: UploadConflictBehavior) -> &'static str {
+    match behavior {
+        UploadConflictBehavior::Fail => "fail",
+        UploadConflictBehavior::Replace => "replace",
+        UploadConflictBehavior::Rename => "rename",
+        UploadConflictBehavior::SkipIfIdentical => "skip_if_identical",
+    }
+}
+
+fn behavior_from_str(raw: &str) -> Result<UploadConflictBehavior, String> {
+    match raw {
+        "fail" => Ok(UploadConflictBehavior::Fail),
+        "replace" => Ok(UploadConflictBehavior::Replace),
+        "rename" => Ok(UploadConflictBehavior::Rename),
+        "skip_if_identical" => Ok(UploadConflictBehavior::SkipIfIdentical),
+        other => Err(format!("invalid upload conflict behavior value: {other}")),
+    }
+}
@@ -0,0 +1,22 @@
+use crate::db;
+
+const INTEGRITY_REUPLOAD_KEY: &str = "upload_reupload_on_integrity_mismatch";
+const DEFAULT_INTEGRITY_REUPLOAD_ENABLED: bool = true;
+
+/// 读取上传完成后内容哈希与本地文件不一致时是否自动重新上传一次。
+pub fn get_upload_integrity_reupload_enabled() -> Result<bool, String> {
+    if let Some(value) = db::get_setting(INTEGRITY_REUPLOAD_KEY)? {
+        return Ok(value == "1");
+    }
+    Ok(DEFAULT_INTEGRITY_REUPLOAD_ENABLED)
+}
+
+pub fn set_upload_integrity_reupload_enabled(enabled: bool) -> Result<bool, String> {
+    db::set_setting(INTEGRITY_REUPLOAD_KEY, if enabled { "1" } else { "0" })?;
+    Ok(enabled)
+}
+
+/// 默认开启：校验失败多由传输中损坏或上传期间文件被修改引起，重传一次通常即可恢复。
+pub fn default_upload_integrity_reupload_enabled() -> bool {
+    DEFAULT_INTEGRITY_REUPLOAD_ENABLED
+}
@@ -0,0 +1,19 @@
+// 按一天内的分钟数描述的时间窗口，供限速时段与免打扰时段共用。
+use chrono::{Local, Timelike};
+
+/// 判断 `minute`（0-1439）是否落在 `[start, end)` 内：结束早于开始表示跨越午夜，开始与结束相同表示全天。
+pub(crate) fn minute_window_contains(start: u32, end: u32, minute: u32) -> bool {
+    if start == end {
+        true
+    } else if start < end {
+        (start..end).contains(&minute)
+    } else {
+        minute >= start || minute < end
+    }
+}
+
+/// 本地时间在当天的分钟数。
+pub(crate) fn local_minute() -> u32 {
+    let now = Local::now();
+    now.hour() * 60 + now.minute()
+}
@@ -0,0 +1,87 @@
+use super::scheduler::TransferScheduler;
+use crate::settings::global_transfer_limit::{
+    default_global_transfer_limit, get_global_transfer_limit,
+};
+use once_cell::sync::Lazy;
+use std::sync::{Mutex, MutexGuard, Weak};
+
+static GLOBAL_TRANSFER_LIMIT: Lazy<GlobalTransferLimit> = Lazy::new(GlobalTransferLimit::new);
+
+/// 上传与下载共享的总并发上限：各调度器在自身名额之外还需占用一个全局名额。
+/// 名额归还或上限调整后唤醒所有已登记的调度器，让另一方向的等待任务也能补位。
+pub struct GlobalTransferLimit {
+    state: Mutex<GlobalState>,
+    schedulers: Mutex<Vec<Weak<TransferScheduler>>>,
+}
+
+struct GlobalState {
+    running: usize,
+    /// `None` 表示不限制。
+    max: Option<usize>,
+}
+
+impl GlobalTransferLimit {
+    fn new() -> Self {
+        let max = get_global_transfer_limit().unwrap_or_else(|err| {
+            eprintln!(
+                "[transfer-queue] failed to load global transfer limit: {err}; fallback to default"
+            );
+            default_global_transfer_limit()
+        });
+        Self {
+            state: Mutex::new(GlobalState { running: 0, max }),
+            schedulers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 获取全局实例。
+    pub fn shared() -> &'static Self {
+        &GLOBAL_TRANSFER_LIMIT
+    }
+
+    /// 调整总并发上限；调低时已在运行的任务不受影响，完成后不再补位。
+    pub fn set_max(&self, max: Option<usize>) {
+        self.lock().max = max.map(|value| value.max(1));
+        self.wake_schedulers();
+    }
+
+    pub(crate) fn register(&self, scheduler: Weak<TransferScheduler>) {
+        let mut schedulers = self.schedulers.lock().unwrap_or_else(|p| p.into_inner());
+        schedulers.retain(|entry| entry.strong_count() > 0);
+        schedulers.push(scheduler);
+    }
+
+    /// 尝试占用一个全局名额，调用方需持有自身调度器的锁，避免与唤醒交错而丢失通知。
+    pub(crate) fn try_acquire(&self) -> bool {
+        let mut state = self.lock();
+        if state.max.is_some_and(|max| state.running >= max) {
+            return false;
+        }
+        state.running += 1;
+        true
+    }
+
+    pub(crate) fn release(&self) {
+        let mut state = self.lock();
+        state.running = state.running.saturating_sub(1);
+        drop(state);
+        self.wake_schedulers();
+    }
+
+    fn wake_schedulers(&self) {
+        let schedulers: Vec<_> = self
+            .schedulers
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect();
+        for scheduler in schedulers {
+            scheduler.wake();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, GlobalState> {
+        self.state.lock().unwrap_or_else(|p| p.into_inner())
+    }
+}
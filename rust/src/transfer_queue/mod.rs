@@ -0,0 +1,5 @@
+pub mod global_limit;
+pub mod scheduler;
+
+pub use global_limit::GlobalTransferLimit;
+pub use scheduler::{SchedulerPermit, TransferScheduler};
@@ -0,0 +1,309 @@
+use super::global_limit::GlobalTransferLimit;
+use crate::api::drive::models::TransferPriority;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+
+/// 有序调度器：替代抢占式信号量，保证等待中的任务严格按队列顺序获得并发名额。
+/// - 新任务插入到同级及更高优先级任务之后，同级先进先出。
+/// - 队列顺序可被手动调整，调整结果优先于优先级。
+/// - 任务被移出队列（取消/删除）时，对应的 `acquire` 立即返回 `None`。
+/// - 参与总并发上限时，队首任务还需占到一个全局名额才会开始。
+pub struct TransferScheduler {
+    state: Mutex<SchedulerState>,
+    cvar: Condvar,
+    global_limit: Option<&'static GlobalTransferLimit>,
+}
+
+struct SchedulerState {
+    running: usize,
+    max: usize,
+    queue: Vec<QueueEntry>,
+}
+
+struct QueueEntry {
+    task_id: String,
+    priority: TransferPriority,
+}
+
+impl TransferScheduler {
+    pub fn new(max: usize) -> Self {
+        Self {
+            state: Mutex::new(SchedulerState {
+                running: 0,
+                max: max.max(1),
+                queue: Vec::new(),
+            }),
+            cvar: Condvar::new(),
+            global_limit: None,
+        }
+    }
+
+    /// 创建同时受上传与下载共享的总并发上限约束的调度器。
+    pub fn with_global_limit(max: usize) -> Arc<Self> {
+        let global_limit = GlobalTransferLimit::shared();
+        let scheduler = Arc::new(Self {
+            global_limit: Some(global_limit),
+            ..Self::new(max)
+        });
+        global_limit.register(Arc::downgrade(&scheduler));
+        scheduler
+    }
+
+    /// 将任务放入等待队列；已在队列中时保持原位。
+    pub fn enqueue(&self, task_id: &str, priority: TransferPriority) {
+        let mut state = self.lock();
+        if state.position(task_id).is_some() {
+            return;
+        }
+        state.insert_by_priority(QueueEntry {
+            task_id: task_id.to_string(),
+            priority,
+        });
+        self.cvar.notify_all();
+    }
+
+    /// 阻塞直到该任务排到队首且有空闲名额；任务在等待期间被移出队列时返回 `None`。
+    pub fn acquire(&self, task_id: &str) -> Option<SchedulerPermit<'_>> {
+        let mut state = self.lock();
+        loop {
+            let position = state.position(task_id)?;
+            if position == 0 && state.running < state.max && self.try_acquire_global() {
+                state.queue.remove(0);
+                state.running += 1;
+                // 队首已变化，唤醒其余等待者检查自己是否可以开始
+                self.cvar.notify_all();
+                return Some(SchedulerPermit { scheduler: self });
+            }
+            state = self.cvar.wait(state).unwrap_or_else(|p| p.into_inner());
+        }
+    }
+
+    /// 将任务移出等待队列，返回任务此前是否在排队。
+    pub fn remove(&self, task_id: &str) -> bool {
+        let mut state = self.lock();
+        let Some(position) = state.position(task_id) else {
+            return false;
+        };
+        state.queue.remove(position);
+        self.cvar.notify_all();
+        true
+    }
+
+    /// 与前一个等待任务交换位置；已在队首时保持不变。
+    pub fn move_up(&self, task_id: &str) -> bool {
+        self.reposition(task_id, |position, _| position.saturating_sub(1))
+    }
+
+    /// 与后一个等待任务交换位置；已在队尾时保持不变。
+    pub fn move_down(&self, task_id: &str) -> bool {
+        self.reposition(task_id, |position, len| (position + 1).min(len - 1))
+    }
+
+    /// 移动到队首，下一个空闲名额即分配给该任务。
+    pub fn move_to_top(&self, task_id: &str) -> bool {
+        self.reposition(task_id, |_, _| 0)
+    }
+
+    /// 修改优先级并按新优先级重新插入队列。
+    pub fn set_priority(&self, task_id: &str, priority: TransferPriority) -> bool {
+        let mut state = self.lock();
+        let Some(position) = state.position(task_id) else {
+            return false;
+        };
+        let mut entry = state.queue.remove(position);
+        entry.priority = priority;
+        state.insert_by_priority(entry);
+        self.cvar.notify_all();
+        true
+    }
+
+    /// 当前等待中的任务 ID，按调度顺序排列。
+    pub fn queued_ids(&self) -> Vec<String> {
+        self.lock()
+            .queue
+            .iter()
+            .map(|entry| entry.task_id.clone())
+            .collect()
+    }
+
+    /// 调整并发上限；调低时已在运行的任务不受影响，完成后不再补位。
+    pub fn set_max(&self, new_max: usize) {
+        let mut state = self.lock();
+        state.max = new_max.max(1);
+        self.cvar.notify_all();
+    }
+
+    fn reposition<F>(&self, task_id: &str, target: F) -> bool
+    where
+        F: FnOnce(usize, usize) -> usize,
+    {
+        let mut state = self.lock();
+        let Some(position) = state.position(task_id) else {
+            return false;
+        };
+        let entry = state.queue.remove(position);
+        let target = target(position, state.queue.len() + 1).min(state.queue.len());
+        state.queue.insert(target, entry);
+        self.cvar.notify_all();
+        true
+    }
+
+    fn release(&self) {
+        let mut state = self.lock();
+        state.running = state.running.saturating_sub(1);
+        self.cvar.notify_all();
+        drop(state);
+        if let Some(global_limit) = self.global_limit {
+            global_limit.release();
+        }
+    }
+
+    fn try_acquire_global(&self) -> bool {
+        self.global_limit
+            .is_none_or(|global_limit| global_limit.try_acquire())
+    }
+
+    /// 全局名额变化时由总并发上限调用；先持有锁再通知，避免等待者错过唤醒。
+    pub(crate) fn wake(&self) {
+        let _state = self.lock();
+        self.cvar.notify_all();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SchedulerState> {
+        self.state.lock().unwrap_or_else(|p| p.into_inner())
+    }
+}
+
+impl SchedulerState {
+    fn position(&self, task_id: &str) -> Option<usize> {
+        self.queue.iter().position(|entry| entry.task_id == task_id)
+    }
+
+    fn insert_by_priority(&mut self, entry: QueueEntry) {
+        let position = self
+            .queue
+            .iter()
+            .rposition(|queued| queued.priority >= entry.priority)
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        self.queue.insert(position, entry);
+    }
+}
+
+/// RAII 名额，Drop 时归还并唤醒下一个等待任务。
+pub struct SchedulerPermit<'a> {
+    scheduler: &'a TransferScheduler,
+}
+
+impl Drop for SchedulerPermit<'_> {
+    fn drop(&mut self) {
+        self.scheduler.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::mpsc, thread, time::Duration};
+
+    fn scheduler_with(ids: &[(&str, TransferPriority)]) -> TransferScheduler {
+        let scheduler = TransferScheduler::new(1);
+        for (id, priority) in ids {
+            scheduler.enqueue(id, *priority);
+        }
+        scheduler
+    }
+
+    #[test]
+    fn higher_priority_goes_first_and_same_level_is_fifo() {
+        let scheduler = scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Low),
+            ("c", TransferPriority::High),
+            ("d", TransferPriority::Normal),
+            ("e", TransferPriority::High),
+        ]);
+        assert_eq!(scheduler.queued_ids(), ["c", "e", "a", "d", "b"]);
+    }
+
+    #[test]
+    fn enqueue_keeps_existing_position() {
+        let scheduler = scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Normal),
+        ]);
+        scheduler.enqueue("a", TransferPriority::High);
+        assert_eq!(scheduler.queued_ids(), ["a", "b"]);
+    }
+
+    #[test]
+    fn manual_reorder_moves_within_bounds() {
+        let scheduler = scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Normal),
+            ("c", TransferPriority::Normal),
+        ]);
+        assert!(scheduler.move_up("a"));
+        assert_eq!(scheduler.queued_ids(), ["a", "b", "c"]);
+        assert!(scheduler.move_down("a"));
+        assert_eq!(scheduler.queued_ids(), ["b", "a", "c"]);
+        assert!(scheduler.move_down("c"));
+        assert_eq!(scheduler.queued_ids(), ["b", "a", "c"]);
+        assert!(scheduler.move_to_top("c"));
+        assert_eq!(scheduler.queued_ids(), ["c", "b", "a"]);
+        assert!(!scheduler.move_up("missing"));
+    }
+
+    #[test]
+    fn set_priority_reinserts_entry() {
+        let scheduler = scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Normal),
+            ("c", TransferPriority::Low),
+        ]);
+        assert!(scheduler.set_priority("c", TransferPriority::High));
+        assert_eq!(scheduler.queued_ids(), ["c", "a", "b"]);
+        assert!(scheduler.set_priority("c", TransferPriority::Normal));
+        assert_eq!(scheduler.queued_ids(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn acquire_follows_queue_order_and_respects_limit() {
+        let scheduler = Arc::new(scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Normal),
+        ]));
+        let first = scheduler.acquire("a").expect("queue head should start");
+        assert_eq!(scheduler.queued_ids(), ["b"]);
+
+        let (sender, receiver) = mpsc::channel();
+        let waiter = {
+            let scheduler = scheduler.clone();
+            thread::spawn(move || {
+                let permit = scheduler.acquire("b");
+                sender.send(permit.is_some()).unwrap();
+            })
+        };
+        // 名额已满，b 需等待 a 归还
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+        drop(first);
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).unwrap());
+        waiter.join().unwrap();
+    }
+
+    #[test]
+    fn removed_task_stops_waiting() {
+        let scheduler = Arc::new(scheduler_with(&[
+            ("a", TransferPriority::Normal),
+            ("b", TransferPriority::Normal),
+        ]));
+        let _running = scheduler.acquire("a").unwrap();
+        let waiter = {
+            let scheduler = scheduler.clone();
+            thread::spawn(move || scheduler.acquire("b").is_none())
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert!(scheduler.remove("b"));
+        assert!(waiter.join().unwrap());
+        assert!(scheduler.acquire("missing").is_none());
+    }
+}
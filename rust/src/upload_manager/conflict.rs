@@ -0,0 +1,37 @@
+// “相同则跳过”冲突策略：上传前比对远端同名文件的大小与内容哈希。
+use crate::api::drive::item_lookup::{find_child_item, ChildItem};
+use crate::content_hash::{hash_local_file, HashAlgorithm};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+/// 远端已有同名文件且大小、内容哈希都与本地一致时返回该文件。
+/// 远端没有可比对的哈希时视为不同，交由覆盖处理，避免误跳过。
+pub(crate) fn find_identical_remote(
+    parent_id: Option<&str>,
+    file_name: &str,
+    local_path: &str,
+    cancel_flag: &AtomicBool,
+) -> Result<Option<ChildItem>, String> {
+    let Some(item) = find_child_item(parent_id, file_name)? else {
+        return Ok(None);
+    };
+    if item.is_folder {
+        return Ok(None);
+    }
+    let local_size = std::fs::metadata(local_path)
+        .map(|meta| meta.len())
+        .map_err(|_| "local file not found".to_string())?;
+    if item.size != Some(local_size) {
+        return Ok(None);
+    }
+    let path = Path::new(local_path);
+    let identical = if let Some(remote_hash) = &item.quick_xor_hash {
+        hash_local_file(path, HashAlgorithm::QuickXor, Some(cancel_flag))? == *remote_hash
+    } else if let Some(remote_hash) = &item.sha1_hash {
+        hash_local_file(path, HashAlgorithm::Sha1, Some(cancel_flag))?
+            .eq_ignore_ascii_case(remote_hash)
+    } else {
+        false
+    };
+    Ok(identical.then_some(item))
+}
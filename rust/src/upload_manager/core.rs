@@ -1,22 +1,52 @@
 // 上传队列核心：对标 download_manager，负责调度、状态管理、持久化与进度广播。
+use super::conflict::find_identical_remote;
+use super::folder_scan::{scan_folder, split_relative_dir, PathFilter};
+use super::integrity::{upload_is_intact, INTEGRITY_MISMATCH_MESSAGE};
+use super::source_snapshot::{source_changed, SourceSnapshot, SOURCE_CHANGED_MESSAGE};
 use super::storage::{SqliteUploadStore, UploadStore};
+use super::url_source::{file_name_from_url, parse_source_url};
 use crate::api::drive::{
-    models::{UploadProgressUpdate, UploadQueueState, UploadStatus, UploadTask},
+    async_monitor::wait_for_async_job,
+    create_folder::ensure_child_folder,
+    details::get_drive_item_details,
+    download::ProgressCallback,
+    models::{
+        DriveItemSummary, TransferEventKind, TransferFailureReason, TransferPriority,
+        TransferRetryPolicy, UploadConflictBehavior, UploadGroup, UploadGroupStatus,
+        UploadProgressUpdate, UploadQueueState, UploadStatus, UploadTask, UploadTaskEvent,
+    },
     upload::{
-        create_upload_session, get_upload_session_status, upload_large_file_with_hooks,
-        upload_small_file_with_hooks, UploadSessionResponse,
+        cancel_upload_session, create_upload_session, get_upload_session_status, parse_next_start,
+        parse_session_expiration, upload_large_file_with_hooks,
+        upload_small_file_from_path_with_hooks, upload_stream_with_hooks, SessionExpiryCallback,
+        UploadOutcome, UploadSessionResponse, SIMPLE_UPLOAD_THRESHOLD_BYTES,
+        UPLOAD_CANCELLED_MESSAGE,
     },
+    upload_attributes::local_file_system_info,
+    upload_from_url::{open_source_url, probe_source_size, start_source_url_upload},
+};
+use crate::bandwidth::TransferDirection;
+use crate::db::current_timestamp_millis;
+use crate::lock::recover_lock;
+use crate::notifications::NotificationCenter;
+use crate::preflight::{check_remote_quota, PreflightFailure};
+use crate::retry::{backoff_delay, load_retry_policy, wait_or_cancel, TransferError};
+use crate::settings::{
+    default_upload_concurrency, default_upload_integrity_reupload_enabled, get_upload_concurrency,
+    get_upload_conflict_behavior, get_upload_integrity_reupload_enabled,
 };
+use crate::transfer_queue::TransferScheduler;
 use once_cell::sync::Lazy;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
     sync::{
         atomic::{AtomicBool, Ordering},
         mpsc::{self, Receiver, SyncSender, TrySendError},
-        Arc, Condvar, Mutex,
+        Arc, Mutex,
     },
     thread,
-    time::{Instant, SystemTime, UNIX_EPOCH},
+    time::Instant,
 };
 use uuid::Uuid;
 
@@ -25,9 +55,16 @@ static UPLOAD_MANAGER: Lazy<UploadManager> = Lazy::new(UploadManager::new);
 // 应用异常退出后，未完成的任务会被标记为失败并附上该提示。
 const INTERRUPTED_UPLOAD_MESSAGE: &str = "应用已关闭或异常退出，上传被中断，请重新上传";
 const CANCELLED_UPLOAD_MESSAGE: &str = "上传已取消";
-const CANCELLED_ERR_FLAG: &str = "upload cancelled";
+const INTERRUPTED_GROUP_MESSAGE: &str = "应用已关闭或异常退出，文件夹上传准备被中断，请重新上传";
+const DUPLICATE_UPLOAD_MESSAGE: &str = "同名文件已在上传队列中";
+const MISSING_SOURCE_MESSAGE: &str = "本地文件已不存在，无法重新上传";
+const REMOTE_FETCH_FAILED_MESSAGE: &str = "服务端拉取网络文件失败";
+// 会话剩余有效期不足该时长时按已过期处理，避免续传到一半会话失效。
+const SESSION_EXPIRY_MARGIN_MS: i64 = 5 * 60 * 1000;
 // 进度广播 channel 的缓冲大小，防止无界内存增长。
 const PROGRESS_CHANNEL_CAP: usize = 64;
+// 生命周期事件的缓冲大小；事件频率远低于进度，留足余量避免丢失终态事件。
+const EVENT_CHANNEL_CAP: usize = 256;
 // 持久化节流：至少累积多少字节或间隔多久才写入 SQLite。
 const PERSIST_BYTES_THRESHOLD: u64 = 256 * 1024;
 const PERSIST_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
@@ -47,9 +84,10 @@ pub struct UploadManager {
     persist_markers: Arc<Mutex<HashMap<String, PersistMarker>>>,
     /// 订阅者列表
     subscribers: Arc<Mutex<Vec<SyncSender<UploadProgressUpdate>>>>,
+    event_subscribers: Arc<Mutex<Vec<SyncSender<UploadTaskEvent>>>>,
     cancel_tokens: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
-    /// 控制并发上传数量
-    concurrency_guard: Arc<Semaphore>,
+    /// 按队列顺序分配上传名额，控制并发上传数量
+    scheduler: Arc<TransferScheduler>,
 }
 
 /// 内存态快照，避免直接暴露给 FRB。
@@ -58,6 +96,53 @@ struct InnerState {
     active: Vec<UploadTask>,
     completed: Vec<UploadTask>,
     failed: Vec<UploadTask>,
+    groups: Vec<UploadGroup>,
+}
+
+/// 一次上传尝试的结果。
+struct UploadedItem {
+    remote_id: String,
+    remote_name: String,
+    skipped_identical: bool,
+}
+
+impl From<DriveItemSummary> for UploadedItem {
+    fn from(value: DriveItemSummary) -> Self {
+        Self {
+            remote_id: value.id,
+            remote_name: value.name,
+            skipped_identical: false,
+        }
+    }
+}
+
+/// 上传完成后复核发现的问题。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum UploadProblem {
+    /// 上传期间本地文件被修改，总是重新上传一次。
+    SourceChanged,
+    /// 远端内容哈希与本地不一致，按设置决定是否重新上传。
+    IntegrityMismatch,
+}
+
+impl UploadProblem {
+    fn reason(self) -> TransferFailureReason {
+        match self {
+            Self::SourceChanged => TransferFailureReason::SourceChanged,
+            Self::IntegrityMismatch => TransferFailureReason::IntegrityMismatch,
+        }
+    }
+
+    fn message(self) -> &'static str {
+        match self {
+            Self::SourceChanged => SOURCE_CHANGED_MESSAGE,
+            Self::IntegrityMismatch => INTEGRITY_MISMATCH_MESSAGE,
+        }
+    }
+
+    fn into_error(self) -> TransferError {
+        TransferError::permanent(self.message()).with_reason(self.reason())
+    }
 }
 
 /// 速度计算用采样点，包含平滑速度。
@@ -78,14 +163,21 @@ struct PersistMarker {
 impl UploadManager {
     /// 构造全局单例，读取持久化记录并归档未完成任务。
     fn new() -> Self {
+        let max_concurrency = get_upload_concurrency().unwrap_or_else(|err| {
+            eprintln!(
+                "[upload-manager] failed to load concurrency setting: {err}; fallback to default"
+            );
+            default_upload_concurrency()
+        });
         let manager = Self {
             state: Arc::new(Mutex::new(InnerState::default())),
             store: Arc::new(SqliteUploadStore::default()),
             progress_meters: Arc::new(Mutex::new(HashMap::new())),
             persist_markers: Arc::new(Mutex::new(HashMap::new())),
             subscribers: Arc::new(Mutex::new(Vec::new())),
+            event_subscribers: Arc::new(Mutex::new(Vec::new())),
             cancel_tokens: Arc::new(Mutex::new(HashMap::new())),
-            concurrency_guard: Arc::new(Semaphore::new(2)),
+            scheduler: TransferScheduler::with_global_limit(max_concurrency),
         };
         manager.restore_from_storage();
         manager
@@ -96,22 +188,41 @@ impl UploadManager {
         UPLOAD_MANAGER.clone()
     }
 
-    /// 重启恢复：对有会话的未完成任务尝试恢复，否则标记为失败，避免“假活跃”。
+    /// 重启恢复：本地文件仍存在的未完成任务重新排队（有未过期的会话时续传），
+    /// 否则标记为失败，避免“假活跃”。
     fn restore_from_storage(&self) {
         let records = self.store.load();
+        let mut groups = self.store.load_groups();
+        for group in groups
+            .iter_mut()
+            .filter(|g| g.status == UploadGroupStatus::Preparing)
+        {
+            group.status = UploadGroupStatus::Failed;
+            group.completed_at = Some(current_timestamp_millis());
+            group.error_message = Some(INTERRUPTED_GROUP_MESSAGE.to_string());
+            self.store.upsert_group(group);
+        }
         let mut active: Vec<UploadTask> = Vec::new();
         let mut completed = Vec::new();
         let mut failed = Vec::new();
         let mut resume_tasks = Vec::new();
+        let mut stale_sessions = Vec::new();
+        let now = current_timestamp_millis();
         for mut task in records {
             match task.status {
-                UploadStatus::InProgress => {
-                    if task.session_url.is_some() && task.size.is_some() {
+                UploadStatus::InProgress | UploadStatus::Queued => {
+                    if source_available(&task) {
+                        task.status = UploadStatus::Queued;
+                        if session_expired(&task, now) || task.source_url.is_some() {
+                            // 续传只会得到 404（网络来源无法续传），直接丢弃会话从头上传。
+                            stale_sessions.extend(reset_session(&mut task));
+                            self.store.upsert(&task);
+                        }
                         resume_tasks.push(task.clone());
                         active.push(task);
                     } else {
                         task.status = UploadStatus::Failed;
-                        task.completed_at = Some(current_timestamp());
+                        task.completed_at = Some(current_timestamp_millis());
                         if task.error_message.is_none() {
                             task.error_message = Some(INTERRUPTED_UPLOAD_MESSAGE.to_string());
                         }
@@ -130,280 +241,1014 @@ impl UploadManager {
             state.active = active;
             state.completed = completed;
             state.failed = failed;
+            state.groups = groups;
+        }
+        // 子任务在重启时全部结束（例如本地文件已删除）的任务组直接进入终态。
+        let group_ids: Vec<String> = recover_lock(&self.state)
+            .groups
+            .iter()
+            .map(|g| g.group_id.clone())
+            .collect();
+        for group_id in &group_ids {
+            self.settle_group(group_id);
         }
 
-        // 异步恢复仍未完成的大文件上传。
+        discard_sessions(stale_sessions);
+        // 异步恢复仍未完成的上传，沿用任务保存的冲突处理方式。
         for task in resume_tasks {
-            self.resume_large_upload(task);
+            self.start_upload(task);
         }
     }
 
-    /// 入队小文件上传：生成任务、持久化、启动上传线程并返回最新队列。
-    pub fn enqueue_small_file(
+    /// 入队上传：只接收本地路径，上传时从磁盘流式读取；按文件大小在简易上传与
+    /// 分片会话之间自动选择，任务可在重启后从同一路径重新开始。
+    /// 未指定冲突处理方式时使用设置中的默认值。
+    pub fn enqueue_file(
         &self,
         parent_id: Option<String>,
         file_name: String,
         local_path: String,
-        bytes: Vec<u8>,
-        overwrite: bool,
+        conflict_behavior: Option<UploadConflictBehavior>,
+        priority: Option<TransferPriority>,
     ) -> Result<UploadQueueState, String> {
         if file_name.trim().is_empty() {
             return Err("file name is required".to_string());
         }
-        let total_size = bytes.len() as u64;
-        let task_id = Uuid::new_v4().to_string();
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if state
-            .active
-            .iter()
-            .any(|t| t.file_name == file_name && t.parent_id == parent_id)
-        {
-            return Err("同名文件已在上传队列中".to_string());
+        let file_meta =
+            std::fs::metadata(&local_path).map_err(|e| format!("无法读取文件大小: {e}"))?;
+        if !file_meta.is_file() {
+            return Err("只能上传文件，不能上传文件夹".to_string());
         }
-        state
-            .failed
-            .retain(|t| t.file_name != file_name || t.parent_id != parent_id);
-        state
-            .completed
-            .retain(|t| t.file_name != file_name || t.parent_id != parent_id);
-
-        let task = UploadTask {
-            task_id: task_id.clone(),
-            file_name: file_name.clone(),
+        let conflict_behavior = resolve_conflict_behavior(conflict_behavior)?;
+        let mut task = new_task(
+            parent_id,
+            file_name,
             local_path,
-            size: Some(total_size),
-            mime_type: None,
-            parent_id: parent_id.clone(),
-            remote_id: None,
-            status: UploadStatus::InProgress,
-            started_at: current_timestamp(),
-            completed_at: None,
-            bytes_uploaded: Some(0),
-            error_message: None,
-            session_url: None,
-        };
-        state.active.push(task.clone());
+            file_meta.len(),
+            priority.unwrap_or_default(),
+            conflict_behavior,
+            None,
+        );
+        if let Ok(snapshot) = SourceSnapshot::capture(&task.local_path) {
+            snapshot.apply_to(&mut task);
+        }
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        insert_queued_task(&mut state, task.clone())?;
         drop(state);
         self.store.upsert(&task);
+        self.start_upload(task);
 
-        let cancel_token = Arc::new(AtomicBool::new(false));
-        self.register_cancel_token(&task_id, cancel_token.clone());
+        Ok(self.snapshot())
+    }
 
-        let manager = self.clone();
-        thread::spawn(move || {
-            let _permit = manager.concurrency_guard.acquire();
-            let progress_cb: Option<Box<dyn FnMut(u64, Option<u64>) + Send>> = Some(Box::new({
-                let manager = manager.clone();
-                let task_id = task_id.clone();
-                move |uploaded, total| {
-                    manager.report_progress(&task_id, uploaded, total);
-                }
-            }));
-            let result = upload_small_file_with_hooks(
-                parent_id,
-                file_name,
-                bytes,
-                overwrite,
-                Some(cancel_token.clone()),
-                progress_cb,
-            );
-            match result {
-                Ok(summary) => manager.mark_success(&task_id, summary.id),
-                Err(err) => {
-                    if err == CANCELLED_ERR_FLAG {
-                        manager.mark_cancelled(&task_id);
-                    } else {
-                        manager.mark_failure(&task_id, err);
-                    }
-                }
-            }
-        });
+    /// 入队网络地址上传：个人版 OneDrive 交给服务端直接拉取，其他情况（或服务端不接受）
+    /// 由本端边下载边写入上传会话，不落地到磁盘。未指定文件名时取地址路径的最后一段。
+    /// 网络来源无法事先比对内容，“相同则跳过”按覆盖处理。
+    pub fn enqueue_url(
+        &self,
+        parent_id: Option<String>,
+        source_url: String,
+        file_name: Option<String>,
+        conflict_behavior: Option<UploadConflictBehavior>,
+        priority: Option<TransferPriority>,
+    ) -> Result<UploadQueueState, String> {
+        let source_url = parse_source_url(&source_url)?;
+        let file_name = match file_name.filter(|name| !name.trim().is_empty()) {
+            Some(name) => name,
+            None => file_name_from_url(&source_url)
+                .ok_or_else(|| "无法从网络地址推断文件名，请指定文件名".to_string())?,
+        };
+        let conflict_behavior = resolve_conflict_behavior(conflict_behavior)?;
+        let mut task = new_task(
+            parent_id,
+            file_name,
+            source_url.clone(),
+            0,
+            priority.unwrap_or_default(),
+            conflict_behavior,
+            None,
+        );
+        task.size = None;
+        task.source_url = Some(source_url);
+        let mut state = recover_lock(&self.state);
+        insert_queued_task(&mut state, task.clone())?;
+        drop(state);
+        self.store.upsert(&task);
+        self.start_upload(task);
 
         Ok(self.snapshot())
     }
 
-    /// 入队大文件上传（分片）：读取本地文件路径，创建 Graph 会话并上传。
-    pub fn enqueue_large_file(
+    /// 入队文件夹上传：在 `parent_id` 下创建同名文件夹并按本地结构逐级创建（已存在则复用），
+    /// 目录下的每个文件作为任务组的子任务上传。扫描与建目录在后台线程完成，期间任务组处于准备状态。
+    pub fn enqueue_folder(
         &self,
         parent_id: Option<String>,
-        file_name: String,
-        local_path: String,
-        overwrite: bool,
+        local_dir: String,
+        include: Vec<String>,
+        exclude: Vec<String>,
+        conflict_behavior: Option<UploadConflictBehavior>,
+        priority: Option<TransferPriority>,
     ) -> Result<UploadQueueState, String> {
-        if file_name.trim().is_empty() {
-            return Err("file name is required".to_string());
+        let root = PathBuf::from(local_dir.trim());
+        let meta = std::fs::metadata(&root).map_err(|e| format!("无法读取文件夹: {e}"))?;
+        if !meta.is_dir() {
+            return Err("只能选择文件夹".to_string());
         }
-        let file_meta = std::fs::metadata(&local_path)
-            .map_err(|e| format!("无法读取文件大小: {e}"))?;
-        let total_size = file_meta.len();
-        let task_id = Uuid::new_v4().to_string();
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if state
-            .active
-            .iter()
-            .any(|t| t.file_name == file_name && t.parent_id == parent_id)
-        {
-            return Err("同名文件已在上传队列中".to_string());
-        }
-        state
-            .failed
-            .retain(|t| t.file_name != file_name || t.parent_id != parent_id);
-        state
-            .completed
-            .retain(|t| t.file_name != file_name || t.parent_id != parent_id);
-
-        let task = UploadTask {
-            task_id: task_id.clone(),
-            file_name: file_name.clone(),
-            local_path: local_path.clone(),
-            size: Some(total_size),
-            mime_type: None,
-            parent_id: parent_id.clone(),
-            remote_id: None,
-            status: UploadStatus::InProgress,
-            started_at: current_timestamp(),
+        let folder_name = root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .filter(|name| !name.trim().is_empty())
+            .ok_or_else(|| "无法确定文件夹名称".to_string())?;
+        let filter = PathFilter::new(&include, &exclude)?;
+        let conflict_behavior = resolve_conflict_behavior(conflict_behavior)?;
+        let group = UploadGroup {
+            group_id: Uuid::new_v4().to_string(),
+            local_root: root.to_string_lossy().into_owned(),
+            folder_name,
+            parent_id,
+            status: UploadGroupStatus::Preparing,
+            created_at: current_timestamp_millis(),
             completed_at: None,
-            bytes_uploaded: Some(0),
             error_message: None,
-            session_url: None,
+            total_files: 0,
+            completed_files: 0,
+            failed_files: 0,
+            total_bytes: 0,
+            uploaded_bytes: 0,
         };
-        state.active.push(task.clone());
-        drop(state);
-        self.store.upsert(&task);
-
-        let cancel_token = Arc::new(AtomicBool::new(false));
-        self.register_cancel_token(&task_id, cancel_token.clone());
+        recover_lock(&self.state).groups.push(group.clone());
+        self.store.upsert_group(&group);
 
         let manager = self.clone();
+        let priority = priority.unwrap_or_default();
         thread::spawn(move || {
-            let _permit = manager.concurrency_guard.acquire();
-            let result = manager.run_large_upload_task(
-                &task_id,
-                parent_id,
-                file_name,
-                local_path,
-                total_size,
-                overwrite,
-                cancel_token.clone(),
+            if let Err(err) =
+                manager.prepare_group(&group, &root, &filter, conflict_behavior, priority)
+            {
+                manager.fail_group(&group.group_id, err);
+            }
+        });
+        Ok(self.snapshot())
+    }
+
+    /// 扫描本地目录并创建远端目录结构，随后一次性登记全部子任务再开始上传，
+    /// 避免先完成的子任务让任务组提前进入终态。任务组在准备期间被取消或移除时直接结束。
+    fn prepare_group(
+        &self,
+        group: &UploadGroup,
+        root: &std::path::Path,
+        filter: &PathFilter,
+        conflict_behavior: UploadConflictBehavior,
+        priority: TransferPriority,
+    ) -> Result<(), String> {
+        let group_id = group.group_id.as_str();
+        let scan = scan_folder(root, filter)?;
+        let root_id = ensure_child_folder(group.parent_id.as_deref(), &group.folder_name)?;
+        let mut folder_ids = HashMap::from([(String::new(), root_id)]);
+        // 单个目录创建失败（如名称被 OneDrive 拒绝）只跳过该子树，其余目录照常上传。
+        let mut skipped_dirs = Vec::new();
+        for dir in &scan.directories {
+            if !self.group_is_preparing(group_id) {
+                return Ok(());
+            }
+            let (parent, name) = split_relative_dir(dir);
+            // 父目录已被跳过时，子目录随整棵子树一起跳过。
+            let Some(parent_remote_id) = folder_ids.get(parent).cloned() else {
+                continue;
+            };
+            match ensure_child_folder(Some(&parent_remote_id), name) {
+                Ok(folder_id) => {
+                    folder_ids.insert(dir.clone(), folder_id);
+                }
+                Err(err) => {
+                    eprintln!("[upload-manager] skip folder {dir} in group {group_id}: {err}");
+                    skipped_dirs.push(format!("{dir}（{err}）"));
+                }
+            }
+        }
+
+        let total_bytes = scan.total_bytes();
+        let mut state = recover_lock(&self.state);
+        let Some(entry) = state
+            .groups
+            .iter_mut()
+            .find(|g| g.group_id == group_id && g.status == UploadGroupStatus::Preparing)
+        else {
+            return Ok(());
+        };
+        entry.status = UploadGroupStatus::Uploading;
+        entry.total_files = scan.files.len().try_into().unwrap_or(u32::MAX);
+        entry.total_bytes = total_bytes;
+        if !skipped_dirs.is_empty() {
+            entry.error_message = Some(format!(
+                "以下文件夹无法创建，其中的文件已跳过：{}",
+                skipped_dirs.join("；")
+            ));
+        }
+        let updated_group = entry.clone();
+        let mut queued = Vec::new();
+        let mut rejected = Vec::new();
+        for file in scan.files {
+            let parent_id = folder_ids.get(&file.relative_dir).cloned();
+            let mut task = new_task(
+                parent_id.clone(),
+                file.file_name,
+                file.local_path.to_string_lossy().into_owned(),
+                file.size,
+                priority,
+                conflict_behavior,
+                Some(group_id.to_string()),
             );
-            match result {
-                Ok(remote_id) => manager.mark_success(&task_id, remote_id),
+            let inserted = match parent_id {
+                Some(_) => insert_queued_task(&mut state, task.clone()),
+                // 所在目录未能创建时不能退回到根目录上传，直接记为失败。
+                None => Err(format!(
+                    "所在文件夹 {} 无法在 OneDrive 中创建，已跳过",
+                    file.relative_dir
+                )),
+            };
+            match inserted {
+                Ok(()) => queued.push(task),
                 Err(err) => {
-                    if err == CANCELLED_ERR_FLAG {
-                        manager.mark_cancelled(&task_id);
-                    } else {
-                        manager.mark_failure(&task_id, err);
-                    }
+                    task.status = UploadStatus::Failed;
+                    task.completed_at = Some(current_timestamp_millis());
+                    task.error_message = Some(err);
+                    state.failed.insert(0, task.clone());
+                    rejected.push(task);
                 }
             }
-        });
+        }
+        drop(state);
 
-        Ok(self.snapshot())
+        self.store.upsert_group(&updated_group);
+        for task in &rejected {
+            self.store.upsert(task);
+        }
+        for task in queued {
+            self.store.upsert(&task);
+            self.start_upload(task);
+        }
+        // 空文件夹或全部子任务入队失败时没有后续回调，需在此结算。
+        self.settle_group(group_id);
+        Ok(())
+    }
+
+    fn group_is_preparing(&self, group_id: &str) -> bool {
+        recover_lock(&self.state)
+            .groups
+            .iter()
+            .any(|g| g.group_id == group_id && g.status == UploadGroupStatus::Preparing)
+    }
+
+    /// 准备阶段出错：任务组标记为失败，已创建的远端文件夹保留。
+    fn fail_group(&self, group_id: &str, err: String) {
+        eprintln!("[upload-manager] failed to prepare folder upload {group_id}: {err}");
+        let mut state = recover_lock(&self.state);
+        let Some(group) = state
+            .groups
+            .iter_mut()
+            .find(|g| g.group_id == group_id && g.status == UploadGroupStatus::Preparing)
+        else {
+            return;
+        };
+        group.status = UploadGroupStatus::Failed;
+        group.completed_at = Some(current_timestamp_millis());
+        group.error_message = Some(err);
+        let group = group.clone();
+        drop(state);
+        self.store.upsert_group(&group);
     }
 
-    /// 恢复已有会话的大文件上传，在应用重启或恢复时调用。
-    fn resume_large_upload(&self, task: UploadTask) {
-        if task.size.is_none() || task.session_url.is_none() {
+    /// 上传中的任务组在所有子任务结束后按成功/失败数量进入终态。
+    fn settle_group(&self, group_id: &str) {
+        let mut state = recover_lock(&self.state);
+        if state
+            .active
+            .iter()
+            .any(|t| t.group_id.as_deref() == Some(group_id))
+        {
+            return;
+        }
+        let completed = count_group_tasks(&state.completed, group_id);
+        let failed = count_group_tasks(&state.failed, group_id);
+        let Some(group) = state
+            .groups
+            .iter_mut()
+            .find(|g| g.group_id == group_id && g.status == UploadGroupStatus::Uploading)
+        else {
             return;
+        };
+        group.status = match (completed, failed) {
+            (_, 0) => UploadGroupStatus::Completed,
+            (0, _) => UploadGroupStatus::Failed,
+            _ => UploadGroupStatus::PartiallyFailed,
+        };
+        group.completed_at = Some(current_timestamp_millis());
+        let group = group.clone();
+        drop(state);
+        self.store.upsert_group(&group);
+    }
+
+    /// 取消任务组：准备中的任务组停止扫描与建目录，上传中的任务组取消所有未结束的子任务。
+    pub fn cancel_group(&self, group_id: &str) -> Result<UploadQueueState, String> {
+        let mut state = recover_lock(&self.state);
+        let Some(group) = state.groups.iter_mut().find(|g| {
+            g.group_id == group_id
+                && matches!(
+                    g.status,
+                    UploadGroupStatus::Preparing | UploadGroupStatus::Uploading
+                )
+        }) else {
+            return Err("未找到对应的文件夹上传任务或已结束".to_string());
+        };
+        group.status = UploadGroupStatus::Cancelled;
+        group.completed_at = Some(current_timestamp_millis());
+        let group = group.clone();
+        let child_ids: Vec<String> = state
+            .active
+            .iter()
+            .filter(|t| t.group_id.as_deref() == Some(group_id))
+            .map(|t| t.task_id.clone())
+            .collect();
+        drop(state);
+        self.store.upsert_group(&group);
+        for task_id in &child_ids {
+            self.signal_cancel(task_id);
+        }
+        Ok(self.snapshot())
+    }
+
+    /// 移除任务组及其全部子任务记录，未结束的子任务会先被取消。
+    pub fn remove_group(&self, group_id: &str) -> Result<UploadQueueState, String> {
+        let mut state = recover_lock(&self.state);
+        let Some(position) = state.groups.iter().position(|g| g.group_id == group_id) else {
+            return Err("未找到对应的文件夹上传任务".to_string());
+        };
+        state.groups.remove(position);
+        let mut removed = Vec::new();
+        let inner = &mut *state;
+        for list in [&mut inner.active, &mut inner.completed, &mut inner.failed] {
+            let (children, rest): (Vec<_>, Vec<_>) = std::mem::take(list)
+                .into_iter()
+                .partition(|t| t.group_id.as_deref() == Some(group_id));
+            *list = rest;
+            removed.extend(children);
+        }
+        drop(state);
+        self.store.remove_group(group_id);
+        discard_sessions(abandoned_sessions(&removed));
+        for task in &removed {
+            let _ = self.signal_cancel(&task.task_id);
+            self.store.remove(&task.task_id);
+            self.clear_progress_meter(&task.task_id);
+            self.clear_cancel_token(&task.task_id);
+            self.emit_event(TransferEventKind::Removed, task);
         }
+        Ok(self.snapshot())
+    }
+
+    /// 为 active 中的任务启动上传线程，用于新任务、应用重启恢复与手动重试。
+    fn start_upload(&self, task: UploadTask) {
         let cancel_token = Arc::new(AtomicBool::new(false));
         self.register_cancel_token(&task.task_id, cancel_token.clone());
+        self.schedule(&task.task_id, task.priority);
         let manager = self.clone();
         thread::spawn(move || {
-            let _permit = manager.concurrency_guard.acquire();
-            let result = manager.run_large_upload_task(
+            manager.run_with_retry(&task.task_id, &cancel_token, || {
+                manager.run_upload_attempt(&task.task_id, cancel_token.clone())
+            });
+        });
+    }
+
+    /// 执行一次上传尝试：已有会话或文件超过简易上传阈值时走分片会话（可续传），
+    /// 否则直接以单个请求流式上传。
+    /// - 开始前比对任务记录的本地文件快照，文件已被修改时丢弃旧进度从头上传。
+    /// - “相同则跳过”的任务在开始传输前先比对远端同名文件。
+    /// - 传输完成后再次比对快照并校验远端内容哈希：文件在上传期间被修改时覆盖刚上传的文件重传一次，
+    ///   哈希不一致时按设置重传一次；重传后仍有问题则以对应的失败原因结束。
+    fn run_upload_attempt(
+        &self,
+        task_id: &str,
+        cancel_token: Arc<AtomicBool>,
+    ) -> Result<UploadedItem, TransferError> {
+        let Some(mut task) = recover_lock(&self.state)
+            .active
+            .iter()
+            .find(|t| t.task_id == task_id)
+            .cloned()
+        else {
+            return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
+        };
+        if let Some(source_url) = task.source_url.clone() {
+            return self.run_url_upload(task, &source_url, &cancel_token);
+        }
+        if task.source_modified_at.is_none() && task.source_fingerprint.is_none() {
+            // 文件夹子任务与旧任务在首次开始时补记快照。
+            task = self.record_source_snapshot(task_id, &task.local_path)?;
+        } else if source_changed(&task).map_err(TransferError::permanent)? {
+            eprintln!("[upload-manager] local file of {task_id} changed since the upload began");
+            task = self.restart_upload(task_id, &task.local_path)?;
+        }
+        let total_size = match task.size {
+            Some(size) => size,
+            None => std::fs::metadata(&task.local_path)
+                .map(|meta| meta.len())
+                .map_err(|_| TransferError::permanent("local file not found"))?,
+        };
+        if task.conflict_behavior == UploadConflictBehavior::SkipIfIdentical
+            && task.session_url.is_none()
+        {
+            let identical = find_identical_remote(
+                task.parent_id.as_deref(),
+                &task.file_name,
+                &task.local_path,
+                &cancel_token,
+            );
+            if cancel_token.load(Ordering::Relaxed) {
+                return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
+            }
+            if let Some(item) = identical.map_err(TransferError::permanent)? {
+                return Ok(UploadedItem {
+                    remote_id: item.id,
+                    remote_name: item.name,
+                    skipped_identical: true,
+                });
+            }
+        }
+        let mut outcome = self.transfer_file(
+            &task,
+            task.file_name.clone(),
+            task.conflict_behavior,
+            total_size,
+            task.session_url.is_some(),
+            &cancel_token,
+        )?;
+        let mut reuploaded = false;
+        loop {
+            let Some(problem) = check_uploaded_file(&task, &outcome, &cancel_token)? else {
+                return Ok(outcome.item.into());
+            };
+            let reupload = match problem {
+                UploadProblem::SourceChanged => true,
+                UploadProblem::IntegrityMismatch => get_upload_integrity_reupload_enabled()
+                    .unwrap_or_else(|_| default_upload_integrity_reupload_enabled()),
+            };
+            if reuploaded || !reupload {
+                return Err(problem.into_error());
+            }
+            reuploaded = true;
+            eprintln!(
+                "[upload-manager] {} ({task_id}); uploading {} again",
+                problem.message(),
+                outcome.item.name
+            );
+            // 覆盖刚上传的文件（按重命名策略处理时可能已改名），不留下内容不符的副本。
+            task = self.restart_upload(task_id, &task.local_path)?;
+            outcome = self.transfer_file(
+                &task,
+                outcome.item.name.clone(),
+                UploadConflictBehavior::Replace,
+                task.size.unwrap_or_default(),
+                false,
+                &cancel_token,
+            )?;
+        }
+    }
+
+    /// 执行一次网络地址上传。数据流无法续传，上一次尝试留下的会话先丢弃。
+    /// - 服务端接受拉取时轮询其进度，按探测到的文件大小换算为字节进度；完成后查询生成的文件。
+    /// - 否则下载流直接写入新建的上传会话，完成后比对边读边算的内容哈希。
+    fn run_url_upload(
+        &self,
+        mut task: UploadTask,
+        source_url: &str,
+        cancel_token: &Arc<AtomicBool>,
+    ) -> Result<UploadedItem, TransferError> {
+        let task_id = task.task_id.clone();
+        if task.session_url.is_some() {
+            task = self.discard_task_session(&task_id)?;
+        }
+        if let Some(monitor_url) = start_source_url_upload(
+            task.parent_id.as_deref(),
+            &task.file_name,
+            source_url,
+            task.conflict_behavior,
+        )? {
+            let total_size = probe_source_size(source_url);
+            self.report_progress(&task_id, 0, total_size);
+            let resource_id = wait_for_async_job(
+                &monitor_url,
+                Some(cancel_token),
+                REMOTE_FETCH_FAILED_MESSAGE,
+                |percentage| {
+                    if let Some(total) = total_size {
+                        let uploaded = (total as f64 * percentage / 100.0) as u64;
+                        self.report_progress(&task_id, uploaded, total_size);
+                    }
+                },
+            )?
+            .ok_or_else(|| TransferError::permanent("服务端拉取已完成，但未返回生成的文件"))?;
+            let item = get_drive_item_details(resource_id).map_err(TransferError::permanent)?;
+            return Ok(UploadedItem {
+                remote_id: item.id,
+                remote_name: item.name,
+                skipped_identical: false,
+            });
+        }
+
+        let stream = open_source_url(source_url)?;
+        if stream.size == 0 {
+            return Err(TransferError::permanent(
+                "网络文件为空，无法通过上传会话上传",
+            ));
+        }
+        self.report_progress(&task_id, 0, Some(stream.size));
+        let session = create_upload_session(
+            task.parent_id.clone(),
+            &task.file_name,
+            task.conflict_behavior,
+            None,
+        )?;
+        self.update_task_session(&task_id, &session);
+        let upload_url = session
+            .upload_url
+            .ok_or_else(|| TransferError::permanent("missing upload session url"))?;
+        let progress_cb: Option<ProgressCallback> = Some(Box::new({
+            let manager = self.clone();
+            let task_id = task_id.clone();
+            move |uploaded, total| {
+                manager.report_progress(&task_id, uploaded, total);
+            }
+        }));
+        let expiry_cb: Option<SessionExpiryCallback> = Some(Box::new({
+            let manager = self.clone();
+            let task_id = task_id.clone();
+            move |expires_at| {
+                manager.update_session_expiry(&task_id, expires_at);
+            }
+        }));
+        let outcome = upload_stream_with_hooks(
+            upload_url,
+            stream.reader,
+            stream.size,
+            cancel_token.clone(),
+            progress_cb,
+            expiry_cb,
+        )?;
+        let intact = outcome
+            .local_hashes
+            .as_ref()
+            .and_then(|local| local.matches(&outcome.remote_hashes))
+            .unwrap_or(true);
+        if !intact {
+            return Err(UploadProblem::IntegrityMismatch.into_error());
+        }
+        Ok(outcome.item.into())
+    }
+
+    /// 传输文件内容：续传已有会话或文件超过简易上传阈值时走分片会话，否则单请求上传。
+    fn transfer_file(
+        &self,
+        task: &UploadTask,
+        file_name: String,
+        conflict_behavior: UploadConflictBehavior,
+        total_size: u64,
+        resume: bool,
+        cancel_token: &Arc<AtomicBool>,
+    ) -> Result<UploadOutcome, TransferError> {
+        if resume || total_size > SIMPLE_UPLOAD_THRESHOLD_BYTES {
+            return self.run_large_upload_task(
                 &task.task_id,
                 task.parent_id.clone(),
-                task.file_name.clone(),
+                file_name,
                 task.local_path.clone(),
-                task.size.unwrap(),
-                false, // overwrite 已体现在既有会话，不再使用
+                total_size,
+                conflict_behavior,
                 cancel_token.clone(),
             );
+        }
+        let progress_cb: Option<ProgressCallback> = Some(Box::new({
+            let manager = self.clone();
+            let task_id = task.task_id.clone();
+            move |uploaded, total| {
+                manager.report_progress(&task_id, uploaded, total);
+            }
+        }));
+        upload_small_file_from_path_with_hooks(
+            task.parent_id.clone(),
+            file_name,
+            &task.local_path,
+            conflict_behavior,
+            Some(cancel_token.clone()),
+            progress_cb,
+        )
+    }
+
+    /// 放入等待队列，上传线程排到队首且有空闲名额时才会开始。
+    fn schedule(&self, task_id: &str, priority: TransferPriority) {
+        self.scheduler.enqueue(task_id, priority);
+        self.sync_active_order();
+        let task = recover_lock(&self.state)
+            .active
+            .iter()
+            .find(|t| t.task_id == task_id)
+            .cloned();
+        if let Some(task) = task {
+            self.emit_event(TransferEventKind::Queued, &task);
+        }
+    }
+
+    /// 按队列顺序获得名额后执行一次上传尝试；瞬时失败按重试策略退避并重新排队，最终写入终态。
+    fn run_with_retry<F>(&self, task_id: &str, cancel_token: &Arc<AtomicBool>, mut attempt: F)
+    where
+        F: FnMut() -> Result<UploadedItem, TransferError>,
+    {
+        let retry_policy = load_retry_policy();
+        loop {
+            let result = {
+                // 退避等待期间不占用名额，让其他任务先行
+                let permit = self.scheduler.acquire(task_id);
+                if permit.is_none() || cancel_token.load(Ordering::Relaxed) {
+                    return self.mark_cancelled(task_id);
+                }
+                if let Err(failure) = self.preflight(task_id) {
+                    return self.mark_failure(task_id, failure.message, Some(failure.reason));
+                }
+                self.mark_started(task_id);
+                attempt()
+            };
             match result {
-                Ok(remote_id) => manager.mark_success(&task.task_id, remote_id),
+                Ok(item) => return self.mark_success(task_id, item),
+                Err(err) if err.is_cancelled() => return self.mark_cancelled(task_id),
                 Err(err) => {
-                    if err == CANCELLED_ERR_FLAG {
-                        manager.mark_cancelled(&task.task_id);
-                    } else {
-                        manager.mark_failure(&task.task_id, err);
+                    if self.wait_for_retry(task_id, &err, &retry_policy, cancel_token) {
+                        self.requeue(task_id);
+                        continue;
                     }
+                    if cancel_token.load(Ordering::Relaxed) {
+                        return self.mark_cancelled(task_id);
+                    }
+                    let reason = err.failure_reason();
+                    return self.mark_failure(task_id, err.message, reason);
                 }
             }
-        });
+        }
+    }
+
+    /// 创建上传会话前确认 OneDrive 剩余配额能容纳本任务与其他进行中上传的剩余数据，
+    /// 避免传到一半才因配额不足失败并留下无用的会话；已有会话的续传不再检查。
+    fn preflight(&self, task_id: &str) -> Result<(), PreflightFailure> {
+        let pending_bytes = {
+            let state = recover_lock(&self.state);
+            let Some(task) = state.active.iter().find(|t| t.task_id == task_id) else {
+                return Ok(());
+            };
+            if task.session_url.is_some() {
+                return Ok(());
+            }
+            state
+                .active
+                .iter()
+                .filter(|t| t.task_id == task_id || matches!(t.status, UploadStatus::InProgress))
+                .map(|t| {
+                    t.size
+                        .unwrap_or(0)
+                        .saturating_sub(t.bytes_uploaded.unwrap_or(0))
+                })
+                .sum::<u64>()
+        };
+        if pending_bytes == 0 {
+            return Ok(());
+        }
+        check_remote_quota(pending_bytes)
+    }
+
+    /// 获得上传名额后将任务切换为进行中。
+    fn mark_started(&self, task_id: &str) {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return;
+        };
+        task.status = UploadStatus::InProgress;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.sync_active_order();
+        self.emit_event(TransferEventKind::Started, &task);
+    }
+
+    /// 退避结束后按任务当前优先级重新排队。
+    fn requeue(&self, task_id: &str) {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return;
+        };
+        task.status = UploadStatus::Queued;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.schedule(task_id, task.priority);
+    }
+
+    /// 按调度顺序整理 active：进行中的任务在前，等待中的任务按队列顺序排在其后。
+    fn sync_active_order(&self) {
+        let queued = self.scheduler.queued_ids();
+        let mut state = recover_lock(&self.state);
+        state
+            .active
+            .sort_by_key(|task| queued.iter().position(|id| *id == task.task_id));
+    }
+
+    /// 判断失败是否可自动重试；可重试时累加计数、写入提示并完成退避等待。
+    /// 大文件任务保留会话与已上传偏移，重试时从断点继续。
+    fn wait_for_retry(
+        &self,
+        task_id: &str,
+        err: &TransferError,
+        policy: &TransferRetryPolicy,
+        cancel_token: &AtomicBool,
+    ) -> bool {
+        if !err.is_transient() {
+            return false;
+        }
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return false;
+        };
+        if task.retry_count >= policy.max_retries {
+            return false;
+        }
+        task.retry_count += 1;
+        let delay = backoff_delay(policy, task.retry_count);
+        task.error_message = Some(format!(
+            "{err}（{} 秒后第 {} 次重试）",
+            delay.as_secs().max(1),
+            task.retry_count
+        ));
+        let task = task.clone();
+        drop(state);
+        eprintln!(
+            "[upload-manager] retry {} for {task_id} in {:?}: {err}",
+            task.retry_count, delay
+        );
+        self.store.upsert(&task);
+        self.emit_event(TransferEventKind::Retrying, &task);
+        wait_or_cancel(delay, cancel_token)
+    }
+
+    /// 将失败/已取消的任务重新放回队列，从本地路径重新上传。
+    /// 会话未过期的任务会先尝试沿用会话续传，冲突处理方式沿用任务创建时的设置。
+    pub fn retry_failed_tasks(&self) -> Result<UploadQueueState, String> {
+        self.retry_matching(|_| true);
+        Ok(self.snapshot())
+    }
+
+    /// 按任务保存的原参数（目标目录、文件名、冲突处理方式、优先级）重新上传单个失败或已取消的任务，
+    /// 会话仍有效时从断点续传，否则新建会话。
+    pub fn retry_task(&self, task_id: &str) -> Result<UploadQueueState, String> {
+        let state = recover_lock(&self.state);
+        let Some(task) = state.failed.iter().find(|t| t.task_id == task_id) else {
+            return Err("未找到对应的失败上传任务".to_string());
+        };
+        if let Some(reason) = retry_blocker(&state, task) {
+            return Err(reason.to_string());
+        }
+        drop(state);
+        self.retry_matching(|t| t.task_id == task_id);
+        Ok(self.snapshot())
+    }
+
+    /// 将满足条件且可以重试的失败任务放回队列，无法重试的任务保留在失败列表。
+    fn retry_matching(&self, matches: impl Fn(&UploadTask) -> bool) {
+        let mut state = recover_lock(&self.state);
+        let mut restarted = Vec::new();
+        let mut kept = Vec::new();
+        let mut stale_sessions = Vec::new();
+        let now = current_timestamp_millis();
+        for mut task in std::mem::take(&mut state.failed) {
+            if !matches(&task) || retry_blocker(&state, &task).is_some() {
+                kept.push(task);
+                continue;
+            }
+            task.status = UploadStatus::Queued;
+            task.completed_at = None;
+            task.error_message = None;
+            task.retry_count = 0;
+            task.failure_reason = None;
+            if session_expired(&task, now) || task.source_url.is_some() {
+                stale_sessions.extend(reset_session(&mut task));
+            }
+            if task.session_url.is_none() {
+                task.bytes_uploaded = Some(0);
+            }
+            if task.size.is_none() && task.source_url.is_none() {
+                task.size = std::fs::metadata(&task.local_path).ok().map(|m| m.len());
+            }
+            state.active.push(task.clone());
+            restarted.push(task);
+        }
+        state.failed = kept;
+        // 重试子任务的任务组重新回到上传中，待子任务结束后再次结算。
+        let mut reopened = Vec::new();
+        for group in state.groups.iter_mut().filter(|g| {
+            g.status != UploadGroupStatus::Preparing
+                && restarted
+                    .iter()
+                    .any(|t| t.group_id.as_deref() == Some(g.group_id.as_str()))
+        }) {
+            group.status = UploadGroupStatus::Uploading;
+            group.completed_at = None;
+            reopened.push(group.clone());
+        }
+        drop(state);
+        discard_sessions(stale_sessions);
+        for group in &reopened {
+            self.store.upsert_group(group);
+        }
+        for task in restarted {
+            self.store.upsert(&task);
+            self.start_upload(task);
+        }
     }
 
     /// 实际执行大文件分片上传，含会话创建/恢复与进度上报。
+    /// 会话已过期或续传时服务端返回 404 的，丢弃旧会话并新建会话从头重传一次。
+    #[allow(clippy::too_many_arguments)]
     fn run_large_upload_task(
         &self,
         task_id: &str,
         parent_id: Option<String>,
         file_name: String,
         local_path: String,
-        total_size: u64,
-        overwrite: bool,
+        mut total_size: u64,
+        conflict_behavior: UploadConflictBehavior,
         cancel_token: Arc<AtomicBool>,
-    ) -> Result<String, String> {
+    ) -> Result<UploadOutcome, TransferError> {
         if !std::path::Path::new(&local_path).exists() {
-            return Err("local file not found".to_string());
-        }
-        // 复用已有会话或创建新会话。
-        let mut upload_url = {
-            let state = recover_lock(&self.state);
-            state
-                .active
-                .iter()
-                .find(|t| t.task_id == task_id)
-                .and_then(|t| t.session_url.clone())
-        };
-        if upload_url.is_none() {
-            let session = create_upload_session(parent_id.clone(), &file_name, overwrite)?;
-            upload_url = session.upload_url.clone();
-            self.update_task_session(task_id, &session);
+            return Err(TransferError::permanent("local file not found"));
         }
-        let upload_url = upload_url.ok_or_else(|| "missing upload session url".to_string())?;
+        let mut renewed = false;
+        loop {
+            // 复用未过期的会话，否则新建会话。
+            let (mut upload_url, expired) = {
+                let now = current_timestamp_millis();
+                let state = recover_lock(&self.state);
+                state
+                    .active
+                    .iter()
+                    .find(|t| t.task_id == task_id)
+                    .map(|t| (t.session_url.clone(), session_expired(t, now)))
+                    .unwrap_or_default()
+            };
+            if expired && !renewed {
+                renewed = true;
+                total_size = self
+                    .restart_upload(task_id, &local_path)?
+                    .size
+                    .unwrap_or_default();
+                upload_url = None;
+            }
+            if upload_url.is_none() {
+                let file_system_info = local_file_system_info(std::path::Path::new(&local_path));
+                let session = create_upload_session(
+                    parent_id.clone(),
+                    &file_name,
+                    conflict_behavior,
+                    file_system_info.as_ref(),
+                )?;
+                upload_url = session.upload_url.clone();
+                self.update_task_session(task_id, &session);
+            }
+            let upload_url =
+                upload_url.ok_or_else(|| TransferError::permanent("missing upload session url"))?;
 
-        // 查询会话状态，决定续传起点。
-        let mut start_offset = {
-            let state = recover_lock(&self.state);
-            state
-                .active
-                .iter()
-                .find(|t| t.task_id == task_id)
-                .and_then(|t| t.bytes_uploaded)
-                .unwrap_or(0)
-        };
-        if let Ok(status) = get_upload_session_status(&upload_url) {
-            if let Some(next) = parse_next_start(&status.next_expected_ranges) {
-                start_offset = next;
+            // 查询会话状态，决定续传起点。
+            let mut start_offset = {
+                let state = recover_lock(&self.state);
+                state
+                    .active
+                    .iter()
+                    .find(|t| t.task_id == task_id)
+                    .and_then(|t| t.bytes_uploaded)
+                    .unwrap_or(0)
+            };
+            match get_upload_session_status(&upload_url) {
+                Ok(status) => {
+                    if let Some(next) = parse_next_start(&status.next_expected_ranges) {
+                        start_offset = next;
+                    }
+                    // 如果服务端已返回最终 item，直接成功。
+                    if let Some(item) = status.drive_item {
+                        return Ok(item.into_outcome(None));
+                    }
+                    if let Some(at) =
+                        parse_session_expiration(status.expiration_date_time.as_deref())
+                    {
+                        self.update_session_expiry(task_id, at);
+                    }
+                }
+                Err(err) if err.is_session_expired() && !renewed => {
+                    renewed = true;
+                    total_size = self
+                        .restart_upload(task_id, &local_path)?
+                        .size
+                        .unwrap_or_default();
+                    continue;
+                }
+                Err(_) => {}
             }
-            // 如果服务端已返回最终 item，直接成功。
-            if let Some(item) = status.drive_item {
-                return Ok(item.id);
+
+            let progress_cb: Option<ProgressCallback> = Some(Box::new({
+                let manager = self.clone();
+                let task_id = task_id.to_string();
+                move |uploaded, total| {
+                    manager.report_progress(&task_id, uploaded, total);
+                }
+            }));
+            let expiry_cb: Option<SessionExpiryCallback> = Some(Box::new({
+                let manager = self.clone();
+                let task_id = task_id.to_string();
+                move |expires_at| {
+                    manager.update_session_expiry(&task_id, expires_at);
+                }
+            }));
+
+            match upload_large_file_with_hooks(
+                upload_url,
+                &local_path,
+                total_size,
+                start_offset,
+                cancel_token.clone(),
+                progress_cb,
+                expiry_cb,
+            ) {
+                Err(err) if err.is_session_expired() && !renewed => {
+                    renewed = true;
+                    total_size = self
+                        .restart_upload(task_id, &local_path)?
+                        .size
+                        .unwrap_or_default();
+                }
+                result => return result,
             }
         }
+    }
 
-        let progress_cb: Option<Box<dyn FnMut(u64, Option<u64>) + Send>> = Some(Box::new({
-            let manager = self.clone();
-            let task_id = task_id.to_string();
-            move |uploaded, total| {
-                manager.report_progress(&task_id, uploaded, total);
-            }
-        }));
+    /// 丢弃旧会话从头重传（会话失效、本地文件被修改或完整性校验失败时）：
+    /// 以当前本地文件作为新的快照，进度归零，返回更新后的任务。
+    fn restart_upload(&self, task_id: &str, local_path: &str) -> Result<UploadTask, TransferError> {
+        let snapshot = SourceSnapshot::capture(local_path).map_err(TransferError::permanent)?;
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
+        };
+        let stale = reset_session(task);
+        snapshot.apply_to(task);
+        let task = task.clone();
+        drop(state);
+        eprintln!("[upload-manager] restarting upload {task_id} with a new session");
+        self.store.upsert(&task);
+        self.emit_progress_snapshot(task_id, 0, task.size);
+        discard_sessions(stale.into_iter().collect());
+        Ok(task)
+    }
 
-        let summary = upload_large_file_with_hooks(
-            upload_url,
-            &local_path,
-            total_size,
-            start_offset,
-            cancel_token,
-            progress_cb,
-        )?;
-        Ok(summary.id)
+    /// 丢弃任务的上传会话并将进度归零，返回更新后的任务。
+    fn discard_task_session(&self, task_id: &str) -> Result<UploadTask, TransferError> {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
+        };
+        let stale = reset_session(task);
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.emit_progress_snapshot(task_id, 0, task.size);
+        discard_sessions(stale.into_iter().collect());
+        Ok(task)
+    }
+
+    /// 记录本地文件快照作为之后比对的基准，返回更新后的任务。
+    fn record_source_snapshot(
+        &self,
+        task_id: &str,
+        local_path: &str,
+    ) -> Result<UploadTask, TransferError> {
+        let snapshot = SourceSnapshot::capture(local_path).map_err(TransferError::permanent)?;
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
+        };
+        snapshot.apply_to(task);
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        Ok(task)
     }
 
-    /// 上传成功：迁移到 completed，写库，推送终态进度。
-    fn mark_success(&self, task_id: &str, remote_id: String) {
+    /// 上传成功：迁移到 completed，写库，推送终态进度；跳过的任务按已完成全部字节展示。
+    fn mark_success(&self, task_id: &str, item: UploadedItem) {
         let mut state = match self.state.lock() {
             Ok(guard) => guard,
             Err(poison) => {
@@ -415,8 +1260,13 @@ impl UploadManager {
         if let Some(pos) = state.active.iter().position(|t| t.task_id == task_id) {
             let mut task = state.active.remove(pos);
             task.status = UploadStatus::Completed;
-            task.completed_at = Some(current_timestamp());
-            task.remote_id = Some(remote_id);
+            task.completed_at = Some(current_timestamp_millis());
+            task.remote_id = Some(item.remote_id);
+            task.remote_name = Some(item.remote_name);
+            task.skipped_identical = item.skipped_identical;
+            if item.skipped_identical {
+                task.bytes_uploaded = task.size;
+            }
             task.error_message = None;
             state.completed.insert(0, task.clone());
             updated = Some(task);
@@ -427,11 +1277,20 @@ impl UploadManager {
             self.clear_progress_meter(task_id);
             self.emit_progress_snapshot(task_id, task.bytes_uploaded.unwrap_or(0), task.size);
             self.clear_cancel_token(task_id);
+            self.emit_event(TransferEventKind::Completed, &task);
+            if let Some(group_id) = &task.group_id {
+                self.settle_group(group_id);
+            }
         }
     }
 
-    /// 上传失败：迁移到 failed，保留错误信息。
-    fn mark_failure(&self, task_id: &str, err: String) {
+    /// 上传失败：迁移到 failed，保留错误信息；预检失败时同时记录失败类型。
+    fn mark_failure(
+        &self,
+        task_id: &str,
+        err: String,
+        failure_reason: Option<TransferFailureReason>,
+    ) {
         let mut state = match self.state.lock() {
             Ok(guard) => guard,
             Err(poison) => {
@@ -443,8 +1302,9 @@ impl UploadManager {
         if let Some(pos) = state.active.iter().position(|t| t.task_id == task_id) {
             let mut task = state.active.remove(pos);
             task.status = UploadStatus::Failed;
-            task.completed_at = Some(current_timestamp());
+            task.completed_at = Some(current_timestamp_millis());
             task.error_message = Some(err.clone());
+            task.failure_reason = failure_reason;
             state.failed.insert(0, task.clone());
             updated = Some(task);
         } else {
@@ -456,6 +1316,10 @@ impl UploadManager {
             self.clear_progress_meter(task_id);
             self.emit_progress_snapshot(task_id, task.bytes_uploaded.unwrap_or(0), task.size);
             self.clear_cancel_token(task_id);
+            self.emit_event(TransferEventKind::Failed, &task);
+            if let Some(group_id) = &task.group_id {
+                self.settle_group(group_id);
+            }
         }
     }
 
@@ -472,7 +1336,7 @@ impl UploadManager {
         if let Some(pos) = state.active.iter().position(|t| t.task_id == task_id) {
             let mut task = state.active.remove(pos);
             task.status = UploadStatus::Cancelled;
-            task.completed_at = Some(current_timestamp());
+            task.completed_at = Some(current_timestamp_millis());
             task.error_message = Some(CANCELLED_UPLOAD_MESSAGE.to_string());
             state.failed.insert(0, task.clone());
             updated = Some(task);
@@ -483,6 +1347,10 @@ impl UploadManager {
             self.clear_progress_meter(task_id);
             self.emit_progress_snapshot(task_id, task.bytes_uploaded.unwrap_or(0), task.size);
             self.clear_cancel_token(task_id);
+            self.emit_event(TransferEventKind::Cancelled, &task);
+            if let Some(group_id) = &task.group_id {
+                self.settle_group(group_id);
+            }
         }
     }
 
@@ -495,30 +1363,95 @@ impl UploadManager {
         }
     }
 
+    /// 更新同时上传的最大数量，从设置项或用户调整处调用。
+    pub fn update_concurrency_limit(&self, new_limit: usize) {
+        self.scheduler.set_max(new_limit.max(1));
+    }
+
+    /// 将等待中的任务前移一位。
+    pub fn move_up(&self, task_id: &str) -> Result<UploadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_up)
+    }
+
+    /// 将等待中的任务后移一位。
+    pub fn move_down(&self, task_id: &str) -> Result<UploadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_down)
+    }
+
+    /// 将等待中的任务移到队首。
+    pub fn move_to_top(&self, task_id: &str) -> Result<UploadQueueState, String> {
+        self.reorder(task_id, TransferScheduler::move_to_top)
+    }
+
+    fn reorder(
+        &self,
+        task_id: &str,
+        apply: fn(&TransferScheduler, &str) -> bool,
+    ) -> Result<UploadQueueState, String> {
+        if !apply(&self.scheduler, task_id) {
+            return Err("任务不在等待队列中，无法调整顺序".to_string());
+        }
+        self.sync_active_order();
+        Ok(self.snapshot())
+    }
+
+    /// 修改未结束任务的优先级，等待中的任务按新优先级重新排队。
+    pub fn set_priority(
+        &self,
+        task_id: &str,
+        priority: TransferPriority,
+    ) -> Result<UploadQueueState, String> {
+        let mut state = recover_lock(&self.state);
+        let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) else {
+            return Err("未找到对应的上传任务或已结束".to_string());
+        };
+        task.priority = priority;
+        let task = task.clone();
+        drop(state);
+        self.store.upsert(&task);
+        self.scheduler.set_priority(task_id, priority);
+        self.sync_active_order();
+        Ok(self.snapshot())
+    }
+
     /// 移除任意状态的任务。
     pub fn remove(&self, task_id: &str) -> Result<UploadQueueState, String> {
         let _ = self.signal_cancel(task_id);
         let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        state.active.retain(|t| t.task_id != task_id);
-        state.completed.retain(|t| t.task_id != task_id);
-        state.failed.retain(|t| t.task_id != task_id);
+        let removed = take_task(&mut state.active, task_id)
+            .or_else(|| take_task(&mut state.completed, task_id))
+            .or_else(|| take_task(&mut state.failed, task_id));
         let snapshot = (*state).clone();
         drop(state);
         self.store.remove(task_id);
         self.clear_progress_meter(task_id);
         self.clear_cancel_token(task_id);
+        if let Some(task) = removed {
+            discard_sessions(abandoned_sessions(std::slice::from_ref(&task)));
+            self.emit_event(TransferEventKind::Removed, &task);
+        }
         Ok(snapshot.into())
     }
 
     /// 清空历史记录（completed/failed），active 保留。
     pub fn clear_history(&self) -> Result<UploadQueueState, String> {
         let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        state.completed.clear();
-        state.failed.clear();
+        let mut removed = std::mem::take(&mut state.completed);
+        removed.append(&mut state.failed);
+        state.groups.retain(|g| {
+            matches!(
+                g.status,
+                UploadGroupStatus::Preparing | UploadGroupStatus::Uploading
+            )
+        });
         let snapshot = (*state).clone();
         drop(state);
         self.store.clear_history();
+        discard_sessions(abandoned_sessions(&removed));
         self.prune_inactive_trackers(&snapshot.active);
+        for task in &removed {
+            self.emit_event(TransferEventKind::Removed, task);
+        }
         Ok(snapshot.into())
     }
 
@@ -528,12 +1461,13 @@ impl UploadManager {
         if state.failed.is_empty() {
             return Ok((*state).clone().into());
         }
-        let failed_ids: Vec<String> = state.failed.iter().map(|t| t.task_id.clone()).collect();
-        state.failed.clear();
+        let removed = std::mem::take(&mut state.failed);
         let snapshot = (*state).clone();
         drop(state);
-        for id in failed_ids {
-            self.store.remove(&id);
+        discard_sessions(abandoned_sessions(&removed));
+        for task in &removed {
+            self.store.remove(&task.task_id);
+            self.emit_event(TransferEventKind::Removed, task);
         }
         Ok(snapshot.into())
     }
@@ -580,7 +1514,7 @@ impl UploadManager {
             bytes_uploaded,
             expected_size: total_size,
             speed_bps: speed,
-            timestamp_millis: current_timestamp(),
+            timestamp_millis: current_timestamp_millis(),
         };
         self.broadcast_update(update);
     }
@@ -668,6 +1602,8 @@ impl UploadManager {
         let mut cloned: Option<UploadTask> = None;
         if let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) {
             task.session_url = session.upload_url.clone();
+            task.session_expires_at =
+                parse_session_expiration(session.expiration_date_time.as_deref());
             cloned = Some(task.clone());
         }
         drop(state);
@@ -676,12 +1612,22 @@ impl UploadManager {
         }
     }
 
+    /// 记录服务端顺延后的会话过期时间，随下一次进度持久化一并写库。
+    fn update_session_expiry(&self, task_id: &str, expires_at: i64) {
+        let mut state = recover_lock(&self.state);
+        if let Some(task) = state.active.iter_mut().find(|t| t.task_id == task_id) {
+            task.session_expires_at = Some(expires_at);
+        }
+    }
+
     fn clear_cancel_token(&self, task_id: &str) {
         let mut tokens = recover_lock(&self.cancel_tokens);
         tokens.remove(task_id);
     }
 
     fn signal_cancel(&self, task_id: &str) -> bool {
+        // 仍在排队的任务直接移出队列，等待中的线程会立即结束
+        self.scheduler.remove(task_id);
         let tokens = recover_lock(&self.cancel_tokens);
         if let Some(token) = tokens.get(task_id) {
             token.store(true, Ordering::Relaxed);
@@ -734,13 +1680,45 @@ impl UploadManager {
                     bytes_uploaded: bytes,
                     expected_size: task.size,
                     speed_bps: None,
-                    timestamp_millis: current_timestamp(),
+                    timestamp_millis: current_timestamp_millis(),
                 });
             }
         }
         rx
     }
 
+    /// 订阅任务生命周期事件（排队、开始、重试、完成、失败、取消、移除）。
+    pub fn subscribe_events(&self) -> Receiver<UploadTaskEvent> {
+        let (tx, rx) = mpsc::sync_channel(EVENT_CHANNEL_CAP);
+        recover_lock(&self.event_subscribers).push(tx);
+        rx
+    }
+
+    fn emit_event(&self, kind: TransferEventKind, task: &UploadTask) {
+        let queue_drained = recover_lock(&self.state).active.is_empty();
+        NotificationCenter::shared().record(
+            TransferDirection::Upload,
+            kind,
+            &task.file_name,
+            task.error_message.as_deref(),
+            queue_drained,
+        );
+        let event = UploadTaskEvent {
+            kind,
+            task: task.clone(),
+            timestamp_millis: current_timestamp_millis(),
+        };
+        let mut subs = recover_lock(&self.event_subscribers);
+        subs.retain_mut(|sender| match sender.try_send(event.clone()) {
+            Ok(_) => true,
+            Err(TrySendError::Full(_)) => {
+                eprintln!("[upload-manager] event subscriber is lagging; dropping event");
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        });
+    }
+
     fn broadcast_update(&self, update: UploadProgressUpdate) {
         let mut subs = recover_lock(&self.subscribers);
         subs.retain_mut(|sender| match sender.try_send(update.clone()) {
@@ -753,81 +1731,186 @@ impl UploadManager {
 
 impl From<InnerState> for UploadQueueState {
     fn from(value: InnerState) -> Self {
+        let groups = value
+            .groups
+            .into_iter()
+            .map(|group| aggregate_group(group, &value.active, &value.completed, &value.failed))
+            .collect();
         Self {
             active: value.active,
             completed: value.completed,
             failed: value.failed,
+            groups,
         }
     }
 }
 
-fn current_timestamp() -> i64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_millis() as i64)
-        .unwrap_or_default()
+/// 由子任务汇总任务组的完成/失败数量与已上传字节。
+fn aggregate_group(
+    mut group: UploadGroup,
+    active: &[UploadTask],
+    completed: &[UploadTask],
+    failed: &[UploadTask],
+) -> UploadGroup {
+    let in_group = |t: &&UploadTask| t.group_id.as_deref() == Some(group.group_id.as_str());
+    group.completed_files = count_group_tasks(completed, &group.group_id);
+    group.failed_files = count_group_tasks(failed, &group.group_id);
+    let completed_bytes: u64 = completed
+        .iter()
+        .filter(in_group)
+        .map(|t| t.size.or(t.bytes_uploaded).unwrap_or(0))
+        .sum();
+    let active_bytes: u64 = active
+        .iter()
+        .filter(in_group)
+        .map(|t| t.bytes_uploaded.unwrap_or(0))
+        .sum();
+    group.uploaded_bytes = completed_bytes + active_bytes;
+    group
 }
 
-fn recover_lock<'a, T>(mutex: &'a Mutex<T>) -> std::sync::MutexGuard<'a, T> {
-    match mutex.lock() {
-        Ok(g) => g,
-        Err(poison) => poison.into_inner(),
+/// 复核刚完成的上传：先确认本地文件未在上传期间变化，再比对远端内容哈希。
+fn check_uploaded_file(
+    task: &UploadTask,
+    outcome: &UploadOutcome,
+    cancel_token: &AtomicBool,
+) -> Result<Option<UploadProblem>, TransferError> {
+    if source_changed(task).map_err(TransferError::permanent)? {
+        return Ok(Some(UploadProblem::SourceChanged));
     }
-}
-
-fn parse_next_start(next_expected: &Option<Vec<String>>) -> Option<u64> {
-    let raw = next_expected.as_ref()?.first()?;
-    if let Some((start, _)) = raw.split_once('-') {
-        return start.parse::<u64>().ok();
+    let intact = upload_is_intact(&task.local_path, outcome, cancel_token);
+    if cancel_token.load(Ordering::Relaxed) {
+        return Err(TransferError::cancelled(UPLOAD_CANCELLED_MESSAGE));
     }
-    raw.parse::<u64>().ok()
+    Ok((!intact.map_err(TransferError::permanent)?).then_some(UploadProblem::IntegrityMismatch))
 }
 
-struct Semaphore {
-    state: Mutex<SemaphoreState>,
-    cvar: Condvar,
+fn count_group_tasks(tasks: &[UploadTask], group_id: &str) -> u32 {
+    tasks
+        .iter()
+        .filter(|t| t.group_id.as_deref() == Some(group_id))
+        .count()
+        .try_into()
+        .unwrap_or(u32::MAX)
 }
 
-struct SemaphoreState {
-    available: usize,
-    max: usize,
+fn new_task(
+    parent_id: Option<String>,
+    file_name: String,
+    local_path: String,
+    total_size: u64,
+    priority: TransferPriority,
+    conflict_behavior: UploadConflictBehavior,
+    group_id: Option<String>,
+) -> UploadTask {
+    UploadTask {
+        task_id: Uuid::new_v4().to_string(),
+        file_name,
+        local_path,
+        size: Some(total_size),
+        mime_type: None,
+        parent_id,
+        remote_id: None,
+        status: UploadStatus::Queued,
+        started_at: current_timestamp_millis(),
+        completed_at: None,
+        bytes_uploaded: Some(0),
+        error_message: None,
+        session_url: None,
+        session_expires_at: None,
+        retry_count: 0,
+        priority,
+        failure_reason: None,
+        group_id,
+        conflict_behavior,
+        remote_name: None,
+        skipped_identical: false,
+        source_modified_at: None,
+        source_fingerprint: None,
+        source_url: None,
+    }
 }
 
-impl Semaphore {
-    fn new(max: usize) -> Self {
-        Self {
-            state: Mutex::new(SemaphoreState {
-                available: max,
-                max,
-            }),
-            cvar: Condvar::new(),
-        }
+/// 任务未指定冲突处理方式时读取设置中的默认值。
+fn resolve_conflict_behavior(
+    behavior: Option<UploadConflictBehavior>,
+) -> Result<UploadConflictBehavior, String> {
+    match behavior {
+        Some(behavior) => Ok(behavior),
+        None => get_upload_conflict_behavior(),
     }
+}
 
-    fn acquire(&self) -> SemaphorePermit<'_> {
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        while state.available == 0 {
-            state = self.cvar.wait(state).unwrap_or_else(|p| p.into_inner());
-        }
-        state.available -= 1;
-        SemaphorePermit { semaphore: self }
+/// 将新任务放入 active；同一目录下的同名文件已在队列中时拒绝，并清理同名的历史记录。
+fn insert_queued_task(state: &mut InnerState, task: UploadTask) -> Result<(), String> {
+    let same_target =
+        |t: &UploadTask| t.file_name == task.file_name && t.parent_id == task.parent_id;
+    if state.active.iter().any(same_target) {
+        return Err(DUPLICATE_UPLOAD_MESSAGE.to_string());
     }
+    state.failed.retain(|t| !same_target(t));
+    state.completed.retain(|t| !same_target(t));
+    state.active.push(task);
+    Ok(())
+}
 
-    fn release(&self) {
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if state.available < state.max {
-            state.available += 1;
-            self.cvar.notify_one();
-        }
+/// 失败任务暂时无法重新上传的原因：本地文件已不存在，或同名文件已在队列中。
+fn retry_blocker(state: &InnerState, task: &UploadTask) -> Option<&'static str> {
+    if !source_available(task) {
+        return Some(MISSING_SOURCE_MESSAGE);
     }
+    state
+        .active
+        .iter()
+        .any(|t| t.file_name == task.file_name && t.parent_id == task.parent_id)
+        .then_some(DUPLICATE_UPLOAD_MESSAGE)
+}
+
+/// 上传来源仍可读取：网络地址任务总是尝试，本地任务要求文件仍存在。
+fn source_available(task: &UploadTask) -> bool {
+    task.source_url.is_some() || std::path::Path::new(&task.local_path).is_file()
+}
+
+/// 会话已过期或剩余有效期不足 `SESSION_EXPIRY_MARGIN_MS`。
+fn session_expired(task: &UploadTask, now: i64) -> bool {
+    task.session_url.is_some()
+        && task
+            .session_expires_at
+            .is_some_and(|at| at <= now + SESSION_EXPIRY_MARGIN_MS)
 }
 
-struct SemaphorePermit<'a> {
-    semaphore: &'a Semaphore,
+/// 丢弃任务的上传会话，下次上传从头新建会话；返回旧会话地址以便清理。
+fn reset_session(task: &mut UploadTask) -> Option<String> {
+    task.session_expires_at = None;
+    task.bytes_uploaded = Some(0);
+    task.session_url.take()
 }
 
-impl<'a> Drop for SemaphorePermit<'a> {
-    fn drop(&mut self) {
-        self.semaphore.release();
+/// 被移除的未完成任务仍持有的上传会话。
+fn abandoned_sessions(tasks: &[UploadTask]) -> Vec<String> {
+    tasks
+        .iter()
+        .filter(|t| !matches!(t.status, UploadStatus::Completed))
+        .filter_map(|t| t.session_url.clone())
+        .collect()
+}
+
+/// 在后台删除不再使用的上传会话，释放服务端暂存的分片；失败只记录日志，会话到期后由服务端清理。
+fn discard_sessions(urls: Vec<String>) {
+    if urls.is_empty() {
+        return;
     }
+    thread::spawn(move || {
+        for url in urls {
+            if let Err(err) = cancel_upload_session(&url) {
+                eprintln!("[upload-manager] failed to discard upload session: {err}");
+            }
+        }
+    });
+}
+
+/// 从列表中取出指定任务，返回被移除的任务。
+fn take_task(tasks: &mut Vec<UploadTask>, task_id: &str) -> Option<UploadTask> {
+    let position = tasks.iter().position(|t| t.task_id == task_id)?;
+    Some(tasks.remove(position))
 }
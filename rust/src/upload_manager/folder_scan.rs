@@ -0,0 +1,259 @@
+// 文件夹上传的本地扫描：按 include/exclude glob 过滤，产出需要创建的目录与需要上传的文件。
+use glob::{MatchOptions, Pattern};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// include/exclude 过滤规则。
+/// - 不含 `/` 的模式匹配文件或目录名（如 `.git`、`*.o`），含 `/` 的模式匹配相对根目录的路径。
+/// - exclude 同时作用于目录与文件，命中的目录整棵跳过；include 只作用于文件，为空时上传全部文件。
+pub struct PathFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl PathFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self, String> {
+        Ok(Self {
+            include: compile_patterns(include)?,
+            exclude: compile_patterns(exclude)?,
+        })
+    }
+
+    fn is_excluded(&self, relative: &str, name: &str) -> bool {
+        matches_any(&self.exclude, relative, name)
+    }
+
+    fn is_included(&self, relative: &str, name: &str) -> bool {
+        self.include.is_empty() || matches_any(&self.include, relative, name)
+    }
+}
+
+/// 待上传的本地文件。
+pub struct ScannedFile {
+    pub local_path: PathBuf,
+    /// 文件所在目录相对根目录的路径，根目录下的文件为空字符串。
+    pub relative_dir: String,
+    pub file_name: String,
+    pub size: u64,
+}
+
+/// 扫描结果：目录按父目录在前排序，便于逐级创建。
+pub struct FolderScan {
+    pub directories: Vec<String>,
+    pub files: Vec<ScannedFile>,
+}
+
+impl FolderScan {
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// 递归扫描 `root`，不跟随目录符号链接，避免循环与越界上传。
+/// 无 include 规则时保留所有未排除的目录（包括空目录）；有 include 规则时只创建包含待上传文件的目录。
+pub fn scan_folder(root: &Path, filter: &PathFilter) -> Result<FolderScan, String> {
+    let mut directories = BTreeSet::new();
+    let mut files = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), String::new())];
+    while let Some((dir, relative_dir)) = pending.pop() {
+        let entries =
+            fs::read_dir(&dir).map_err(|e| format!("无法读取目录 {}: {e}", dir.display()))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("无法读取目录 {}: {e}", dir.display()))?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative = join_relative(&relative_dir, &name);
+            if filter.is_excluded(&relative, &name) {
+                continue;
+            }
+            let file_type = entry
+                .file_type()
+                .map_err(|e| format!("无法读取 {}: {e}", entry.path().display()))?;
+            if file_type.is_dir() {
+                if filter.include.is_empty() {
+                    directories.insert(relative.clone());
+                }
+                pending.push((entry.path(), relative));
+                continue;
+            }
+            // 文件符号链接按目标文件上传，指向目录的链接跳过。
+            let metadata = match fs::metadata(entry.path()) {
+                Ok(meta) if meta.is_file() => meta,
+                _ => continue,
+            };
+            if !filter.is_included(&relative, &name) {
+                continue;
+            }
+            insert_ancestors(&mut directories, &relative_dir);
+            files.push(ScannedFile {
+                local_path: entry.path(),
+                relative_dir: relative_dir.clone(),
+                file_name: name,
+                size: metadata.len(),
+            });
+        }
+    }
+    files.sort_by(|a, b| {
+        (a.relative_dir.as_str(), a.file_name.as_str())
+            .cmp(&(b.relative_dir.as_str(), b.file_name.as_str()))
+    });
+    // BTreeSet 的字典序保证父目录排在子目录之前。
+    Ok(FolderScan {
+        directories: directories.into_iter().collect(),
+        files,
+    })
+}
+
+/// 拆分相对目录，返回 (父目录, 目录名)。
+pub fn split_relative_dir(relative: &str) -> (&str, &str) {
+    match relative.rsplit_once('/') {
+        Some((parent, name)) => (parent, name),
+        None => ("", relative),
+    }
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>, String> {
+    patterns
+        .iter()
+        .map(|raw| raw.trim().trim_matches('/'))
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| Pattern::new(raw).map_err(|e| format!("无效的匹配规则 {raw}: {e}")))
+        .collect()
+}
+
+fn matches_any(patterns: &[Pattern], relative: &str, name: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        if pattern.as_str().contains('/') {
+            pattern.matches_with(relative, MATCH_OPTIONS)
+        } else {
+            pattern.matches_with(name, MATCH_OPTIONS)
+        }
+    })
+}
+
+fn join_relative(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent}/{name}")
+    }
+}
+
+fn insert_ancestors(directories: &mut BTreeSet<String>, relative_dir: &str) {
+    let mut current = relative_dir;
+    while !current.is_empty() && directories.insert(current.to_string()) {
+        current = split_relative_dir(current).0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_tree(name: &str, files: &[&str], dirs: &[&str]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("skydrivex-scan-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in dirs {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, file.as_bytes()).unwrap();
+        }
+        root
+    }
+
+    fn filter(include: &[&str], exclude: &[&str]) -> PathFilter {
+        let owned = |patterns: &[&str]| patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        PathFilter::new(&owned(include), &owned(exclude)).unwrap()
+    }
+
+    fn file_paths(scan: &FolderScan) -> Vec<String> {
+        scan.files
+            .iter()
+            .map(|file| join_relative(&file.relative_dir, &file.file_name))
+            .collect()
+    }
+
+    #[test]
+    fn walks_tree_with_parents_before_children() {
+        let root = temp_tree(
+            "walk",
+            &["a.txt", "src/main.rs", "src/util/mod.rs"],
+            &["empty"],
+        );
+        let scan = scan_folder(&root, &filter(&[], &[])).unwrap();
+        assert_eq!(scan.directories, vec!["empty", "src", "src/util"]);
+        assert_eq!(
+            file_paths(&scan),
+            vec!["a.txt", "src/main.rs", "src/util/mod.rs"]
+        );
+        assert_eq!(
+            scan.total_bytes(),
+            ("a.txt".len() + "src/main.rs".len() + "src/util/mod.rs".len()) as u64
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn exclude_skips_whole_directories_and_matching_files() {
+        let root = temp_tree(
+            "exclude",
+            &[".git/config", "src/main.rs", "src/main.o", "build/out/app"],
+            &[],
+        );
+        let scan = scan_folder(&root, &filter(&[], &[".git", "*.o", "build/out"])).unwrap();
+        assert_eq!(scan.directories, vec!["build", "src"]);
+        assert_eq!(file_paths(&scan), vec!["src/main.rs"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn include_limits_files_and_keeps_only_their_directories() {
+        let root = temp_tree(
+            "include",
+            &[
+                "notes.md",
+                "docs/guide.md",
+                "docs/img/logo.png",
+                "other/a.txt",
+            ],
+            &["empty"],
+        );
+        let scan = scan_folder(&root, &filter(&["*.md"], &[])).unwrap();
+        assert_eq!(scan.directories, vec!["docs"]);
+        assert_eq!(file_paths(&scan), vec!["notes.md", "docs/guide.md"]);
+
+        // 含 `/` 的模式按相对路径匹配，`*` 不跨越目录层级。
+        let scan = scan_folder(&root, &filter(&["docs/*"], &[])).unwrap();
+        assert_eq!(file_paths(&scan), vec!["docs/guide.md"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn directory_symlinks_are_not_followed() {
+        let root = temp_tree("symlink", &["real/a.txt"], &[]);
+        std::os::unix::fs::symlink(root.join("real"), root.join("link")).unwrap();
+        std::os::unix::fs::symlink(root.join("real/a.txt"), root.join("b.txt")).unwrap();
+        let scan = scan_folder(&root, &filter(&[], &[])).unwrap();
+        assert_eq!(scan.directories, vec!["real"]);
+        assert_eq!(file_paths(&scan), vec!["b.txt", "real/a.txt"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn invalid_patterns_and_relative_dirs() {
+        assert!(PathFilter::new(&["[".to_string()], &[]).is_err());
+        assert_eq!(split_relative_dir("a/b/c"), ("a/b", "c"));
+        assert_eq!(split_relative_dir("a"), ("", "a"));
+    }
+}
@@ -0,0 +1,26 @@
+// 上传完成后的完整性校验：比对 Graph 返回的内容哈希与本地文件。
+use crate::api::drive::upload::UploadOutcome;
+use crate::content_hash::{digest_local_file, ContentHashes};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+/// 远端与本地内容哈希不一致时任务的错误信息，仅用于展示，失败原因由错误上的 `reason` 记录。
+pub(crate) const INTEGRITY_MISMATCH_MESSAGE: &str = "上传完成后远端内容哈希与本地文件不一致";
+
+/// 远端内容与本地文件一致时返回 `true`。
+/// - 上传过程中已得到完整的本地摘要时直接比较，否则（续传、简易上传）重新读取本地文件计算。
+/// - 远端未返回可比较的哈希时无法校验，按通过处理。
+pub(crate) fn upload_is_intact(
+    local_path: &str,
+    outcome: &UploadOutcome,
+    cancel_flag: &AtomicBool,
+) -> Result<bool, String> {
+    if outcome.remote_hashes == ContentHashes::default() {
+        return Ok(true);
+    }
+    let local = match &outcome.local_hashes {
+        Some(hashes) => hashes.clone(),
+        None => digest_local_file(Path::new(local_path), Some(cancel_flag))?,
+    };
+    Ok(local.matches(&outcome.remote_hashes).unwrap_or(true))
+}
@@ -1,5 +1,10 @@
+mod conflict;
 pub mod core;
+mod folder_scan;
+mod integrity;
+mod source_snapshot;
 pub mod storage;
+mod url_source;
 
 pub use core::UploadManager;
 pub use storage::{SqliteUploadStore, UploadStore};
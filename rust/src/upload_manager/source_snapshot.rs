@@ -0,0 +1,61 @@
+// 上传源文件的变更检测：记录本地文件的大小、修改时间与首尾采样哈希，
+// 续传前和上传完成后重新比对，避免把改写前后的内容拼接成损坏的远端文件。
+use crate::api::drive::models::UploadTask;
+use crate::content_hash::sample_local_file;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+/// 首尾各采样的字节数。
+const SAMPLE_BYTES: u64 = 64 * 1024;
+
+/// 上传期间本地文件被修改时的错误信息，仅用于展示，失败原因由错误上的 `reason` 记录。
+pub(crate) const SOURCE_CHANGED_MESSAGE: &str = "上传期间本地文件已被修改";
+
+/// 本地文件在某一时刻的状态。
+pub(crate) struct SourceSnapshot {
+    size: u64,
+    modified_at: Option<i64>,
+    fingerprint: Option<String>,
+}
+
+impl SourceSnapshot {
+    pub(crate) fn capture(local_path: &str) -> Result<Self, String> {
+        let meta = std::fs::metadata(local_path).map_err(|_| "local file not found".to_string())?;
+        let modified_at = meta
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as i64);
+        Ok(Self {
+            size: meta.len(),
+            modified_at,
+            fingerprint: sample_local_file(Path::new(local_path), SAMPLE_BYTES).ok(),
+        })
+    }
+
+    /// 以该快照作为任务新的比对基准。
+    pub(crate) fn apply_to(self, task: &mut UploadTask) {
+        task.size = Some(self.size);
+        task.source_modified_at = self.modified_at;
+        task.source_fingerprint = self.fingerprint;
+    }
+}
+
+/// 与任务记录的快照比对；旧任务缺少的字段不参与比较。
+pub(crate) fn source_changed(task: &UploadTask) -> Result<bool, String> {
+    let current = SourceSnapshot::capture(&task.local_path)?;
+    if task.size.is_some_and(|size| size != current.size) {
+        return Ok(true);
+    }
+    if let (Some(recorded), Some(now)) = (task.source_modified_at, current.modified_at) {
+        if recorded != now {
+            return Ok(true);
+        }
+    }
+    if let (Some(recorded), Some(now)) = (&task.source_fingerprint, &current.fingerprint) {
+        if recorded != now {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
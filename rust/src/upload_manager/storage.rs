@@ -1,7 +1,11 @@
-use crate::api::drive::models::{UploadStatus, UploadTask};
+use crate::api::drive::models::{
+    TransferFailureReason, TransferPriority, UploadConflictBehavior, UploadGroup,
+    UploadGroupStatus, UploadStatus, UploadTask,
+};
 use crate::db::{
-    clear_finished_upload_tasks, delete_upload_task, load_upload_tasks, upsert_upload_task,
-    UploadTaskRecord,
+    clear_finished_upload_groups, clear_finished_upload_tasks, delete_upload_group,
+    delete_upload_task, load_upload_groups, load_upload_tasks, upsert_upload_group,
+    upsert_upload_task, UploadGroupRecord, UploadTaskRecord,
 };
 
 /// 上传队列持久化接口，方便未来替换存储实现或编写单测。
@@ -10,6 +14,9 @@ pub trait UploadStore: Send + Sync {
     fn upsert(&self, task: &UploadTask);
     fn remove(&self, task_id: &str);
     fn clear_history(&self);
+    fn load_groups(&self) -> Vec<UploadGroup>;
+    fn upsert_group(&self, group: &UploadGroup);
+    fn remove_group(&self, group_id: &str);
 }
 
 /// 默认的 SQLite 实现。
@@ -51,9 +58,41 @@ impl UploadStore for SqliteUploadStore {
     }
 
     fn clear_history(&self) {
-        if let Err(err) = clear_finished_upload_tasks(UploadStatus::InProgress as i64) {
+        let active_statuses = [
+            status_to_i64(&UploadStatus::InProgress),
+            status_to_i64(&UploadStatus::Queued),
+        ];
+        if let Err(err) = clear_finished_upload_tasks(&active_statuses) {
             eprintln!("[upload-store] failed to clear upload history: {err}");
         }
+        let active_group_statuses = [
+            group_status_to_i64(UploadGroupStatus::Preparing),
+            group_status_to_i64(UploadGroupStatus::Uploading),
+        ];
+        if let Err(err) = clear_finished_upload_groups(&active_group_statuses) {
+            eprintln!("[upload-store] failed to clear upload groups: {err}");
+        }
+    }
+
+    fn load_groups(&self) -> Vec<UploadGroup> {
+        load_upload_groups()
+            .map(|records| records.into_iter().map(group_from_record).collect())
+            .unwrap_or_default()
+    }
+
+    fn upsert_group(&self, group: &UploadGroup) {
+        if let Err(err) = upsert_upload_group(&record_from_group(group)) {
+            eprintln!(
+                "[upload-store] failed to upsert group {}: {err}",
+                group.group_id
+            );
+        }
+    }
+
+    fn remove_group(&self, group_id: &str) {
+        if let Err(err) = delete_upload_group(group_id) {
+            eprintln!("[upload-store] failed to delete group {group_id}: {err}");
+        }
     }
 }
 
@@ -73,6 +112,17 @@ fn record_from_task(task: &UploadTask) -> UploadTaskRecord {
         error_message: task.error_message.clone(),
         session_url: task.session_url.clone(),
         updated_at_millis: crate::db::current_timestamp_millis(),
+        retry_count: Some(task.retry_count as i64),
+        priority: Some(priority_to_i64(task.priority)),
+        failure_reason: task.failure_reason.map(failure_reason_to_i64),
+        group_id: task.group_id.clone(),
+        conflict_behavior: Some(conflict_behavior_to_i64(task.conflict_behavior)),
+        remote_name: task.remote_name.clone(),
+        skipped_identical: Some(task.skipped_identical as i64),
+        session_expires_at: task.session_expires_at,
+        source_modified_at: task.source_modified_at,
+        source_fingerprint: task.source_fingerprint.clone(),
+        source_url: task.source_url.clone(),
     }
 }
 
@@ -99,6 +149,59 @@ fn task_from_record(record: UploadTaskRecord) -> UploadTask {
         }),
         error_message: record.error_message,
         session_url: record.session_url,
+        session_expires_at: record.session_expires_at,
+        retry_count: record
+            .retry_count
+            .and_then(|v| v.try_into().ok())
+            .unwrap_or(0),
+        priority: record.priority.map(priority_from_i64).unwrap_or_default(),
+        failure_reason: record.failure_reason.and_then(failure_reason_from_i64),
+        group_id: record.group_id,
+        // 旧记录未保存冲突策略，重启恢复时一直按重命名处理。
+        conflict_behavior: record
+            .conflict_behavior
+            .map(conflict_behavior_from_i64)
+            .unwrap_or(UploadConflictBehavior::Rename),
+        remote_name: record.remote_name,
+        skipped_identical: record.skipped_identical.unwrap_or(0) != 0,
+        source_modified_at: record.source_modified_at,
+        source_fingerprint: record.source_fingerprint,
+        source_url: record.source_url,
+    }
+}
+
+/// 完成/失败数量与已上传字节由子任务汇总，读取时置零，由管理器重新计算。
+fn record_from_group(group: &UploadGroup) -> UploadGroupRecord {
+    UploadGroupRecord {
+        group_id: group.group_id.clone(),
+        local_root: group.local_root.clone(),
+        folder_name: group.folder_name.clone(),
+        parent_id: group.parent_id.clone(),
+        status: group_status_to_i64(group.status),
+        created_at: group.created_at,
+        completed_at: group.completed_at,
+        error_message: group.error_message.clone(),
+        total_files: group.total_files as i64,
+        total_bytes: group.total_bytes.try_into().unwrap_or(i64::MAX),
+        updated_at_millis: crate::db::current_timestamp_millis(),
+    }
+}
+
+fn group_from_record(record: UploadGroupRecord) -> UploadGroup {
+    UploadGroup {
+        group_id: record.group_id,
+        local_root: record.local_root,
+        folder_name: record.folder_name,
+        parent_id: record.parent_id,
+        status: group_status_from_i64(record.status),
+        created_at: record.created_at,
+        completed_at: record.completed_at,
+        error_message: record.error_message,
+        total_files: record.total_files.try_into().unwrap_or(0),
+        completed_files: 0,
+        failed_files: 0,
+        total_bytes: record.total_bytes.try_into().unwrap_or(0),
+        uploaded_bytes: 0,
     }
 }
 
@@ -108,6 +211,7 @@ fn status_to_i64(status: &UploadStatus) -> i64 {
         UploadStatus::Completed => 1,
         UploadStatus::Failed => 2,
         UploadStatus::Cancelled => 3,
+        UploadStatus::Queued => 4,
     }
 }
 
@@ -116,6 +220,88 @@ fn status_from_i64(value: i64) -> UploadStatus {
         1 => UploadStatus::Completed,
         2 => UploadStatus::Failed,
         3 => UploadStatus::Cancelled,
+        4 => UploadStatus::Queued,
         _ => UploadStatus::InProgress,
     }
 }
+
+fn group_status_to_i64(status: UploadGroupStatus) -> i64 {
+    match status {
+        UploadGroupStatus::Preparing => 0,
+        UploadGroupStatus::Uploading => 1,
+        UploadGroupStatus::Completed => 2,
+        UploadGroupStatus::PartiallyFailed => 3,
+        UploadGroupStatus::Failed => 4,
+        UploadGroupStatus::Cancelled => 5,
+    }
+}
+
+fn group_status_from_i64(value: i64) -> UploadGroupStatus {
+    match value {
+        0 => UploadGroupStatus::Preparing,
+        2 => UploadGroupStatus::Completed,
+        3 => UploadGroupStatus::PartiallyFailed,
+        4 => UploadGroupStatus::Failed,
+        5 => UploadGroupStatus::Cancelled,
+        _ => UploadGroupStatus::Uploading,
+    }
+}
+
+fn priority_to_i64(priority: TransferPriority) -> i64 {
+    match priority {
+        TransferPriority::Low => 0,
+        TransferPriority::Normal => 1,
+        TransferPriority::High => 2,
+    }
+}
+
+fn priority_from_i64(value: i64) -> TransferPriority {
+    match value {
+        0 => TransferPriority::Low,
+        2 => TransferPriority::High,
+        _ => TransferPriority::Normal,
+    }
+}
+
+fn conflict_behavior_to_i64(behavior: UploadConflictBehavior) -> i64 {
+    match behavior {
+        UploadConflictBehavior::Fail => 0,
+        UploadConflictBehavior::Replace => 1,
+        UploadConflictBehavior::Rename => 2,
+        UploadConflictBehavior::SkipIfIdentical => 3,
+    }
+}
+
+fn conflict_behavior_from_i64(value: i64) -> UploadConflictBehavior {
+    match value {
+        0 => UploadConflictBehavior::Fail,
+        1 => UploadConflictBehavior::Replace,
+        3 => UploadConflictBehavior::SkipIfIdentical,
+        _ => UploadConflictBehavior::Rename,
+    }
+}
+
+fn failure_reason_to_i64(reason: TransferFailureReason) -> i64 {
+    match reason {
+        TransferFailureReason::InsufficientDiskSpace => 0,
+        TransferFailureReason::InsufficientQuota => 1,
+        TransferFailureReason::IntegrityMismatch => 2,
+        TransferFailureReason::SourceChanged => 3,
+        TransferFailureReason::AccessDenied => 4,
+        TransferFailureReason::NotFound => 5,
+        TransferFailureReason::RetriesExhausted => 6,
+    }
+}
+
+fn failure_reason_from_i64(value: i64) -> Option<TransferFailureReason> {
+    match value {
+        0 => Some(TransferFailureReason::InsufficientDiskSpace),
+        1 => Some(TransferFailureReason::InsufficientQuota),
+        2 => Some(TransferFailureReason::IntegrityMismatch),
+        3 => Some(TransferFailureReason::SourceChanged),
+        4 => Some(TransferFailureReason::AccessDenied),
+        5 => Some(TransferFailureReason::NotFound),
+        6 => Some(TransferFailureReason::RetriesExhausted),
+        _ => None,
+    }
+}
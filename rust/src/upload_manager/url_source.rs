@@ -0,0 +1,21 @@
+// 网络地址上传的辅助逻辑：校验来源地址与推断文件名。
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+/// 只接受 http/https 地址，返回规范化后的地址。
+pub(crate) fn parse_source_url(raw: &str) -> Result<String, String> {
+    let url = Url::parse(raw.trim()).map_err(|_| "无效的网络地址".to_string())?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("只支持 http/https 地址".to_string());
+    }
+    Ok(url.to_string())
+}
+
+/// 以地址路径的最后一段（解码后）作为默认文件名。
+pub(crate) fn file_name_from_url(source_url: &str) -> Option<String> {
+    let url = Url::parse(source_url).ok()?;
+    let segment = url.path_segments()?.rev().find(|s| !s.is_empty())?;
+    let name = percent_decode_str(segment).decode_utf8().ok()?;
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}